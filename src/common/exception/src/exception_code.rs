@@ -156,6 +156,8 @@ build_exceptions! {
     ParquetFileInvalid(1201),
     /// InvalidUtf8String is used when given string is not a valid utf8 string.
     InvalidUtf8String(1202),
+    /// OrcFileInvalid is used when given orc file is invalid.
+    OrcFileInvalid(1203),
 
     // Table related errors starts here.
 
@@ -199,7 +201,13 @@ build_exceptions! {
     IllegalCloudControlMessageFormat(1703),
 
     // Geometry errors.
-    GeometryError(1801)
+    GeometryError(1801),
+
+    // Transaction errors.
+    /// A statement failed in the explicit transaction, which has to be ended by ROLLBACK.
+    CurrentTransactionIsAborted(1901),
+    /// The statement can't be executed in an explicit transaction.
+    UnsupportedInTransaction(1902)
 }
 
 // Meta service errors [2001, 3000].
@@ -290,6 +298,11 @@ build_exceptions! {
     IllegalConnection(2511),
     ConnectionAlreadyExists(2512),
 
+    // Pipe error codes.
+    UnknownPipe(2513),
+    IllegalPipe(2514),
+    PipeAlreadyExists(2515),
+
     // User defined function error codes.
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
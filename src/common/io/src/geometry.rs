@@ -15,10 +15,15 @@
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use geo::Geometry;
+use geozero::wkb::Ewkb;
 use geozero::CoordDimensions;
+use geozero::ToGeo;
 use geozero::ToWkb;
 use wkt::TryFromWkt;
 
+/// The flag in the geometry type of EWKB, indicates that there is a SRID after the type.
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
 pub fn parse_to_ewkb(buf: &[u8], srid: Option<i32>) -> Result<Vec<u8>> {
     let wkt = std::str::from_utf8(buf).map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
     let input_wkt = wkt.trim().to_ascii_uppercase();
@@ -38,6 +43,37 @@ pub fn parse_to_ewkb(buf: &[u8], srid: Option<i32>) -> Result<Vec<u8>> {
     let geom: Geometry<f64> = Geometry::try_from_wkt_str(geo_part)
         .map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
 
-    geom.to_ewkb(CoordDimensions::xy(), parsed_srid)
+    geo_to_ewkb(geom, parsed_srid)
+}
+
+/// Read the SRID in the header of an EWKB, returns `None` if there is no SRID.
+pub fn read_ewkb_srid(buf: &[u8]) -> Option<i32> {
+    if buf.len() < 9 {
+        return None;
+    }
+    let little_endian = buf[0] == 1;
+    let read_u32 = |bytes: [u8; 4]| {
+        if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        }
+    };
+    let geom_type = read_u32(buf[1..5].try_into().unwrap());
+    if geom_type & EWKB_SRID_FLAG == 0 {
+        return None;
+    }
+    Some(read_u32(buf[5..9].try_into().unwrap()) as i32)
+}
+
+/// Decode an EWKB to a geometry and its SRID.
+pub fn ewkb_to_geo(buf: &[u8]) -> Result<(Geometry<f64>, Option<i32>)> {
+    let geom = Ewkb(buf).to_geo().map_err(ErrorCode::from)?;
+    Ok((geom, read_ewkb_srid(buf)))
+}
+
+/// Encode a geometry to EWKB, with the SRID if any.
+pub fn geo_to_ewkb(geom: Geometry<f64>, srid: Option<i32>) -> Result<Vec<u8>> {
+    geom.to_ewkb(CoordDimensions::xy(), srid)
         .map_err(ErrorCode::from)
 }
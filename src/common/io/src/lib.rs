@@ -47,4 +47,7 @@ pub use decimal::display_decimal_128;
 pub use decimal::display_decimal_256;
 pub use escape::escape_string;
 pub use escape::escape_string_with_quote;
+pub use geometry::ewkb_to_geo;
+pub use geometry::geo_to_ewkb;
 pub use geometry::parse_to_ewkb;
+pub use geometry::read_ewkb_srid;
@@ -58,6 +58,10 @@ pub mod parquet_rs;
 pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
+pub mod orc;
+pub use orc::read_orc_metadata;
+pub use orc::read_orc_schema;
+
 mod stage;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
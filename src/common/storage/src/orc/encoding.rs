@@ -0,0 +1,470 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decompression and run length decoders of ORC streams.
+//!
+//! See <https://orc.apache.org/specification/ORCv1/> for the details of the encodings.
+
+use std::io::Read;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::orc::proto::CompressionKind;
+
+fn invalid(msg: impl ToString) -> ErrorCode {
+    ErrorCode::OrcFileInvalid(msg.to_string())
+}
+
+/// Decompress a stream which is split into chunks, each chunk has a 3 bytes header
+/// `(chunk_length << 1) | is_original`.
+pub fn decompress(kind: CompressionKind, block_size: u64, data: &[u8]) -> Result<Vec<u8>> {
+    if kind == CompressionKind::None {
+        return Ok(data.to_vec());
+    }
+
+    let mut output = Vec::with_capacity(data.len() * 2);
+    let mut pos = 0;
+    while pos < data.len() {
+        if pos + 3 > data.len() {
+            return Err(invalid("truncated compression chunk header"));
+        }
+        let header =
+            data[pos] as usize | (data[pos + 1] as usize) << 8 | (data[pos + 2] as usize) << 16;
+        pos += 3;
+        let is_original = header & 1 == 1;
+        let length = header >> 1;
+        if pos + length > data.len() {
+            return Err(invalid("truncated compression chunk"));
+        }
+        let chunk = &data[pos..pos + length];
+        pos += length;
+
+        if is_original {
+            output.extend_from_slice(chunk);
+            continue;
+        }
+        match kind {
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut output)
+                    .map_err(|e| invalid(format!("zlib decompress failed: {e}")))?;
+            }
+            CompressionKind::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(|e| invalid(format!("snappy decompress failed: {e}")))?;
+                output.extend_from_slice(&decompressed);
+            }
+            CompressionKind::Lz4 => {
+                let decompressed = lz4::block::decompress(chunk, Some(block_size as i32))
+                    .map_err(|e| invalid(format!("lz4 decompress failed: {e}")))?;
+                output.extend_from_slice(&decompressed);
+            }
+            CompressionKind::Zstd => {
+                let decompressed = zstd::stream::decode_all(chunk)
+                    .map_err(|e| invalid(format!("zstd decompress failed: {e}")))?;
+                output.extend_from_slice(&decompressed);
+            }
+            CompressionKind::None | CompressionKind::Lzo => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "compression {:?} of orc file is not supported",
+                    kind
+                )));
+            }
+        }
+    }
+    Ok(output)
+}
+
+pub struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8> {
+        let b = *self
+            .data
+            .get(self.pos)
+            .ok_or_else(|| invalid("unexpected end of stream"))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.pos + n > self.data.len() {
+            return Err(invalid("unexpected end of stream"));
+        }
+        let bytes = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    /// Read a big endian unsigned integer of `n` bytes.
+    fn read_be(&mut self, n: usize) -> Result<u64> {
+        let mut v = 0u64;
+        for b in self.read_bytes(n)? {
+            v = (v << 8) | *b as u64;
+        }
+        Ok(v)
+    }
+
+    pub fn read_varint(&mut self) -> Result<u64> {
+        let mut result = 0u64;
+        let mut shift = 0;
+        loop {
+            let b = self.read_u8()?;
+            if shift < 64 {
+                result |= ((b & 0x7f) as u64) << shift;
+            }
+            if b & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    pub fn read_signed_varint(&mut self) -> Result<i64> {
+        Ok(zigzag_decode(self.read_varint()?))
+    }
+
+    /// Read an unbounded base 128 varint in zigzag encoding, which is used by decimals.
+    pub fn read_signed_varint128(&mut self) -> Result<i128> {
+        let mut result = 0u128;
+        let mut shift = 0;
+        loop {
+            let b = self.read_u8()?;
+            if shift < 128 {
+                result |= ((b & 0x7f) as u128) << shift;
+            }
+            if b & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        Ok((result >> 1) as i128 ^ -((result & 1) as i128))
+    }
+
+    /// Read `count` big endian bit packed values of `width` bits, the remaining bits of
+    /// the last byte are discarded.
+    fn read_bit_packed(&mut self, width: usize, count: usize, output: &mut Vec<u64>) -> Result<()> {
+        let mut current = 0u64;
+        let mut bits_left = 0;
+        for _ in 0..count {
+            let mut value = 0u64;
+            let mut need = width;
+            while need > 0 {
+                if bits_left == 0 {
+                    current = self.read_u8()? as u64;
+                    bits_left = 8;
+                }
+                let take = need.min(bits_left);
+                let bits = (current >> (bits_left - take)) & ((1 << take) - 1);
+                value = (value << take) | bits;
+                bits_left -= take;
+                need -= take;
+            }
+            output.push(value);
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn zigzag_decode(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+/// Decode byte RLE: a control byte `0..=127` is followed by a byte repeated `control + 3`
+/// times, a negative control byte is followed by `-control` literal bytes.
+pub fn decode_byte_rle(data: &[u8], count: usize) -> Result<Vec<u8>> {
+    let mut reader = ByteReader::new(data);
+    let mut output = Vec::with_capacity(count);
+    while output.len() < count {
+        let control = reader.read_u8()? as i8;
+        if control >= 0 {
+            let length = control as usize + 3;
+            let value = reader.read_u8()?;
+            output.extend(std::iter::repeat(value).take(length));
+        } else {
+            let length = -(control as i32) as usize;
+            output.extend_from_slice(reader.read_bytes(length)?);
+        }
+    }
+    output.truncate(count);
+    Ok(output)
+}
+
+/// Booleans are packed into bytes (most significant bit first) and then byte RLE encoded.
+pub fn decode_boolean_rle(data: &[u8], count: usize) -> Result<Vec<bool>> {
+    let bytes = decode_byte_rle(data, (count + 7) / 8)?;
+    Ok((0..count)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+/// Decode integer RLE, `v2` selects the encoding version, which depends on the column encoding.
+pub fn decode_int_rle(data: &[u8], count: usize, signed: bool, v2: bool) -> Result<Vec<i64>> {
+    let mut reader = ByteReader::new(data);
+    let mut output = Vec::with_capacity(count);
+    while output.len() < count {
+        if v2 {
+            decode_int_rle_v2_run(&mut reader, signed, &mut output)?;
+        } else {
+            decode_int_rle_v1_run(&mut reader, signed, &mut output)?;
+        }
+    }
+    output.truncate(count);
+    Ok(output)
+}
+
+fn read_int(reader: &mut ByteReader, signed: bool) -> Result<i64> {
+    if signed {
+        reader.read_signed_varint()
+    } else {
+        Ok(reader.read_varint()? as i64)
+    }
+}
+
+fn decode_int_rle_v1_run(
+    reader: &mut ByteReader,
+    signed: bool,
+    output: &mut Vec<i64>,
+) -> Result<()> {
+    let control = reader.read_u8()? as i8;
+    if control >= 0 {
+        let length = control as usize + 3;
+        let delta = reader.read_u8()? as i8 as i64;
+        let base = read_int(reader, signed)?;
+        output.extend((0..length as i64).map(|i| base.wrapping_add(i * delta)));
+    } else {
+        for _ in 0..-(control as i32) {
+            output.push(read_int(reader, signed)?);
+        }
+    }
+    Ok(())
+}
+
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn mask(width: usize) -> u64 {
+    if width >= 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+fn decode_int_rle_v2_run(
+    reader: &mut ByteReader,
+    signed: bool,
+    output: &mut Vec<i64>,
+) -> Result<()> {
+    let first = reader.read_u8()?;
+    let decode = |v: u64| {
+        if signed { zigzag_decode(v) } else { v as i64 }
+    };
+    match first >> 6 {
+        // short repeat
+        0 => {
+            let width = ((first >> 3) & 0x07) as usize + 1;
+            let length = (first & 0x07) as usize + 3;
+            let value = decode(reader.read_be(width)?);
+            output.extend(std::iter::repeat(value).take(length));
+        }
+        // direct
+        1 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let length = (((first & 1) as usize) << 8 | reader.read_u8()? as usize) + 1;
+            let mut values = Vec::with_capacity(length);
+            reader.read_bit_packed(width, length, &mut values)?;
+            output.extend(values.into_iter().map(decode));
+        }
+        // patched base
+        2 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let length = (((first & 1) as usize) << 8 | reader.read_u8()? as usize) + 1;
+            let third = reader.read_u8()?;
+            let base_width = ((third >> 5) & 0x07) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1f);
+            let fourth = reader.read_u8()?;
+            let patch_gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+            let patch_list_length = (fourth & 0x1f) as usize;
+
+            // the base value is stored in sign-magnitude format
+            let base = reader.read_be(base_width)?;
+            let sign_mask = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(length);
+            reader.read_bit_packed(width, length, &mut values)?;
+            let mut patches = Vec::with_capacity(patch_list_length);
+            reader.read_bit_packed(
+                closest_fixed_bits(patch_width + patch_gap_width),
+                patch_list_length,
+                &mut patches,
+            )?;
+
+            let mut index = 0;
+            for patch in patches {
+                let gap = (patch >> patch_width) as usize;
+                let patch = patch & mask(patch_width);
+                index += gap;
+                // a gap larger than 255 is split into entries with gap 255 and patch 0
+                if gap == 255 && patch == 0 {
+                    continue;
+                }
+                let value = values
+                    .get_mut(index)
+                    .ok_or_else(|| invalid("patch index out of range"))?;
+                *value |= patch << width;
+            }
+            output.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        }
+        // delta
+        _ => {
+            let code = (first >> 1) & 0x1f;
+            let width = if code == 0 { 0 } else { decode_bit_width(code) };
+            let length = (((first & 1) as usize) << 8 | reader.read_u8()? as usize) + 1;
+            let base = read_int(reader, signed)?;
+            let delta_base = reader.read_signed_varint()?;
+
+            output.push(base);
+            if length == 1 {
+                return Ok(());
+            }
+            let mut prev = base.wrapping_add(delta_base);
+            output.push(prev);
+            if width == 0 {
+                // fixed delta
+                for _ in 2..length {
+                    prev = prev.wrapping_add(delta_base);
+                    output.push(prev);
+                }
+            } else {
+                let mut deltas = Vec::with_capacity(length - 2);
+                reader.read_bit_packed(width, length - 2, &mut deltas)?;
+                for delta in deltas {
+                    prev = if delta_base < 0 {
+                        prev.wrapping_sub(delta as i64)
+                    } else {
+                        prev.wrapping_add(delta as i64)
+                    };
+                    output.push(prev);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_rle() {
+        let data = [0x61, 0x00, 0xfe, 0x44, 0x45];
+        let values = decode_byte_rle(&data, 102).unwrap();
+        assert_eq!(values.len(), 102);
+        assert!(values[..100].iter().all(|v| *v == 0));
+        assert_eq!(&values[100..], &[0x44, 0x45]);
+    }
+
+    #[test]
+    fn test_boolean_rle() {
+        let data = [0xff, 0x80];
+        let values = decode_boolean_rle(&data, 3).unwrap();
+        assert_eq!(values, vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_int_rle_v1() {
+        // run of 100 values starting from 7 with delta -1
+        let data = [0x61, 0xff, 0x0e];
+        let values = decode_int_rle(&data, 100, true, false).unwrap();
+        assert_eq!(values[0], 7);
+        assert_eq!(values[99], -92);
+
+        // literals: 2, 3, 6, 7, 11
+        let data = [0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b];
+        let values = decode_int_rle(&data, 5, false, false).unwrap();
+        assert_eq!(values, vec![2, 3, 6, 7, 11]);
+    }
+
+    #[test]
+    fn test_int_rle_v2() {
+        // short repeat: 10000 x 5
+        let data = [0x0a, 0x27, 0x10];
+        let values = decode_int_rle(&data, 5, false, true).unwrap();
+        assert_eq!(values, vec![10000; 5]);
+
+        // direct: 23713, 43806, 57005, 48879
+        let data = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        let values = decode_int_rle(&data, 4, false, true).unwrap();
+        assert_eq!(values, vec![23713, 43806, 57005, 48879]);
+
+        // patched base
+        let data = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        let values = decode_int_rle(&data, 20, false, true).unwrap();
+        assert_eq!(values, vec![
+            2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120, 2130,
+            2140, 2150, 2160, 2170, 2180, 2190
+        ]);
+
+        // delta: 2, 3, 5, 7, 11, 13, 17, 19, 23, 29
+        let data = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        let values = decode_int_rle(&data, 10, false, true).unwrap();
+        assert_eq!(values, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A native reader of ORC files.
+//!
+//! Only the top level columns of primitive types are supported, they are decoded stripe
+//! by stripe into `Column`s. The statistics of stripes can be used to prune stripes
+//! before reading them.
+
+mod encoding;
+pub mod proto;
+mod reader;
+mod statistics;
+
+pub use reader::read_orc_metadata;
+pub use reader::read_orc_schema;
+pub use reader::OrcFileMeta;
+pub use reader::OrcStripe;
+pub use statistics::OrcColumnStatistics;
@@ -0,0 +1,810 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::Decimal128Type;
+use databend_common_expression::types::decimal::Decimal256Type;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::Int16Type;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::Int8Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt16Type;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::UInt8Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::Column;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use opendal::Operator;
+use prost::Message;
+
+use crate::orc::encoding::decode_boolean_rle;
+use crate::orc::encoding::decode_byte_rle;
+use crate::orc::encoding::decode_int_rle;
+use crate::orc::encoding::decompress;
+use crate::orc::encoding::ByteReader;
+use crate::orc::proto::ColumnEncodingKind;
+use crate::orc::proto::CompressionKind;
+use crate::orc::proto::Footer;
+use crate::orc::proto::Metadata;
+use crate::orc::proto::PostScript;
+use crate::orc::proto::StreamKind;
+use crate::orc::proto::StripeFooter;
+use crate::orc::proto::StripeInformation;
+use crate::orc::proto::Type;
+use crate::orc::proto::TypeKind;
+
+const ORC_MAGIC: &str = "ORC";
+// The tail of most files fits in the first read.
+const DEFAULT_TAIL_READ_SIZE: u64 = 16 * 1024;
+const DEFAULT_COMPRESSION_BLOCK_SIZE: u64 = 256 * 1024;
+// Seconds of 2015-01-01 00:00:00 UTC, the base of the seconds of ORC timestamps.
+const ORC_TIMESTAMP_BASE_SECONDS: i64 = 1420070400;
+
+type I256 = <Decimal256Type as ValueType>::Scalar;
+
+fn invalid(msg: impl ToString) -> ErrorCode {
+    ErrorCode::OrcFileInvalid(msg.to_string())
+}
+
+/// The metadata in the tail of an ORC file.
+#[derive(Clone, Debug)]
+pub struct OrcFileMeta {
+    pub compression: CompressionKind,
+    pub compression_block_size: u64,
+    pub footer: Footer,
+    /// The statistics of every stripe, may be empty if the writer did not write them.
+    pub metadata: Metadata,
+}
+
+/// Read the metadata of an ORC file, `file_size` is fetched from the storage if not given.
+#[async_backtrace::framed]
+pub async fn read_orc_metadata(
+    operator: &Operator,
+    path: &str,
+    file_size: Option<u64>,
+) -> Result<OrcFileMeta> {
+    let file_size = match file_size {
+        Some(size) => size,
+        None => operator.stat(path).await?.content_length(),
+    };
+    if file_size < ORC_MAGIC.len() as u64 + 1 {
+        return Err(invalid(format!("{path} is too small to be an orc file")));
+    }
+
+    let read_size = file_size.min(DEFAULT_TAIL_READ_SIZE);
+    let mut tail = operator
+        .read_with(path)
+        .range(file_size - read_size..file_size)
+        .await?;
+    let tail_size = OrcFileMeta::tail_size(&tail)? as u64;
+    if tail_size > file_size {
+        return Err(invalid(format!("the tail of orc file {path} is broken")));
+    }
+    if tail_size > read_size {
+        tail = operator
+            .read_with(path)
+            .range(file_size - tail_size..file_size)
+            .await?;
+    }
+    OrcFileMeta::try_from_tail(&tail).map_err(|e| e.add_message(format!("file: {path}")))
+}
+
+/// Read the schema of an ORC file as a `TableSchema`.
+#[async_backtrace::framed]
+pub async fn read_orc_schema(operator: &Operator, path: &str) -> Result<TableSchema> {
+    read_orc_metadata(operator, path, None)
+        .await?
+        .table_schema()
+}
+
+impl OrcFileMeta {
+    fn read_post_script(tail: &[u8]) -> Result<(PostScript, usize)> {
+        let ps_len = *tail.last().ok_or_else(|| invalid("empty orc file"))? as usize;
+        if tail.len() < ps_len + 1 {
+            return Err(invalid("the post script of orc file is truncated"));
+        }
+        let ps = PostScript::decode(&tail[tail.len() - 1 - ps_len..tail.len() - 1])
+            .map_err(|e| invalid(format!("invalid post script: {e}")))?;
+        if ps.magic.as_ref().is_some_and(|magic| magic != ORC_MAGIC) {
+            return Err(invalid("not an orc file"));
+        }
+        Ok((ps, ps_len))
+    }
+
+    /// The size of post script, footer and metadata at the end of the file.
+    fn tail_size(tail: &[u8]) -> Result<usize> {
+        let (ps, ps_len) = Self::read_post_script(tail)?;
+        Ok(1 + ps_len + ps.footer_length() as usize + ps.metadata_length() as usize)
+    }
+
+    /// Parse the metadata from the bytes at the end of the file, which may be the whole file.
+    pub fn try_from_tail(tail: &[u8]) -> Result<Self> {
+        let (ps, ps_len) = Self::read_post_script(tail)?;
+        let compression = ps.compression();
+        let compression_block_size = match ps.compression_block_size() {
+            0 => DEFAULT_COMPRESSION_BLOCK_SIZE,
+            size => size,
+        };
+
+        let footer_end = tail.len() - 1 - ps_len;
+        let footer_len = ps.footer_length() as usize;
+        let metadata_len = ps.metadata_length() as usize;
+        if footer_end < footer_len + metadata_len {
+            return Err(invalid("the footer of orc file is truncated"));
+        }
+        let footer_start = footer_end - footer_len;
+        let footer = decompress(
+            compression,
+            compression_block_size,
+            &tail[footer_start..footer_end],
+        )?;
+        let footer = Footer::decode(footer.as_slice())
+            .map_err(|e| invalid(format!("invalid footer: {e}")))?;
+
+        let metadata = if metadata_len > 0 {
+            let metadata = decompress(
+                compression,
+                compression_block_size,
+                &tail[footer_start - metadata_len..footer_start],
+            )?;
+            Metadata::decode(metadata.as_slice())
+                .map_err(|e| invalid(format!("invalid metadata: {e}")))?
+        } else {
+            Metadata::default()
+        };
+
+        if footer.types.first().map(|t| t.kind()) != Some(TypeKind::Struct) {
+            return Err(invalid("the root type of orc file must be a struct"));
+        }
+
+        Ok(OrcFileMeta {
+            compression,
+            compression_block_size,
+            footer,
+            metadata,
+        })
+    }
+
+    pub fn num_rows(&self) -> u64 {
+        self.footer.number_of_rows()
+    }
+
+    pub fn stripes(&self) -> &[StripeInformation] {
+        &self.footer.stripes
+    }
+
+    /// The top level columns and their column ids.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, u32)> {
+        let root = &self.footer.types[0];
+        root.field_names
+            .iter()
+            .map(|name| name.as_str())
+            .zip(root.subtypes.iter().copied())
+    }
+
+    /// Find the column id of a top level column.
+    pub fn column_id(&self, name: &str, case_sensitive: bool) -> Option<u32> {
+        self.fields()
+            .find(|(field_name, _)| {
+                if case_sensitive {
+                    *field_name == name
+                } else {
+                    field_name.eq_ignore_ascii_case(name)
+                }
+            })
+            .map(|(_, id)| id)
+    }
+
+    fn column_type(&self, column_id: u32) -> Result<&Type> {
+        self.footer
+            .types
+            .get(column_id as usize)
+            .ok_or_else(|| invalid(format!("column id {column_id} is out of range")))
+    }
+
+    /// Map the top level columns to a `TableSchema`, all the columns are nullable.
+    pub fn table_schema(&self) -> Result<TableSchema> {
+        let fields = self
+            .fields()
+            .map(|(name, id)| {
+                let data_type = orc_type_to_table_type(self.column_type(id)?)?;
+                Ok(TableField::new(name, data_type.wrap_nullable()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TableSchema::new(fields))
+    }
+}
+
+fn orc_type_to_table_type(ty: &Type) -> Result<TableDataType> {
+    let data_type = match ty.kind() {
+        TypeKind::Boolean => TableDataType::Boolean,
+        TypeKind::Byte => TableDataType::Number(NumberDataType::Int8),
+        TypeKind::Short => TableDataType::Number(NumberDataType::Int16),
+        TypeKind::Int => TableDataType::Number(NumberDataType::Int32),
+        TypeKind::Long => TableDataType::Number(NumberDataType::Int64),
+        TypeKind::Float => TableDataType::Number(NumberDataType::Float32),
+        TypeKind::Double => TableDataType::Number(NumberDataType::Float64),
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => TableDataType::String,
+        TypeKind::Binary => TableDataType::Binary,
+        TypeKind::Date => TableDataType::Date,
+        TypeKind::Timestamp | TypeKind::TimestampInstant => TableDataType::Timestamp,
+        TypeKind::Decimal => {
+            // files written by hive 0.11 have no precision and scale
+            let size = match ty.precision() {
+                0 => DecimalSize {
+                    precision: 38,
+                    scale: 10,
+                },
+                precision => DecimalSize {
+                    precision: precision as u8,
+                    scale: ty.scale() as u8,
+                },
+            };
+            TableDataType::Decimal(DecimalDataType::from_size(size)?)
+        }
+        kind => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "orc type {:?} is not supported yet",
+                kind
+            )));
+        }
+    };
+    Ok(data_type)
+}
+
+/// The streams of a stripe needed to decode some columns.
+pub struct OrcStripe {
+    compression: CompressionKind,
+    compression_block_size: u64,
+    num_rows: usize,
+    footer: StripeFooter,
+    streams: HashMap<(u32, StreamKind), Range<u64>>,
+    // The bytes of the file in `data_range`.
+    data: Vec<u8>,
+    data_range: Range<u64>,
+}
+
+fn is_data_stream(kind: StreamKind) -> bool {
+    matches!(
+        kind,
+        StreamKind::Present
+            | StreamKind::Data
+            | StreamKind::Length
+            | StreamKind::DictionaryData
+            | StreamKind::Secondary
+    )
+}
+
+impl OrcStripe {
+    fn stripe_info(meta: &OrcFileMeta, stripe: usize) -> Result<&StripeInformation> {
+        meta.footer
+            .stripes
+            .get(stripe)
+            .ok_or_else(|| invalid(format!("stripe {stripe} is out of range")))
+    }
+
+    fn footer_range(info: &StripeInformation) -> Range<u64> {
+        let start = info.offset() + info.index_length() + info.data_length();
+        start..start + info.footer_length()
+    }
+
+    fn decode_footer(meta: &OrcFileMeta, data: &[u8]) -> Result<StripeFooter> {
+        let footer = decompress(meta.compression, meta.compression_block_size, data)?;
+        StripeFooter::decode(footer.as_slice())
+            .map_err(|e| invalid(format!("invalid stripe footer: {e}")))
+    }
+
+    // The streams are stored one after another from the start of the stripe.
+    fn stream_ranges(
+        info: &StripeInformation,
+        footer: &StripeFooter,
+    ) -> HashMap<(u32, StreamKind), Range<u64>> {
+        let mut offset = info.offset();
+        let mut streams = HashMap::with_capacity(footer.streams.len());
+        for stream in footer.streams.iter() {
+            let range = offset..offset + stream.length();
+            offset = range.end;
+            streams.insert((stream.column(), stream.kind()), range);
+        }
+        streams
+    }
+
+    /// Create the stripe from the bytes of the whole file.
+    pub fn try_create(meta: &OrcFileMeta, stripe: usize, file: &[u8]) -> Result<Self> {
+        let info = Self::stripe_info(meta, stripe)?;
+        let slice = |range: Range<u64>| {
+            file.get(range.start as usize..range.end as usize)
+                .ok_or_else(|| invalid(format!("stripe {stripe} is truncated")))
+        };
+        let footer = Self::decode_footer(meta, slice(Self::footer_range(info))?)?;
+        let streams = Self::stream_ranges(info, &footer);
+        let data_range = info.offset()..info.offset() + info.index_length() + info.data_length();
+        Ok(OrcStripe {
+            compression: meta.compression,
+            compression_block_size: meta.compression_block_size,
+            num_rows: info.number_of_rows() as usize,
+            data: slice(data_range.clone())?.to_vec(),
+            footer,
+            streams,
+            data_range,
+        })
+    }
+
+    /// Read the streams of the given columns of a stripe from the storage.
+    #[async_backtrace::framed]
+    pub async fn read(
+        operator: &Operator,
+        path: &str,
+        meta: &OrcFileMeta,
+        stripe: usize,
+        column_ids: &[u32],
+    ) -> Result<Self> {
+        let info = Self::stripe_info(meta, stripe)?;
+        let footer = operator
+            .read_with(path)
+            .range(Self::footer_range(info))
+            .await?;
+        let footer = Self::decode_footer(meta, &footer)?;
+        let streams = Self::stream_ranges(info, &footer);
+
+        // Read the smallest range which covers all the streams of the columns.
+        let data_range = streams
+            .iter()
+            .filter(|((column, kind), _)| column_ids.contains(column) && is_data_stream(*kind))
+            .fold(None, |acc: Option<Range<u64>>, (_, range)| match acc {
+                None => Some(range.clone()),
+                Some(acc) => Some(acc.start.min(range.start)..acc.end.max(range.end)),
+            })
+            .unwrap_or(0..0);
+        let data = if data_range.is_empty() {
+            vec![]
+        } else {
+            operator.read_with(path).range(data_range.clone()).await?
+        };
+
+        Ok(OrcStripe {
+            compression: meta.compression,
+            compression_block_size: meta.compression_block_size,
+            num_rows: info.number_of_rows() as usize,
+            footer,
+            streams,
+            data,
+            data_range,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn stream(&self, column_id: u32, kind: StreamKind) -> Result<Option<Vec<u8>>> {
+        let Some(range) = self.streams.get(&(column_id, kind)) else {
+            return Ok(None);
+        };
+        if range.start < self.data_range.start || range.end > self.data_range.end {
+            return Err(ErrorCode::Internal(format!(
+                "stream {:?} of column {} is not loaded",
+                kind, column_id
+            )));
+        }
+        let start = (range.start - self.data_range.start) as usize;
+        let end = (range.end - self.data_range.start) as usize;
+        let data = decompress(
+            self.compression,
+            self.compression_block_size,
+            &self.data[start..end],
+        )?;
+        Ok(Some(data))
+    }
+
+    fn required_stream(&self, column_id: u32, kind: StreamKind) -> Result<Vec<u8>> {
+        self.stream(column_id, kind)?.ok_or_else(|| {
+            invalid(format!(
+                "stream {:?} of column {} is missing",
+                kind, column_id
+            ))
+        })
+    }
+
+    /// Decode a column of the stripe as the given data type.
+    ///
+    /// The integer types can be read as any number or decimal type, and the decimal
+    /// type is rescaled to the given scale.
+    pub fn decode_column(
+        &self,
+        meta: &OrcFileMeta,
+        column_id: u32,
+        data_type: &TableDataType,
+    ) -> Result<Column> {
+        let ty = meta.column_type(column_id)?;
+        let present = self
+            .stream(column_id, StreamKind::Present)?
+            .map(|data| decode_boolean_rle(&data, self.num_rows))
+            .transpose()?;
+        let count = present
+            .as_ref()
+            .map_or(self.num_rows, |p| p.iter().filter(|v| **v).count());
+        let v2 = matches!(
+            self.footer
+                .columns
+                .get(column_id as usize)
+                .map(|encoding| encoding.kind()),
+            Some(ColumnEncodingKind::DirectV2) | Some(ColumnEncodingKind::DictionaryV2)
+        );
+
+        let values = match ty.kind() {
+            TypeKind::Boolean => {
+                let data = self.required_stream(column_id, StreamKind::Data)?;
+                OrcValues::Boolean(decode_boolean_rle(&data, count)?)
+            }
+            TypeKind::Byte => {
+                let data = self.required_stream(column_id, StreamKind::Data)?;
+                let values = decode_byte_rle(&data, count)?;
+                OrcValues::Int(values.into_iter().map(|v| v as i8 as i64).collect())
+            }
+            TypeKind::Short | TypeKind::Int | TypeKind::Long => {
+                let data = self.required_stream(column_id, StreamKind::Data)?;
+                OrcValues::Int(decode_int_rle(&data, count, true, v2)?)
+            }
+            TypeKind::Date => {
+                let data = self.required_stream(column_id, StreamKind::Data)?;
+                let values = decode_int_rle(&data, count, true, v2)?;
+                OrcValues::Date(values.into_iter().map(|v| v as i32).collect())
+            }
+            TypeKind::Float => {
+                let data = self.required_stream(column_id, StreamKind::Data)?;
+                if data.len() < count * 4 {
+                    return Err(invalid(format!("float column {column_id} is truncated")));
+                }
+                OrcValues::Float(
+                    data.chunks_exact(4)
+                        .take(count)
+                        .map(|b| f32::from_le_bytes(b.try_into().unwrap()) as f64)
+                        .collect(),
+                )
+            }
+            TypeKind::Double => {
+                let data = self.required_stream(column_id, StreamKind::Data)?;
+                if data.len() < count * 8 {
+                    return Err(invalid(format!("double column {column_id} is truncated")));
+                }
+                OrcValues::Float(
+                    data.chunks_exact(8)
+                        .take(count)
+                        .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+                        .collect(),
+                )
+            }
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                self.decode_bytes(column_id, count, v2)?
+            }
+            TypeKind::Decimal => {
+                let scale = match ty.precision() {
+                    0 => 10,
+                    _ => ty.scale(),
+                };
+                let data = self.required_stream(column_id, StreamKind::Data)?;
+                let scales = self.required_stream(column_id, StreamKind::Secondary)?;
+                let scales = decode_int_rle(&scales, count, true, v2)?;
+                let mut reader = ByteReader::new(&data);
+                let values = scales
+                    .into_iter()
+                    .map(|value_scale| {
+                        let value = reader.read_signed_varint128()?;
+                        rescale_decimal::<i128>(value, value_scale as i32, scale as i32)
+                            .ok_or_else(|| invalid("decimal value overflow"))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                OrcValues::Decimal(values, scale as u8)
+            }
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.required_stream(column_id, StreamKind::Data)?;
+                let seconds = decode_int_rle(&seconds, count, true, v2)?;
+                let nanos = self.required_stream(column_id, StreamKind::Secondary)?;
+                let nanos = decode_int_rle(&nanos, count, false, v2)?;
+                OrcValues::Timestamp(
+                    seconds
+                        .into_iter()
+                        .zip(nanos)
+                        .map(|(seconds, nanos)| to_timestamp_micros(seconds, nanos))
+                        .collect(),
+                )
+            }
+            kind => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "orc type {:?} is not supported yet",
+                    kind
+                )));
+            }
+        };
+
+        let column = values.into_column(data_type.remove_nullable(), present.as_deref())?;
+        if data_type.is_nullable() {
+            let validity = present.map(Bitmap::from);
+            Ok(column.wrap_nullable(validity))
+        } else if present.is_some_and(|p| p.iter().any(|v| !*v)) {
+            Err(ErrorCode::BadBytes(format!(
+                "orc column {} contains null values, but the type {} is not nullable",
+                column_id, data_type
+            )))
+        } else {
+            Ok(column)
+        }
+    }
+
+    fn decode_bytes(&self, column_id: u32, count: usize, v2: bool) -> Result<OrcValues> {
+        let data = self.required_stream(column_id, StreamKind::Data)?;
+        let lengths = self.required_stream(column_id, StreamKind::Length)?;
+        let encoding = self
+            .footer
+            .columns
+            .get(column_id as usize)
+            .map(|encoding| encoding.kind())
+            .unwrap_or(ColumnEncodingKind::Direct);
+
+        let split = |data: &[u8], lengths: Vec<i64>| -> Result<Vec<Range<usize>>> {
+            let mut offset = 0usize;
+            lengths
+                .into_iter()
+                .map(|len| {
+                    if len < 0 || offset + len as usize > data.len() {
+                        return Err(invalid(format!("string column {column_id} is truncated")));
+                    }
+                    let range = offset..offset + len as usize;
+                    offset = range.end;
+                    Ok(range)
+                })
+                .collect()
+        };
+
+        match encoding {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::DirectV2 => {
+                let lengths = decode_int_rle(&lengths, count, false, v2)?;
+                let ranges = split(&data, lengths)?;
+                Ok(OrcValues::Bytes(data, ranges))
+            }
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2 => {
+                let dictionary_size = self.footer.columns[column_id as usize].dictionary_size();
+                let dictionary = self
+                    .stream(column_id, StreamKind::DictionaryData)?
+                    .unwrap_or_default();
+                let lengths = decode_int_rle(&lengths, dictionary_size as usize, false, v2)?;
+                let entries = split(&dictionary, lengths)?;
+                let indices = decode_int_rle(&data, count, false, v2)?;
+                let ranges = indices
+                    .into_iter()
+                    .map(|index| {
+                        entries.get(index as usize).cloned().ok_or_else(|| {
+                            invalid(format!("dictionary index {index} is out of range"))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(OrcValues::Bytes(dictionary, ranges))
+            }
+        }
+    }
+}
+
+fn to_timestamp_micros(seconds: i64, nanos: i64) -> i64 {
+    // The lowest 3 bits is the number of trailing zeros which are removed, minus 1.
+    let zeros = nanos & 0x07;
+    let mut nanos = nanos >> 3;
+    if zeros != 0 {
+        nanos *= 10i64.pow(zeros as u32 + 1);
+    }
+    let mut seconds = seconds + ORC_TIMESTAMP_BASE_SECONDS;
+    // The seconds of timestamps before 1970 are truncated towards zero by the writer.
+    if seconds < 0 && nanos > 999_999 {
+        seconds -= 1;
+    }
+    seconds * 1_000_000 + nanos / 1_000
+}
+
+fn rescale_decimal<T: Decimal>(value: i128, from_scale: i32, to_scale: i32) -> Option<T> {
+    let value = T::from_i128(value);
+    if to_scale >= from_scale {
+        value.checked_mul(T::e((to_scale - from_scale) as u32))
+    } else {
+        value.checked_div(T::e((from_scale - to_scale) as u32))
+    }
+}
+
+/// The decoded non-null values of a column.
+enum OrcValues {
+    Boolean(Vec<bool>),
+    Int(Vec<i64>),
+    Float(Vec<f64>),
+    Decimal(Vec<i128>, u8),
+    Date(Vec<i32>),
+    Timestamp(Vec<i64>),
+    // The data and the range of every value.
+    Bytes(Vec<u8>, Vec<Range<usize>>),
+}
+
+// Fill the null positions with default values.
+fn expand<T: Default + Clone>(values: Vec<T>, present: Option<&[bool]>) -> Vec<T> {
+    match present {
+        None => values,
+        Some(present) => {
+            let mut values = values.into_iter();
+            present
+                .iter()
+                .map(|valid| match valid {
+                    true => values.next().unwrap_or_default(),
+                    false => T::default(),
+                })
+                .collect()
+        }
+    }
+}
+
+fn cast_ints<T: TryFrom<i64>>(values: Vec<i64>) -> Result<Vec<T>> {
+    values
+        .into_iter()
+        .map(|v| {
+            T::try_from(v).map_err(|_| {
+                ErrorCode::BadBytes(format!(
+                    "orc value {v} is out of the range of {}",
+                    std::any::type_name::<T>()
+                ))
+            })
+        })
+        .collect()
+}
+
+fn decimal_column(values: Vec<i128>, scale: u8, ty: &DecimalDataType) -> Result<Column> {
+    let size = ty.size();
+    let overflow = || ErrorCode::BadBytes(format!("orc decimal value overflow {:?}", ty));
+    match ty {
+        DecimalDataType::Decimal128(_) => {
+            let values = values
+                .into_iter()
+                .map(|v| rescale_decimal::<i128>(v, scale as i32, size.scale as i32))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(overflow)?;
+            Ok(Decimal128Type::from_data_with_size(values, size))
+        }
+        DecimalDataType::Decimal256(_) => {
+            let values = values
+                .into_iter()
+                .map(|v| rescale_decimal::<I256>(v, scale as i32, size.scale as i32))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(overflow)?;
+            Ok(Decimal256Type::from_data_with_size(values, size))
+        }
+    }
+}
+
+impl OrcValues {
+    fn type_name(&self) -> &'static str {
+        match self {
+            OrcValues::Boolean(_) => "boolean",
+            OrcValues::Int(_) => "integer",
+            OrcValues::Float(_) => "float",
+            OrcValues::Decimal(_, _) => "decimal",
+            OrcValues::Date(_) => "date",
+            OrcValues::Timestamp(_) => "timestamp",
+            OrcValues::Bytes(_, _) => "string",
+        }
+    }
+
+    fn into_column(self, data_type: TableDataType, present: Option<&[bool]>) -> Result<Column> {
+        let type_name = self.type_name();
+        let column = match (self, &data_type) {
+            (OrcValues::Boolean(v), TableDataType::Boolean) => {
+                BooleanType::from_data(expand(v, present))
+            }
+            (OrcValues::Int(v), TableDataType::Number(ty)) => {
+                let v = expand(v, present);
+                match ty {
+                    NumberDataType::UInt8 => UInt8Type::from_data(cast_ints::<u8>(v)?),
+                    NumberDataType::UInt16 => UInt16Type::from_data(cast_ints::<u16>(v)?),
+                    NumberDataType::UInt32 => UInt32Type::from_data(cast_ints::<u32>(v)?),
+                    NumberDataType::UInt64 => UInt64Type::from_data(cast_ints::<u64>(v)?),
+                    NumberDataType::Int8 => Int8Type::from_data(cast_ints::<i8>(v)?),
+                    NumberDataType::Int16 => Int16Type::from_data(cast_ints::<i16>(v)?),
+                    NumberDataType::Int32 => Int32Type::from_data(cast_ints::<i32>(v)?),
+                    NumberDataType::Int64 => Int64Type::from_data(v),
+                    NumberDataType::Float32 => {
+                        Float32Type::from_data(v.into_iter().map(|v| v as f32).collect::<Vec<_>>())
+                    }
+                    NumberDataType::Float64 => {
+                        Float64Type::from_data(v.into_iter().map(|v| v as f64).collect::<Vec<_>>())
+                    }
+                }
+            }
+            (OrcValues::Int(v), TableDataType::Decimal(ty)) => decimal_column(
+                expand(v, present).into_iter().map(|v| v as i128).collect(),
+                0,
+                ty,
+            )?,
+            (OrcValues::Float(v), TableDataType::Number(NumberDataType::Float32)) => {
+                let v = expand(v, present);
+                Float32Type::from_data(v.into_iter().map(|v| v as f32).collect::<Vec<_>>())
+            }
+            (OrcValues::Float(v), TableDataType::Number(NumberDataType::Float64)) => {
+                Float64Type::from_data(expand(v, present))
+            }
+            (OrcValues::Decimal(v, scale), TableDataType::Decimal(ty)) => {
+                decimal_column(expand(v, present), scale, ty)?
+            }
+            (OrcValues::Decimal(v, scale), TableDataType::Number(NumberDataType::Float64)) => {
+                let v = expand(v, present);
+                Float64Type::from_data(
+                    v.into_iter()
+                        .map(|v| v.to_float64(scale))
+                        .collect::<Vec<_>>(),
+                )
+            }
+            (OrcValues::Date(v), TableDataType::Date) => DateType::from_data(expand(v, present)),
+            (OrcValues::Date(v), TableDataType::Timestamp) => TimestampType::from_data(
+                expand(v, present)
+                    .into_iter()
+                    .map(|v| v as i64 * 86_400_000_000)
+                    .collect(),
+            ),
+            (OrcValues::Timestamp(v), TableDataType::Timestamp) => {
+                TimestampType::from_data(expand(v, present))
+            }
+            (OrcValues::Bytes(data, ranges), TableDataType::String) => {
+                let ranges = expand(ranges, present);
+                let mut builder = StringColumnBuilder::with_capacity(ranges.len(), data.len());
+                for range in ranges {
+                    let s = std::str::from_utf8(&data[range]).map_err(|e| {
+                        ErrorCode::InvalidUtf8String(format!("invalid utf8 string in orc: {e}"))
+                    })?;
+                    builder.put_str(s);
+                    builder.commit_row();
+                }
+                Column::String(builder.build())
+            }
+            (OrcValues::Bytes(data, ranges), TableDataType::Binary) => {
+                let ranges = expand(ranges, present);
+                let mut builder = BinaryColumnBuilder::with_capacity(ranges.len(), data.len());
+                for range in ranges {
+                    builder.put_slice(&data[range]);
+                    builder.commit_row();
+                }
+                Column::Binary(builder.build())
+            }
+            _ => {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "cannot read orc {} column as {}",
+                    type_name, data_type
+                )));
+            }
+        };
+        Ok(column)
+    }
+}
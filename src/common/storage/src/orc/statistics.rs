@@ -0,0 +1,221 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::Decimal256Type;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::number::F32;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+
+use crate::orc::proto::ColumnStatistics;
+use crate::orc::OrcFileMeta;
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+type I256 = <Decimal256Type as ValueType>::Scalar;
+
+/// The min/max values of a column in a stripe, converted to the type of the table column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrcColumnStatistics {
+    pub min: Scalar,
+    pub max: Scalar,
+    pub null_count: u64,
+}
+
+impl OrcFileMeta {
+    /// Get the statistics of a column in a stripe, returns `None` if the writer did not
+    /// write them or they can't be represented as `data_type`.
+    pub fn stripe_column_statistics(
+        &self,
+        stripe: usize,
+        column_id: u32,
+        data_type: &TableDataType,
+    ) -> Option<OrcColumnStatistics> {
+        let num_rows = self.footer.stripes.get(stripe)?.number_of_rows();
+        let stats = self
+            .metadata
+            .stripe_stats
+            .get(stripe)?
+            .col_stats
+            .get(column_id as usize)?;
+        // `number_of_values` doesn't count the null values.
+        let null_count = num_rows.saturating_sub(stats.number_of_values());
+        if stats.number_of_values() == 0 {
+            return Some(OrcColumnStatistics {
+                min: Scalar::Null,
+                max: Scalar::Null,
+                null_count,
+            });
+        }
+
+        let (min, max) = min_max_scalars(stats, &data_type.remove_nullable())?;
+        Some(OrcColumnStatistics {
+            min,
+            max,
+            null_count,
+        })
+    }
+}
+
+fn int_scalar(v: i64, ty: &NumberDataType) -> Option<Scalar> {
+    let scalar = match ty {
+        NumberDataType::UInt8 => NumberScalar::UInt8(v.try_into().ok()?),
+        NumberDataType::UInt16 => NumberScalar::UInt16(v.try_into().ok()?),
+        NumberDataType::UInt32 => NumberScalar::UInt32(v.try_into().ok()?),
+        NumberDataType::UInt64 => NumberScalar::UInt64(v.try_into().ok()?),
+        NumberDataType::Int8 => NumberScalar::Int8(v.try_into().ok()?),
+        NumberDataType::Int16 => NumberScalar::Int16(v.try_into().ok()?),
+        NumberDataType::Int32 => NumberScalar::Int32(v.try_into().ok()?),
+        NumberDataType::Int64 => NumberScalar::Int64(v),
+        NumberDataType::Float32 => NumberScalar::Float32(F32::from(v as f32)),
+        NumberDataType::Float64 => NumberScalar::Float64(F64::from(v as f64)),
+    };
+    Some(Scalar::Number(scalar))
+}
+
+fn float_scalar(v: f64, ty: &NumberDataType) -> Option<Scalar> {
+    let scalar = match ty {
+        NumberDataType::Float32 => NumberScalar::Float32(F32::from(v as f32)),
+        NumberDataType::Float64 => NumberScalar::Float64(F64::from(v)),
+        _ => return None,
+    };
+    Some(Scalar::Number(scalar))
+}
+
+/// Parse the decimal string in statistics like `-12.340` to an integer of the given scale,
+/// returns `None` if the value can't be represented exactly.
+fn parse_decimal(s: &str, scale: u8) -> Option<i128> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let (int_part, frac_part) = s.split_once('.').unwrap_or((s, ""));
+    let frac_part = frac_part.trim_end_matches('0');
+    if frac_part.len() > scale as usize
+        || !int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut value = 0i128;
+    let digits = int_part
+        .bytes()
+        .chain(frac_part.bytes())
+        .chain(std::iter::repeat(b'0').take(scale as usize - frac_part.len()));
+    for b in digits {
+        value = value.checked_mul(10)?.checked_add((b - b'0') as i128)?;
+    }
+    Some(if negative { -value } else { value })
+}
+
+fn decimal_scalar(v: i128, ty: &DecimalDataType) -> Option<Scalar> {
+    let size = ty.size();
+    let scalar = match ty {
+        DecimalDataType::Decimal128(_) => {
+            if v < i128::min_for_precision(size.precision)
+                || v > i128::max_for_precision(size.precision)
+            {
+                return None;
+            }
+            DecimalScalar::Decimal128(v, size)
+        }
+        DecimalDataType::Decimal256(_) => DecimalScalar::Decimal256(I256::from_i128(v), size),
+    };
+    Some(Scalar::Decimal(scalar))
+}
+
+fn min_max_scalars(
+    stats: &ColumnStatistics,
+    data_type: &TableDataType,
+) -> Option<(Scalar, Scalar)> {
+    match data_type {
+        TableDataType::Number(ty) => {
+            if let Some(s) = &stats.int_statistics {
+                Some((int_scalar(s.minimum?, ty)?, int_scalar(s.maximum?, ty)?))
+            } else if let Some(s) = &stats.double_statistics {
+                Some((float_scalar(s.minimum?, ty)?, float_scalar(s.maximum?, ty)?))
+            } else {
+                None
+            }
+        }
+        TableDataType::Decimal(ty) => {
+            let scale = ty.size().scale;
+            let (min, max) = if let Some(s) = &stats.int_statistics {
+                let e = i128::e(scale as u32);
+                (
+                    (s.minimum? as i128).checked_mul(e)?,
+                    (s.maximum? as i128).checked_mul(e)?,
+                )
+            } else {
+                let s = stats.decimal_statistics.as_ref()?;
+                (
+                    parse_decimal(s.minimum.as_ref()?, scale)?,
+                    parse_decimal(s.maximum.as_ref()?, scale)?,
+                )
+            };
+            Some((decimal_scalar(min, ty)?, decimal_scalar(max, ty)?))
+        }
+        TableDataType::String => {
+            let s = stats.string_statistics.as_ref()?;
+            let min = s.minimum.as_ref().or(s.lower_bound.as_ref())?;
+            let max = s.maximum.as_ref().or(s.upper_bound.as_ref())?;
+            Some((Scalar::String(min.clone()), Scalar::String(max.clone())))
+        }
+        TableDataType::Boolean => {
+            let trues = *stats.bucket_statistics.as_ref()?.count.first()?;
+            let min = trues == stats.number_of_values();
+            let max = trues > 0;
+            Some((Scalar::Boolean(min), Scalar::Boolean(max)))
+        }
+        TableDataType::Date => {
+            let s = stats.date_statistics.as_ref()?;
+            Some((Scalar::Date(s.minimum?), Scalar::Date(s.maximum?)))
+        }
+        TableDataType::Timestamp => {
+            // The statistics are in milliseconds and may be adjusted by the timezone of the
+            // writer, while the values are read as they are, so widen the range by a day.
+            let s = stats.timestamp_statistics.as_ref()?;
+            let min = s.minimum_utc.or(s.minimum)?.checked_sub(MILLIS_PER_DAY)?;
+            let max = s.maximum_utc.or(s.maximum)?.checked_add(MILLIS_PER_DAY)?;
+            Some((
+                Scalar::Timestamp(min.checked_mul(1000)?),
+                Scalar::Timestamp(max.checked_mul(1000)?),
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_decimal;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("12.34", 2), Some(1234));
+        assert_eq!(parse_decimal("-12.3", 3), Some(-12300));
+        assert_eq!(parse_decimal("7", 1), Some(70));
+        assert_eq!(parse_decimal("0.120", 2), Some(12));
+        assert_eq!(parse_decimal("0.123", 2), None);
+        assert_eq!(parse_decimal("1e10", 2), None);
+    }
+}
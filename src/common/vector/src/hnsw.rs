@@ -0,0 +1,439 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::cosine_distance;
+use crate::l2_distance;
+
+/// The option key of a vector index to choose the distance.
+pub const VECTOR_METRIC_OPTION: &str = "metric";
+/// The option key of a vector index to set the max neighbors of a node.
+pub const VECTOR_M_OPTION: &str = "m";
+/// The option key of a vector index to set the candidates kept while building.
+pub const VECTOR_EF_CONSTRUCTION_OPTION: &str = "ef_construction";
+
+const DEFAULT_M: usize = 16;
+const DEFAULT_EF_CONSTRUCTION: usize = 64;
+
+/// The highest layer a node can be placed in.
+const MAX_LEVEL: usize = 16;
+
+/// The distance function of a vector index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+}
+
+impl VectorDistance {
+    /// The name of the scalar function computing the distance.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            VectorDistance::Cosine => "cosine_distance",
+            VectorDistance::L2 => "l2_distance",
+        }
+    }
+
+    pub fn distance(&self, from: &[f32], to: &[f32]) -> Result<f32> {
+        match self {
+            VectorDistance::Cosine => cosine_distance(from, to),
+            VectorDistance::L2 => l2_distance(from, to),
+        }
+    }
+}
+
+impl FromStr for VectorDistance {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(VectorDistance::Cosine),
+            "l2" => Ok(VectorDistance::L2),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown vector distance '{s}', expecting 'cosine' or 'l2'"
+            ))),
+        }
+    }
+}
+
+impl Display for VectorDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorDistance::Cosine => write!(f, "cosine"),
+            VectorDistance::L2 => write!(f, "l2"),
+        }
+    }
+}
+
+/// The options of a vector index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HnswOptions {
+    pub distance: VectorDistance,
+    pub m: usize,
+    pub ef_construction: usize,
+}
+
+impl HnswOptions {
+    /// Get the options from the options of a vector index, the distance defaults to cosine.
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let distance = match options.get(VECTOR_METRIC_OPTION) {
+            Some(metric) => metric.parse()?,
+            None => VectorDistance::Cosine,
+        };
+        let m = parse_option(options, VECTOR_M_OPTION, DEFAULT_M, 2..=100)?;
+        let ef_construction = parse_option(
+            options,
+            VECTOR_EF_CONSTRUCTION_OPTION,
+            DEFAULT_EF_CONSTRUCTION,
+            1..=1000,
+        )?;
+        Ok(HnswOptions {
+            distance,
+            m,
+            ef_construction,
+        })
+    }
+}
+
+fn parse_option(
+    options: &BTreeMap<String, String>,
+    key: &str,
+    default: usize,
+    range: std::ops::RangeInclusive<usize>,
+) -> Result<usize> {
+    let Some(value) = options.get(key) else {
+        return Ok(default);
+    };
+    match value.parse::<usize>() {
+        Ok(v) if range.contains(&v) => Ok(v),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "Invalid vector index option {key} = '{value}', expecting an integer in [{}, {}]",
+            range.start(),
+            range.end()
+        ))),
+    }
+}
+
+/// A node of the graph with its distance to the query, ordered by the distance.
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+/// A Hierarchical Navigable Small World graph for approximate nearest neighbour search.
+///
+/// Every node is linked to its closest nodes in each layer from 0 up to the level of
+/// the node, the upper layers are sparser and used to find a good entry point of the
+/// lower layers. The nodes are the positions of the vectors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HnswIndex {
+    distance: VectorDistance,
+    vectors: Vec<Vec<f32>>,
+    /// The neighbors of each node, from layer 0 up to the level of the node.
+    neighbors: Vec<Vec<Vec<u32>>>,
+    /// The first node in the highest layer.
+    entry_point: Option<u32>,
+}
+
+impl HnswIndex {
+    /// Build the graph, each node is linked to at most `m` neighbors in the upper layers,
+    /// and `2 * m` neighbors in layer 0.
+    ///
+    /// The level of a node is derived from its position, so the graph of the same vectors
+    /// is always the same.
+    pub fn build(
+        distance: VectorDistance,
+        m: usize,
+        ef_construction: usize,
+        vectors: Vec<Vec<f32>>,
+    ) -> Result<Self> {
+        if m < 2 {
+            return Err(ErrorCode::BadArguments(format!(
+                "The max neighbors of a vector index must be at least 2, but got {m}"
+            )));
+        }
+        if let Some(first) = vectors.first() {
+            if let Some(vector) = vectors.iter().find(|v| v.len() != first.len()) {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "Vector length not equal: {:} != {:}",
+                    first.len(),
+                    vector.len(),
+                )));
+            }
+        }
+
+        let level_factor = 1.0 / (m as f64).ln();
+        let mut index = HnswIndex {
+            distance,
+            neighbors: Vec::with_capacity(vectors.len()),
+            vectors,
+            entry_point: None,
+        };
+        for node in 0..index.vectors.len() {
+            let level = random_level(node as u64, level_factor);
+            index.insert(node as u32, level, m, ef_construction.max(m))?;
+        }
+        Ok(index)
+    }
+
+    /// Restore the graph from the vectors and the neighbors of the nodes.
+    pub fn from_parts(
+        distance: VectorDistance,
+        vectors: Vec<Vec<f32>>,
+        neighbors: Vec<Vec<Vec<u32>>>,
+    ) -> Result<Self> {
+        let num_nodes = vectors.len();
+        if neighbors.len() != num_nodes
+            || neighbors.iter().any(|layers| {
+                layers.is_empty()
+                    || layers
+                        .iter()
+                        .flatten()
+                        .any(|node| *node as usize >= num_nodes)
+            })
+        {
+            return Err(ErrorCode::StorageOther("invalid vector index graph"));
+        }
+
+        let mut entry_point = None;
+        let mut max_level = 0;
+        for (node, layers) in neighbors.iter().enumerate() {
+            if entry_point.is_none() || layers.len() - 1 > max_level {
+                entry_point = Some(node as u32);
+                max_level = layers.len() - 1;
+            }
+        }
+        Ok(HnswIndex {
+            distance,
+            vectors,
+            neighbors,
+            entry_point,
+        })
+    }
+
+    pub fn distance(&self) -> VectorDistance {
+        self.distance
+    }
+
+    pub fn vectors(&self) -> &[Vec<f32>] {
+        &self.vectors
+    }
+
+    pub fn neighbors(&self) -> &[Vec<Vec<u32>>] {
+        &self.neighbors
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    /// Returns the `k` nodes closest to the query with their distances, closest first.
+    ///
+    /// `ef` is the number of candidates kept while searching layer 0, a larger `ef`
+    /// gives a better recall but is slower.
+    pub fn search(&self, query: &[f32], k: usize, ef: usize) -> Result<Vec<(u32, f32)>> {
+        let Some(entry_point) = self.entry_point else {
+            return Ok(vec![]);
+        };
+        if k == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut entry = vec![Candidate {
+            distance: self.distance.distance(query, self.vector(entry_point))?,
+            node: entry_point,
+        }];
+        for layer in (1..=self.level(entry_point)).rev() {
+            entry = self.search_layer(query, entry, 1, layer)?;
+        }
+        let candidates = self.search_layer(query, entry, ef.max(k), 0)?;
+        Ok(candidates
+            .into_iter()
+            .take(k)
+            .map(|candidate| (candidate.node, candidate.distance))
+            .collect())
+    }
+
+    fn vector(&self, node: u32) -> &[f32] {
+        &self.vectors[node as usize]
+    }
+
+    fn level(&self, node: u32) -> usize {
+        self.neighbors[node as usize].len() - 1
+    }
+
+    fn insert(&mut self, node: u32, level: usize, m: usize, ef_construction: usize) -> Result<()> {
+        self.neighbors.push(vec![Vec::new(); level + 1]);
+        let Some(entry_point) = self.entry_point else {
+            self.entry_point = Some(node);
+            return Ok(());
+        };
+
+        let query = self.vector(node).to_vec();
+        let max_level = self.level(entry_point);
+        let mut entry = vec![Candidate {
+            distance: self.distance.distance(&query, self.vector(entry_point))?,
+            node: entry_point,
+        }];
+        for layer in (level + 1..=max_level).rev() {
+            entry = self.search_layer(&query, entry, 1, layer)?;
+        }
+        for layer in (0..=level.min(max_level)).rev() {
+            let candidates = self.search_layer(&query, entry, ef_construction, layer)?;
+            let max_neighbors = if layer == 0 { 2 * m } else { m };
+            let selected = candidates
+                .iter()
+                .take(m)
+                .map(|candidate| candidate.node)
+                .collect::<Vec<_>>();
+            for neighbor in selected.iter() {
+                self.connect(*neighbor, node, layer, max_neighbors)?;
+            }
+            self.neighbors[node as usize][layer] = selected;
+            entry = candidates;
+        }
+
+        if level > max_level {
+            self.entry_point = Some(node);
+        }
+        Ok(())
+    }
+
+    /// Link `from` to `to` in the layer, only the closest neighbors are kept if `from`
+    /// has too many links.
+    fn connect(&mut self, from: u32, to: u32, layer: usize, max_neighbors: usize) -> Result<()> {
+        let links = &mut self.neighbors[from as usize][layer];
+        links.push(to);
+        if links.len() <= max_neighbors {
+            return Ok(());
+        }
+
+        let mut candidates = self.neighbors[from as usize][layer]
+            .iter()
+            .map(|node| {
+                Ok(Candidate {
+                    distance: self
+                        .distance
+                        .distance(self.vector(from), self.vector(*node))?,
+                    node: *node,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        candidates.sort();
+        self.neighbors[from as usize][layer] = candidates
+            .into_iter()
+            .take(max_neighbors)
+            .map(|candidate| candidate.node)
+            .collect();
+        Ok(())
+    }
+
+    /// Greedy search in a layer, returns at most `ef` nodes closest to the query,
+    /// closest first.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry: Vec<Candidate>,
+        ef: usize,
+        layer: usize,
+    ) -> Result<Vec<Candidate>> {
+        let mut visited = entry
+            .iter()
+            .map(|candidate| candidate.node)
+            .collect::<HashSet<_>>();
+        let mut candidates = entry
+            .iter()
+            .copied()
+            .map(Reverse)
+            .collect::<BinaryHeap<_>>();
+        let mut results = entry.into_iter().collect::<BinaryHeap<_>>();
+
+        while let Some(Reverse(candidate)) = candidates.pop() {
+            if results.peek().is_some_and(|furthest| candidate > *furthest) {
+                break;
+            }
+            let Some(links) = self.neighbors[candidate.node as usize].get(layer) else {
+                continue;
+            };
+            for neighbor in links.iter() {
+                if !visited.insert(*neighbor) {
+                    continue;
+                }
+                let next = Candidate {
+                    distance: self.distance.distance(query, self.vector(*neighbor))?,
+                    node: *neighbor,
+                };
+                if results.len() < ef || results.peek().is_some_and(|furthest| next < *furthest) {
+                    candidates.push(Reverse(next));
+                    results.push(next);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+        Ok(results.into_sorted_vec())
+    }
+}
+
+/// The level of a node, exponentially decaying, derived from the hash of the node.
+fn random_level(node: u64, level_factor: f64) -> usize {
+    // splitmix64
+    let mut hash = node.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^= hash >> 31;
+
+    // uniform in (0, 1]
+    let uniform = ((hash >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+    ((-uniform.ln() * level_factor) as usize).min(MAX_LEVEL)
+}
@@ -13,8 +13,15 @@
 // limitations under the License.
 
 mod distance;
+mod hnsw;
 
 pub use distance::cosine_distance;
 pub use distance::cosine_distance_64;
 pub use distance::l2_distance;
 pub use distance::l2_distance_64;
+pub use hnsw::HnswIndex;
+pub use hnsw::HnswOptions;
+pub use hnsw::VectorDistance;
+pub use hnsw::VECTOR_EF_CONSTRUCTION_OPTION;
+pub use hnsw::VECTOR_METRIC_OPTION;
+pub use hnsw::VECTOR_M_OPTION;
@@ -0,0 +1,107 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_vector::HnswIndex;
+use databend_common_vector::HnswOptions;
+use databend_common_vector::VectorDistance;
+
+#[test]
+fn test_hnsw_search() {
+    // A 10x10 grid.
+    let vectors = (0..100)
+        .map(|i| vec![(i % 10) as f32, (i / 10) as f32])
+        .collect::<Vec<_>>();
+    let index = HnswIndex::build(VectorDistance::L2, 4, 16, vectors.clone()).unwrap();
+    assert_eq!(index.len(), 100);
+
+    let result = index.search(&[3.1, 4.2], 3, 100).unwrap();
+    let nodes = result.iter().map(|(node, _)| *node).collect::<Vec<_>>();
+    assert_eq!(nodes, vec![43, 53, 44]);
+    approx::assert_relative_eq!(result[0].1, 0.223_606_6);
+
+    // Same as brute force.
+    let query = [7.6, 0.3];
+    let mut expected = (0..100u32)
+        .map(|node| {
+            let distance = VectorDistance::L2
+                .distance(&query, &vectors[node as usize])
+                .unwrap();
+            (node, distance)
+        })
+        .collect::<Vec<_>>();
+    expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+    expected.truncate(10);
+    assert_eq!(index.search(&query, 10, 100).unwrap(), expected);
+
+    let restored = HnswIndex::from_parts(
+        VectorDistance::L2,
+        index.vectors().to_vec(),
+        index.neighbors().to_vec(),
+    )
+    .unwrap();
+    assert_eq!(restored, index);
+
+    assert!(index.search(&[1.0], 3, 100).is_err());
+    assert!(index.search(&query, 0, 100).unwrap().is_empty());
+}
+
+#[test]
+fn test_hnsw_build() {
+    let empty = HnswIndex::build(VectorDistance::Cosine, 16, 64, vec![]).unwrap();
+    assert!(empty.is_empty());
+    assert!(empty.search(&[1.0, 2.0], 3, 64).unwrap().is_empty());
+
+    let vectors = vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]];
+    let index = HnswIndex::build(VectorDistance::Cosine, 16, 64, vectors).unwrap();
+    let result = index.search(&[2.0, 2.1], 1, 64).unwrap();
+    assert_eq!(result[0].0, 2);
+
+    assert!(HnswIndex::build(VectorDistance::L2, 1, 64, vec![]).is_err());
+    assert!(HnswIndex::build(VectorDistance::L2, 16, 64, vec![vec![1.0], vec![1.0, 2.0]]).is_err());
+    assert!(
+        HnswIndex::from_parts(VectorDistance::L2, vec![vec![1.0]], vec![vec![vec![1]]]).is_err()
+    );
+
+    assert_eq!(
+        "COSINE".parse::<VectorDistance>().unwrap(),
+        VectorDistance::Cosine
+    );
+    assert_eq!(VectorDistance::L2.function_name(), "l2_distance");
+    assert!("dot".parse::<VectorDistance>().is_err());
+}
+
+#[test]
+fn test_hnsw_options() {
+    let options = HnswOptions::from_options(&BTreeMap::new()).unwrap();
+    assert_eq!(options, HnswOptions {
+        distance: VectorDistance::Cosine,
+        m: 16,
+        ef_construction: 64,
+    });
+
+    let mut options = BTreeMap::new();
+    options.insert("metric".to_string(), "L2".to_string());
+    options.insert("m".to_string(), "8".to_string());
+    let parsed = HnswOptions::from_options(&options).unwrap();
+    assert_eq!(parsed.distance, VectorDistance::L2);
+    assert_eq!(parsed.m, 8);
+
+    options.insert("m".to_string(), "1".to_string());
+    assert!(HnswOptions::from_options(&options).is_err());
+    options.insert("m".to_string(), "8".to_string());
+    options.insert("ef_construction".to_string(), "many".to_string());
+    assert!(HnswOptions::from_options(&options).is_err());
+}
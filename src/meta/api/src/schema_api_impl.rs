@@ -159,6 +159,9 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2818,41 +2821,13 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 txn_req.if_then.extend(match_operations)
             }
 
-            for req in &req.update_stream_meta {
-                let stream_id = TableId {
-                    table_id: req.stream_id,
-                };
-                let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
-                    get_pb_value(self, &stream_id).await?;
-
-                if stream_meta_seq == 0 || stream_meta.is_none() {
-                    return Err(KVAppError::AppError(AppError::UnknownStreamId(
-                        UnknownStreamId::new(req.stream_id, "update_table_meta"),
-                    )));
-                }
-
-                if req.seq.match_seq(stream_meta_seq).is_err() {
-                    return Err(KVAppError::AppError(AppError::from(
-                        StreamVersionMismatched::new(
-                            req.stream_id,
-                            req.seq,
-                            stream_meta_seq,
-                            "update_table_meta",
-                        ),
-                    )));
-                }
-
-                let mut new_stream_meta = stream_meta.unwrap();
-                new_stream_meta.options = req.options.clone();
-                new_stream_meta.updated_on = Utc::now();
-
-                txn_req
-                    .condition
-                    .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
-                txn_req
-                    .if_then
-                    .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
-            }
+            build_update_stream_meta_txn(
+                self,
+                &req.update_stream_meta,
+                &mut txn_req,
+                "update_table_meta",
+            )
+            .await?;
 
             if let Some(deduplicated_label) = req.deduplicated_label.clone() {
                 txn_req
@@ -2919,6 +2894,108 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+            for req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+                let Some(table_meta) = table_meta else {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                };
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+                if let Some(copied_files) = &req.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            copied_files,
+                            tb_meta_seq,
+                            copied_files.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations);
+                }
+                build_update_stream_meta_txn(
+                    self,
+                    &req.update_stream_meta,
+                    &mut txn_req,
+                    "update_multi_table_meta",
+                )
+                .await?;
+                if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label));
+                }
+                table_metas.push((req, tb_meta_seq, table_meta));
+            }
+
+            let (succ, _) = send_txn(self, txn_req).await?;
+
+            debug!(succ = succ; "update_multi_table_meta");
+
+            if succ {
+                let mut share_table_info: Option<Vec<ShareTableInfoMap>> = None;
+                for (_, _, table_meta) in &table_metas {
+                    if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.get_or_insert_with(Vec::new).extend(infos);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply { share_table_info });
+            }
+
+            // The txn fails either because a table or stream is changed, which will be
+            // reported in the next round, or because of the duplicated copied files.
+            for (req, tb_meta_seq, _) in &table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (seq, _): (_, Option<TableMeta>) = get_pb_value(self, &tbid).await?;
+                let fail_if_duplicated = req
+                    .copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false);
+                if seq == *tb_meta_seq && fail_if_duplicated {
+                    return Err(KVAppError::AppError(AppError::from(
+                        DuplicatedUpsertFiles::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -4579,6 +4656,42 @@ fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
     TxnOp::put_with_expire(deduplicated_label, 1_i8.to_le_bytes().to_vec(), expire_at)
 }
 
+/// Append the conditions and operations to update the options of streams to a txn.
+async fn build_update_stream_meta_txn(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    reqs: &[UpdateStreamMetaReq],
+    txn_req: &mut TxnRequest,
+    ctx: &str,
+) -> Result<(), KVAppError> {
+    for req in reqs {
+        let stream_id = TableId {
+            table_id: req.stream_id,
+        };
+        let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+            get_pb_value(kv_api, &stream_id).await?;
+        let Some(mut new_stream_meta) = stream_meta else {
+            return Err(KVAppError::AppError(AppError::UnknownStreamId(
+                UnknownStreamId::new(req.stream_id, ctx),
+            )));
+        };
+        if req.seq.match_seq(stream_meta_seq).is_err() {
+            return Err(KVAppError::AppError(AppError::from(
+                StreamVersionMismatched::new(req.stream_id, req.seq, stream_meta_seq, ctx),
+            )));
+        }
+
+        new_stream_meta.options = req.options.clone();
+        new_stream_meta.updated_on = Utc::now();
+        txn_req
+            .condition
+            .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+        txn_req
+            .if_then
+            .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
+    }
+    Ok(())
+}
+
 fn set_update_expire_operation(
     key: &TableCopiedFileNameIdent,
     file_info: &TableCopiedFileInfo,
@@ -105,6 +105,7 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -298,6 +299,7 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -1430,6 +1432,7 @@ impl SchemaApiTestSuite {
                         bucket: "bucket".to_string(),
                         ..Default::default()
                     })),
+                    rest: None,
                 }),
                 created_on: Utc::now(),
             },
@@ -2656,6 +2659,106 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                create_option: CreateOption::CreateIfNotExists(false),
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    create_option: CreateOption::CreateIfNotExists(false),
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, seq: u64, data_bytes: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            }
+        };
+
+        info!("--- update the metas of tables, normal case");
+        {
+            let mut update_table_metas = vec![];
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                update_table_metas.push(update_req(table.as_ref(), table.ident.seq, 1));
+            }
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await?;
+
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                assert_eq!(table.meta.statistics.data_bytes, 1);
+            }
+        }
+
+        info!("--- update the metas of tables: version mismatch, nothing is changed");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(tb1.as_ref(), tb1.ident.seq, 2),
+                        update_req(tb2.as_ref(), tb2.ident.seq + 1, 2),
+                    ],
+                })
+                .await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            assert_eq!(tb1.meta.statistics.data_bytes, 1);
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -19,6 +19,7 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod pipe;
 mod principal_identity;
 mod role_info;
 mod user_auth;
@@ -37,6 +38,7 @@ pub use file_format::*;
 pub use network_policy::NetworkPolicy;
 pub use ownership_info::OwnershipInfo;
 pub use password_policy::PasswordPolicy;
+pub use pipe::PipeInfo;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
@@ -45,10 +47,13 @@ pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
 pub use user_defined_file_format::UserDefinedFileFormat;
 pub use user_defined_function::LambdaUDF;
+pub use user_defined_function::UDAFServer;
 pub use user_defined_function::UDFDefinition;
+pub use user_defined_function::UDFScript;
 pub use user_defined_function::UDFServer;
 pub use user_defined_function::UdfName;
 pub use user_defined_function::UserDefinedFunction;
+pub use user_defined_function::UDTF;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
 pub use user_grant::OwnershipObject;
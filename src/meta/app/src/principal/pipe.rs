@@ -0,0 +1,31 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A pipe wraps a `COPY INTO <table>` statement, which is run to load the new files
+/// in the stage. The files already loaded are skipped by the copied files of the table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct PipeInfo {
+    pub name: String,
+    /// The SQL text of the `COPY INTO <table>` statement.
+    pub copy_stmt: String,
+    /// Whether the pipe is refreshed periodically by the query nodes in the background.
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
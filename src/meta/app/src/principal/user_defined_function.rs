@@ -54,10 +54,43 @@ pub struct UDFServer {
     pub return_type: DataType,
 }
 
+/// A UDF runs in the query process, `code` is the module or script of the `language`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDFScript {
+    pub code: Vec<u8>,
+    pub handler: String,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+}
+
+/// An aggregate UDF, the states are opaque binaries which are
+/// initialized, accumulated, merged and finalized by the UDF server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDAFServer {
+    pub address: String,
+    pub handler: String,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+}
+
+/// A table function defined by a SQL query, the parameters are
+/// substituted into the query when the function is called.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDTF {
+    pub arg_types: Vec<(String, DataType)>,
+    pub return_types: Vec<(String, DataType)>,
+    pub sql: String,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum UDFDefinition {
     LambdaUDF(LambdaUDF),
     UDFServer(UDFServer),
+    UDFScript(UDFScript),
+    UDAFServer(UDAFServer),
+    UDTF(UDTF),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -110,6 +143,18 @@ impl UserDefinedFunction {
     }
 }
 
+impl UDFDefinition {
+    /// Returns true if the function is an aggregate function.
+    pub fn is_aggregate(&self) -> bool {
+        matches!(self, UDFDefinition::UDAFServer(_))
+    }
+
+    /// Returns true if the function is a table function.
+    pub fn is_table_function(&self) -> bool {
+        matches!(self, UDFDefinition::UDTF(_))
+    }
+}
+
 impl Display for UDFDefinition {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, " (")?;
@@ -144,6 +189,63 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
                 )?;
             }
+            UDFDefinition::UDFScript(UDFScript {
+                code,
+                arg_types,
+                return_type,
+                handler,
+                language,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} AS <{} bytes>",
+                    code.len()
+                )?;
+            }
+            UDFDefinition::UDAFServer(UDAFServer {
+                address,
+                arg_types,
+                return_type,
+                handler,
+                language,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
+                )?;
+            }
+            UDFDefinition::UDTF(UDTF {
+                arg_types,
+                return_types,
+                sql,
+            }) => {
+                for (i, (name, ty)) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name} {ty}")?;
+                }
+                write!(f, ") RETURNS TABLE (")?;
+                for (i, (name, ty)) in return_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name} {ty}")?;
+                }
+                write!(f, ") AS $${sql}$$")?;
+            }
         }
         Ok(())
     }
@@ -71,6 +71,21 @@ pub struct HiveCatalogOption {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct IcebergCatalogOption {
     pub storage_params: Box<StorageParams>,
+    /// List namespaces and tables through the Iceberg REST catalog,
+    /// tables are discovered by walking `storage_params` if it's not set.
+    pub rest: Option<IcebergRestCatalogOption>,
+}
+
+/// Option for accessing an iceberg REST catalog
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IcebergRestCatalogOption {
+    /// Address of the REST catalog, such as `http://127.0.0.1:8181`.
+    pub uri: String,
+    pub warehouse: Option<String>,
+    /// Bearer token to access the catalog.
+    pub token: Option<String>,
+    /// OAuth2 client credential exchanged for the token, `client_id:client_secret`.
+    pub credential: Option<String>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
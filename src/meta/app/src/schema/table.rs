@@ -249,6 +249,51 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    // Inverted and vector indexes of this table, keyed by index name.
+    pub indexes: BTreeMap<String, TableIndex>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableIndexType {
+    #[default]
+    Inverted,
+    Vector,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+            TableIndexType::Vector => write!(f, "VECTOR"),
+        }
+    }
+}
+
+/// An index defined on some columns of a table, built for each block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableIndex {
+    pub index_type: TableIndexType,
+    pub name: String,
+    // The ids of the indexed columns.
+    pub column_ids: Vec<u32>,
+    // Whether the index is refreshed after new data written.
+    pub sync_creation: bool,
+    // The version of the index, changed when the index is recreated,
+    // so the index files of the old version are not used any more.
+    pub version: String,
+    // Options of the index, such as the tokenizer of an inverted index
+    // or the distance of a vector index.
+    pub options: BTreeMap<String, String>,
 }
 
 impl TableMeta {
@@ -363,6 +408,7 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            indexes: BTreeMap::new(),
         }
     }
 }
@@ -639,6 +685,12 @@ pub struct UpdateTableMetaReq {
     pub deduplicated_label: Option<String>,
 }
 
+/// Update the metas of several tables in one transaction, all or nothing.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
 impl UpsertTableOptionReq {
     pub fn new(
         table_ident: &TableIdent,
@@ -695,6 +747,11 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -21,6 +21,7 @@ use databend_common_meta_app::schema as mt;
 use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_protos::pb;
 
@@ -91,6 +92,12 @@ impl FromToProto for mt::CatalogMeta {
                                 reason: "CatalogMeta.option.catalog_option.iceberg.StorageParams is None".to_string(),
                             })?,
                         )?),
+                        rest: v.rest.map(|rest| IcebergRestCatalogOption {
+                            uri: rest.uri,
+                            warehouse: rest.warehouse,
+                            token: rest.token,
+                            credential: rest.credential,
+                        }),
                     })
                 }
             },
@@ -126,6 +133,12 @@ impl FromToProto for mt::CatalogMeta {
                             ver: VER,
                             min_reader_ver: MIN_READER_VER,
                             storage_params: Some(v.storage_params.to_pb()?),
+                            rest: v.rest.map(|rest| pb::IcebergRestCatalogOption {
+                                uri: rest.uri,
+                                warehouse: rest.warehouse,
+                                token: rest.token,
+                                credential: rest.credential,
+                            }),
                         },
                     )),
                 }),
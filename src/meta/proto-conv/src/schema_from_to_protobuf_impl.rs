@@ -258,6 +258,9 @@ impl FromToProto for ex::TableDataType {
                     }
                     Dt24::VariantT(_) => ex::TableDataType::Variant,
                     Dt24::GeometryT(_) => ex::TableDataType::Geometry,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::UuidT(_) => ex::TableDataType::Uuid,
+                    Dt24::InetT(_) => ex::TableDataType::Inet,
                     Dt24::DecimalT(x) => {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
@@ -324,6 +327,9 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
             TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::Uuid => new_pb_dt24(Dt24::UuidT(pb::Empty {})),
+            TableDataType::Inet => new_pb_dt24(Dt24::InetT(pb::Empty {})),
         };
         Ok(x)
     }
@@ -106,6 +106,192 @@ impl FromToProto for mt::UDFServer {
     }
 }
 
+impl FromToProto for mt::UDFScript {
+    type PB = pb::UdfScript;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UdfScript) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let mut arg_types = Vec::with_capacity(p.arg_types.len());
+        for arg_type in p.arg_types {
+            let arg_type = DataType::from(&TableDataType::from_pb(arg_type)?);
+            arg_types.push(arg_type);
+        }
+        let return_type = DataType::from(&TableDataType::from_pb(p.return_type.ok_or_else(
+            || Incompatible {
+                reason: "UDFScript.return_type can not be None".to_string(),
+            },
+        )?)?);
+
+        Ok(mt::UDFScript {
+            code: p.code,
+            arg_types,
+            return_type,
+            handler: p.handler,
+            language: p.language,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UdfScript, Incompatible> {
+        let mut arg_types = Vec::with_capacity(self.arg_types.len());
+        for arg_type in self.arg_types.iter() {
+            let arg_type = infer_schema_type(arg_type)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()?;
+            arg_types.push(arg_type);
+        }
+        let return_type = infer_schema_type(&self.return_type)
+            .map_err(|e| Incompatible {
+                reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+            })?
+            .to_pb()?;
+
+        Ok(pb::UdfScript {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            code: self.code.clone(),
+            handler: self.handler.clone(),
+            language: self.language.clone(),
+            arg_types,
+            return_type: Some(return_type),
+        })
+    }
+}
+
+impl FromToProto for mt::UDAFServer {
+    type PB = pb::UdafServer;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UdafServer) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let mut arg_types = Vec::with_capacity(p.arg_types.len());
+        for arg_type in p.arg_types {
+            let arg_type = DataType::from(&TableDataType::from_pb(arg_type)?);
+            arg_types.push(arg_type);
+        }
+        let return_type = DataType::from(&TableDataType::from_pb(p.return_type.ok_or_else(
+            || Incompatible {
+                reason: "UDAFServer.return_type can not be None".to_string(),
+            },
+        )?)?);
+
+        Ok(mt::UDAFServer {
+            address: p.address,
+            arg_types,
+            return_type,
+            handler: p.handler,
+            language: p.language,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UdafServer, Incompatible> {
+        let mut arg_types = Vec::with_capacity(self.arg_types.len());
+        for arg_type in self.arg_types.iter() {
+            let arg_type = infer_schema_type(arg_type)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()?;
+            arg_types.push(arg_type);
+        }
+        let return_type = infer_schema_type(&self.return_type)
+            .map_err(|e| Incompatible {
+                reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+            })?
+            .to_pb()?;
+
+        Ok(pb::UdafServer {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            address: self.address.clone(),
+            handler: self.handler.clone(),
+            language: self.language.clone(),
+            arg_types,
+            return_type: Some(return_type),
+        })
+    }
+}
+
+impl FromToProto for mt::UDTF {
+    type PB = pb::Udtf;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::Udtf) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        if p.arg_names.len() != p.arg_types.len() {
+            return Err(Incompatible {
+                reason: "UDTF.arg_names and UDTF.arg_types have different lengths".to_string(),
+            });
+        }
+        if p.return_names.len() != p.return_types.len() {
+            return Err(Incompatible {
+                reason: "UDTF.return_names and UDTF.return_types have different lengths"
+                    .to_string(),
+            });
+        }
+
+        let mut arg_types = Vec::with_capacity(p.arg_types.len());
+        for (name, arg_type) in p.arg_names.into_iter().zip(p.arg_types) {
+            let arg_type = DataType::from(&TableDataType::from_pb(arg_type)?);
+            arg_types.push((name, arg_type));
+        }
+        let mut return_types = Vec::with_capacity(p.return_types.len());
+        for (name, return_type) in p.return_names.into_iter().zip(p.return_types) {
+            let return_type = DataType::from(&TableDataType::from_pb(return_type)?);
+            return_types.push((name, return_type));
+        }
+
+        Ok(mt::UDTF {
+            arg_types,
+            return_types,
+            sql: p.sql,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Udtf, Incompatible> {
+        let mut arg_names = Vec::with_capacity(self.arg_types.len());
+        let mut arg_types = Vec::with_capacity(self.arg_types.len());
+        for (name, arg_type) in self.arg_types.iter() {
+            let arg_type = infer_schema_type(arg_type)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()?;
+            arg_names.push(name.clone());
+            arg_types.push(arg_type);
+        }
+        let mut return_names = Vec::with_capacity(self.return_types.len());
+        let mut return_types = Vec::with_capacity(self.return_types.len());
+        for (name, return_type) in self.return_types.iter() {
+            let return_type = infer_schema_type(return_type)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()?;
+            return_names.push(name.clone());
+            return_types.push(return_type);
+        }
+
+        Ok(pb::Udtf {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            arg_names,
+            arg_types,
+            return_names,
+            return_types,
+            sql: self.sql.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::UserDefinedFunction {
     type PB = pb::UserDefinedFunction;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -120,6 +306,15 @@ impl FromToProto for mt::UserDefinedFunction {
             Some(pb::user_defined_function::Definition::UdfServer(udf_server)) => {
                 mt::UDFDefinition::UDFServer(mt::UDFServer::from_pb(udf_server)?)
             }
+            Some(pb::user_defined_function::Definition::UdfScript(udf_script)) => {
+                mt::UDFDefinition::UDFScript(mt::UDFScript::from_pb(udf_script)?)
+            }
+            Some(pb::user_defined_function::Definition::UdafServer(udaf_server)) => {
+                mt::UDFDefinition::UDAFServer(mt::UDAFServer::from_pb(udaf_server)?)
+            }
+            Some(pb::user_defined_function::Definition::Udtf(udtf)) => {
+                mt::UDFDefinition::UDTF(mt::UDTF::from_pb(udtf)?)
+            }
             None => {
                 return Err(Incompatible {
                     reason: "UserDefinedFunction.definition cannot be None".to_string(),
@@ -146,6 +341,15 @@ impl FromToProto for mt::UserDefinedFunction {
             mt::UDFDefinition::UDFServer(udf_server) => {
                 pb::user_defined_function::Definition::UdfServer(udf_server.to_pb()?)
             }
+            mt::UDFDefinition::UDFScript(udf_script) => {
+                pb::user_defined_function::Definition::UdfScript(udf_script.to_pb()?)
+            }
+            mt::UDFDefinition::UDAFServer(udaf_server) => {
+                pb::user_defined_function::Definition::UdafServer(udaf_server.to_pb()?)
+            }
+            mt::UDFDefinition::UDTF(udtf) => {
+                pb::user_defined_function::Definition::Udtf(udtf.to_pb()?)
+            }
         };
 
         Ok(pb::UserDefinedFunction {
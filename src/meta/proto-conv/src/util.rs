@@ -109,7 +109,18 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (77, "2024-01-22: Remove: allow_anonymous in S3 Config", ),
     (78, "2024-01-29: Refactor: GrantEntry::UserPrivilegeType and ShareGrantEntry::ShareGrantObjectPrivilege use from_bits_truncate deserialize", ),
     (79, "2024-01-31: Add: udf.proto/UserDefinedFunction add created_on field", ),
-    (80, "2024-02-01: Add: Add: datatype.proto/DataType Geometry type")
+    (80, "2024-02-01: Add: Add: datatype.proto/DataType Geometry type"),
+    (81, "2024-02-05: Add: file_format.proto/FileFormatParams add AvroFileFormatParams"),
+    (82, "2024-02-06: Add: file_format.proto/FileFormatParams add OrcFileFormatParams"),
+    (83, "2024-02-07: Add: pipe.proto/PipeInfo"),
+    (84, "2024-02-08: Add: table.proto/TableMeta add indexes, TableIndex"),
+    (85, "2024-02-09: Add: table.proto/TableIndex add index_type"),
+    (86, "2024-02-12: Add: udf.proto/UserDefinedFunction add UDFScript"),
+    (87, "2024-02-14: Add: udf.proto/UserDefinedFunction add UDAFServer"),
+    (88, "2024-02-18: Add: udf.proto/UserDefinedFunction add UDTF"),
+    (89, "2024-02-19: Add: datatype.proto/DataType Interval type"),
+    (90, "2024-02-20: Add: datatype.proto/DataType Uuid and Inet types"),
+    (91, "2024-02-21: Add: catalog.proto/IcebergCatalogOption add rest"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -84,3 +84,14 @@ mod v077_s3_remove_allow_anonymous;
 mod v078_grantentry;
 mod v079_udf_created_on;
 mod v080_geometry_datatype;
+mod v081_avro_format_params;
+mod v082_orc_format_params;
+mod v083_pipe;
+mod v084_table_index;
+mod v085_table_vector_index;
+mod v086_udf_script;
+mod v087_udaf_server;
+mod v088_udtf;
+mod v089_interval_datatype;
+mod v090_uuid_inet_datatype;
+mod v091_iceberg_rest_catalog;
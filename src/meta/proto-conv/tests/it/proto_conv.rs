@@ -213,6 +213,7 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        indexes: btreemap! {},
     }
 }
 
@@ -322,6 +323,7 @@ fn new_catalog_meta() -> databend_common_meta_app::schema::CatalogMeta {
                     ..Default::default()
                 },
             )),
+            rest: None,
         }),
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
     }
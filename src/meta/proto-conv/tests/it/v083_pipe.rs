@@ -0,0 +1,54 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v83_pipe() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 6, 109, 121, 112, 105, 112, 101, 18, 48, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 116,
+        49, 32, 70, 82, 79, 77, 32, 64, 115, 49, 32, 70, 73, 76, 69, 95, 70, 79, 82, 77, 65, 84,
+        32, 61, 32, 40, 84, 89, 80, 69, 32, 61, 32, 67, 83, 86, 41, 24, 1, 42, 7, 108, 111, 97,
+        100, 32, 116, 49, 50, 23, 50, 48, 50, 52, 45, 48, 50, 45, 48, 55, 32, 49, 48, 58, 48, 48,
+        58, 48, 57, 32, 85, 84, 67, 58, 23, 50, 48, 50, 52, 45, 48, 50, 45, 48, 55, 32, 49, 48, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 83, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::principal::PipeInfo {
+        name: "mypipe".to_string(),
+        copy_stmt: "COPY INTO t1 FROM @s1 FILE_FORMAT = (TYPE = CSV)".to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "load t1".to_string(),
+        create_on: Utc.with_ymd_and_hms(2024, 2, 7, 10, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2024, 2, 7, 10, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 83, want())
+}
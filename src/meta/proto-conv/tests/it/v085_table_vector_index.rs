@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema::TableIndexType;
+use maplit::btreemap;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v85_table_vector_index() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 4, 105, 100, 120, 49, 18, 1, 3, 34, 4, 118, 101, 114, 49, 42, 16, 10, 6, 109, 101, 116,
+        114, 105, 99, 18, 6, 99, 111, 115, 105, 110, 101, 48, 1, 160, 6, 85, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::schema::TableIndex {
+        index_type: TableIndexType::Vector,
+        name: "idx1".to_string(),
+        column_ids: vec![3],
+        sync_creation: false,
+        version: "ver1".to_string(),
+        options: btreemap! {"metric".to_string() => "cosine".to_string()},
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 85, want())
+}
@@ -0,0 +1,65 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDFScript;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v86_udf_wasm() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 8, 119, 97, 115, 109, 95, 97, 100, 100, 18, 21, 84, 104, 105, 115, 32, 105, 115, 32,
+        97, 32, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 50, 84, 10, 8, 0, 97, 115,
+        109, 1, 0, 0, 0, 18, 3, 97, 100, 100, 26, 4, 119, 97, 115, 109, 34, 17, 154, 2, 8, 58, 0,
+        160, 6, 86, 168, 6, 24, 160, 6, 86, 168, 6, 24, 34, 17, 154, 2, 8, 58, 0, 160, 6, 86, 168,
+        6, 24, 160, 6, 86, 168, 6, 24, 42, 17, 154, 2, 8, 66, 0, 160, 6, 86, 168, 6, 24, 160, 6,
+        86, 168, 6, 24, 160, 6, 86, 168, 6, 24, 42, 23, 50, 48, 50, 51, 45, 49, 50, 45, 49, 53, 32,
+        48, 49, 58, 50, 54, 58, 48, 57, 32, 85, 84, 67, 160, 6, 86, 168, 6, 24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "wasm_add".to_string(),
+        description: "This is a description".to_string(),
+        definition: UDFDefinition::UDFScript(UDFScript {
+            code: vec![0, 97, 115, 109, 1, 0, 0, 0],
+            handler: "add".to_string(),
+            language: "wasm".to_string(),
+            arg_types: vec![
+                DataType::Number(NumberDataType::Int32),
+                DataType::Number(NumberDataType::Int32),
+            ],
+            return_type: DataType::Number(NumberDataType::Int64),
+        }),
+        created_on: DateTime::<Utc>::from_timestamp(1702603569, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 86, want())
+}
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v90_schema() -> anyhow::Result<()> {
+    let schema_v90 = vec![
+        10, 28, 10, 1, 97, 26, 17, 154, 2, 8, 34, 0, 160, 6, 90, 168, 6, 24, 160, 6, 90, 168, 6,
+        24, 160, 6, 90, 168, 6, 24, 10, 22, 10, 1, 117, 26, 9, 138, 3, 0, 160, 6, 90, 168, 6, 24,
+        32, 1, 160, 6, 90, 168, 6, 24, 10, 22, 10, 1, 105, 26, 9, 146, 3, 0, 160, 6, 90, 168, 6,
+        24, 32, 2, 160, 6, 90, 168, 6, 24, 10, 32, 10, 2, 110, 117, 26, 18, 178, 2, 9, 138, 3, 0,
+        160, 6, 90, 168, 6, 24, 160, 6, 90, 168, 6, 24, 32, 3, 160, 6, 90, 168, 6, 24, 10, 32, 10,
+        2, 97, 105, 26, 18, 186, 2, 9, 146, 3, 0, 160, 6, 90, 168, 6, 24, 160, 6, 90, 168, 6, 24,
+        32, 4, 160, 6, 90, 168, 6, 24, 24, 5, 160, 6, 90, 168, 6, 24,
+    ];
+
+    let fields = vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt64)),
+        TableField::new("u", TableDataType::Uuid),
+        TableField::new("i", TableDataType::Inet),
+        TableField::new("nu", TableDataType::Nullable(Box::new(TableDataType::Uuid))),
+        TableField::new("ai", TableDataType::Array(Box::new(TableDataType::Inet))),
+    ];
+    let want = || TableSchema::new(fields.clone());
+    common::test_load_old(func_name!(), schema_v90.as_slice(), 90, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -324,6 +324,9 @@ pub enum TypeName {
     },
     Variant,
     Geometry,
+    Interval,
+    Uuid,
+    Inet,
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -904,6 +907,15 @@ impl Display for TypeName {
             TypeName::Geometry => {
                 write!(f, "GEOMETRY")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
+            TypeName::Uuid => {
+                write!(f, "UUID")?;
+            }
+            TypeName::Inet => {
+                write!(f, "INET")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -1033,6 +1033,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let mut children = Vec::new();
         self.visit_table_ref(&insert.catalog, &insert.database, &insert.table);
         children.push(self.children.pop().unwrap());
+        if let Some(partition) = &insert.partition {
+            let partition_format_ctx = AstFormatContext::new(partition.to_string());
+            children.push(FormatTreeNode::new(partition_format_ctx));
+        }
         if !insert.columns.is_empty() {
             let mut columns_children = Vec::with_capacity(insert.columns.len());
             for column in insert.columns.iter() {
@@ -1555,6 +1559,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddPartition { partition, .. } => {
+                let action_name = format!("Action Add {partition}");
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropPartition { partition, .. } => {
+                let action_name = format!("Action Drop {partition}");
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::RecoverPartitions => {
+                let action_name = "Action RecoverPartitions".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1710,6 +1729,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_stream(&mut self, stmt: &'ast AlterStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_stream_point(&stmt.stream_point);
+        let point_child = self.children.pop().unwrap();
+
+        let name = "AlterStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, point_child]);
+        self.children.push(node);
+    }
+
     fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
         let child = self.children.pop().unwrap();
@@ -1783,6 +1814,106 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            index_child,
+            table_child,
+            columns_child,
+        ]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_inverted_index(&mut self, stmt: &'ast RefreshInvertedIndexStmt) {
+        let mut children = Vec::new();
+        self.visit_index_ref(&stmt.index_name);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        if let Some(limit) = stmt.limit {
+            let name = format!("Refresh index limit {}", limit);
+            let limit_format_ctx = AstFormatContext::new(name);
+            children.push(FormatTreeNode::new(limit_format_ctx));
+        }
+
+        let name = "RefreshInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_create_vector_index(&mut self, stmt: &'ast CreateVectorIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        self.visit_identifier(&stmt.column);
+        let column_child = self.children.pop().unwrap();
+
+        let name = "CreateVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node =
+            FormatTreeNode::with_children(format_ctx, vec![index_child, table_child, column_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_vector_index(&mut self, stmt: &'ast DropVectorIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_vector_index(&mut self, stmt: &'ast RefreshVectorIndexStmt) {
+        let mut children = Vec::new();
+        self.visit_index_ref(&stmt.index_name);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        if let Some(limit) = stmt.limit {
+            let name = format!("Refresh index limit {}", limit);
+            let limit_format_ctx = AstFormatContext::new(name);
+            children.push(FormatTreeNode::new(limit_format_ctx));
+        }
+
+        let name = "RefreshVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2130,6 +2261,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 address,
                 handler,
                 language,
+            }
+            | UDFDefinition::UDAFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language,
             } => {
                 if !arg_types.is_empty() {
                     let mut arg_types_children = Vec::with_capacity(arg_types.len());
@@ -2163,6 +2301,81 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
             }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                handler,
+                language,
+                ..
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for arg_type in arg_types.iter() {
+                        let type_format_ctx = AstFormatContext::new(format!("DataType {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {return_type}"));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+
+                let handler_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptHandler {handler}"));
+                children.push(FormatTreeNode::new(handler_format_ctx));
+
+                let language_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptLanguage {language}"));
+                children.push(FormatTreeNode::new(language_format_ctx));
+            }
+            UDFDefinition::UDTF {
+                arg_types,
+                return_types,
+                sql,
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for (name, arg_type) in arg_types.iter() {
+                        let type_format_ctx =
+                            AstFormatContext::new(format!("UdfParameter {name} {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let mut return_types_children = Vec::with_capacity(return_types.len());
+                for (name, return_type) in return_types.iter() {
+                    let type_format_ctx =
+                        AstFormatContext::new(format!("UdfReturnColumn {name} {return_type}"));
+                    return_types_children.push(FormatTreeNode::new(type_format_ctx));
+                }
+                let return_format_ctx = AstFormatContext::with_children(
+                    "UdfReturnTypes".to_string(),
+                    return_types_children.len(),
+                );
+                children.push(FormatTreeNode::with_children(
+                    return_format_ctx,
+                    return_types_children,
+                ));
+
+                let sql_format_ctx = AstFormatContext::new(format!("UdfDefinition {sql}"));
+                children.push(FormatTreeNode::new(sql_format_ctx));
+            }
         }
 
         if let Some(description) = &stmt.description {
@@ -2226,6 +2439,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 address,
                 handler,
                 language,
+            }
+            | UDFDefinition::UDAFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language,
             } => {
                 if !arg_types.is_empty() {
                     let mut arg_types_children = Vec::with_capacity(arg_types.len());
@@ -2259,6 +2479,81 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
             }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                handler,
+                language,
+                ..
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for arg_type in arg_types.iter() {
+                        let type_format_ctx = AstFormatContext::new(format!("DataType {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {return_type}"));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+
+                let handler_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptHandler {handler}"));
+                children.push(FormatTreeNode::new(handler_format_ctx));
+
+                let language_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptLanguage {language}"));
+                children.push(FormatTreeNode::new(language_format_ctx));
+            }
+            UDFDefinition::UDTF {
+                arg_types,
+                return_types,
+                sql,
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for (name, arg_type) in arg_types.iter() {
+                        let type_format_ctx =
+                            AstFormatContext::new(format!("UdfParameter {name} {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let mut return_types_children = Vec::with_capacity(return_types.len());
+                for (name, return_type) in return_types.iter() {
+                    let type_format_ctx =
+                        AstFormatContext::new(format!("UdfReturnColumn {name} {return_type}"));
+                    return_types_children.push(FormatTreeNode::new(type_format_ctx));
+                }
+                let return_format_ctx = AstFormatContext::with_children(
+                    "UdfReturnTypes".to_string(),
+                    return_types_children.len(),
+                );
+                children.push(FormatTreeNode::with_children(
+                    return_format_ctx,
+                    return_types_children,
+                ));
+
+                let sql_format_ctx = AstFormatContext::new(format!("UdfDefinition {sql}"));
+                children.push(FormatTreeNode::new(sql_format_ctx));
+            }
         }
 
         if let Some(description) = &stmt.description {
@@ -3090,6 +3385,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
     fn visit_stream_point(&mut self, point: &'ast StreamPoint) {
         match point {
             StreamPoint::AtStream { database, name } => self.visit_table_ref(&None, database, name),
+            StreamPoint::AtPoint(point) => self.visit_time_travel_point(point),
         }
     }
 
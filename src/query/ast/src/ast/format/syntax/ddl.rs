@@ -239,6 +239,11 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        action @ (AlterTableAction::AddPartition { .. }
+        | AlterTableAction::DropPartition { .. }
+        | AlterTableAction::RecoverPartitions) => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
     }
 }
 
@@ -367,6 +372,8 @@ pub(crate) fn pretty_create_stream(stmt: CreateStreamStmt) -> RcDoc<'static> {
                             .append(RcDoc::text(name.to_string())),
                     )
                     .append(RcDoc::text(")"))
+            } else if let Some(point) = stmt.stream_point {
+                RcDoc::text(point.to_string())
             } else {
                 RcDoc::nil()
             },
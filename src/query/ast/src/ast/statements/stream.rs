@@ -20,6 +20,7 @@ use databend_common_meta_app::schema::CreateOption;
 use crate::ast::write_dot_separated_list;
 use crate::ast::Identifier;
 use crate::ast::ShowLimit;
+use crate::ast::TimeTravelPoint;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum StreamPoint {
@@ -27,6 +28,7 @@ pub enum StreamPoint {
         database: Option<Identifier>,
         name: Identifier,
     },
+    AtPoint(TimeTravelPoint),
 }
 
 impl Display for StreamPoint {
@@ -37,6 +39,12 @@ impl Display for StreamPoint {
                 write_dot_separated_list(f, database.iter().chain(Some(name)))?;
                 write!(f, ")")
             }
+            StreamPoint::AtPoint(TimeTravelPoint::Snapshot(sid)) => {
+                write!(f, " AT (SNAPSHOT => '{sid}')")
+            }
+            StreamPoint::AtPoint(TimeTravelPoint::Timestamp(ts)) => {
+                write!(f, " AT (TIMESTAMP => {ts})")
+            }
         }
     }
 }
@@ -88,6 +96,32 @@ impl Display for CreateStreamStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterStreamStmt {
+    pub if_exists: bool,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub stream: Identifier,
+    pub stream_point: StreamPoint,
+}
+
+impl Display for AlterStreamStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER STREAM ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(self.database.iter())
+                .chain(Some(&self.stream)),
+        )?;
+        write!(f, " SET{}", self.stream_point)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DropStreamStmt {
     pub if_exists: bool,
@@ -26,6 +26,7 @@ use crate::ast::write_comma_separated_map;
 use crate::ast::write_dot_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::Literal;
 use crate::ast::Query;
 use crate::ast::TableReference;
 use crate::ast::TimeTravelPoint;
@@ -373,6 +374,16 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    AddPartition {
+        if_not_exists: bool,
+        partition: PartitionSpec,
+        location: Option<String>,
+    },
+    DropPartition {
+        if_exists: bool,
+        partition: PartitionSpec,
+    },
+    RecoverPartitions,
 }
 
 impl Display for AlterTableAction {
@@ -427,11 +438,57 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::AddPartition {
+                if_not_exists,
+                partition,
+                location,
+            } => {
+                write!(f, "ADD ")?;
+                if *if_not_exists {
+                    write!(f, "IF NOT EXISTS ")?;
+                }
+                write!(f, "{partition}")?;
+                if let Some(location) = location {
+                    write!(f, " LOCATION '{location}'")?;
+                }
+            }
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => {
+                write!(f, "DROP ")?;
+                if *if_exists {
+                    write!(f, "IF EXISTS ")?;
+                }
+                write!(f, "{partition}")?;
+            }
+            AlterTableAction::RecoverPartitions => {
+                write!(f, "RECOVER PARTITIONS")?;
+            }
         };
         Ok(())
     }
 }
 
+/// Values of the partition columns, such as `PARTITION (dt = '2024-01-01', hour = 1)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartitionSpec {
+    pub values: Vec<(Identifier, Literal)>,
+}
+
+impl Display for PartitionSpec {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "PARTITION (")?;
+        for (i, (column, value)) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{column} = {value}")?;
+        }
+        write!(f, ")")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AddColumnOption {
     End,
@@ -35,6 +35,25 @@ pub enum UDFDefinition {
         handler: String,
         language: String,
     },
+    UDFScript {
+        arg_types: Vec<TypeName>,
+        return_type: TypeName,
+        code: String,
+        handler: String,
+        language: String,
+    },
+    UDAFServer {
+        arg_types: Vec<TypeName>,
+        return_type: TypeName,
+        address: String,
+        handler: String,
+        language: String,
+    },
+    UDTF {
+        arg_types: Vec<(Identifier, TypeName)>,
+        return_types: Vec<(Identifier, TypeName)>,
+        sql: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -69,6 +88,13 @@ impl Display for UDFDefinition {
                 address,
                 handler,
                 language,
+            }
+            | UDFDefinition::UDAFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language,
             } => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, arg_types)?;
@@ -77,6 +103,37 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
                 )?;
             }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}' AS '{code}'"
+                )?;
+            }
+            UDFDefinition::UDTF {
+                arg_types,
+                return_types,
+                sql,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(
+                    f,
+                    arg_types.iter().map(|(name, ty)| format!("{name} {ty}")),
+                )?;
+                write!(f, ") RETURNS TABLE (")?;
+                write_comma_separated_list(
+                    f,
+                    return_types.iter().map(|(name, ty)| format!("{name} {ty}")),
+                )?;
+                write!(f, ") AS $${sql}$$")?;
+            }
         }
         Ok(())
     }
@@ -88,6 +145,9 @@ impl Display for CreateUDFStmt {
         if let CreateOption::CreateOrReplace = self.create_option {
             write!(f, " OR REPLACE")?;
         }
+        if let UDFDefinition::UDAFServer { .. } = self.definition {
+            write!(f, " AGGREGATE")?;
+        }
         write!(f, " FUNCTION")?;
         if let CreateOption::CreateIfNotExists(if_not_exists) = self.create_option {
             if if_not_exists {
@@ -1133,6 +1133,18 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             unit,
         },
     );
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
     let date_trunc = map(
         rule! {
             DATE_TRUNC ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ ")"
@@ -1203,6 +1215,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
             | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
@@ -1452,6 +1465,13 @@ pub fn literal_string(i: Input) -> IResult<String> {
     )(i)
 }
 
+/// Parses a `$$ ... $$` string, the text between the delimiters is taken as is.
+pub fn code_string(i: Input) -> IResult<String> {
+    map(rule! { LiteralCodeString }, |token| {
+        token.text()[2..token.text().len() - 2].to_string()
+    })(i)
+}
+
 pub fn literal_string_eq_ignore_case(s: &str) -> impl FnMut(Input) -> IResult<()> + '_ {
     move |i| {
         map_res(rule! { QuotedString }, |token| {
@@ -1587,6 +1607,9 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
+    let ty_uuid = value(TypeName::Uuid, rule! { UUID });
+    let ty_inet = value(TypeName::Inet, rule! { INET | IPV6 });
     map_res(
         alt((
             rule! {
@@ -1616,6 +1639,9 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_string
             | #ty_variant
             | #ty_geometry
+            | #ty_interval
+            | #ty_uuid
+            | #ty_inet
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
@@ -212,15 +212,26 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule! {
             INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #dot_separated_idents_1_to_3
+            ~ #partition_spec?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
         },
-        |(_, opt_hints, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(
+            _,
+            opt_hints,
+            overwrite,
+            _,
+            (catalog, database, table),
+            partition,
+            opt_columns,
+            source,
+        )| {
             Statement::Insert(InsertStmt {
                 hints: opt_hints,
                 catalog,
                 database,
                 table,
+                partition,
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
@@ -786,6 +797,18 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             })
         },
     );
+    let msck_repair_table = map(
+        rule! {
+            MSCK ~ REPAIR ~ TABLE ~ #table_reference_only
+        },
+        |(_, _, _, table_reference)| {
+            Statement::AlterTable(AlterTableStmt {
+                if_exists: false,
+                table_reference,
+                action: AlterTableAction::RecoverPartitions,
+            })
+        },
+    );
     let rename_table = map(
         rule! {
             RENAME ~ TABLE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ TO ~ #dot_separated_idents_1_to_3
@@ -1007,6 +1030,140 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_inverted_index = map_res(
+        rule! {
+            CREATE ~ (OR ~ REPLACE)? ~ ASYNC? ~ INVERTED ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ #table_option
+        },
+        |(
+            _,
+            opt_or_replace,
+            opt_async,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            index_options,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                create_option,
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                sync_creation: opt_async.is_none(),
+                index_options,
+            }))
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let refresh_inverted_index = map(
+        rule! {
+            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+                index_name,
+                catalog,
+                database,
+                table,
+                limit: opt_limit.map(|(_, limit)| limit),
+            })
+        },
+    );
+
+    let create_vector_index = map_res(
+        rule! {
+            CREATE ~ (OR ~ REPLACE)? ~ ASYNC? ~ VECTOR ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#ident ~ ^")"
+            ~ #table_option
+        },
+        |(
+            _,
+            opt_or_replace,
+            opt_async,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+            index_options,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateVectorIndex(CreateVectorIndexStmt {
+                create_option,
+                index_name,
+                catalog,
+                database,
+                table,
+                column,
+                sync_creation: opt_async.is_none(),
+                index_options,
+            }))
+        },
+    );
+
+    let drop_vector_index = map(
+        rule! {
+            DROP ~ VECTOR ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropVectorIndex(DropVectorIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let refresh_vector_index = map(
+        rule! {
+            REFRESH ~ VECTOR ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshVectorIndex(RefreshVectorIndexStmt {
+                index_name,
+                catalog,
+                database,
+                table,
+                limit: opt_limit.map(|(_, limit)| limit),
+            })
+        },
+    );
+
     let create_virtual_column = map_res(
         rule! {
             CREATE ~ (OR ~ REPLACE)? ~ VIRTUAL ~ COLUMN ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #dot_separated_idents_1_to_3
@@ -1248,6 +1405,23 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             }))
         },
     );
+    let create_udaf = map_res(
+        rule! {
+            CREATE ~ (OR ~ REPLACE)? ~ AGGREGATE ~ ^FUNCTION ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ #udaf_definition
+            ~ ( DESC ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, udf_name, definition, opt_description)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateUDF(CreateUDFStmt {
+                create_option,
+                udf_name,
+                description: opt_description.map(|(_, _, description)| description),
+                definition,
+            }))
+        },
+    );
     let drop_udf = map(
         rule! {
             DROP ~ FUNCTION ~ ( IF ~ ^EXISTS )? ~ #ident
@@ -1874,6 +2048,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ WORK? });
+    let abort = value(Statement::Abort, rule! { (ABORT | ROLLBACK) ~ WORK? });
+
     let statement_body = alt((
         // query, explain,show
         rule!(
@@ -1890,6 +2068,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #show_locks : "`SHOW LOCKS [IN ACCOUNT] [WHERE ...]`"
             | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
             | #vacuum_temp_files : "VACUUM TEMPORARY FILES [RETAIN number SECONDS|DAYS] [LIMIT number]"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT [WORK]`"
+            | #abort : "`(ABORT | ROLLBACK) [WORK]`"
         ),
         // database
         rule!(
@@ -1938,6 +2119,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
+            | #msck_repair_table : "`MSCK REPAIR TABLE [<database>.]<table>`"
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table>`"
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
@@ -1956,6 +2138,12 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE [OR REPLACE] [ASYNC] INVERTED INDEX [IF NOT EXISTS] <index> ON <table> (<column>, ...) [tokenizer = '<tokenizer>']`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON <table>`"
+            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON <table> [LIMIT <limit>]`"
+            | #create_vector_index: "`CREATE [OR REPLACE] [ASYNC] VECTOR INDEX [IF NOT EXISTS] <index> ON <table> (<column>) [metric = 'cosine' | 'l2'] [m = <m>] [ef_construction = <ef>]`"
+            | #drop_vector_index: "`DROP VECTOR INDEX [IF EXISTS] <index> ON <table>`"
+            | #refresh_vector_index: "`REFRESH VECTOR INDEX <index> ON <table> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1972,7 +2160,8 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
-            | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
+            | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> {ADDRESS=<udf_server_address> | AS <code>}} [DESC = <description>]`"
+            | #create_udaf : "`CREATE [OR REPLACE] AGGREGATE FUNCTION [IF NOT EXISTS] <name> (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address> [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
             | #set_role: "`SET [DEFAULT] ROLE <role>`"
@@ -2862,17 +3051,66 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_partition = map(
+        rule! {
+            ADD ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #partition_spec ~ ( LOCATION ~ ^#literal_string )?
+        },
+        |(_, opt_if_not_exists, partition, opt_location)| AlterTableAction::AddPartition {
+            if_not_exists: opt_if_not_exists.is_some(),
+            partition,
+            location: opt_location.map(|(_, location)| location),
+        },
+    );
+
+    let drop_partition = map(
+        rule! {
+            DROP ~ ( IF ~ ^EXISTS )? ~ #partition_spec
+        },
+        |(_, opt_if_exists, partition)| AlterTableAction::DropPartition {
+            if_exists: opt_if_exists.is_some(),
+            partition,
+        },
+    );
+
+    let recover_partitions = map(
+        rule! {
+            RECOVER ~ PARTITIONS
+        },
+        |(_, _)| AlterTableAction::RecoverPartitions,
+    );
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
         | #rename_table
         | #rename_column
+        | #add_partition
+        | #drop_partition
         | #add_column
         | #drop_column
         | #modify_column
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #recover_partitions
+    )(i)
+}
+
+pub fn partition_spec(i: Input) -> IResult<PartitionSpec> {
+    map(
+        rule! {
+            PARTITION ~ ^"(" ~ ^#comma_separated_list1(partition_value) ~ ^")"
+        },
+        |(_, _, values, _)| PartitionSpec { values },
+    )(i)
+}
+
+fn partition_value(i: Input) -> IResult<(Identifier, Literal)> {
+    map(
+        rule! {
+            #ident ~ ^"=" ~ ^#literal
+        },
+        |(column, _, value)| (column, value),
     )(i)
 }
 
@@ -3532,12 +3770,72 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
         },
     );
 
+    let udf_script = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
+            ~ RETURNS ~ #udf_arg_type
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ AS ~ ^#literal_string
+        },
+        |(_, arg_types, _, _, return_type, _, language, _, _, handler, _, code)| {
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language: language.to_string(),
+            }
+        },
+    );
+
+    let udtf = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udtf_column) ~ ")"
+            ~ RETURNS ~ TABLE ~ "(" ~ #comma_separated_list1(udtf_column) ~ ")"
+            ~ AS ~ ^#code_string
+        },
+        |(_, arg_types, _, _, _, _, return_types, _, _, sql)| UDFDefinition::UDTF {
+            arg_types,
+            return_types,
+            sql,
+        },
+    );
+
     rule!(
         #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
+        | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> AS <code>"
+        | #udtf: "(<parameter> <arg_type>, ...) RETURNS TABLE (<column> <column_type>, ...) AS $$<query>$$"
         | #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
     )(i)
 }
 
+fn udtf_column(i: Input) -> IResult<(Identifier, TypeName)> {
+    rule! { #ident ~ #udf_arg_type }
+    (i)
+}
+
+pub fn udaf_definition(i: Input) -> IResult<UDFDefinition> {
+    map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
+            ~ RETURNS ~ #udf_arg_type
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ ADDRESS ~ ^"=" ~ ^#literal_string
+        },
+        |(_, arg_types, _, _, return_type, _, language, _, _, handler, _, _, address)| {
+            UDFDefinition::UDAFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language: language.to_string(),
+            }
+        },
+    )(i)
+}
+
 pub fn merge_update_expr(i: Input) -> IResult<MergeUpdateExpr> {
     map(
         rule! { #dot_separated_idents_1_to_2 ~ "=" ~ ^#expr },
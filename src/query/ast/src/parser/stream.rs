@@ -15,6 +15,7 @@
 use nom::combinator::map;
 
 use super::statement::parse_create_option;
+use crate::ast::AlterStreamStmt;
 use crate::ast::CreateStreamStmt;
 use crate::ast::DescribeStreamStmt;
 use crate::ast::DropStreamStmt;
@@ -23,6 +24,7 @@ use crate::ast::Statement;
 use crate::ast::StreamPoint;
 use crate::parser::expr::literal_bool;
 use crate::parser::expr::literal_string;
+use crate::parser::query::travel_point;
 use crate::parser::statement::show_limit;
 use crate::parser::token::TokenKind::*;
 use crate::rule;
@@ -34,7 +36,8 @@ use crate::Input;
 
 pub fn stream_table(i: Input) -> IResult<Statement> {
     rule!(
-         #create_stream: "`CREATE [OR REPLACE] STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [<stream_point>] [COMMENT = '<string_literal>']`"
+         #create_stream: "`CREATE [OR REPLACE] STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [AT ({STREAM | SNAPSHOT | TIMESTAMP} => <point>)] [APPEND_ONLY = <bool>] [COMMENT = '<string_literal>']`"
+         | #alter_stream: "`ALTER STREAM [IF EXISTS] [<database>.]<stream> SET AT ({STREAM | SNAPSHOT | TIMESTAMP} => <point>)`"
          | #drop_stream: "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
          | #show_streams: "`SHOW [FULL] STREAMS [FROM <database>] [<show_limit>]`"
          | #describe_stream: "`DESCRIBE STREAM [<database>.]<stream>`"
@@ -83,6 +86,24 @@ fn create_stream(i: Input) -> IResult<Statement> {
     )(i)
 }
 
+fn alter_stream(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            ALTER ~ STREAM ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+            ~ SET ~ #stream_point
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream), _, stream_point)| {
+            Statement::AlterStream(AlterStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                stream_point,
+            })
+        },
+    )(i)
+}
+
 fn drop_stream(i: Input) -> IResult<Statement> {
     map(
         rule! {
@@ -104,8 +125,11 @@ fn stream_point(i: Input) -> IResult<StreamPoint> {
         rule! { AT ~ "(" ~ STREAM ~ "=>" ~  #dot_separated_idents_1_to_2 ~ ")" },
         |(_, _, _, _, (database, name), _)| StreamPoint::AtStream { database, name },
     );
+    let at_point = map(rule! { AT ~ #travel_point }, |(_, point)| {
+        StreamPoint::AtPoint(point)
+    });
     rule!(
-        #at_stream
+        #at_stream | #at_point
     )(i)
 }
 
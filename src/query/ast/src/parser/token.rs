@@ -151,6 +151,9 @@ pub enum TokenKind {
     #[regex(r#"'([^'\\]|\\.|'')*'"#)]
     QuotedString,
 
+    #[regex(r"\$\$([^\$]|(\$[^\$]))*\$\$")]
+    LiteralCodeString,
+
     #[regex(r#"@([^\s`;'"()]|\\\s|\\'|\\"|\\\\)+"#)]
     AtString,
 
@@ -315,6 +318,8 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
     #[token("ALL", ignore(ascii_case))]
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
@@ -323,6 +328,8 @@ pub enum TokenKind {
     ADD,
     #[token("AFTER", ignore(ascii_case))]
     AFTER,
+    #[token("AGGREGATE", ignore(ascii_case))]
+    AGGREGATE,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
@@ -361,6 +368,8 @@ pub enum TokenKind {
     ATTACH,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -411,6 +420,8 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -629,6 +640,8 @@ pub enum TokenKind {
     IN,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
+    #[token("INET", ignore(ascii_case))]
+    INET,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -649,6 +662,10 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
+    #[token("IPV6", ignore(ascii_case))]
+    IPV6,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -669,6 +686,8 @@ pub enum TokenKind {
     KILL,
     #[token("LATERAL", ignore(ascii_case))]
     LATERAL,
+    #[token("LOCATION", ignore(ascii_case))]
+    LOCATION,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
     #[token("LOCKS", ignore(ascii_case))]
@@ -720,6 +739,8 @@ pub enum TokenKind {
     MONTH,
     #[token("MODIFY", ignore(ascii_case))]
     MODIFY,
+    #[token("MSCK", ignore(ascii_case))]
+    MSCK,
     #[token("MATERIALIZED", ignore(ascii_case))]
     MATERIALIZED,
     #[token("NON_DISPLAY", ignore(ascii_case))]
@@ -770,6 +791,8 @@ pub enum TokenKind {
     OVERWRITE,
     #[token("PARTITION", ignore(ascii_case))]
     PARTITION,
+    #[token("PARTITIONS", ignore(ascii_case))]
+    PARTITIONS,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
     #[token("PASSWORD", ignore(ascii_case))]
@@ -826,6 +849,8 @@ pub enum TokenKind {
     READ_ONLY,
     #[token("RECLUSTER", ignore(ascii_case))]
     RECLUSTER,
+    #[token("RECOVER", ignore(ascii_case))]
+    RECOVER,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
@@ -836,6 +861,8 @@ pub enum TokenKind {
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
+    #[token("REPAIR", ignore(ascii_case))]
+    REPAIR,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
     #[token("RETURN_FAILED_ONLY", ignore(ascii_case))]
@@ -1014,6 +1041,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -1056,6 +1085,8 @@ pub enum TokenKind {
     USERS,
     #[token("USING", ignore(ascii_case))]
     USING,
+    #[token("UUID", ignore(ascii_case))]
+    UUID,
     #[token("VACUUM", ignore(ascii_case))]
     VACUUM,
     #[token("VALUES", ignore(ascii_case))]
@@ -1068,6 +1099,8 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
@@ -1114,6 +1147,8 @@ pub enum TokenKind {
     CUBE,
     #[token("ROLLUP", ignore(ascii_case))]
     ROLLUP,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("INDEXES", ignore(ascii_case))]
     INDEXES,
     #[token("ADDRESS", ignore(ascii_case))]
@@ -1124,6 +1159,8 @@ pub enum TokenKind {
     READ,
     #[token("WRITE", ignore(ascii_case))]
     WRITE,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
     #[token("UDF", ignore(ascii_case))]
     UDF,
     #[token("HANDLER", ignore(ascii_case))]
@@ -567,6 +567,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
 
+    fn visit_alter_stream(&mut self, _stmt: &'ast AlterStreamStmt) {}
+
     fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
 
     fn visit_show_streams(&mut self, _stmt: &'ast ShowStreamsStmt) {}
@@ -578,6 +580,18 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+
+    fn visit_refresh_inverted_index(&mut self, _stmt: &'ast RefreshInvertedIndexStmt) {}
+
+    fn visit_create_vector_index(&mut self, _stmt: &'ast CreateVectorIndexStmt) {}
+
+    fn visit_drop_vector_index(&mut self, _stmt: &'ast DropVectorIndexStmt) {}
+
+    fn visit_refresh_vector_index(&mut self, _stmt: &'ast RefreshVectorIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &'ast AlterVirtualColumnStmt) {}
@@ -702,6 +716,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_task(&mut self, _stmt: &'ast AlterTaskStmt) {}
 
+    fn visit_create_pipe(&mut self, _stmt: &'ast CreatePipeStmt) {}
+
+    fn visit_alter_pipe(&mut self, _stmt: &'ast AlterPipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &'ast DropPipeStmt) {}
+
+    fn visit_describe_pipe(&mut self, _stmt: &'ast DescribePipeStmt) {}
+
     fn visit_with(&mut self, with: &'ast With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter() {
@@ -581,6 +581,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
 
+    fn visit_alter_stream(&mut self, _stmt: &mut AlterStreamStmt) {}
+
     fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
 
     fn visit_show_streams(&mut self, _stmt: &mut ShowStreamsStmt) {}
@@ -592,6 +594,18 @@ pub trait VisitorMut: Sized {
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
+    fn visit_refresh_inverted_index(&mut self, _stmt: &mut RefreshInvertedIndexStmt) {}
+
+    fn visit_create_vector_index(&mut self, _stmt: &mut CreateVectorIndexStmt) {}
+
+    fn visit_drop_vector_index(&mut self, _stmt: &mut DropVectorIndexStmt) {}
+
+    fn visit_refresh_vector_index(&mut self, _stmt: &mut RefreshVectorIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &mut AlterVirtualColumnStmt) {}
@@ -716,6 +730,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_task(&mut self, _stmt: &mut AlterTaskStmt) {}
 
+    fn visit_create_pipe(&mut self, _stmt: &mut CreatePipeStmt) {}
+
+    fn visit_alter_pipe(&mut self, _stmt: &mut AlterPipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &mut DropPipeStmt) {}
+
+    fn visit_describe_pipe(&mut self, _stmt: &mut DescribePipeStmt) {}
+
     fn visit_with(&mut self, with: &mut With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter_mut() {
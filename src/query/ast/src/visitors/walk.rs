@@ -303,6 +303,7 @@ pub fn walk_stream_point<'a, V: Visitor<'a>>(visitor: &mut V, point: &'a StreamP
 
             visitor.visit_identifier(name);
         }
+        StreamPoint::AtPoint(point) => visitor.visit_time_travel_point(point),
     }
 }
 
@@ -430,12 +431,19 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
         Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
+        Statement::AlterStream(stmt) => visitor.visit_alter_stream(stmt),
         Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
         Statement::ShowStreams(stmt) => visitor.visit_show_streams(stmt),
         Statement::DescribeStream(stmt) => visitor.visit_describe_stream(stmt),
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::RefreshIndex(stmt) => visitor.visit_refresh_index(stmt),
+        Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
+        Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
+        Statement::RefreshInvertedIndex(stmt) => visitor.visit_refresh_inverted_index(stmt),
+        Statement::CreateVectorIndex(stmt) => visitor.visit_create_vector_index(stmt),
+        Statement::DropVectorIndex(stmt) => visitor.visit_drop_vector_index(stmt),
+        Statement::RefreshVectorIndex(stmt) => visitor.visit_refresh_vector_index(stmt),
         Statement::CreateVirtualColumn(stmt) => visitor.visit_create_virtual_column(stmt),
         Statement::AlterVirtualColumn(stmt) => visitor.visit_alter_virtual_column(stmt),
         Statement::DropVirtualColumn(stmt) => visitor.visit_drop_virtual_column(stmt),
@@ -525,9 +533,12 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::DropConnection(stmt) => visitor.visit_drop_connection(stmt),
         Statement::DescribeConnection(stmt) => visitor.visit_describe_connection(stmt),
         Statement::ShowConnections(stmt) => visitor.visit_show_connections(stmt),
-        Statement::CreatePipe(_) => todo!(),
-        Statement::AlterPipe(_) => todo!(),
-        Statement::DropPipe(_) => todo!(),
-        Statement::DescribePipe(_) => todo!(),
+        Statement::CreatePipe(stmt) => visitor.visit_create_pipe(stmt),
+        Statement::AlterPipe(stmt) => visitor.visit_alter_pipe(stmt),
+        Statement::DropPipe(stmt) => visitor.visit_drop_pipe(stmt),
+        Statement::DescribePipe(stmt) => visitor.visit_describe_pipe(stmt),
+        Statement::Begin => {}
+        Statement::Commit => {}
+        Statement::Abort => {}
     }
 }
@@ -101,6 +101,12 @@ fn test_statement() {
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"CREATE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
+        r#"CREATE ASYNC INVERTED INDEX IF NOT EXISTS idx2 ON t1 (title, body) tokenizer = 'english';"#,
+        r#"DROP INVERTED INDEX IF EXISTS idx2 ON db1.t1;"#,
+        r#"REFRESH INVERTED INDEX idx2 ON t1 LIMIT 10;"#,
+        r#"CREATE VECTOR INDEX IF NOT EXISTS idx3 ON t1 (embedding) metric = 'l2' m = 8;"#,
+        r#"DROP VECTOR INDEX idx3 ON db1.t1;"#,
+        r#"REFRESH VECTOR INDEX idx3 ON t1;"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
         r#"create or replace table a (c decimal(38))"#,
@@ -139,6 +145,8 @@ fn test_statement() {
         r#"create stream test2.s1 on table test.t append_only = false;"#,
         r#"create stream if not exists test2.s2 on table test.t at (stream => test1.s1) comment = 'this is a stream';"#,
         r#"create or replace stream test2.s1 on table test.t append_only = false;"#,
+        r#"create or replace stream test2.s1 on table test.t at (snapshot => '9828b23f74664ff3806f44bbc1925ea5') append_only = false;"#,
+        r#"alter stream if exists test2.s1 set at (snapshot => '9828b23f74664ff3806f44bbc1925ea5');"#,
         r#"show full streams from default.test2 like 's%';"#,
         r#"describe stream test2.s2;"#,
         r#"drop stream if exists test2.s2;"#,
@@ -194,6 +202,7 @@ fn test_statement() {
         r#"insert into t (c1, c2) values (1, 2);   "#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert into t partition (dt = '2024-01-01') (c1) values (1);"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage 's3://load/files/' credentials=(aws_key_id='1a2b3c', aws_secret_key='4x5y6z') file_format=(type = CSV, compression = GZIP record_delimiter=',')"#,
@@ -233,6 +242,9 @@ fn test_statement() {
         r#"ALTER TABLE t MODIFY a int;"#,
         r#"ALTER TABLE t MODIFY COLUMN a DROP STORED;"#,
         r#"ALTER TABLE t SET OPTIONS(SNAPSHOT_LOCATION='1/7/_ss/101fd790dbbe4238a31a8f2e2f856179_v4.mpk',block_per_segment = 500);"#,
+        r#"ALTER TABLE t ADD IF NOT EXISTS PARTITION (dt = '2024-01-01', hour = 1) LOCATION 's3://bucket/t/dt=2024-01-01/hour=1';"#,
+        r#"ALTER TABLE t DROP PARTITION (dt = '2024-01-01');"#,
+        r#"MSCK REPAIR TABLE db.t;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -572,8 +584,16 @@ fn test_statement() {
         "CREATE OR REPLACE FUNCTION isnotempty_test_replace AS(p) -> not(is_null(p))  DESC = 'This is a description';",
         "CREATE FUNCTION binary_reverse (BINARY) RETURNS BINARY LANGUAGE python HANDLER = 'binary_reverse' ADDRESS = 'http://0.0.0.0:8815';",
         "CREATE OR REPLACE FUNCTION binary_reverse (BINARY) RETURNS BINARY LANGUAGE python HANDLER = 'binary_reverse' ADDRESS = 'http://0.0.0.0:8815';",
+        "CREATE FUNCTION wasm_add (INT, INT) RETURNS INT LANGUAGE wasm HANDLER = 'add' AS '@my_stage/add.wasm';",
+        "CREATE OR REPLACE AGGREGATE FUNCTION weighted_avg (INT, INT) RETURNS INT LANGUAGE python HANDLER = 'weighted_avg' ADDRESS = 'http://0.0.0.0:8815';",
+        "CREATE FUNCTION range_of (n INT) RETURNS TABLE (x BIGINT) AS $$ SELECT number AS x FROM numbers(n) $$;",
         "DROP FUNCTION binary_reverse;",
         "DROP FUNCTION isnotempty;",
+        "BEGIN",
+        "BEGIN TRANSACTION;",
+        "COMMIT",
+        "ROLLBACK WORK",
+        "ABORT",
     ];
 
     for case in cases {
@@ -856,6 +876,10 @@ fn test_expr() {
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
         r#"(current_timestamp, current_timestamp(), now())"#,
         r#"ARRAY_REDUCE([1,2,3], (acc,t) -> acc + t)"#,
+        r#"INTERVAL '3 days 2 hours'"#,
+        r#"col1::INTERVAL"#,
+        r#"CAST(col1 AS UUID)"#,
+        r#"col1::IPV6"#,
     ];
 
     for case in cases {
@@ -15,6 +15,8 @@
 use std::fmt::Debug;
 
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::F32;
+use databend_common_expression::ColumnId;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
@@ -96,6 +98,22 @@ pub struct PushDownInfo {
     pub agg_index: Option<AggIndexInfo>,
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
+    /// Optional nearest neighbor search by a vector index.
+    pub vector_index: Option<VectorIndexInfo>,
+}
+
+/// The `ORDER BY <distance>(column, query) LIMIT n` of a query, which can be answered
+/// by the vector index on the column.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    pub index_name: String,
+    pub index_version: String,
+    /// The indexed column.
+    pub column_id: ColumnId,
+    /// The query vector.
+    pub query: Vec<F32>,
+    /// The number of nearest vectors to find.
+    pub limit: usize,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 
 // #[derive(Debug, Clone)]
@@ -27,6 +28,8 @@ pub struct BasicColumnStatistics {
     pub ndv: Option<u64>,
     // Count of null values
     pub null_count: u64,
+    // Histogram of the non-null values, collected by `ANALYZE TABLE`
+    pub histogram: Option<ColumnHistogram>,
 }
 
 impl From<ColumnStatistics> for BasicColumnStatistics {
@@ -36,6 +39,7 @@ impl From<ColumnStatistics> for BasicColumnStatistics {
             max: Datum::from_scalar(value.max),
             ndv: value.distinct_of_values,
             null_count: value.null_count,
+            histogram: None,
         }
     }
 }
@@ -47,6 +51,7 @@ impl BasicColumnStatistics {
             max: None,
             ndv: None,
             null_count: 0,
+            histogram: None,
         }
     }
 
@@ -59,6 +64,8 @@ impl BasicColumnStatistics {
             _ => None,
         };
         self.null_count += other.null_count;
+        // The histograms can't be merged.
+        self.histogram = None;
     }
 
     // If the data type is int and max - min + 1 < ndv, then adjust ndv to max - min + 1.
@@ -123,6 +130,7 @@ impl BasicColumnStatistics {
             max: self.max.clone(),
             ndv,
             null_count: self.null_count,
+            histogram: self.histogram.clone(),
         })
     }
 }
@@ -111,6 +111,12 @@ pub trait Table: Sync + Send {
         vec![]
     }
 
+    /// Names of the columns the data files are partitioned by, like the
+    /// `<column>=<value>` directories of hive tables.
+    fn partition_keys(&self) -> Vec<String> {
+        vec![]
+    }
+
     fn change_tracking_enabled(&self) -> bool {
         false
     }
@@ -145,6 +151,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the data can be appended by all the nodes of the cluster, the block metas
+    /// produced by `append_data` are sent to the node that commits the insertion.
+    fn support_distributed_insert(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn alter_table_cluster_keys(
         &self,
@@ -28,6 +28,7 @@ use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AlterStreamPlan;
 use databend_common_sql::plans::CreateStreamPlan;
 use databend_common_sql::plans::DropStreamPlan;
 use databend_common_sql::plans::StreamNavigation;
@@ -123,6 +124,27 @@ impl StreamHandler for RealStreamHandler {
                 };
                 options.insert(OPT_KEY_MODE.to_string(), stream_mode.to_string());
             }
+            Some(StreamNavigation::AtPoint(point)) => {
+                let navigated = table.navigate_to(point).await?;
+                let (table_version, snapshot_loc) =
+                    navigated_offset(table.as_ref(), navigated.as_ref()).await?;
+                let stream_mode = if plan.append_only {
+                    MODE_APPEND_ONLY
+                } else {
+                    MODE_STANDARD
+                };
+                options.insert(OPT_KEY_MODE.to_string(), stream_mode.to_string());
+                options.insert(OPT_KEY_TABLE_NAME.to_string(), plan.table_name.clone());
+                options.insert(
+                    OPT_KEY_DATABASE_NAME.to_string(),
+                    plan.table_database.clone(),
+                );
+                options.insert(OPT_KEY_TABLE_ID.to_string(), table_id.to_string());
+                options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
+                if let Some(snapshot_loc) = snapshot_loc {
+                    options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+                }
+            }
             None => {
                 let stream_mode = if plan.append_only {
                     MODE_APPEND_ONLY
@@ -163,6 +185,62 @@ impl StreamHandler for RealStreamHandler {
         catalog.create_table(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn do_alter_stream(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &AlterStreamPlan,
+    ) -> Result<()> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(&plan.catalog).await?;
+        let stream = match catalog
+            .get_table(&tenant, &plan.database, &plan.stream_name)
+            .await
+        {
+            Ok(stream) => stream,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_TABLE => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        let stream = StreamTable::try_from_table(stream.as_ref())?;
+
+        let (table_version, snapshot_loc) = match &plan.navigation {
+            StreamNavigation::AtStream { database, name } => {
+                let other = catalog.get_table(&tenant, database, name).await?;
+                let other = StreamTable::try_from_table(other.as_ref())?;
+                if other.source_table_id() != stream.source_table_id() {
+                    return Err(ErrorCode::IllegalStream(format!(
+                        "The stream '{name}' is not match the table '{}.{}'",
+                        stream.source_table_database(),
+                        stream.source_table_name()
+                    )));
+                }
+                (other.offset(), other.snapshot_loc())
+            }
+            StreamNavigation::AtPoint(point) => {
+                let table = stream.source_table(ctx.clone()).await?;
+                let navigated = table.navigate_to(point).await?;
+                navigated_offset(table.as_ref(), navigated.as_ref()).await?
+            }
+        };
+
+        // The offset is reset in place, so the stream keeps its mode, comment and grants.
+        let req = UpsertTableOptionReq {
+            table_id: stream.get_id(),
+            seq: MatchSeq::Exact(stream.get_table_info().ident.seq),
+            options: HashMap::from([
+                (
+                    OPT_KEY_TABLE_VER.to_string(),
+                    Some(table_version.to_string()),
+                ),
+                (OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc),
+            ]),
+        };
+        catalog
+            .upsert_table_option(&tenant, &plan.database, req)
+            .await?;
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn do_drop_stream(
         &self,
@@ -222,3 +300,34 @@ impl RealStreamHandler {
         Ok(())
     }
 }
+
+/// Returns the table version and the snapshot location of a stream offset at the
+/// `navigated` snapshot of `table`.
+///
+/// The rows written after the snapshot carry an `_origin_version` no less than the table
+/// version their commit is based on, which is greater than the one the snapshot is committed
+/// upon, so the next version is a valid offset even if the table meta has changed since.
+async fn navigated_offset(
+    table: &dyn Table,
+    navigated: &dyn Table,
+) -> Result<(u64, Option<String>)> {
+    let fuse_table = FuseTable::try_from_table(table)?;
+    let navigated = FuseTable::try_from_table(navigated)?;
+    let snapshot_loc = navigated.snapshot_loc().await?;
+    if snapshot_loc == fuse_table.snapshot_loc().await? {
+        return Ok((table.get_table_info().ident.seq, snapshot_loc));
+    }
+
+    let table_version = navigated
+        .read_table_snapshot()
+        .await?
+        .and_then(|snapshot| snapshot.prev_table_seq)
+        .ok_or_else(|| {
+            ErrorCode::IllegalStream(format!(
+                "The table version of the snapshot {:?} of the table '{}' is unknown, it was written by an older version",
+                snapshot_loc,
+                table.name()
+            ))
+        })?;
+    Ok((table_version + 1, snapshot_loc))
+}
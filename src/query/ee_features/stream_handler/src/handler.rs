@@ -19,6 +19,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::DropTableReply;
+use databend_common_sql::plans::AlterStreamPlan;
 use databend_common_sql::plans::CreateStreamPlan;
 use databend_common_sql::plans::DropStreamPlan;
 
@@ -30,6 +31,12 @@ pub trait StreamHandler: Sync + Send {
         plan: &CreateStreamPlan,
     ) -> Result<CreateTableReply>;
 
+    async fn do_alter_stream(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &AlterStreamPlan,
+    ) -> Result<()>;
+
     async fn do_drop_stream(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -55,6 +62,15 @@ impl StreamHandlerWrapper {
         self.handler.do_create_stream(ctx, plan).await
     }
 
+    #[async_backtrace::framed]
+    pub async fn do_alter_stream(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &AlterStreamPlan,
+    ) -> Result<()> {
+        self.handler.do_alter_stream(ctx, plan).await
+    }
+
     #[async_backtrace::framed]
     pub async fn do_drop_stream(
         &self,
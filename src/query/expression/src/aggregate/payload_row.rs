@@ -28,9 +28,12 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::InetType;
+use crate::types::IntervalType;
 use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
@@ -49,6 +52,7 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         },
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Interval | DataType::Uuid | DataType::Inet => 16,
         // use address instead
         DataType::Binary
         | DataType::String
@@ -135,6 +139,16 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Interval(buffer) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
+        Column::Uuid(buffer) | Column::Inet(buffer) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -299,6 +313,42 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Interval(_) => row_match_column_type::<IntervalType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
+        Column::Uuid(_) => row_match_column_type::<UuidType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
+        Column::Inet(_) => row_match_column_type::<InetType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
         Column::Bitmap(v) | Column::Binary(v) | Column::Variant(v) | Column::Geometry(v) => {
             row_match_binary_column(
                 v,
@@ -20,12 +20,21 @@ use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Field as ArrowField;
 use arrow_schema::Fields;
 use arrow_schema::Schema as ArrowSchema;
+use databend_common_arrow::arrow::array::Array as Arrow2Array;
+use databend_common_arrow::arrow::array::ListArray;
+use databend_common_arrow::arrow::array::MapArray;
+use databend_common_arrow::arrow::array::PrimitiveArray;
+use databend_common_arrow::arrow::array::StructArray;
 use databend_common_arrow::arrow::datatypes::DataType as Arrow2DataType;
 use databend_common_arrow::arrow::datatypes::Field as Arrow2Field;
+use databend_common_arrow::arrow::datatypes::IntervalUnit as Arrow2IntervalUnit;
+use databend_common_arrow::arrow::types::months_days_ns;
 use databend_common_exception::Result;
 
 use super::EXTENSION_KEY;
 use crate::converts::arrow2::table_field_to_arrow2_field_ignore_inside_nullable;
+use crate::converts::arrow2::ARROW_EXT_TYPE_INTERVAL;
+use crate::types::interval::months_days_micros;
 use crate::Column;
 use crate::DataBlock;
 use crate::DataField;
@@ -38,7 +47,7 @@ impl From<&DataSchema> for ArrowSchema {
         let fields = schema
             .fields
             .iter()
-            .map(|f| arrow_field_from_arrow2_field(Arrow2Field::from(f)))
+            .map(|f| arrow_field_from_arrow2_field(Arrow2Field::from(f), true))
             .collect::<Vec<_>>();
         ArrowSchema {
             fields: Fields::from(fields),
@@ -52,7 +61,7 @@ impl From<&TableSchema> for ArrowSchema {
         let fields = schema
             .fields
             .iter()
-            .map(|f| arrow_field_from_arrow2_field(Arrow2Field::from(f)))
+            .map(|f| arrow_field_from_arrow2_field(Arrow2Field::from(f), true))
             .collect::<Vec<_>>();
         ArrowSchema {
             fields: Fields::from(fields),
@@ -66,12 +75,17 @@ impl From<&TableSchema> for ArrowSchema {
 /// So when casting from TableSchema to Arrow2 schema, the inner type inherit the nullable property from outer type.
 ///
 /// But when casting from TableSchema to Arrow-rs schema, there is no such problem, so the inside nullable is ignored.
+///
+/// The schema describes the layout of the fuse parquet files, so the intervals are kept as decimals.
 pub fn table_schema_to_arrow_schema_ignore_inside_nullable(schema: &TableSchema) -> ArrowSchema {
     let fields = schema
         .fields
         .iter()
         .map(|f| {
-            arrow_field_from_arrow2_field(table_field_to_arrow2_field_ignore_inside_nullable(f))
+            arrow_field_from_arrow2_field(
+                table_field_to_arrow2_field_ignore_inside_nullable(f),
+                false,
+            )
         })
         .collect::<Vec<_>>();
     ArrowSchema {
@@ -82,18 +96,32 @@ pub fn table_schema_to_arrow_schema_ignore_inside_nullable(schema: &TableSchema)
 
 impl From<&TableField> for ArrowField {
     fn from(field: &TableField) -> Self {
-        arrow_field_from_arrow2_field(Arrow2Field::from(field))
+        arrow_field_from_arrow2_field(Arrow2Field::from(field), true)
     }
 }
 
 impl From<&DataField> for ArrowField {
     fn from(field: &DataField) -> Self {
-        arrow_field_from_arrow2_field(Arrow2Field::from(field))
+        arrow_field_from_arrow2_field(Arrow2Field::from(field), true)
     }
 }
 
 impl DataBlock {
     pub fn to_record_batch(self, data_schema: &DataSchema) -> Result<RecordBatch> {
+        self.to_record_batch_with(data_schema, Column::into_arrow_rs)
+    }
+
+    /// Like [`DataBlock::to_record_batch`], but keeps the layout of the fuse parquet files,
+    /// where the intervals are stored as decimals.
+    pub fn to_storage_record_batch(self, data_schema: &DataSchema) -> Result<RecordBatch> {
+        self.to_record_batch_with(data_schema, |column| column.as_arrow().into())
+    }
+
+    fn to_record_batch_with(
+        self,
+        data_schema: &DataSchema,
+        into_array: impl Fn(Column) -> Arc<dyn arrow_array::Array>,
+    ) -> Result<RecordBatch> {
         let mut arrays = Vec::with_capacity(self.columns().len());
         let mut arrow_fields = Vec::with_capacity(self.columns().len());
         for (entry, f) in self
@@ -103,7 +131,7 @@ impl DataBlock {
             .zip(data_schema.fields())
         {
             let column = entry.value.to_owned().into_column().unwrap();
-            let array = column.into_arrow_rs();
+            let array = into_array(column);
             let arrow_field = ArrowField::new(
                 f.name(),
                 array.data_type().clone(),
@@ -119,37 +147,119 @@ impl DataBlock {
 
 impl Column {
     pub fn into_arrow_rs(self) -> Arc<dyn arrow_array::Array> {
-        let arrow2_array: Box<dyn databend_common_arrow::arrow::array::Array> = self.as_arrow();
+        let arrow2_array: Box<dyn Arrow2Array> = native_interval_array(self.as_arrow());
         let arrow_array: Arc<dyn arrow_array::Array> = arrow2_array.into();
         arrow_array
     }
 }
 
-fn arrow_field_from_arrow2_field(field: Arrow2Field) -> ArrowField {
+/// Replaces the packed intervals, which arrow2 keeps as decimals, with Arrow's native
+/// month-day-nano intervals.
+fn native_interval_array(array: Box<dyn Arrow2Array>) -> Box<dyn Arrow2Array> {
+    match array.data_type() {
+        Arrow2DataType::Extension(name, _, _) if name == ARROW_EXT_TYPE_INTERVAL => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .expect("fail to export `Interval`: array should be `PrimitiveArray<i128>`");
+            let values = array
+                .values()
+                .iter()
+                .map(|v| {
+                    let interval = months_days_micros(*v);
+                    months_days_ns::new(
+                        interval.months(),
+                        interval.days(),
+                        interval.microseconds().saturating_mul(1000),
+                    )
+                })
+                .collect::<Vec<_>>();
+            Box::new(PrimitiveArray::<months_days_ns>::new(
+                Arrow2DataType::Interval(Arrow2IntervalUnit::MonthDayNano),
+                values.into(),
+                array.validity().cloned(),
+            ))
+        }
+        Arrow2DataType::LargeList(field) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<ListArray<i64>>()
+                .expect("fail to export `Array`: array should be `ListArray<i64>`");
+            let values = native_interval_array(array.values().clone());
+            let field =
+                Arrow2Field::new(&field.name, values.data_type().clone(), field.is_nullable);
+            Box::new(ListArray::<i64>::new(
+                Arrow2DataType::LargeList(Box::new(field)),
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        Arrow2DataType::Map(field, ordered) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<MapArray>()
+                .expect("fail to export `Map`: array should be `MapArray`");
+            let entries = native_interval_array(array.field().clone());
+            let field =
+                Arrow2Field::new(&field.name, entries.data_type().clone(), field.is_nullable);
+            Box::new(MapArray::new(
+                Arrow2DataType::Map(Box::new(field), *ordered),
+                array.offsets().clone(),
+                entries,
+                array.validity().cloned(),
+            ))
+        }
+        Arrow2DataType::Struct(fields) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .expect("fail to export `Tuple`: array should be `StructArray`");
+            let values = array
+                .values()
+                .iter()
+                .map(|value| native_interval_array(value.clone()))
+                .collect::<Vec<_>>();
+            let fields = fields
+                .iter()
+                .zip(values.iter())
+                .map(|(f, v)| Arrow2Field::new(&f.name, v.data_type().clone(), f.is_nullable))
+                .collect();
+            Box::new(StructArray::new(
+                Arrow2DataType::Struct(fields),
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        _ => array,
+    }
+}
+
+fn arrow_field_from_arrow2_field(field: Arrow2Field, native_interval: bool) -> ArrowField {
     let mut metadata = HashMap::new();
 
-    let arrow2_data_type = if let Arrow2DataType::Extension(extension_type, ty, _) = field.data_type
-    {
-        metadata.insert(EXTENSION_KEY.to_string(), extension_type.clone());
-        *ty
-    } else {
-        field.data_type
+    let arrow2_data_type = match field.data_type {
+        Arrow2DataType::Extension(extension_type, _, _)
+            if native_interval && extension_type == ARROW_EXT_TYPE_INTERVAL =>
+        {
+            Arrow2DataType::Interval(Arrow2IntervalUnit::MonthDayNano)
+        }
+        Arrow2DataType::Extension(extension_type, ty, _) => {
+            metadata.insert(EXTENSION_KEY.to_string(), extension_type.clone());
+            *ty
+        }
+        other => other,
     };
 
+    let convert = |f: Arrow2Field| arrow_field_from_arrow2_field(f, native_interval);
     let data_type = match arrow2_data_type {
-        Arrow2DataType::List(f) => ArrowDataType::List(Arc::new(arrow_field_from_arrow2_field(*f))),
-        Arrow2DataType::LargeList(f) => {
-            ArrowDataType::LargeList(Arc::new(arrow_field_from_arrow2_field(*f)))
-        }
+        Arrow2DataType::List(f) => ArrowDataType::List(Arc::new(convert(*f))),
+        Arrow2DataType::LargeList(f) => ArrowDataType::LargeList(Arc::new(convert(*f))),
         Arrow2DataType::FixedSizeList(f, size) => {
-            ArrowDataType::FixedSizeList(Arc::new(arrow_field_from_arrow2_field(*f)), size as _)
-        }
-        Arrow2DataType::Map(f, ordered) => {
-            ArrowDataType::Map(Arc::new(arrow_field_from_arrow2_field(*f)), ordered)
-        }
-        Arrow2DataType::Struct(f) => {
-            ArrowDataType::Struct(f.into_iter().map(arrow_field_from_arrow2_field).collect())
+            ArrowDataType::FixedSizeList(Arc::new(convert(*f)), size as _)
         }
+        Arrow2DataType::Map(f, ordered) => ArrowDataType::Map(Arc::new(convert(*f)), ordered),
+        Arrow2DataType::Struct(f) => ArrowDataType::Struct(f.into_iter().map(convert).collect()),
         other => other.into(),
     };
 
@@ -16,8 +16,10 @@ use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use databend_common_arrow::arrow::datatypes::Field as ArrowField;
+use databend_common_arrow::arrow::datatypes::IntervalUnit;
 use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::arrow::datatypes::TimeUnit;
+use databend_common_arrow::arrow::types::months_days_ns;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
@@ -25,10 +27,14 @@ use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INET;
+use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_UUID;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::array::ArrayColumn;
 use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::DataType;
@@ -122,6 +128,7 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
 
         ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
         ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => TableDataType::Interval,
         ArrowDataType::Map(f, _) => {
             let inner_ty = arrow_type_to_table_type(&f.data_type, f.is_nullable)?;
             TableDataType::Map(Box::new(inner_ty))
@@ -144,6 +151,9 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
             ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
             ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
             ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+            ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
+            ARROW_EXT_TYPE_UUID => TableDataType::Uuid,
+            ARROW_EXT_TYPE_INET => TableDataType::Inet,
             _ => arrow_type_to_table_type(data_type, is_nullable)?,
         },
         _ => {
@@ -776,6 +786,49 @@ impl Column {
                         unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
                     Column::Geometry(BinaryColumn::new(arrow_col.values().clone(), offsets))
                 }
+                (DataType::Interval, ArrowDataType::Decimal(38, 0)) => {
+                    let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                    .expect("fail to read `Interval` from arrow: array should be `PrimitiveArray<i128>`");
+                    let values = unsafe {
+                        std::mem::transmute::<Buffer<i128>, Buffer<months_days_micros>>(
+                            arrow_col.values().clone(),
+                        )
+                    };
+                    Column::Interval(values)
+                }
+                (DataType::Interval, ArrowDataType::Interval(IntervalUnit::MonthDayNano)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<months_days_ns>>()
+                        .expect(
+                            "fail to read `Interval` from arrow: array should be `PrimitiveArray<months_days_ns>`",
+                        );
+                    let values = arrow_col
+                        .values()
+                        .iter()
+                        .map(|v| months_days_micros::new(v.months(), v.days(), v.ns() / 1000))
+                        .collect();
+                    Column::Interval(values)
+                }
+                (DataType::Uuid | DataType::Inet, ArrowDataType::Decimal(38, 0)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect(
+                            "fail to read `Uuid` or `Inet` from arrow: array should be `PrimitiveArray<i128>`",
+                        );
+                    let values = unsafe {
+                        std::mem::transmute::<Buffer<i128>, Buffer<u128>>(
+                            arrow_col.values().clone(),
+                        )
+                    };
+                    match data_type {
+                        DataType::Uuid => Column::Uuid(values),
+                        _ => Column::Inet(values),
+                    }
+                }
                 (data_type, ArrowDataType::Extension(_, arrow_type, _)) => {
                     from_arrow_with_arrow_type(arrow_col, arrow_type, data_type)?
                 }
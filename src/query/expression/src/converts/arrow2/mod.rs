@@ -20,6 +20,9 @@ pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
+pub const ARROW_EXT_TYPE_UUID: &str = "Uuid";
+pub const ARROW_EXT_TYPE_INET: &str = "Inet";
 
 pub use to::set_validities;
 pub use to::table_field_to_arrow2_field_ignore_inside_nullable;
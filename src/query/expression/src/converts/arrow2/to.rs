@@ -24,8 +24,12 @@ use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INET;
+use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_UUID;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
@@ -183,6 +187,23 @@ fn table_type_to_arrow_type(
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        // The packed months, days and microseconds are stored as a 16-byte decimal.
+        TableDataType::Interval => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INTERVAL.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
+        // The 16 bytes of the UUIDs and the IPv6 addresses are stored as decimals as well.
+        TableDataType::Uuid => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_UUID.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
+        TableDataType::Inet => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INET.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
     }
 }
 
@@ -346,6 +367,27 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values = unsafe {
+                    std::mem::transmute::<Buffer<months_days_micros>, Buffer<i128>>(col.clone())
+                };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::Uuid(col) | Column::Inet(col) => {
+                let values =
+                    unsafe { std::mem::transmute::<Buffer<u128>, Buffer<i128>>(col.clone()) };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Date(col) => Box::new(
                 databend_common_arrow::arrow::array::PrimitiveArray::<i32>::try_new(
                     arrow_type,
@@ -15,6 +15,8 @@
 use databend_common_datavalues::DataValue;
 use ordered_float::OrderedFloat;
 
+use crate::types::inet::inet_to_string;
+use crate::types::uuid::uuid_to_string;
 use crate::Scalar;
 
 pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
@@ -41,6 +43,9 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         Scalar::Boolean(x) => DataValue::Boolean(*x),
         Scalar::Variant(x) => DataValue::String(x.clone()),
         Scalar::Geometry(x) => DataValue::String(x.clone()),
+        Scalar::Interval(x) => DataValue::String(x.to_string().into_bytes()),
+        Scalar::Uuid(x) => DataValue::String(uuid_to_string(*x).into_bytes()),
+        Scalar::Inet(x) => DataValue::String(inet_to_string(*x).into_bytes()),
         Scalar::String(x) => DataValue::String(x.as_bytes().to_vec()),
         Scalar::Array(x) => {
             let values = (0..x.len())
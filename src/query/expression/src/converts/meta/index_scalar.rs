@@ -79,6 +79,9 @@ impl TryFrom<Scalar> for IndexScalar {
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
+            | Scalar::Interval(_)
+            | Scalar::Uuid(_)
+            | Scalar::Inet(_)
             | Scalar::EmptyArray
             | Scalar::EmptyMap => return Err(()),
         })
@@ -14,6 +14,7 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::BitAnd;
 use std::ops::BitOr;
 use std::ops::Not;
@@ -106,6 +107,9 @@ pub struct FunctionContext {
 
     pub external_server_connect_timeout_secs: u64,
     pub external_server_request_timeout_secs: u64,
+
+    pub wasm_udf_max_fuel: u64,
+    pub wasm_udf_max_memory: u64,
 }
 
 #[derive(Clone)]
@@ -154,6 +158,9 @@ pub struct FunctionRegistry {
     pub auto_try_cast_rules: Vec<(DataType, DataType)>,
 
     pub properties: HashMap<String, FunctionProperty>,
+
+    /// Functions that are only built by the planner, they can't be called by name in SQL.
+    pub internal_functions: HashSet<String>,
 }
 
 impl Function {
@@ -289,15 +296,17 @@ impl FunctionRegistry {
             .keys()
             .chain(self.factories.keys())
             .chain(self.aliases.keys())
+            .filter(|name| !self.internal_functions.contains(*name))
             .unique()
             .cloned()
             .collect()
     }
 
     pub fn contains(&self, func_name: &str) -> bool {
-        self.funcs.contains_key(func_name)
+        (self.funcs.contains_key(func_name)
             || self.factories.contains_key(func_name)
-            || self.aliases.contains_key(func_name)
+            || self.aliases.contains_key(func_name))
+            && !self.internal_functions.contains(func_name)
     }
 
     pub fn get(&self, id: &FunctionID) -> Option<Arc<Function>> {
@@ -415,6 +424,17 @@ impl FunctionRegistry {
             .push((Box::new(factory), id));
     }
 
+    /// Register a function factory that is invisible to `contains`, so only the
+    /// planner can build the function by name.
+    pub fn register_internal_function_factory(
+        &mut self,
+        name: &str,
+        factory: impl FunctionFactory,
+    ) {
+        self.register_function_factory(name, factory);
+        self.internal_functions.insert(name.to_string());
+    }
+
     pub fn register_aliases(&mut self, fn_name: &str, aliases: &[&str]) {
         for alias in aliases {
             self.aliases.insert(alias.to_string(), fn_name.to_string());
@@ -457,6 +477,7 @@ impl FunctionRegistry {
             .keys()
             .chain(self.funcs.keys())
             .chain(self.factories.keys())
+            .filter(|name| !self.internal_functions.contains(*name))
             .map(|s| s.to_string())
             .sorted()
             .dedup()
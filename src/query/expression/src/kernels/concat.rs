@@ -343,6 +343,27 @@ impl Column {
                 columns.map(|col| col.into_geometry().unwrap()),
                 capacity,
             )),
+            Column::Interval(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
+                );
+                Column::Interval(builder.into())
+            }
+            Column::Uuid(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_uuid().unwrap()),
+                    capacity,
+                );
+                Column::Uuid(builder.into())
+            }
+            Column::Inet(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_inet().unwrap()),
+                    capacity,
+                );
+                Column::Inet(builder.into())
+            }
         };
         Ok(column)
     }
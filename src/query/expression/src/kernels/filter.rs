@@ -186,6 +186,18 @@ impl Column {
                 let column = Self::filter_binary_scalars(column, filter);
                 Column::Geometry(column)
             }
+            Column::Interval(column) => {
+                let column = Self::filter_primitive_types(column, filter);
+                Column::Interval(column)
+            }
+            Column::Uuid(column) => {
+                let column = Self::filter_primitive_types(column, filter);
+                Column::Uuid(column)
+            }
+            Column::Inet(column) => {
+                let column = Self::filter_primitive_types(column, filter);
+                Column::Inet(column)
+            }
         }
     }
 
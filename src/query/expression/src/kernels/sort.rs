@@ -31,6 +31,9 @@ use databend_common_exception::Result;
 
 use crate::converts::arrow2::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::converts::arrow2::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::converts::arrow2::ARROW_EXT_TYPE_INET;
+use crate::converts::arrow2::ARROW_EXT_TYPE_INTERVAL;
+use crate::converts::arrow2::ARROW_EXT_TYPE_UUID;
 use crate::converts::arrow2::ARROW_EXT_TYPE_VARIANT;
 use crate::types::DataType;
 use crate::utils::arrow::column_to_arrow_array;
@@ -228,6 +231,38 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_interval(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Interval)
+        .unwrap()
+        .as_interval()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Interval)
+        .unwrap()
+        .as_interval()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| left[i].cmp(&right[j])))
+}
+
+/// The UUIDs and the IPv6 addresses are stored as `i128` but ordered as `u128`.
+fn compare_u128(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = left
+        .as_any()
+        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+        .unwrap()
+        .clone();
+
+    Ok(Box::new(move |i, j| {
+        (left.value(i) as u128).cmp(&(right.value(j) as u128))
+    }))
+}
+
 fn compare_null() -> ArrowResult<DynComparator> {
     Ok(Box::new(move |_, _| Ordering::Equal))
 }
@@ -256,6 +291,8 @@ fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparat
     match left.data_type() {
         ArrowType::Extension(name, _, _) => match name.as_str() {
             ARROW_EXT_TYPE_VARIANT => compare_variant(left, right),
+            ARROW_EXT_TYPE_INTERVAL => compare_interval(left, right),
+            ARROW_EXT_TYPE_UUID | ARROW_EXT_TYPE_INET => compare_u128(left, right),
             ARROW_EXT_TYPE_EMPTY_ARRAY | ARROW_EXT_TYPE_EMPTY_MAP => compare_null(),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Sort not supported for data type {:?}",
@@ -197,6 +197,15 @@ impl Column {
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::Interval(column) => {
+                Column::Interval(Self::take_primitive_types(column, indices).into())
+            }
+            Column::Uuid(column) => {
+                Column::Uuid(Self::take_primitive_types(column, indices).into())
+            }
+            Column::Inet(column) => {
+                Column::Inet(Self::take_primitive_types(column, indices).into())
+            }
         }
     }
 
@@ -41,11 +41,14 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::InetType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberColumnVec;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::types::F32;
@@ -381,6 +384,18 @@ impl Column {
                 let builder = GeometryType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<GeometryType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
+            Column::Uuid(_) => {
+                let builder = UuidType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<UuidType>(columns, builder, indices)
+            }
+            Column::Inet(_) => {
+                let builder = InetType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<InetType>(columns, builder, indices)
+            }
         }
     }
 
@@ -596,6 +611,27 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Geometry(columns)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
+            Column::Uuid(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| UuidType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Uuid(columns)
+            }
+            Column::Inet(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| InetType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Inet(columns)
+            }
         }
     }
 
@@ -736,6 +772,15 @@ impl Column {
             ColumnVec::Geometry(columns) => GeometryType::upcast_column(
                 Self::take_block_vec_binary_types(columns, indices, binary_items_buf.as_mut()),
             ),
+            ColumnVec::Interval(columns) => {
+                Column::Interval(Self::take_block_vec_primitive_types(columns, indices).into())
+            }
+            ColumnVec::Uuid(columns) => {
+                Column::Uuid(Self::take_block_vec_primitive_types(columns, indices).into())
+            }
+            ColumnVec::Inet(columns) => {
+                Column::Inet(Self::take_block_vec_primitive_types(columns, indices).into())
+            }
         }
     }
 
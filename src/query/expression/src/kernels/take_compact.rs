@@ -188,6 +188,15 @@ impl Column {
             Column::Geometry(column) => GeometryType::upcast_column(
                 Self::take_compact_binary_types(column, indices, num_rows),
             ),
+            Column::Interval(column) => Column::Interval(
+                Self::take_compacted_primitive_types(column, indices, num_rows).into(),
+            ),
+            Column::Uuid(column) => {
+                Column::Uuid(Self::take_compacted_primitive_types(column, indices, num_rows).into())
+            }
+            Column::Inet(column) => {
+                Column::Inet(Self::take_compacted_primitive_types(column, indices, num_rows).into())
+            }
         }
     }
 
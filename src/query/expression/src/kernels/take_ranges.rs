@@ -165,6 +165,18 @@ impl Column {
                 let column = Self::take_ranges_binary_types(column, ranges, num_rows);
                 Column::Geometry(column)
             }
+            Column::Interval(column) => {
+                let column = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Interval(column)
+            }
+            Column::Uuid(column) => {
+                let column = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Uuid(column)
+            }
+            Column::Inet(column) => {
+                let column = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Inet(column)
+            }
         }
     }
 
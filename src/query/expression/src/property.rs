@@ -19,6 +19,8 @@ use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::GeometryDomain;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -32,10 +34,13 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::InetType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::with_decimal_type;
 use crate::with_number_type;
@@ -105,6 +110,11 @@ pub enum Domain {
     /// `Map(None)` means that the map is empty, thus there is no inner domain information.
     Map(Option<Box<Domain>>),
     Tuple(Vec<Domain>),
+    /// The bounding box of the geometries.
+    Geometry(GeometryDomain),
+    Interval(SimpleDomain<months_days_micros>),
+    Uuid(SimpleDomain<u128>),
+    Inet(SimpleDomain<u128>),
     /// For certain types, like `Variant`, the domain is useless therefore is not defined.
     Undefined,
 }
@@ -192,9 +202,11 @@ impl Domain {
             DataType::Array(ty) => Domain::Array(Some(Box::new(Domain::full(ty)))),
             DataType::EmptyMap => Domain::Map(None),
             DataType::Map(ty) => Domain::Map(Some(Box::new(Domain::full(ty)))),
-            DataType::Binary | DataType::Bitmap | DataType::Variant | DataType::Geometry => {
-                Domain::Undefined
-            }
+            DataType::Geometry => Domain::Geometry(GeometryDomain::full()),
+            DataType::Interval => Domain::Interval(IntervalType::full_domain()),
+            DataType::Uuid => Domain::Uuid(UuidType::full_domain()),
+            DataType::Inet => Domain::Inet(InetType::full_domain()),
+            DataType::Binary | DataType::Bitmap | DataType::Variant => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -317,6 +329,21 @@ impl Domain {
                     .map(|(self_tup, other_tup)| self_tup.merge(other_tup))
                     .collect(),
             ),
+            (Domain::Geometry(this), Domain::Geometry(other)) => {
+                Domain::Geometry(this.merge(other))
+            }
+            (Domain::Interval(this), Domain::Interval(other)) => Domain::Interval(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
+            (Domain::Uuid(this), Domain::Uuid(other)) => Domain::Uuid(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
+            (Domain::Inet(this), Domain::Inet(other)) => Domain::Inet(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
             (Domain::Undefined, Domain::Undefined) => Domain::Undefined,
             (this, other) => unreachable!("unable to merge {this:?} with {other:?}"),
         }
@@ -383,6 +410,11 @@ impl Domain {
                 Some(Scalar::Timestamp(*min))
             }
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
+            Domain::Interval(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::Interval(*min))
+            }
+            Domain::Uuid(SimpleDomain { min, max }) if min == max => Some(Scalar::Uuid(*min)),
+            Domain::Inet(SimpleDomain { min, max }) if min == max => Some(Scalar::Inet(*min)),
             Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -17,6 +17,7 @@ use ethnum::i256;
 
 use super::row_converter::null_sentinel;
 use crate::types::binary::BinaryColumnBuilder;
+use crate::types::interval::months_days_micros;
 use crate::types::F32;
 use crate::types::F64;
 
@@ -75,6 +76,19 @@ encode_unsigned!(1, u8);
 encode_unsigned!(2, u16);
 encode_unsigned!(4, u32);
 encode_unsigned!(8, u64);
+encode_unsigned!(16, u128);
+
+impl FixedLengthEncoding for months_days_micros {
+    type Encoded = [u8; 32];
+
+    fn encode(self) -> [u8; 32] {
+        // Intervals are ordered by their length first, then by the packed value.
+        let mut b = [0; 32];
+        b[..16].copy_from_slice(&self.total_micros().encode());
+        b[16..].copy_from_slice(&self.0.encode());
+        b
+    }
+}
 
 impl FixedLengthEncoding for F32 {
     type Encoded = [u8; 4];
@@ -190,6 +190,9 @@ pub enum TableDataType {
     },
     Variant,
     Geometry,
+    Interval,
+    Uuid,
+    Inet,
 }
 
 impl DataSchema {
@@ -1161,6 +1164,9 @@ impl From<&TableDataType> for DataType {
             }
             TableDataType::Variant => DataType::Variant,
             TableDataType::Geometry => DataType::Geometry,
+            TableDataType::Interval => DataType::Interval,
+            TableDataType::Uuid => DataType::Uuid,
+            TableDataType::Inet => DataType::Inet,
         }
     }
 }
@@ -1400,6 +1406,9 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
         DataType::Geometry => Ok(TableDataType::Geometry),
+        DataType::Interval => Ok(TableDataType::Interval),
+        DataType::Uuid => Ok(TableDataType::Uuid),
+        DataType::Inet => Ok(TableDataType::Inet),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -23,6 +23,8 @@ pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
 pub mod geometry;
+pub mod inet;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -30,6 +32,7 @@ pub mod number;
 pub mod number_class;
 pub mod string;
 pub mod timestamp;
+pub mod uuid;
 pub mod variant;
 
 use std::cmp::Ordering;
@@ -52,6 +55,8 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::inet::InetType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -59,6 +64,7 @@ pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringType;
 pub use self::timestamp::TimestampType;
+pub use self::uuid::UuidType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::values::Column;
@@ -87,6 +93,9 @@ pub enum DataType {
     Tuple(Vec<DataType>),
     Variant,
     Geometry,
+    Interval,
+    Uuid,
+    Inet,
 
     // Used internally for generic types
     Generic(usize),
@@ -140,7 +149,10 @@ impl DataType {
             | DataType::Date
             | DataType::Bitmap
             | DataType::Variant
-            | DataType::Geometry => false,
+            | DataType::Geometry
+            | DataType::Interval
+            | DataType::Uuid
+            | DataType::Inet => false,
             DataType::Nullable(ty) => ty.has_generic(),
             DataType::Array(ty) => ty.has_generic(),
             DataType::Map(ty) => ty.has_generic(),
@@ -164,6 +176,9 @@ impl DataType {
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
+            | DataType::Interval
+            | DataType::Uuid
+            | DataType::Inet
             | DataType::Generic(_) => false,
             DataType::Nullable(box DataType::Nullable(_) | box DataType::Null) => true,
             DataType::Nullable(ty) => ty.has_nested_nullable(),
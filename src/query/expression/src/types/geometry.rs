@@ -15,6 +15,11 @@
 use std::cmp::Ordering;
 use std::ops::Range;
 
+use databend_common_io::ewkb_to_geo;
+use databend_common_io::geo_to_ewkb;
+use geo::BoundingRect;
+use geo::Geometry;
+use geo::Point;
 use geozero::wkb::FromWkb;
 use geozero::wkb::WkbDialect;
 use geozero::wkt::Ewkt;
@@ -39,7 +44,7 @@ impl ValueType for GeometryType {
     type Scalar = Vec<u8>;
     type ScalarRef<'a> = &'a [u8];
     type Column = BinaryColumn;
-    type Domain = ();
+    type Domain = GeometryDomain;
     type ColumnIterator<'a> = BinaryIterator<'a>;
     type ColumnBuilder = BinaryColumnBuilder;
 
@@ -65,11 +70,7 @@ impl ValueType for GeometryType {
     }
 
     fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
-        if domain.is_undefined() {
-            Some(())
-        } else {
-            None
-        }
+        domain.as_geometry().cloned()
     }
 
     fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
@@ -98,8 +99,8 @@ impl ValueType for GeometryType {
         Column::Geometry(col)
     }
 
-    fn upcast_domain(_domain: Self::Domain) -> Domain {
-        Domain::Undefined
+    fn upcast_domain(domain: Self::Domain) -> Domain {
+        Domain::Geometry(domain)
     }
 
     fn column_len(col: &Self::Column) -> usize {
@@ -166,13 +167,107 @@ impl ArgType for GeometryType {
         DataType::Geometry
     }
 
-    fn full_domain() -> Self::Domain {}
+    fn full_domain() -> Self::Domain {
+        GeometryDomain::full()
+    }
 
     fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
         BinaryColumnBuilder::with_capacity(capacity, 0)
     }
 }
 
+/// The bounding box of the geometries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryDomain {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl GeometryDomain {
+    pub fn full() -> Self {
+        GeometryDomain {
+            min_x: f64::NEG_INFINITY,
+            min_y: f64::NEG_INFINITY,
+            max_x: f64::INFINITY,
+            max_y: f64::INFINITY,
+        }
+    }
+
+    /// The bounding box of a geometry, it's full if the geometry is empty or invalid.
+    pub fn from_ewkb(buf: &[u8]) -> Self {
+        match ewkb_to_geo(buf).map(|(geom, _)| geom.bounding_rect()) {
+            Ok(Some(rect)) => GeometryDomain {
+                min_x: rect.min().x,
+                min_y: rect.min().y,
+                max_x: rect.max().x,
+                max_y: rect.max().y,
+            },
+            _ => GeometryDomain::full(),
+        }
+    }
+
+    pub fn from_column(col: &BinaryColumn) -> Self {
+        // empty values are the placeholders of nulls
+        col.iter()
+            .filter(|v| !v.is_empty())
+            .map(GeometryDomain::from_ewkb)
+            .reduce(|a, b| a.merge(&b))
+            .unwrap_or_else(GeometryDomain::full)
+    }
+
+    pub fn is_full(&self) -> bool {
+        *self == GeometryDomain::full()
+    }
+
+    pub fn merge(&self, other: &GeometryDomain) -> Self {
+        GeometryDomain {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    pub fn intersects(&self, other: &GeometryDomain) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    /// Encode the bounding box as the min and max statistics of a geometry column,
+    /// i.e. the lower left and the upper right corners.
+    pub fn to_min_max(&self) -> Option<(Scalar, Scalar)> {
+        if !(self.min_x.is_finite()
+            && self.min_y.is_finite()
+            && self.max_x.is_finite()
+            && self.max_y.is_finite())
+        {
+            return None;
+        }
+        let min = geo_to_ewkb(Geometry::from(Point::new(self.min_x, self.min_y)), None).ok()?;
+        let max = geo_to_ewkb(Geometry::from(Point::new(self.max_x, self.max_y)), None).ok()?;
+        Some((Scalar::Geometry(min), Scalar::Geometry(max)))
+    }
+
+    /// Decode the bounding box from the min and max statistics of a geometry column.
+    pub fn from_min_max(min: &Scalar, max: &Scalar) -> Option<Self> {
+        let point = |scalar: &Scalar| match ewkb_to_geo(scalar.as_geometry()?) {
+            Ok((Geometry::Point(point), _)) => Some(point),
+            _ => None,
+        };
+        let (min, max) = (point(min)?, point(max)?);
+        Some(GeometryDomain {
+            min_x: min.x(),
+            min_y: min.y(),
+            max_x: max.x(),
+            max_y: max.y(),
+        })
+    }
+}
+
 pub(crate) fn compare_geometry(left: &[u8], right: &[u8]) -> Option<Ordering> {
     let mut left_data = std::io::Cursor::new(left);
     let mut right_data = std::io::Cursor::new(right);
@@ -0,0 +1,271 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::net::Ipv6Addr;
+use std::ops::Range;
+
+use databend_common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// Parses an IPv4 or IPv6 address, the IPv4 address is stored as the IPv4-mapped
+/// IPv6 address `::ffff:a.b.c.d`.
+pub fn string_to_inet(s: &str) -> Result<u128, String> {
+    match s.trim().parse::<IpAddr>() {
+        Ok(IpAddr::V4(addr)) => Ok(u128::from(addr.to_ipv6_mapped())),
+        Ok(IpAddr::V6(addr)) => Ok(u128::from(addr)),
+        Err(_) => Err(format!("invalid IP address '{s}'")),
+    }
+}
+
+/// Formats the address, the IPv4-mapped addresses are formatted as IPv4 addresses.
+pub fn inet_to_string(value: u128) -> String {
+    let addr = Ipv6Addr::from(value);
+    match addr.to_ipv4_mapped() {
+        Some(addr) => addr.to_string(),
+        None => addr.to_string(),
+    }
+}
+
+/// Parses a CIDR like `192.168.0.0/16` or `2001:db8::/32` to the network address and
+/// the prefix length in the IPv6 address space. An address without the prefix length
+/// is a network of a single address.
+pub fn string_to_cidr(s: &str) -> Result<(u128, u8), String> {
+    let invalid = || format!("invalid CIDR '{s}'");
+    let (addr, prefix) = match s.trim().split_once('/') {
+        Some((addr, prefix)) => (addr, Some(prefix.parse::<u8>().map_err(|_| invalid())?)),
+        None => (s.trim(), None),
+    };
+    match addr.parse::<IpAddr>().map_err(|_| invalid())? {
+        IpAddr::V4(addr) => {
+            let prefix = prefix.unwrap_or(32);
+            if prefix > 32 {
+                return Err(invalid());
+            }
+            Ok((u128::from(addr.to_ipv6_mapped()), prefix + 96))
+        }
+        IpAddr::V6(addr) => {
+            let prefix = prefix.unwrap_or(128);
+            if prefix > 128 {
+                return Err(invalid());
+            }
+            Ok((u128::from(addr), prefix))
+        }
+    }
+}
+
+/// Returns the first and the last address of the network with the prefix length.
+pub fn cidr_to_range(addr: u128, prefix: u8) -> (u128, u128) {
+    let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+    (addr & mask, addr | !mask)
+}
+
+/// An IPv4 or IPv6 address stored as a `u128` in the IPv6 address space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InetType;
+
+impl ValueType for InetType {
+    type Scalar = u128;
+    type ScalarRef<'a> = u128;
+    type Column = Buffer<u128>;
+    type Domain = SimpleDomain<u128>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, u128>>;
+    type ColumnBuilder = Vec<u128>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: u128) -> u128 {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Inet(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::Inet(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<u128>> {
+        domain.as_inet().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Inet(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Inet(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(builder: Self::ColumnBuilder) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Inet(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Inet(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Inet(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<u128>) -> Domain {
+        Domain::Inet(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for InetType {
+    fn data_type() -> DataType {
+        DataType::Inet
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: 0,
+            max: u128::MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
@@ -0,0 +1,462 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_PER_SEC: i64 = 1_000_000;
+pub const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SEC;
+pub const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+pub const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+/// The number of days of a month when an interval is compared or averaged.
+pub const DAYS_PER_MONTH: i64 = 30;
+
+/// An interval of months, days and microseconds.
+///
+/// The three components are packed into a `i128`, months in the highest 32 bits,
+/// days in the next 32 bits and microseconds in the lowest 64 bits, so a column of
+/// intervals has the same layout as a column of `Decimal128`.
+///
+/// The components are kept apart because the length of a month or a day in
+/// microseconds depends on the timestamp that the interval is added to. When
+/// intervals are compared, a month is taken as 30 days and a day as 24 hours.
+#[allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[repr(transparent)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub const ZERO: months_days_micros = months_days_micros(0);
+
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        let months = (months as i128) << 96;
+        let days = ((days as u32) as i128) << 64;
+        let microseconds = (microseconds as u64) as i128;
+        months_days_micros(months | days | microseconds)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    #[inline]
+    pub fn microseconds(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// The length of the interval in microseconds, a month is taken as 30 days.
+    pub fn total_micros(&self) -> i128 {
+        (self.months() as i128 * DAYS_PER_MONTH as i128 + self.days() as i128)
+            * MICROS_PER_DAY as i128
+            + self.microseconds() as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.microseconds().checked_add(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_sub(other.months())?,
+            self.days().checked_sub(other.days())?,
+            self.microseconds().checked_sub(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.microseconds().checked_neg()?,
+        ))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Break the ties by the packed value to be consistent with `Eq`,
+        // e.g. `1 month` and `30 days` have the same length but are not equal.
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+/// Displays the interval like `1 year 2 months 3 days 04:05:06.000007`, which can be
+/// parsed back by [`string_to_interval`].
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        let (years, months) = (self.months() / 12, self.months() % 12);
+        for (value, unit) in [(years as i64, "year"), (months as i64, "month")]
+            .into_iter()
+            .chain(std::iter::once((self.days() as i64, "day")))
+        {
+            match value {
+                0 => {}
+                1 | -1 => parts.push(format!("{value} {unit}")),
+                _ => parts.push(format!("{value} {unit}s")),
+            }
+        }
+
+        let micros = self.microseconds();
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let micros = micros.unsigned_abs();
+            let hours = micros / MICROS_PER_HOUR as u64;
+            let minutes = micros % MICROS_PER_HOUR as u64 / MICROS_PER_MINUTE as u64;
+            let seconds = micros % MICROS_PER_MINUTE as u64 / MICROS_PER_SEC as u64;
+            let fraction = micros % MICROS_PER_SEC as u64;
+            if fraction == 0 {
+                parts.push(format!("{sign}{hours:02}:{minutes:02}:{seconds:02}"));
+            } else {
+                parts.push(format!(
+                    "{sign}{hours:02}:{minutes:02}:{seconds:02}.{fraction:06}"
+                ));
+            }
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// Parses an interval like `3 days 2 hours`, `1 year -2 months`, `1.5 seconds` or
+/// `1 day 02:30:00`, the units can be singular, plural or abbreviated.
+pub fn string_to_interval(s: &str) -> Result<months_days_micros, String> {
+    let invalid = || format!("invalid interval '{s}'");
+    let mut months: i64 = 0;
+    let mut days: i64 = 0;
+    let mut micros: i128 = 0;
+
+    let mut tokens = s.split_whitespace().peekable();
+    if tokens.peek().is_none() {
+        return Err(invalid());
+    }
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            micros += parse_time(token).ok_or_else(invalid)? as i128;
+            continue;
+        }
+
+        // The unit may follow the number without a space, e.g. `3days`.
+        let split = token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let (number, unit) = if split < token.len() {
+            (&token[..split], token[split..].to_string())
+        } else {
+            let unit = tokens.next().ok_or_else(invalid)?;
+            (token, unit.to_string())
+        };
+        let number = number.parse::<f64>().map_err(|_| invalid())?;
+        if !number.is_finite() {
+            return Err(invalid());
+        }
+
+        let unit = unit.to_ascii_lowercase();
+        match unit.as_str() {
+            "y" | "yr" | "yrs" | "year" | "years" => months += whole(number * 12.0)?,
+            "mon" | "mons" | "month" | "months" => months += whole(number)?,
+            "w" | "week" | "weeks" => days += whole(number * 7.0)?,
+            "d" | "day" | "days" => days += whole(number)?,
+            "h" | "hr" | "hrs" | "hour" | "hours" => {
+                micros += (number * MICROS_PER_HOUR as f64).round() as i128
+            }
+            "m" | "min" | "mins" | "minute" | "minutes" => {
+                micros += (number * MICROS_PER_MINUTE as f64).round() as i128
+            }
+            "s" | "sec" | "secs" | "second" | "seconds" => {
+                micros += (number * MICROS_PER_SEC as f64).round() as i128
+            }
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => {
+                micros += (number * 1_000.0).round() as i128
+            }
+            "us" | "usec" | "usecs" | "microsecond" | "microseconds" => {
+                micros += number.round() as i128
+            }
+            _ => return Err(format!("invalid interval unit '{unit}' in '{s}'")),
+        }
+    }
+
+    Ok(months_days_micros::new(
+        i32::try_from(months).map_err(|_| format!("interval '{s}' is out of range"))?,
+        i32::try_from(days).map_err(|_| format!("interval '{s}' is out of range"))?,
+        i64::try_from(micros).map_err(|_| format!("interval '{s}' is out of range"))?,
+    ))
+}
+
+fn whole(value: f64) -> Result<i64, String> {
+    if value.fract() != 0.0 {
+        return Err(format!(
+            "fractional months or days are not supported in interval: {value}"
+        ));
+    }
+    Ok(value as i64)
+}
+
+/// Parses `[-]HH:MM[:SS[.ffffff]]` to microseconds.
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut parts = s.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours.checked_mul(MICROS_PER_HOUR)?
+        + minutes * MICROS_PER_MINUTE
+        + (seconds * MICROS_PER_SEC as f64).round() as i64;
+    Some(if negative { -micros } else { micros })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = SimpleDomain<months_days_micros>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<months_days_micros>> {
+        domain.as_interval().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(builder: Self::ColumnBuilder) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<months_days_micros>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: months_days_micros::new(i32::MIN, i32::MIN, i64::MIN),
+            max: months_days_micros::new(i32::MAX, i32::MAX, i64::MAX),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
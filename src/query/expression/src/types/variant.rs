@@ -23,8 +23,10 @@ use super::binary::BinaryColumn;
 use super::binary::BinaryColumnBuilder;
 use super::binary::BinaryIterator;
 use super::date::date_to_string;
+use super::inet::inet_to_string;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
+use super::uuid::uuid_to_string;
 use crate::date_helper::TzLUT;
 use crate::property::Domain;
 use crate::types::map::KvPair;
@@ -212,6 +214,9 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::String(s) => jsonb::Value::String(s.into()),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
+        ScalarRef::Uuid(i) => uuid_to_string(i).into(),
+        ScalarRef::Inet(i) => inet_to_string(i).into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -19,6 +19,7 @@ use itertools::Itertools;
 
 use crate::types::decimal::*;
 use crate::types::geometry::GeometryType;
+use crate::types::interval::IntervalType;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::*;
 use crate::types::*;
@@ -73,6 +74,9 @@ impl_from_data! { TimestampType }
 impl_from_data! { VariantType }
 impl_from_data! { BitmapType }
 impl_from_data! { GeometryType }
+impl_from_data! { IntervalType }
+impl_from_data! { UuidType }
+impl_from_data! { InetType }
 
 impl<'a> FromData<&'a [u8]> for BinaryType {
     fn from_data(d: Vec<&'a [u8]>) -> Column {
@@ -43,6 +43,8 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::GeometryDomain;
+use crate::types::inet::inet_to_string;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -53,6 +55,7 @@ use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
 use crate::types::timestamp::timestamp_to_string;
+use crate::types::uuid::uuid_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::NumberClass;
@@ -168,6 +171,9 @@ impl<'a> Debug for ScalarRef<'a> {
                 let geom = Ewkb(s.to_vec()).to_ewkt(None).unwrap();
                 write!(f, "{geom:?}")
             }
+            ScalarRef::Interval(i) => write!(f, "{i}"),
+            ScalarRef::Uuid(i) => write!(f, "{}", uuid_to_string(*i)),
+            ScalarRef::Inet(i) => write!(f, "{}", inet_to_string(*i)),
         }
     }
 }
@@ -192,6 +198,9 @@ impl Debug for Column {
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
             Column::Geometry(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
+            Column::Uuid(col) => write!(f, "{col:?}"),
+            Column::Inet(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -257,6 +266,9 @@ impl<'a> Display for ScalarRef<'a> {
                 let geom = Ewkb(s.to_vec()).to_ewkt(None).unwrap();
                 write!(f, "'{geom:?}'")
             }
+            ScalarRef::Interval(i) => write!(f, "'{i}'"),
+            ScalarRef::Uuid(i) => write!(f, "'{}'", uuid_to_string(*i)),
+            ScalarRef::Inet(i) => write!(f, "'{}'", inet_to_string(*i)),
         }
     }
 }
@@ -522,6 +534,9 @@ impl Display for DataType {
             }
             DataType::Variant => write!(f, "Variant"),
             DataType::Geometry => write!(f, "Geometry"),
+            DataType::Interval => write!(f, "Interval"),
+            DataType::Uuid => write!(f, "Uuid"),
+            DataType::Inet => write!(f, "Inet"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -570,6 +585,9 @@ impl Display for TableDataType {
             }
             TableDataType::Variant => write!(f, "Variant"),
             TableDataType::Geometry => write!(f, "Geometry"),
+            TableDataType::Interval => write!(f, "Interval"),
+            TableDataType::Uuid => write!(f, "Uuid"),
+            TableDataType::Inet => write!(f, "Inet"),
         }
     }
 }
@@ -1030,11 +1048,32 @@ impl Display for Domain {
                 let val_domain = &inner_domain[1];
                 write!(f, "{{[{key_domain}], [{val_domain}]}}")
             }
+            Domain::Geometry(domain) => write!(f, "{domain}"),
+            Domain::Interval(domain) => write!(f, "{domain}"),
+            Domain::Uuid(domain) => write!(f, "{domain}"),
+            Domain::Inet(domain) => write!(f, "{domain}"),
             Domain::Undefined => write!(f, "Undefined"),
         }
     }
 }
 
+impl Display for GeometryDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{x: {}, y: {}}}",
+            SimpleDomain {
+                min: display_f64(self.min_x),
+                max: display_f64(self.max_x),
+            },
+            SimpleDomain {
+                min: display_f64(self.min_y),
+                max: display_f64(self.max_y),
+            }
+        )
+    }
+}
+
 /// Display a float as with fixed number of fractional digits to avoid test failures due to
 /// rounding differences between MacOS and Linus.
 fn display_f32(num: f32) -> String {
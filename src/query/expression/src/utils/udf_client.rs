@@ -135,6 +135,28 @@ impl UDFFlightClient {
         input_batch: RecordBatch,
     ) -> Result<RecordBatch> {
         let descriptor = FlightDescriptor::new_path(vec![func_name.to_string()]);
+        self.exchange(descriptor, input_batch).await
+    }
+
+    /// Exchange a batch with one of the phases of an aggregate function,
+    /// the phase is the second element of the descriptor path.
+    #[async_backtrace::framed]
+    pub async fn do_aggregate_exchange(
+        &mut self,
+        func_name: &str,
+        phase: &str,
+        input_batch: RecordBatch,
+    ) -> Result<RecordBatch> {
+        let descriptor = FlightDescriptor::new_path(vec![func_name.to_string(), phase.to_string()]);
+        self.exchange(descriptor, input_batch).await
+    }
+
+    #[async_backtrace::framed]
+    async fn exchange(
+        &mut self,
+        descriptor: FlightDescriptor,
+        input_batch: RecordBatch,
+    ) -> Result<RecordBatch> {
         let flight_data_stream = FlightDataEncoderBuilder::new()
             .with_flight_descriptor(Some(descriptor))
             .build(stream::iter(vec![Ok(input_batch)]))
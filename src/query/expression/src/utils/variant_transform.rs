@@ -40,6 +40,9 @@ pub fn contains_variant(data_type: &DataType) -> bool {
         | DataType::Date
         | DataType::Bitmap
         | DataType::Geometry
+        | DataType::Interval
+        | DataType::Uuid
+        | DataType::Inet
         | DataType::Generic(_) => false,
         DataType::Nullable(ty) => contains_variant(ty.as_ref()),
         DataType::Array(ty) => contains_variant(ty.as_ref()),
@@ -80,7 +83,10 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::Binary(_)
         | ScalarRef::String(_)
         | ScalarRef::Bitmap(_)
-        | ScalarRef::Geometry(_) => scalar.to_owned(),
+        | ScalarRef::Geometry(_)
+        | ScalarRef::Interval(_)
+        | ScalarRef::Uuid(_)
+        | ScalarRef::Inet(_) => scalar.to_owned(),
         ScalarRef::Array(col) => Scalar::Array(transform_column(&col, decode)?),
         ScalarRef::Map(col) => Scalar::Map(transform_column(&col, decode)?),
         ScalarRef::Tuple(scalars) => {
@@ -61,7 +61,9 @@ use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
 use crate::types::decimal::DecimalType;
 use crate::types::geometry::compare_geometry;
+use crate::types::geometry::GeometryDomain;
 use crate::types::geometry::GeometryType;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -125,6 +127,9 @@ pub enum Scalar {
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
     Geometry(Vec<u8>),
+    Interval(months_days_micros),
+    Uuid(u128),
+    Inet(u128),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -146,6 +151,9 @@ pub enum ScalarRef<'a> {
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
     Geometry(&'a [u8]),
+    Interval(months_days_micros),
+    Uuid(u128),
+    Inet(u128),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -167,6 +175,9 @@ pub enum Column {
     Tuple(Vec<Column>),
     Variant(BinaryColumn),
     Geometry(BinaryColumn),
+    Interval(Buffer<months_days_micros>),
+    Uuid(Buffer<u128>),
+    Inet(Buffer<u128>),
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -188,6 +199,9 @@ pub enum ColumnVec {
     Tuple(Vec<ColumnVec>),
     Variant(Vec<BinaryColumn>),
     Geometry(Vec<BinaryColumn>),
+    Interval(Vec<Buffer<months_days_micros>>),
+    Uuid(Vec<Buffer<u128>>),
+    Inet(Vec<Buffer<u128>>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -209,6 +223,9 @@ pub enum ColumnBuilder {
     Tuple(Vec<ColumnBuilder>),
     Variant(BinaryColumnBuilder),
     Geometry(BinaryColumnBuilder),
+    Interval(Vec<months_days_micros>),
+    Uuid(Vec<u128>),
+    Inet(Vec<u128>),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -364,6 +381,9 @@ impl Scalar {
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
             Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
+            Scalar::Uuid(i) => ScalarRef::Uuid(*i),
+            Scalar::Inet(i) => ScalarRef::Inet(*i),
         }
     }
 
@@ -410,6 +430,9 @@ impl Scalar {
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
             DataType::Geometry => Scalar::Geometry(vec![]),
+            DataType::Interval => Scalar::Interval(months_days_micros::ZERO),
+            DataType::Uuid => Scalar::Uuid(0),
+            DataType::Inet => Scalar::Inet(0),
 
             _ => unimplemented!(),
         }
@@ -429,7 +452,10 @@ impl Scalar {
             | Scalar::String(_)
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
-            | Scalar::Geometry(_) => false,
+            | Scalar::Geometry(_)
+            | Scalar::Interval(_)
+            | Scalar::Uuid(_)
+            | Scalar::Inet(_) => false,
             Scalar::Array(_) | Scalar::Map(_) | Scalar::Tuple(_) => true,
         }
     }
@@ -440,6 +466,7 @@ impl Scalar {
             Scalar::Decimal(d) => d.is_positive(),
             Scalar::Timestamp(t) => *t > 0,
             Scalar::Date(d) => *d > 0,
+            Scalar::Interval(i) => i.total_micros() > 0,
             _ => unreachable!("is_positive() called on non-numeric scalar"),
         }
     }
@@ -483,6 +510,9 @@ impl<'a> ScalarRef<'a> {
             }
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
             ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
+            ScalarRef::Uuid(i) => Scalar::Uuid(*i),
+            ScalarRef::Inet(i) => Scalar::Inet(*i),
         }
     }
 
@@ -544,10 +574,13 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Binary(_)
-            | ScalarRef::Bitmap(_)
-            | ScalarRef::Variant(_)
-            | ScalarRef::Geometry(_) => Domain::Undefined,
+            ScalarRef::Geometry(buf) => Domain::Geometry(GeometryDomain::from_ewkb(buf)),
+            ScalarRef::Interval(i) => Domain::Interval(SimpleDomain { min: *i, max: *i }),
+            ScalarRef::Uuid(i) => Domain::Uuid(SimpleDomain { min: *i, max: *i }),
+            ScalarRef::Inet(i) => Domain::Inet(SimpleDomain { min: *i, max: *i }),
+            ScalarRef::Binary(_) | ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => {
+                Domain::Undefined
+            }
         }
     }
 
@@ -577,6 +610,9 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
             ScalarRef::Geometry(buf) => buf.len(),
+            ScalarRef::Interval(_) => 16,
+            ScalarRef::Uuid(_) => 16,
+            ScalarRef::Inet(_) => 16,
         }
     }
 
@@ -610,6 +646,9 @@ impl<'a> ScalarRef<'a> {
             }
             ScalarRef::Variant(_) => DataType::Variant,
             ScalarRef::Geometry(_) => DataType::Geometry,
+            ScalarRef::Interval(_) => DataType::Interval,
+            ScalarRef::Uuid(_) => DataType::Uuid,
+            ScalarRef::Inet(_) => DataType::Inet,
         }
     }
 
@@ -675,6 +714,9 @@ impl<'a> ScalarRef<'a> {
             }
             (ScalarRef::Variant(_), ScalarRef::Variant(_)) => Some(DataType::Variant),
             (ScalarRef::Geometry(_), ScalarRef::Geometry(_)) => Some(DataType::Geometry),
+            (ScalarRef::Interval(_), ScalarRef::Interval(_)) => Some(DataType::Interval),
+            (ScalarRef::Uuid(_), ScalarRef::Uuid(_)) => Some(DataType::Uuid),
+            (ScalarRef::Inet(_), ScalarRef::Inet(_)) => Some(DataType::Inet),
             _ => None,
         }
     }
@@ -697,6 +739,9 @@ impl<'a> ScalarRef<'a> {
                 (ScalarRef::Bitmap(_), DataType::Bitmap) => true,
                 (ScalarRef::Variant(_), DataType::Variant) => true,
                 (ScalarRef::Geometry(_), DataType::Geometry) => true,
+                (ScalarRef::Interval(_), DataType::Interval) => true,
+                (ScalarRef::Uuid(_), DataType::Uuid) => true,
+                (ScalarRef::Inet(_), DataType::Inet) => true,
                 (ScalarRef::Array(val), DataType::Array(ty)) => val.data_type() == *ty,
                 (ScalarRef::Map(val), DataType::Map(ty)) => val.data_type() == *ty,
                 (ScalarRef::Tuple(val), DataType::Tuple(ty)) => {
@@ -734,6 +779,9 @@ impl PartialOrd for Scalar {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
             }
             (Scalar::Geometry(g1), Scalar::Geometry(g2)) => compare_geometry(g1, g2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
+            (Scalar::Uuid(i1), Scalar::Uuid(i2)) => i1.partial_cmp(i2),
+            (Scalar::Inet(i1), Scalar::Inet(i2)) => i1.partial_cmp(i2),
             _ => None,
         }
     }
@@ -770,6 +818,9 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
             (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => compare_geometry(g1, g2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Uuid(i1), ScalarRef::Uuid(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Inet(i1), ScalarRef::Inet(i2)) => i1.partial_cmp(i2),
             _ => None,
         }
     }
@@ -820,6 +871,9 @@ impl Hash for ScalarRef<'_> {
             }
             ScalarRef::Variant(v) => v.hash(state),
             ScalarRef::Geometry(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
+            ScalarRef::Uuid(v) => v.hash(state),
+            ScalarRef::Inet(v) => v.hash(state),
         }
     }
 }
@@ -862,6 +916,11 @@ impl PartialOrd for Column {
             (Column::Geometry(col1), Column::Geometry(col2)) => {
                 col1.iter().partial_cmp_by(col2.iter(), compare_geometry)
             }
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::Uuid(col1), Column::Uuid(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Inet(col1), Column::Inet(col2)) => col1.iter().partial_cmp(col2.iter()),
             _ => None,
         }
     }
@@ -893,6 +952,9 @@ impl Column {
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
             Column::Geometry(col) => col.len(),
+            Column::Interval(col) => col.len(),
+            Column::Uuid(col) => col.len(),
+            Column::Inet(col) => col.len(),
         }
     }
 
@@ -920,6 +982,9 @@ impl Column {
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
             Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
+            Column::Uuid(col) => Some(ScalarRef::Uuid(col.get(index).cloned()?)),
+            Column::Inet(col) => Some(ScalarRef::Inet(col.get(index).cloned()?)),
         }
     }
 
@@ -950,6 +1015,9 @@ impl Column {
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
             Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
+            Column::Uuid(col) => ScalarRef::Uuid(*col.get_unchecked(index)),
+            Column::Inet(col) => ScalarRef::Inet(*col.get_unchecked(index)),
         }
     }
 
@@ -1001,6 +1069,15 @@ impl Column {
             ),
             Column::Variant(col) => Column::Variant(col.slice(range)),
             Column::Geometry(col) => Column::Geometry(col.slice(range)),
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Uuid(col) => {
+                Column::Uuid(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Inet(col) => {
+                Column::Inet(col.clone().sliced(range.start, range.end - range.start))
+            }
         }
     }
 
@@ -1077,9 +1154,29 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Binary(_) | Column::Bitmap(_) | Column::Variant(_) | Column::Geometry(_) => {
-                Domain::Undefined
+            Column::Geometry(col) => Domain::Geometry(GeometryDomain::from_column(col)),
+            Column::Interval(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Interval(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
             }
+            Column::Uuid(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Uuid(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            Column::Inet(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Inet(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            Column::Binary(_) | Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
         }
     }
 
@@ -1119,6 +1216,9 @@ impl Column {
             }
             Column::Variant(_) => DataType::Variant,
             Column::Geometry(_) => DataType::Geometry,
+            Column::Interval(_) => DataType::Interval,
+            Column::Uuid(_) => DataType::Uuid,
+            Column::Inet(_) => DataType::Inet,
         }
     }
 
@@ -1303,6 +1403,28 @@ impl Column {
                 });
                 GeometryType::from_data(data)
             }
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        months_days_micros::new(
+                            rng.gen_range(-120..=120),
+                            rng.gen_range(-60..=60),
+                            rng.gen_range(-86_400_000_000..=86_400_000_000),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::Uuid => UuidType::from_data(
+                (0..len)
+                    .map(|_| SmallRng::from_entropy().gen::<u128>())
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::Inet => InetType::from_data(
+                (0..len)
+                    .map(|_| SmallRng::from_entropy().gen::<u128>())
+                    .collect::<Vec<_>>(),
+            ),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1366,6 +1488,9 @@ impl Column {
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.memory_size(),
             Column::Geometry(col) => col.memory_size(),
+            Column::Interval(col) => col.len() * 16,
+            Column::Uuid(col) => col.len() * 16,
+            Column::Inet(col) => col.len() * 16,
         }
     }
 
@@ -1384,6 +1509,9 @@ impl Column {
             Column::Number(NumberColumn::Int64(col)) | Column::Timestamp(col) => col.len() * 8,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
+            Column::Interval(col) => col.len() * 16,
+            Column::Uuid(col) => col.len() * 16,
+            Column::Inet(col) => col.len() * 16,
             Column::Boolean(c) => c.len(),
             Column::Binary(col)
             | Column::Bitmap(col)
@@ -1497,6 +1625,9 @@ impl ColumnBuilder {
             ),
             Column::Variant(col) => ColumnBuilder::Variant(BinaryColumnBuilder::from_column(col)),
             Column::Geometry(col) => ColumnBuilder::Geometry(BinaryColumnBuilder::from_column(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
+            Column::Uuid(col) => ColumnBuilder::Uuid(buffer_into_mut(col)),
+            Column::Inet(col) => ColumnBuilder::Inet(buffer_into_mut(col)),
         }
     }
 
@@ -1560,6 +1691,9 @@ impl ColumnBuilder {
             }
             ScalarRef::Variant(s) => ColumnBuilder::Variant(BinaryColumnBuilder::repeat(s, n)),
             ScalarRef::Geometry(s) => ColumnBuilder::Geometry(BinaryColumnBuilder::repeat(s, n)),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
+            ScalarRef::Uuid(i) => ColumnBuilder::Uuid(vec![*i; n]),
+            ScalarRef::Inet(i) => ColumnBuilder::Inet(vec![*i; n]),
         }
     }
 
@@ -1582,6 +1716,9 @@ impl ColumnBuilder {
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
             ColumnBuilder::Geometry(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
+            ColumnBuilder::Uuid(builder) => builder.len(),
+            ColumnBuilder::Inet(builder) => builder.len(),
         }
     }
 
@@ -1618,6 +1755,9 @@ impl ColumnBuilder {
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Interval(col) => col.len() * 16,
+            ColumnBuilder::Uuid(col) => col.len() * 16,
+            ColumnBuilder::Inet(col) => col.len() * 16,
         }
     }
 
@@ -1653,6 +1793,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Variant(_) => DataType::Variant,
             ColumnBuilder::Geometry(_) => DataType::Geometry,
+            ColumnBuilder::Interval(_) => DataType::Interval,
+            ColumnBuilder::Uuid(_) => DataType::Uuid,
+            ColumnBuilder::Inet(_) => DataType::Inet,
         }
     }
 
@@ -1731,6 +1874,9 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Geometry(BinaryColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
+            DataType::Uuid => ColumnBuilder::Uuid(Vec::with_capacity(capacity)),
+            DataType::Inet => ColumnBuilder::Inet(Vec::with_capacity(capacity)),
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -1787,6 +1933,15 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
+                builder.push(value);
+            }
+            (ColumnBuilder::Uuid(builder), ScalarRef::Uuid(value)) => {
+                builder.push(value);
+            }
+            (ColumnBuilder::Inet(builder), ScalarRef::Inet(value)) => {
+                builder.push(value);
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         }
     }
@@ -1817,6 +1972,9 @@ impl ColumnBuilder {
                 builder.commit_row();
             }
             ColumnBuilder::Geometry(builder) => builder.commit_row(),
+            ColumnBuilder::Interval(builder) => builder.push(months_days_micros::ZERO),
+            ColumnBuilder::Uuid(builder) => builder.push(0),
+            ColumnBuilder::Inet(builder) => builder.push(0),
         }
     }
 
@@ -1870,6 +2028,15 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                builder.push(months_days_micros(i128::de_binary(reader)));
+            }
+            ColumnBuilder::Uuid(builder) => {
+                builder.push(i128::de_binary(reader) as u128);
+            }
+            ColumnBuilder::Inet(builder) => {
+                builder.push(i128::de_binary(reader) as u128);
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -1975,6 +2142,24 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(months_days_micros(i128::de_binary(&mut reader)));
+                }
+            }
+            ColumnBuilder::Uuid(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(i128::de_binary(&mut reader) as u128);
+                }
+            }
+            ColumnBuilder::Inet(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(i128::de_binary(&mut reader) as u128);
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2066,6 +2251,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Variant(builder) => builder.pop().map(Scalar::Variant),
             ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
+            ColumnBuilder::Uuid(builder) => builder.pop().map(Scalar::Uuid),
+            ColumnBuilder::Inet(builder) => builder.pop().map(Scalar::Inet),
         }
     }
 
@@ -2107,6 +2295,15 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Uuid(builder), Column::Uuid(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Inet(builder), Column::Inet(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2155,6 +2352,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Variant(builder) => Column::Variant(builder.build()),
             ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
+            ColumnBuilder::Uuid(builder) => Column::Uuid(builder.into()),
+            ColumnBuilder::Inet(builder) => Column::Inet(builder.into()),
         }
     }
 
@@ -2183,6 +2383,9 @@ impl ColumnBuilder {
             ),
             ColumnBuilder::Variant(builder) => Scalar::Variant(builder.build_scalar()),
             ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
+            ColumnBuilder::Uuid(builder) => Scalar::Uuid(builder[0]),
+            ColumnBuilder::Inet(builder) => Scalar::Inet(builder[0]),
         }
     }
 }
@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use arrow_schema::Field as ArrowField;
+use arrow_schema::IntervalUnit;
 use databend_common_arrow::arrow::array::new_empty_array;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::Column;
+use databend_common_expression::DataField;
 
 #[test]
 fn test_from_arrow_extension_to_column() -> Result<()> {
@@ -30,3 +35,33 @@ fn test_from_arrow_extension_to_column() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_interval_to_arrow_rs() -> Result<()> {
+    let values = vec![
+        months_days_micros::new(1, 2, 3),
+        months_days_micros::new(-14, 0, -86_400_000_000),
+    ];
+    let column = Column::Interval(values.into());
+
+    let array = column.clone().into_arrow_rs();
+    assert_eq!(
+        array.data_type(),
+        &arrow_schema::DataType::Interval(IntervalUnit::MonthDayNano)
+    );
+    let arrow_field = ArrowField::from(&DataField::new("i", DataType::Interval));
+    assert_eq!(
+        arrow_field.data_type(),
+        &arrow_schema::DataType::Interval(IntervalUnit::MonthDayNano)
+    );
+    assert_eq!(Column::from_arrow_rs(array, &DataType::Interval)?, column);
+
+    let array_type = DataType::Array(Box::new(DataType::Interval));
+    let array_column = Column::Array(Box::new(ArrayColumn {
+        values: column,
+        offsets: vec![0, 1, 2].into(),
+    }));
+    let array = array_column.clone().into_arrow_rs();
+    assert_eq!(Column::from_arrow_rs(array, &array_type)?, array_column);
+    Ok(())
+}
@@ -27,10 +27,14 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::inet::string_to_inet;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::NumberColumnBuilder;
 use databend_common_expression::with_decimal_type;
@@ -101,6 +105,9 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
             ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, value),
+            ColumnBuilder::Inet(c) => self.read_inet(c, value),
             _ => unimplemented!(),
         }
     }
@@ -339,6 +346,39 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let interval = string_to_interval(v).map_err(ErrorCode::BadBytes)?;
+                column.push(interval);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Interval value")),
+        }
+    }
+
+    fn read_uuid(&self, column: &mut Vec<u128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let uuid = string_to_uuid(v).map_err(ErrorCode::BadBytes)?;
+                column.push(uuid);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect UUID value")),
+        }
+    }
+
+    fn read_inet(&self, column: &mut Vec<u128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let inet = string_to_inet(v).map_err(ErrorCode::BadBytes)?;
+                column.push(inet);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect INET value")),
+        }
+    }
+
     fn read_array(&self, column: &mut ArrayColumnBuilder<AnyType>, value: &Value) -> Result<()> {
         match value {
             Value::Array(vals) => {
@@ -28,10 +28,14 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::inet::string_to_inet;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::NumberColumnBuilder;
 use databend_common_expression::with_decimal_type;
@@ -138,6 +142,9 @@ impl NestedValues {
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader),
             ColumnBuilder::Geometry(c) => self.read_geometry(c, reader),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, reader),
+            ColumnBuilder::Inet(c) => self.read_inet(c, reader),
             ColumnBuilder::EmptyArray { .. } => {
                 unreachable!("EmptyArray")
             }
@@ -334,6 +341,48 @@ impl NestedValues {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<months_days_micros>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        let data = std::str::from_utf8(&buf)
+            .map_err(|_| ErrorCode::BadBytes("Incorrect Interval value"))?;
+        let interval = string_to_interval(data).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_uuid<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<u128>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        let data =
+            std::str::from_utf8(&buf).map_err(|_| ErrorCode::BadBytes("Incorrect UUID value"))?;
+        let uuid = string_to_uuid(data).map_err(ErrorCode::BadBytes)?;
+        column.push(uuid);
+        Ok(())
+    }
+
+    fn read_inet<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<u128>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        let data =
+            std::str::from_utf8(&buf).map_err(|_| ErrorCode::BadBytes("Incorrect INET value"))?;
+        let inet = string_to_inet(data).map_err(ErrorCode::BadBytes)?;
+        column.push(inet);
+        Ok(())
+    }
+
     fn read_nullable<R: AsRef<[u8]>>(
         &self,
         column: &mut NullableColumnBuilder<AnyType>,
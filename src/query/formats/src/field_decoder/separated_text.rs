@@ -27,8 +27,12 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::inet::string_to_inet;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberColumnBuilder;
@@ -172,6 +176,9 @@ impl SeparatedTextDecoder {
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, data),
             ColumnBuilder::Variant(c) => self.read_variant(c, data),
             ColumnBuilder::Geometry(c) => self.read_geometry(c, data),
+            ColumnBuilder::Interval(c) => self.read_interval(c, data),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, data),
+            ColumnBuilder::Inet(c) => self.read_inet(c, data),
             ColumnBuilder::EmptyArray { .. } => {
                 unreachable!("EmptyArray")
             }
@@ -338,6 +345,30 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, data: &[u8]) -> Result<()> {
+        let data = std::str::from_utf8(data)
+            .map_err(|_| ErrorCode::BadBytes("Incorrect Interval value"))?;
+        let interval = string_to_interval(data).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_uuid(&self, column: &mut Vec<u128>, data: &[u8]) -> Result<()> {
+        let data =
+            std::str::from_utf8(data).map_err(|_| ErrorCode::BadBytes("Incorrect UUID value"))?;
+        let uuid = string_to_uuid(data).map_err(ErrorCode::BadBytes)?;
+        column.push(uuid);
+        Ok(())
+    }
+
+    fn read_inet(&self, column: &mut Vec<u128>, data: &[u8]) -> Result<()> {
+        let data =
+            std::str::from_utf8(data).map_err(|_| ErrorCode::BadBytes("Incorrect INET value"))?;
+        let inet = string_to_inet(data).map_err(ErrorCode::BadBytes)?;
+        column.push(inet);
+        Ok(())
+    }
+
     fn read_array(&self, column: &mut ArrayColumnBuilder<AnyType>, data: &[u8]) -> Result<()> {
         let mut cursor = Cursor::new(data);
         self.nested_decoder.read_array(column, &mut cursor)
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use databend_common_expression::date_helper::DateConverter;
+use databend_common_expression::types::inet::inet_to_string;
 use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::uuid::uuid_to_string;
 use databend_common_expression::DataBlock;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
@@ -98,6 +100,9 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => serde_json::to_value(v.to_string()).unwrap(),
+        ScalarRef::Uuid(v) => serde_json::to_value(uuid_to_string(v)).unwrap(),
+        ScalarRef::Inet(v) => serde_json::to_value(inet_to_string(v)).unwrap(),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
@@ -21,6 +21,9 @@ use borsh::BorshSerialize;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::DAYS_PER_MONTH;
+use databend_common_expression::types::interval::MICROS_PER_DAY;
 use databend_common_expression::types::*;
 use databend_common_expression::utils::arithmetics_type::ResultTypeOfUnary;
 use databend_common_expression::with_number_mapped_type;
@@ -28,6 +31,7 @@ use databend_common_expression::Scalar;
 use num_traits::AsPrimitive;
 
 use super::aggregate_sum::DecimalSumState;
+use super::aggregate_sum::IntervalSumState;
 use super::borsh_deserialize_state;
 use super::borsh_serialize_state;
 use super::AggregateUnaryFunction;
@@ -213,6 +217,55 @@ where
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+struct IntervalAvgState {
+    pub sum: IntervalSumState,
+    pub count: u64,
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalAvgState {
+    fn add(&mut self, other: months_days_micros) -> Result<()> {
+        self.count += 1;
+        self.sum.add_interval(&other);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.count += rhs.count;
+        self.sum.merge_state(&rhs.sum);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<months_days_micros>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if self.count == 0 {
+            builder.push(months_days_micros::ZERO);
+            return Ok(());
+        }
+        // The remainder of the months is carried to the days, and the remainder
+        // of the days is carried to the microseconds.
+        let count = self.count as i64;
+        let months = self.sum.months / count;
+        let days = self.sum.days + self.sum.months % count * DAYS_PER_MONTH;
+        let micros = self.sum.micros + (days % count) as i128 * MICROS_PER_DAY as i128;
+        let value = IntervalSumState::to_interval(months, days / count, micros / count as i128)?;
+        builder.push(value);
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        borsh_serialize_state(writer, self)
+    }
+
+    fn deserialize(reader: &mut &[u8]) -> Result<Self>
+    where Self: Sized {
+        borsh_deserialize_state(reader)
+    }
+}
+
 pub fn try_create_aggregate_avg_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -303,6 +356,14 @@ pub fn try_create_aggregate_avg_function(
                 Ok(Arc::new(func))
             }
         }
+        DataType::Interval => {
+            AggregateUnaryFunction::<IntervalAvgState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                DataType::Interval,
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
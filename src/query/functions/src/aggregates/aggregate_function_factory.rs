@@ -121,6 +121,8 @@ impl CombinatorDescription {
 pub struct AggregateFunctionFactory {
     case_insensitive_desc: HashMap<String, AggregateFunctionDescription>,
     case_insensitive_combinator_desc: Vec<(String, CombinatorDescription)>,
+    // Functions that are only built by the planner, they can't be called by name in SQL.
+    internal_desc: HashMap<String, AggregateFunctionDescription>,
 }
 
 impl AggregateFunctionFactory {
@@ -128,6 +130,7 @@ impl AggregateFunctionFactory {
         AggregateFunctionFactory {
             case_insensitive_desc: Default::default(),
             case_insensitive_combinator_desc: Default::default(),
+            internal_desc: Default::default(),
         }
     }
 
@@ -140,6 +143,12 @@ impl AggregateFunctionFactory {
         case_insensitive_desc.insert(name.to_lowercase(), desc);
     }
 
+    /// Register a function that is invisible to `contains` and combinators, the planner
+    /// checks the permissions of the function before it builds the function by name.
+    pub fn register_internal(&mut self, name: &str, desc: AggregateFunctionDescription) {
+        self.internal_desc.insert(name.to_lowercase(), desc);
+    }
+
     pub fn register_combinator(&mut self, suffix: &str, desc: CombinatorDescription) {
         for (exists_suffix, _) in &self.case_insensitive_combinator_desc {
             if exists_suffix.eq_ignore_ascii_case(suffix) {
@@ -231,6 +240,11 @@ impl AggregateFunctionFactory {
             return (desc.aggregate_function_creator)(name, params, arguments);
         }
 
+        if let Some(desc) = self.internal_desc.get(&lowercase_name) {
+            *features = desc.features.clone();
+            return (desc.aggregate_function_creator)(name, params, arguments);
+        }
+
         // find suffix
         for (suffix, desc) in &self.case_insensitive_combinator_desc {
             if let Some(nested_name) = lowercase_name.strip_suffix(suffix) {
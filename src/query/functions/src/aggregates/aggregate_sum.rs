@@ -18,6 +18,7 @@ use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::number::*;
 use databend_common_expression::types::*;
 use databend_common_expression::utils::arithmetics_type::ResultTypeOfUnary;
@@ -183,6 +184,72 @@ where
     }
 }
 
+/// The components of the intervals are summed up separately in wider integers,
+/// the overflow is only checked when the result is built.
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct IntervalSumState {
+    pub months: i64,
+    pub days: i64,
+    pub micros: i128,
+}
+
+impl IntervalSumState {
+    pub fn add_interval(&mut self, value: &months_days_micros) {
+        self.months += value.months() as i64;
+        self.days += value.days() as i64;
+        self.micros += value.microseconds() as i128;
+    }
+
+    pub fn merge_state(&mut self, rhs: &Self) {
+        self.months += rhs.months;
+        self.days += rhs.days;
+        self.micros += rhs.micros;
+    }
+
+    pub fn to_interval(months: i64, days: i64, micros: i128) -> Result<months_days_micros> {
+        match (
+            i32::try_from(months),
+            i32::try_from(days),
+            i64::try_from(micros),
+        ) {
+            (Ok(months), Ok(days), Ok(micros)) => Ok(months_days_micros::new(months, days, micros)),
+            _ => Err(ErrorCode::Overflow(format!(
+                "Interval overflow: {months} months {days} days {micros} microseconds"
+            ))),
+        }
+    }
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalSumState {
+    fn add(&mut self, other: months_days_micros) -> Result<()> {
+        self.add_interval(&other);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.merge_state(rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<months_days_micros>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(Self::to_interval(self.months, self.days, self.micros)?);
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        borsh_serialize_state(writer, self)
+    }
+
+    fn deserialize(reader: &mut &[u8]) -> Result<Self>
+    where Self: Sized {
+        borsh_deserialize_state(reader)
+    }
+}
+
 pub fn try_create_aggregate_sum_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -263,6 +330,14 @@ pub fn try_create_aggregate_sum_function(
                 )
             }
         }
+        DataType::Interval => {
+            AggregateUnaryFunction::<IntervalSumState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                DataType::Interval,
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
@@ -0,0 +1,549 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::udf_client::UDFFlightClient;
+use databend_common_expression::variant_transform::contains_variant;
+use databend_common_expression::variant_transform::transform_variant;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use once_cell::sync::OnceCell;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::StateAddr;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+pub const UDAF_SERVER_FUNC_NAME: &str = "udaf_server";
+
+// The phases of an aggregate function on the UDF server, each phase is
+// an exchange of a batch with the path `[<handler>, <phase>]`:
+//
+// - init:       `(group UInt32)` -> `(state Binary)`, the initial state of a group.
+// - accumulate: `(group UInt32, <args>...)` -> `(state Binary)`, one state per group,
+//               accumulated from the initial state by the rows of the group.
+// - merge:      `(group UInt32, state Binary)` -> `(state Binary)`, one state per group,
+//               merged from all the states of the group.
+// - finalize:   `(state Binary)` -> `(result <return_type>)`, one result per state.
+const PHASE_INIT: &str = "init";
+const PHASE_ACCUMULATE: &str = "accumulate";
+const PHASE_MERGE: &str = "merge";
+const PHASE_FINALIZE: &str = "finalize";
+
+// The max number of rows accumulated row by row before they are sent to the UDF server.
+const MAX_PENDING_ROWS: usize = 8192;
+
+/// The state of a group is opaque to the query, `state` is `None` until some rows
+/// are accumulated or some states are merged into it.
+#[derive(Default)]
+struct UDAFState {
+    state: Option<Vec<u8>>,
+    // The rows accumulated by `accumulate_row`, they are sent to the UDF server
+    // in one batch before the state is read.
+    pending: Vec<ColumnBuilder>,
+    pending_rows: usize,
+}
+
+/// An aggregate function whose states are kept by the query, but are
+/// accumulated, merged and finalized by the UDF server.
+pub struct AggregateUDAFServerFunction {
+    display_name: String,
+    address: String,
+    handler: String,
+    return_type: DataType,
+    connect_timeout: u64,
+    request_timeout: u64,
+    client: OnceCell<UDFFlightClient>,
+    initial_state: OnceCell<Vec<u8>>,
+}
+
+impl AggregateUDAFServerFunction {
+    fn client(&self) -> Result<UDFFlightClient> {
+        let client = self.client.get_or_try_init(|| {
+            let address = self.address.clone();
+            let connect_timeout = self.connect_timeout;
+            let request_timeout = self.request_timeout;
+            GlobalIORuntime::instance().block_on(async move {
+                UDFFlightClient::connect(&address, connect_timeout, request_timeout).await
+            })
+        })?;
+        Ok(client.clone())
+    }
+
+    fn exchange(
+        &self,
+        phase: &'static str,
+        columns: Vec<(String, Column)>,
+        num_rows: usize,
+        result_rows: usize,
+    ) -> Result<BlockEntry> {
+        let mut fields = Vec::with_capacity(columns.len());
+        let mut entries = Vec::with_capacity(columns.len());
+        for (name, column) in columns {
+            let data_type = column.data_type();
+            let value = if contains_variant(&data_type) {
+                transform_variant(&Value::Column(column), true)?
+            } else {
+                Value::Column(column)
+            };
+            fields.push(DataField::new(&name, data_type.clone()));
+            entries.push(BlockEntry::new(data_type, value));
+        }
+        let input_batch = DataBlock::new(entries, num_rows)
+            .to_record_batch(&DataSchema::new(fields))
+            .map_err(|err| ErrorCode::from_string(format!("{err}")))?;
+
+        let mut client = self.client()?;
+        let handler = self.handler.clone();
+        let result_batch = GlobalIORuntime::instance().block_on(async move {
+            client
+                .do_aggregate_exchange(&handler, phase, input_batch)
+                .await
+        })?;
+
+        let schema = DataSchema::try_from(&(*result_batch.schema()))?;
+        let (result_block, _) =
+            DataBlock::from_record_batch(&schema, &result_batch).map_err(|err| {
+                ErrorCode::UDFDataError(format!(
+                    "Cannot convert arrow record batch to data block: {err}"
+                ))
+            })?;
+        if result_block.num_columns() == 0 {
+            return Err(ErrorCode::EmptyDataFromServer(
+                "Get empty data from UDF Server",
+            ));
+        }
+        if result_block.num_rows() != result_rows {
+            return Err(ErrorCode::UDFDataError(format!(
+                "UDF server should return {} rows in phase {}, but it returned {} rows",
+                result_rows,
+                phase,
+                result_block.num_rows()
+            )));
+        }
+        Ok(result_block.get_by_offset(0).clone())
+    }
+
+    fn exchange_states(
+        &self,
+        phase: &'static str,
+        columns: Vec<(String, Column)>,
+        num_rows: usize,
+        num_groups: usize,
+    ) -> Result<Vec<Vec<u8>>> {
+        let entry = self.exchange(phase, columns, num_rows, num_groups)?;
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_groups);
+        let states =
+            BinaryType::try_downcast_column(&column.remove_nullable()).ok_or_else(|| {
+                ErrorCode::UDFSchemaMismatch(format!(
+                    "UDF server should return binary states, but got: {}",
+                    entry.data_type
+                ))
+            })?;
+        Ok(states.iter().map(|state| state.to_vec()).collect())
+    }
+
+    fn initial_state(&self) -> Result<&[u8]> {
+        let state = self.initial_state.get_or_try_init(|| {
+            let groups = UInt32Type::from_data(vec![0]);
+            let inputs = vec![("group".to_string(), groups)];
+            let mut states = self.exchange_states(PHASE_INIT, inputs, 1, 1)?;
+            Ok::<_, ErrorCode>(states.remove(0))
+        })?;
+        Ok(state)
+    }
+
+    fn accumulate_places(&self, places: &[StateAddr], columns: &[Column]) -> Result<()> {
+        if places.is_empty() {
+            return Ok(());
+        }
+        let mut group_places = Vec::new();
+        let mut group_ids = HashMap::new();
+        let groups = places
+            .iter()
+            .map(|place| {
+                *group_ids.entry(place.addr()).or_insert_with(|| {
+                    group_places.push(*place);
+                    group_places.len() as u32 - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut inputs = Vec::with_capacity(columns.len() + 1);
+        inputs.push(("group".to_string(), UInt32Type::from_data(groups)));
+        for (i, column) in columns.iter().enumerate() {
+            inputs.push((format!("arg{}", i + 1), column.clone()));
+        }
+        let states =
+            self.exchange_states(PHASE_ACCUMULATE, inputs, places.len(), group_places.len())?;
+        self.merge_places(group_places.into_iter().zip(states.into_iter().map(Some)))
+    }
+
+    /// Accumulate the pending rows of the places by one exchange.
+    fn flush_pending<'a>(&self, places: impl IntoIterator<Item = &'a StateAddr>) -> Result<()> {
+        let mut row_places = Vec::new();
+        let mut builders: Vec<ColumnBuilder> = Vec::new();
+        for place in places {
+            let state = place.get::<UDAFState>();
+            if state.pending_rows == 0 {
+                continue;
+            }
+            let pending = std::mem::take(&mut state.pending);
+            row_places.extend(std::iter::repeat(*place).take(state.pending_rows));
+            state.pending_rows = 0;
+            if builders.is_empty() {
+                builders = pending;
+            } else {
+                for (builder, rows) in builders.iter_mut().zip(pending) {
+                    builder.append_column(&rows.build());
+                }
+            }
+        }
+        if row_places.is_empty() {
+            return Ok(());
+        }
+        let columns = builders
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+        self.accumulate_places(&row_places, &columns)
+    }
+
+    /// Merge the states into the places, the states of the same place are merged
+    /// with its own state by one exchange.
+    fn merge_places(
+        &self,
+        states: impl IntoIterator<Item = (StateAddr, Option<Vec<u8>>)>,
+    ) -> Result<()> {
+        let mut group_places: Vec<StateAddr> = Vec::new();
+        let mut group_states: Vec<Vec<Vec<u8>>> = Vec::new();
+        let mut group_ids = HashMap::new();
+        for (place, state) in states {
+            let Some(state) = state else {
+                continue;
+            };
+            let id = *group_ids.entry(place.addr()).or_insert_with(|| {
+                group_places.push(place);
+                group_states.push(place.get::<UDAFState>().state.take().into_iter().collect());
+                group_places.len() - 1
+            });
+            group_states[id].push(state);
+        }
+
+        let mut merged_places = Vec::new();
+        let mut groups = Vec::new();
+        let mut builder = BinaryColumnBuilder::with_capacity(0, 0);
+        for (place, mut states) in group_places.into_iter().zip(group_states) {
+            if states.len() == 1 {
+                place.get::<UDAFState>().state = states.pop();
+                continue;
+            }
+            for state in states {
+                groups.push(merged_places.len() as u32);
+                builder.put_slice(&state);
+                builder.commit_row();
+            }
+            merged_places.push(place);
+        }
+        if merged_places.is_empty() {
+            return Ok(());
+        }
+
+        let num_rows = groups.len();
+        let inputs = vec![
+            ("group".to_string(), UInt32Type::from_data(groups)),
+            ("state".to_string(), Column::Binary(builder.build())),
+        ];
+        let states = self.exchange_states(PHASE_MERGE, inputs, num_rows, merged_places.len())?;
+        for (place, state) in merged_places.into_iter().zip(states) {
+            place.get::<UDAFState>().state = Some(state);
+        }
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateUDAFServerFunction {
+    fn name(&self) -> &str {
+        "AggregateUDAFServerFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(UDAFState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<UDAFState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let (columns, num_rows) = match validity {
+            Some(validity) => (
+                columns.iter().map(|c| c.filter(validity)).collect(),
+                input_rows - validity.unset_bits(),
+            ),
+            None => (columns.to_vec(), input_rows),
+        };
+        self.accumulate_places(&vec![place; num_rows], &columns)
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let places = places
+            .iter()
+            .map(|place| place.next(offset))
+            .collect::<Vec<_>>();
+        self.accumulate_places(&places, columns)
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        // The rows are buffered to avoid an exchange per row, e.g. in window functions.
+        let state = place.get::<UDAFState>();
+        if state.pending.is_empty() {
+            state.pending = columns
+                .iter()
+                .map(|c| ColumnBuilder::with_capacity(&c.data_type(), 0))
+                .collect();
+        }
+        for (builder, column) in state.pending.iter_mut().zip(columns) {
+            builder.push(column.index(row).unwrap());
+        }
+        state.pending_rows += 1;
+        if state.pending_rows >= MAX_PENDING_ROWS {
+            self.flush_pending([&place])?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.flush_pending([&place])?;
+        let state = place.get::<UDAFState>();
+        borsh_serialize_state(writer, &state.state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let rhs: Option<Vec<u8>> = borsh_deserialize_state(reader)?;
+        self.merge_places([(place, rhs)])
+    }
+
+    fn batch_merge(&self, places: &[StateAddr], offset: usize, column: &Column) -> Result<()> {
+        let c = column.as_binary().unwrap();
+        let states = places
+            .iter()
+            .zip(c.iter())
+            .map(|(place, mut data)| Ok((place.next(offset), borsh_deserialize_state(&mut data)?)))
+            .collect::<Result<Vec<_>>>()?;
+        self.merge_places(states)
+    }
+
+    fn batch_merge_single(&self, place: StateAddr, column: &Column) -> Result<()> {
+        let c = column.as_binary().unwrap();
+        let states = c
+            .iter()
+            .map(|mut data| Ok((place, borsh_deserialize_state(&mut data)?)))
+            .collect::<Result<Vec<_>>>()?;
+        self.merge_places(states)
+    }
+
+    fn batch_merge_states(
+        &self,
+        places: &[StateAddr],
+        rhses: &[StateAddr],
+        offset: usize,
+    ) -> Result<()> {
+        let rhses = rhses.iter().map(|rhs| rhs.next(offset)).collect::<Vec<_>>();
+        self.flush_pending(&rhses)?;
+        let states = places.iter().zip(rhses.iter()).map(|(place, rhs)| {
+            let state = rhs.get::<UDAFState>().state.clone();
+            (place.next(offset), state)
+        });
+        self.merge_places(states)
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.flush_pending([&rhs])?;
+        let state = rhs.get::<UDAFState>().state.clone();
+        self.merge_places([(place, state)])
+    }
+
+    fn batch_merge_result(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        builder: &mut ColumnBuilder,
+    ) -> Result<()> {
+        if places.is_empty() {
+            return Ok(());
+        }
+        let places = places
+            .iter()
+            .map(|place| place.next(offset))
+            .collect::<Vec<_>>();
+        self.flush_pending(&places)?;
+
+        let mut states = BinaryColumnBuilder::with_capacity(places.len(), 0);
+        for place in &places {
+            match &place.get::<UDAFState>().state {
+                Some(state) => states.put_slice(state),
+                None => states.put_slice(self.initial_state()?),
+            }
+            states.commit_row();
+        }
+
+        let inputs = vec![("state".to_string(), Column::Binary(states.build()))];
+        let entry = self.exchange(PHASE_FINALIZE, inputs, places.len(), places.len())?;
+        if entry.data_type != self.return_type {
+            return Err(ErrorCode::UDFSchemaMismatch(format!(
+                "UDF server return incorrect type, expected: {}, but got: {}",
+                self.return_type, entry.data_type
+            )));
+        }
+        let value = if contains_variant(&self.return_type) {
+            transform_variant(&entry.value, false)?
+        } else {
+            entry.value
+        };
+        builder.append_column(&value.convert_to_full_column(&self.return_type, places.len()));
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.batch_merge_result(&[place], 0, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<UDAFState>();
+        std::ptr::drop_in_place(state);
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        nested_function: AggregateFunctionRef,
+        _params: Vec<Scalar>,
+        _arguments: Vec<DataType>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // The nulls are passed to the UDF server as they are.
+        Ok(Some(nested_function))
+    }
+}
+
+impl fmt::Display for AggregateUDAFServerFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// The params of the builtin `udaf_server` function, which calls the aggregate
+/// function `handler` on the UDF server.
+pub fn udaf_server_params(
+    address: &str,
+    handler: &str,
+    return_type: &DataType,
+    connect_timeout: u64,
+    request_timeout: u64,
+) -> Vec<Scalar> {
+    // The type of the default value keeps the return type except the nullability.
+    vec![
+        Scalar::String(address.to_string()),
+        Scalar::String(handler.to_string()),
+        Scalar::default_value(&return_type.remove_nullable()),
+        Scalar::Boolean(return_type.is_nullable()),
+        Scalar::Number(NumberScalar::UInt64(connect_timeout)),
+        Scalar::Number(NumberScalar::UInt64(request_timeout)),
+    ]
+}
+
+pub fn try_create_aggregate_udaf_server_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    _argument_types: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    let [
+        Scalar::String(address),
+        Scalar::String(handler),
+        return_type,
+        Scalar::Boolean(nullable),
+        Scalar::Number(NumberScalar::UInt64(connect_timeout)),
+        Scalar::Number(NumberScalar::UInt64(request_timeout)),
+    ] = params.as_slice()
+    else {
+        return Err(ErrorCode::BadArguments(format!(
+            "Invalid params of aggregate function {display_name}"
+        )));
+    };
+    let return_type = return_type.as_ref().infer_data_type();
+    let return_type = if *nullable {
+        return_type.wrap_nullable()
+    } else {
+        return_type
+    };
+
+    Ok(Arc::new(AggregateUDAFServerFunction {
+        display_name: display_name.to_string(),
+        address: address.clone(),
+        handler: handler.clone(),
+        return_type,
+        connect_timeout: *connect_timeout,
+        request_timeout: *request_timeout,
+        client: OnceCell::new(),
+        initial_state: OnceCell::new(),
+    }))
+}
+
+pub fn aggregate_udaf_server_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_udaf_server_function))
+}
@@ -52,6 +52,8 @@ use crate::aggregates::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_udaf_server_function_desc;
+use crate::aggregates::UDAF_SERVER_FUNC_NAME;
 
 pub struct Aggregators;
 
@@ -134,6 +136,10 @@ impl Aggregators {
             "intersect_count",
             aggregate_bitmap_intersect_count_function_desc(),
         );
+
+        // The address of the UDF server is checked when the user-defined aggregate
+        // function is resolved, so `udaf_server` can't be called by name.
+        factory.register_internal(UDAF_SERVER_FUNC_NAME, aggregate_udaf_server_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
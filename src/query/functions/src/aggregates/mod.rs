@@ -41,6 +41,7 @@ mod aggregate_skewness;
 mod aggregate_stddev;
 mod aggregate_string_agg;
 mod aggregate_sum;
+mod aggregate_udaf_server;
 mod aggregate_unary;
 mod aggregate_window_funnel;
 mod aggregator;
@@ -67,6 +68,7 @@ pub use aggregate_retention::*;
 pub use aggregate_skewness::*;
 pub use aggregate_string_agg::*;
 pub use aggregate_sum::*;
+pub use aggregate_udaf_server::*;
 pub use aggregate_unary::*;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
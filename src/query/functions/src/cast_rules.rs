@@ -71,6 +71,8 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Binary),
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
+    (DataType::String, DataType::Uuid),
+    (DataType::String, DataType::Inet),
     (DataType::String, DataType::Boolean),
     (DataType::Date, DataType::Timestamp),
     (
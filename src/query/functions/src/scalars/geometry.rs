@@ -12,22 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::f64::consts::PI;
+
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::geometry::GeometryType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::Int32Type;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
 use databend_common_expression::types::F64;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
+use databend_common_io::ewkb_to_geo;
+use databend_common_io::geo_to_ewkb;
 use databend_common_io::parse_to_ewkb;
+use geo::relate::IntersectionMatrix;
+use geo::Area;
+use geo::BooleanOps;
+use geo::Centroid;
+use geo::Coord;
+use geo::EuclideanDistance;
+use geo::EuclideanLength;
 use geo::Geometry;
+use geo::GeometryCollection;
+use geo::LineString;
+use geo::MultiPolygon;
 use geo::Point;
+use geo::Polygon;
+use geo::Relate;
+use geozero::geojson::GeoJson;
 use geozero::wkb::Ewkb;
 use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToJson;
 use geozero::ToWkb;
 use geozero::ToWkt;
+use jsonb::parse_value;
 
 // const GEO_TYPE_ID_MASK: u32 = 0x2000_0000;
 
@@ -41,11 +66,18 @@ pub fn register(registry: &mut FunctionRegistry) {
         "st_geometryfromtext",
         "st_geomfromtext",
     ]);
+    registry.register_aliases("st_geometryfromgeojson", &["st_geomfromgeojson"]);
+    registry.register_aliases("st_aswkb", &["st_asbinary"]);
 
     // functions
     registry.register_passthrough_nullable_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType, _, _>(
         "st_makegeompoint",
-        |_,_, _| FunctionDomain::Full,
+        |_, x, y| FunctionDomain::Domain(GeometryDomain {
+            min_x: *x.min,
+            min_y: *y.min,
+            max_x: *x.max,
+            max_y: *y.max,
+        }),
         vectorize_with_builder_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType>(|longitude, latitude, builder, ctx| {
             if let Some(validity) = &ctx.validity {
                 if !validity.get_bit(builder.len()) {
@@ -126,6 +158,228 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geometryfromgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|json, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match GeoJson(json)
+                .to_geo()
+                .map_err(|e| e.to_string())
+                .and_then(|geom| geo_to_ewkb(geom, None).map_err(|e| e.to_string()))
+            {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, VariantType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, VariantType>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match Ewkb(ewkb).to_json() {
+                Ok(json) => match parse_value(json.as_bytes()) {
+                    Ok(value) => value.write_to_vec(&mut builder.data),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                },
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, BinaryType>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            // WKB doesn't have the SRID.
+            match Ewkb(ewkb).to_wkb(CoordDimensions::xy()) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_x",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0.0));
+                    return;
+                }
+            }
+            match read_point(ewkb) {
+                Ok(point) => builder.push(F64::from(point.x())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_y",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0.0));
+                    return;
+                }
+            }
+            match read_point(ewkb) {
+                Ok(point) => builder.push(F64::from(point.y())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0.0));
+                    return;
+                }
+            }
+            match ewkb_to_geo(ewkb) {
+                Ok((geom, _)) => builder.push(F64::from(geom.unsigned_area())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_length",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0.0));
+                    return;
+                }
+            }
+            match ewkb_to_geo(ewkb) {
+                Ok((geom, _)) => builder.push(F64::from(euclidean_length(&geom))),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, NumberType<F64>, _, _>(
+            "st_distance",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, GeometryType, NumberType<F64>>(
+                |l, r, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.push(F64::from(0.0));
+                            return;
+                        }
+                    }
+                    match read_geometry_pair(l, r) {
+                        Ok((l, r, _)) => builder.push(F64::from(l.euclidean_distance(&r))),
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e);
+                            builder.push(F64::from(0.0));
+                        }
+                    }
+                },
+            ),
+        );
+
+    register_spatial_predicate(registry, "st_contains", IntersectionMatrix::is_contains);
+    register_spatial_predicate(registry, "st_intersects", IntersectionMatrix::is_intersects);
+    register_spatial_predicate(registry, "st_within", IntersectionMatrix::is_within);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_centroid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            let result = ewkb_to_geo(ewkb).and_then(|(geom, srid)| {
+                let centroid = match geom.centroid() {
+                    Some(point) => Geometry::from(point),
+                    None => Geometry::GeometryCollection(GeometryCollection(vec![])),
+                };
+                geo_to_ewkb(centroid, srid)
+            });
+            match result {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            "st_buffer",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                |ewkb, distance, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+                    let result =
+                        ewkb_to_geo(ewkb)
+                            .map_err(|e| e.to_string())
+                            .and_then(|(geom, srid)| {
+                                let buffered = buffer(&geom, distance.0)?;
+                                geo_to_ewkb(buffered, srid).map_err(|e| e.to_string())
+                            });
+                    match result {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+
     // registry.register_passthrough_nullable_2_arg::<GeometryType, Int32Type, GeometryType, _, _>(
     //     "st_transform",
     //     |_, _, _| FunctionDomain::MayThrow,
@@ -226,6 +480,193 @@ pub fn register(registry: &mut FunctionRegistry) {
     // );
 }
 
+/// Register a spatial relationship predicate, the blocks whose bounding boxes
+/// are disjoint can be pruned since the predicate must be false.
+fn register_spatial_predicate(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    predicate: fn(&IntersectionMatrix) -> bool,
+) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        name,
+        |_, l, r| {
+            if l.intersects(r) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(BooleanDomain {
+                    has_false: true,
+                    has_true: false,
+                })
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            move |l, r, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                match read_geometry_pair(l, r) {
+                    Ok((l, r, _)) => builder.push(predicate(&l.relate(&r))),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+fn read_point(ewkb: &[u8]) -> Result<Point<f64>, String> {
+    match ewkb_to_geo(ewkb).map_err(|e| e.to_string())? {
+        (Geometry::Point(point), _) => Ok(point),
+        _ => Err("the geometry is not a point".to_string()),
+    }
+}
+
+/// Decode the geometries of a binary function, they must have the same SRID.
+fn read_geometry_pair(
+    l: &[u8],
+    r: &[u8],
+) -> Result<(Geometry<f64>, Geometry<f64>, Option<i32>), String> {
+    let (l, l_srid) = ewkb_to_geo(l).map_err(|e| e.to_string())?;
+    let (r, r_srid) = ewkb_to_geo(r).map_err(|e| e.to_string())?;
+    if l_srid != r_srid {
+        return Err(format!(
+            "incompatible SRID: {} and {}",
+            l_srid.unwrap_or_default(),
+            r_srid.unwrap_or_default()
+        ));
+    }
+    Ok((l, r, l_srid))
+}
+
+/// The length of the linear geometries, it's 0 for the points and polygons.
+fn euclidean_length(geom: &Geometry<f64>) -> f64 {
+    match geom {
+        Geometry::Line(line) => line.euclidean_length(),
+        Geometry::LineString(line_string) => line_string.euclidean_length(),
+        Geometry::MultiLineString(multi_line_string) => multi_line_string.euclidean_length(),
+        Geometry::GeometryCollection(collection) => collection.iter().map(euclidean_length).sum(),
+        _ => 0.0,
+    }
+}
+
+/// The number of segments used to approximate a quarter circle, same as PostGIS.
+const BUFFER_QUAD_SEGS: usize = 8;
+
+/// The geometry that represents all points whose distance from the geometry is less than or
+/// equal to `distance`, built as the union of the geometry, the circles around the vertices
+/// and the rectangles around the segments.
+fn buffer(geom: &Geometry<f64>, distance: f64) -> Result<Geometry<f64>, String> {
+    if distance.is_nan() || distance < 0.0 {
+        return Err(format!(
+            "the distance of buffer must be a non-negative number, but got {distance}"
+        ));
+    }
+    if distance == 0.0 {
+        return Ok(geom.clone());
+    }
+    let mut pieces = vec![];
+    collect_buffer_pieces(geom, distance, &mut pieces);
+    let mut result = pieces
+        .into_iter()
+        .fold(MultiPolygon::new(vec![]), |acc, piece| {
+            acc.union(&MultiPolygon::new(vec![piece]))
+        });
+    if result.0.len() == 1 {
+        Ok(Geometry::Polygon(result.0.remove(0)))
+    } else {
+        Ok(Geometry::MultiPolygon(result))
+    }
+}
+
+fn collect_buffer_pieces(geom: &Geometry<f64>, distance: f64, pieces: &mut Vec<Polygon<f64>>) {
+    match geom {
+        Geometry::Point(point) => pieces.push(circle(point.0, distance)),
+        Geometry::MultiPoint(multi_point) => {
+            pieces.extend(multi_point.iter().map(|point| circle(point.0, distance)))
+        }
+        Geometry::Line(line) => {
+            let line_string = LineString::new(vec![line.start, line.end]);
+            collect_line_string_pieces(&line_string, distance, pieces)
+        }
+        Geometry::LineString(line_string) => {
+            collect_line_string_pieces(line_string, distance, pieces)
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string.iter() {
+                collect_line_string_pieces(line_string, distance, pieces)
+            }
+        }
+        Geometry::Polygon(polygon) => {
+            pieces.push(polygon.clone());
+            collect_line_string_pieces(polygon.exterior(), distance, pieces);
+            for interior in polygon.interiors() {
+                collect_line_string_pieces(interior, distance, pieces);
+            }
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon.iter() {
+                collect_buffer_pieces(&Geometry::Polygon(polygon.clone()), distance, pieces);
+            }
+        }
+        Geometry::Rect(rect) => {
+            collect_buffer_pieces(&Geometry::Polygon(rect.to_polygon()), distance, pieces)
+        }
+        Geometry::Triangle(triangle) => {
+            collect_buffer_pieces(&Geometry::Polygon(triangle.to_polygon()), distance, pieces)
+        }
+        Geometry::GeometryCollection(collection) => {
+            for geom in collection.iter() {
+                collect_buffer_pieces(geom, distance, pieces);
+            }
+        }
+    }
+}
+
+fn collect_line_string_pieces(
+    line_string: &LineString<f64>,
+    distance: f64,
+    pieces: &mut Vec<Polygon<f64>>,
+) {
+    pieces.extend(line_string.coords().map(|c| circle(*c, distance)));
+    for line in line_string.lines() {
+        let (dx, dy) = (line.end.x - line.start.x, line.end.y - line.start.y);
+        let len = dx.hypot(dy);
+        if len == 0.0 {
+            continue;
+        }
+        // the offset perpendicular to the segment
+        let (ox, oy) = (-dy / len * distance, dx / len * distance);
+        pieces.push(Polygon::new(
+            LineString::from(vec![
+                (line.start.x + ox, line.start.y + oy),
+                (line.end.x + ox, line.end.y + oy),
+                (line.end.x - ox, line.end.y - oy),
+                (line.start.x - ox, line.start.y - oy),
+            ]),
+            vec![],
+        ));
+    }
+}
+
+fn circle(center: Coord<f64>, radius: f64) -> Polygon<f64> {
+    let num_segs = BUFFER_QUAD_SEGS * 4;
+    let coords = (0..num_segs)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / num_segs as f64;
+            (
+                center.x + radius * angle.cos(),
+                center.y + radius * angle.sin(),
+            )
+        })
+        .collect::<Vec<_>>();
+    Polygon::new(LineString::from(coords), vec![])
+}
+
 // fn make_crs(srid: i32) -> String {
 //     format!("EPSG:{}", srid)
 // }
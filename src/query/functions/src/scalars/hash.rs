@@ -25,11 +25,13 @@ use databend_common_expression::types::number::F64;
 use databend_common_expression::types::ArgType;
 use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DateType;
+use databend_common_expression::types::InetType;
 use databend_common_expression::types::NumberClass;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UuidType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::types::ALL_INTEGER_TYPES;
 use databend_common_expression::types::ALL_NUMBER_CLASSES;
@@ -74,6 +76,9 @@ pub fn register(registry: &mut FunctionRegistry) {
         });
     }
 
+    register_simple_domain_type_hash::<UuidType>(registry);
+    register_simple_domain_type_hash::<InetType>(registry);
+
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "md5",
         |_, _| FunctionDomain::MayThrow,
@@ -311,6 +316,13 @@ macro_rules! for_all_integer_types{
 
 for_all_integer_types! { integer_impl }
 
+impl DFHash for u128 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self, state);
+    }
+}
+
 impl DFHash for i256 {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::inet::cidr_to_range;
+use databend_common_expression::types::inet::inet_to_string;
+use databend_common_expression::types::inet::string_to_cidr;
+use databend_common_expression::types::inet::string_to_inet;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::InetType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("to_inet", &["to_ipv6"]);
+    registry.register_aliases("try_to_inet", &["try_to_ipv6"]);
+
+    // cast(xx AS inet)
+    // to_inet(xx)
+    registry.register_passthrough_nullable_1_arg::<StringType, InetType, _, _>(
+        "to_inet",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_inet,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, InetType, _, _>(
+        "try_to_inet",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_inet),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<InetType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_inet_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<InetType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_inet_to_string),
+    );
+
+    // is_ip_address_in_range('192.168.1.10', '192.168.0.0/16')
+    registry.register_passthrough_nullable_2_arg::<InetType, StringType, BooleanType, _, _>(
+        "is_ip_address_in_range",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<InetType, StringType, BooleanType>(
+            |addr, cidr, output, ctx| match string_to_cidr(cidr) {
+                Ok((network, prefix)) => {
+                    let (first, last) = cidr_to_range(network, prefix);
+                    output.push(first <= addr && addr <= last);
+                }
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    // The first and the last address of the network, e.g. `10.0.0.0` and `10.0.0.255` of `10.0.0.0/24`.
+    registry.register_passthrough_nullable_1_arg::<StringType, InetType, _, _>(
+        "cidr_first_address",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, InetType>(|cidr, output, ctx| {
+            match string_to_cidr(cidr) {
+                Ok((network, prefix)) => output.push(cidr_to_range(network, prefix).0),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            }
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<StringType, InetType, _, _>(
+        "cidr_last_address",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, InetType>(|cidr, output, ctx| {
+            match string_to_cidr(cidr) {
+                Ok((network, prefix)) => output.push(cidr_to_range(network, prefix).1),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            }
+        }),
+    );
+}
+
+fn eval_string_to_inet(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<InetType> {
+    vectorize_with_builder_1_arg::<StringType, InetType>(|val, output, ctx| {
+        match string_to_inet(val) {
+            Ok(addr) => output.push(addr),
+            Err(err) => {
+                ctx.set_error(output.len(), format!("cannot parse to type `INET`: {err}"));
+                output.push(0);
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_inet_to_string(val: ValueRef<InetType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<InetType, StringType>(|val, output, _| {
+        write!(output.data, "{}", inet_to_string(val)).unwrap();
+        output.commit_row();
+    })(val, ctx)
+}
@@ -0,0 +1,206 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::IntervalType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::date_helper::AddDaysImpl;
+use databend_common_expression::utils::date_helper::AddMonthsImpl;
+use databend_common_expression::utils::date_helper::TzLUT;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_interval_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_interval_to_string),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match add_interval(ts, interval, ctx.func_ctx.tz, false)
+                {
+                    Ok(ts) => output.push(ts),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| match add_interval(ts, interval, ctx.func_ctx.tz, false)
+                {
+                    Ok(ts) => output.push(ts),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match add_interval(ts, interval, ctx.func_ctx.tz, true)
+                {
+                    Ok(ts) => output.push(ts),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| match lhs.checked_add(&rhs) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::ZERO);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| match lhs.checked_sub(&rhs) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::ZERO);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            match val.checked_neg() {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::ZERO);
+                }
+            }
+        }),
+    );
+}
+
+fn eval_string_to_interval(
+    val: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<IntervalType> {
+    vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+        match string_to_interval(val) {
+            Ok(interval) => output.push(interval),
+            Err(err) => {
+                ctx.set_error(
+                    output.len(),
+                    format!("cannot parse to type `INTERVAL`: {err}"),
+                );
+                output.push(months_days_micros::ZERO);
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_interval_to_string(
+    val: ValueRef<IntervalType>,
+    ctx: &mut EvalContext,
+) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+        write!(output.data, "{val}").unwrap();
+        output.commit_row();
+    })(val, ctx)
+}
+
+/// Add the interval to the timestamp, the months are added in the calendar of the
+/// session timezone, so `2024-01-31 + 1 month` is `2024-02-29`.
+fn add_interval(
+    ts: i64,
+    interval: months_days_micros,
+    tz: TzLUT,
+    negative: bool,
+) -> Result<i64, String> {
+    let interval = if negative {
+        interval
+            .checked_neg()
+            .ok_or_else(|| "interval out of range".to_string())?
+    } else {
+        interval
+    };
+
+    let mut ts = ts;
+    if interval.months() != 0 {
+        ts = AddMonthsImpl::eval_timestamp(ts, tz, interval.months())?;
+    }
+    if interval.days() != 0 {
+        ts = AddDaysImpl::eval_timestamp(ts, interval.days())?;
+    }
+    let ts = ts
+        .checked_add(interval.microseconds())
+        .ok_or_else(|| "timestamp out of range".to_string())?;
+    check_timestamp(ts)
+}
@@ -28,19 +28,34 @@ mod geo;
 mod geo_h3;
 mod geometry;
 mod hash;
+mod inet;
+mod interval;
 mod map;
 mod math;
 mod other;
+mod search;
 mod string;
 mod string_multi_args;
 mod tuple;
+mod uuid;
 mod variant;
 mod vector;
+mod wasm_udf;
 
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use search::match_terms;
+pub use search::relevance_score;
+pub use search::Tokenizer;
+pub use search::TOKENIZER_OPTION;
+pub use wasm_udf::cache_wasm_module;
+pub use wasm_udf::check_wasm_udf;
+pub use wasm_udf::set_wasm_code_loader;
+pub use wasm_udf::wasm_udf_params;
+pub use wasm_udf::WasmCodeLoader;
+pub use wasm_udf::WASM_UDF_FUNC_NAME;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -64,4 +79,9 @@ pub fn register(registry: &mut FunctionRegistry) {
     vector::register(registry);
     bitmap::register(registry);
     geometry::register(registry);
+    search::register(registry);
+    wasm_udf::register(registry);
+    interval::register(registry);
+    uuid::register(registry);
+    inet::register(registry);
 }
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io::Write;
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -28,7 +27,6 @@ use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::UInt32Type;
 use databend_common_expression::types::number::UInt8Type;
 use databend_common_expression::types::number::F64;
-use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::ArgType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
@@ -42,6 +40,7 @@ use databend_common_expression::types::NumberType;
 use databend_common_expression::types::SimpleDomain;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UuidType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::Column;
@@ -215,22 +214,14 @@ pub fn register(registry: &mut FunctionRegistry) {
             |val, _| val.to_owned(),
         );
 
-    registry.register_0_arg_core::<StringType, _, _>(
+    registry.register_0_arg_core::<UuidType, _, _>(
         "gen_random_uuid",
         |_| FunctionDomain::Full,
         |ctx| {
-            let mut values: Vec<u8> = Vec::with_capacity(ctx.num_rows * 36);
-            let mut offsets: Vec<u64> = Vec::with_capacity(ctx.num_rows);
-            offsets.push(0);
-
-            for _ in 0..ctx.num_rows {
-                let value = Uuid::new_v4();
-                offsets.push(offsets.last().unwrap() + 36u64);
-                write!(&mut values, "{:x}", value).unwrap();
-            }
-
-            let col = StringColumn::new(values.into(), offsets.into());
-            Value::Column(col)
+            let values = (0..ctx.num_rows)
+                .map(|_| Uuid::new_v4().as_u128())
+                .collect::<Vec<_>>();
+            Value::Column(values.into())
         },
     );
 }
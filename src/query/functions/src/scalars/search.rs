@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_with_builder_3_arg;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+
+/// The option key of an inverted index to choose the tokenizer.
+pub const TOKENIZER_OPTION: &str = "tokenizer";
+
+/// The stop words dropped by the english tokenizer, same as the default set of Lucene.
+const ENGLISH_STOP_WORDS: [&str; 33] = [
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// Splits text into the terms stored in an inverted index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Tokenizer {
+    /// Splits on non-alphanumeric characters and lowercases the terms.
+    #[default]
+    Standard,
+    /// Same as `Standard`, but drops the english stop words.
+    English,
+}
+
+impl FromStr for Tokenizer {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "standard" => Ok(Tokenizer::Standard),
+            "english" => Ok(Tokenizer::English),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown tokenizer '{s}', expecting 'standard' or 'english'"
+            ))),
+        }
+    }
+}
+
+impl Display for Tokenizer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tokenizer::Standard => write!(f, "standard"),
+            Tokenizer::English => write!(f, "english"),
+        }
+    }
+}
+
+impl Tokenizer {
+    /// Get the tokenizer from the options of an inverted index.
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(TOKENIZER_OPTION) {
+            Some(tokenizer) => tokenizer.parse(),
+            None => Ok(Tokenizer::default()),
+        }
+    }
+
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(|term| term.to_lowercase())
+            .filter(|term| match self {
+                Tokenizer::Standard => true,
+                Tokenizer::English => !ENGLISH_STOP_WORDS.contains(&term.as_str()),
+            })
+            .collect()
+    }
+
+    /// Returns the number of terms in the text and the frequency of each term.
+    pub fn term_frequencies(&self, text: &str) -> (usize, HashMap<String, u32>) {
+        let terms = self.tokenize(text);
+        let mut frequencies = HashMap::with_capacity(terms.len());
+        for term in terms.iter() {
+            *frequencies.entry(term.clone()).or_insert(0) += 1;
+        }
+        (terms.len(), frequencies)
+    }
+}
+
+/// A text matches the query if it contains all the terms of the query.
+pub fn match_terms(frequencies: &HashMap<String, u32>, query_terms: &[String]) -> bool {
+    !query_terms.is_empty()
+        && query_terms
+            .iter()
+            .all(|term| frequencies.contains_key(term))
+}
+
+/// The relevance of a text to the query, the log-scaled frequencies of the query terms
+/// normalized by the length of the text.
+pub fn relevance_score(
+    num_terms: usize,
+    frequencies: &HashMap<String, u32>,
+    query_terms: &[String],
+) -> f64 {
+    if num_terms == 0 {
+        return 0.0;
+    }
+    let score: f64 = query_terms
+        .iter()
+        .filter_map(|term| frequencies.get(term))
+        .map(|frequency| 1.0 + (*frequency as f64).ln())
+        .sum();
+    score / (num_terms as f64).sqrt()
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |text, query, tokenizer, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                match tokenizer.parse::<Tokenizer>() {
+                    Ok(tokenizer) => {
+                        let query_terms = tokenizer.tokenize(query);
+                        let (_, frequencies) = tokenizer.term_frequencies(text);
+                        builder.push(match_terms(&frequencies, &query_terms));
+                    }
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.message());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, NumberType<F64>, _, _>(
+        "score",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, NumberType<F64>>(
+            |text, query, tokenizer, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(F64::from(0.0));
+                        return;
+                    }
+                }
+                match tokenizer.parse::<Tokenizer>() {
+                    Ok(tokenizer) => {
+                        let query_terms = tokenizer.tokenize(query);
+                        let (num_terms, frequencies) = tokenizer.term_frequencies(text);
+                        let score = relevance_score(num_terms, &frequencies, &query_terms);
+                        builder.push(F64::from(score));
+                    }
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.message());
+                        builder.push(F64::from(0.0));
+                    }
+                }
+            },
+        ),
+    );
+}
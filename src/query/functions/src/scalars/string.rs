@@ -15,8 +15,6 @@
 use std::cmp::Ordering;
 use std::io::Write;
 
-use databend_common_base::base::uuid::Uuid;
-use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::string::StringColumn;
@@ -255,17 +253,6 @@ pub fn register(registry: &mut FunctionRegistry) {
             }),
     );
 
-    registry.register_passthrough_nullable_1_arg::<Decimal128Type, StringType, _, _>(
-        "to_uuid",
-        |_, _| FunctionDomain::Full,
-        vectorize_with_builder_1_arg::<Decimal128Type, StringType>(|arg, output, _| {
-            let uuid = Uuid::from_u128(arg as u128);
-            let str = uuid.as_simple().to_string();
-            output.put_str(str.as_str());
-            output.commit_row();
-        }),
-    );
-
     registry.register_2_arg::<StringType, StringType, NumberType<i8>, _, _>(
         "strcmp",
         |_, lhs, rhs| {
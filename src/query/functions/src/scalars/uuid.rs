@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::decimal::Decimal128Type;
+use databend_common_expression::types::uuid::string_to_uuid;
+use databend_common_expression::types::uuid::uuid_to_string;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UuidType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // The 128 bits of the decimal are taken as the UUID, e.g. the block id of a stream.
+    registry.register_1_arg::<Decimal128Type, UuidType, _, _>(
+        "to_uuid",
+        |_, _| FunctionDomain::Full,
+        |arg, _| arg as u128,
+    );
+
+    // cast(xx AS uuid)
+    // to_uuid(xx)
+    registry.register_passthrough_nullable_1_arg::<StringType, UuidType, _, _>(
+        "to_uuid",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_uuid,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, UuidType, _, _>(
+        "try_to_uuid",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_uuid),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UuidType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_uuid_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<UuidType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_uuid_to_string),
+    );
+}
+
+fn eval_string_to_uuid(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<UuidType> {
+    vectorize_with_builder_1_arg::<StringType, UuidType>(|val, output, ctx| {
+        match string_to_uuid(val) {
+            Ok(uuid) => output.push(uuid),
+            Err(err) => {
+                ctx.set_error(output.len(), format!("cannot parse to type `UUID`: {err}"));
+                output.push(0);
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_uuid_to_string(val: ValueRef<UuidType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<UuidType, StringType>(|val, output, _| {
+        write!(output.data, "{}", uuid_to_string(val)).unwrap();
+        output.commit_row();
+    })(val, ctx)
+}
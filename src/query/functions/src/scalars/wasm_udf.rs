@@ -0,0 +1,435 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Scalar UDFs implemented by WebAssembly modules, executed inside the query process.
+//!
+//! A module must export its linear memory as `memory`, an allocator
+//! `alloc(len: i32) -> i32`, and the handler of the UDF with the signature
+//! `handler(num_rows: i32, arg_ptr_1: i32, ..., arg_ptr_n: i32, out_ptr: i32)`.
+//!
+//! The arguments and the result are the values buffers of Arrow columns, i.e. the
+//! little-endian values of a fixed-width number type laid out contiguously, so only
+//! the number types are supported. The rows with null arguments are skipped, only the
+//! valid rows are passed to the module and their results are null.
+//!
+//! Every call runs in a new instance limited by the fuel and memory settings of
+//! the query, the module can't import any host function.
+//!
+//! The function call only carries the hash of the module, the module is compiled by
+//! the planner and taken from the cache. If it's not cached, e.g. on the other nodes
+//! of the cluster, the code is loaded by the UDF name with the registered loader.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use wasmtime::Config;
+use wasmtime::Engine;
+use wasmtime::ExternType;
+use wasmtime::Instance;
+use wasmtime::Module;
+use wasmtime::Store;
+use wasmtime::StoreLimits;
+use wasmtime::StoreLimitsBuilder;
+use wasmtime::Val;
+use wasmtime::ValType;
+
+pub const WASM_UDF_FUNC_NAME: &str = "wasm_udf";
+
+/// The compiled modules are cached by the hash of the code, it's cleared when it's full.
+const MAX_CACHED_MODULES: usize = 64;
+
+static WASM_ENGINE: LazyLock<Engine> = LazyLock::new(|| {
+    let mut config = Config::new();
+    config.consume_fuel(true);
+    Engine::new(&config).expect("failed to create the WebAssembly engine")
+});
+
+static WASM_MODULES: LazyLock<Mutex<HashMap<String, Module>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Loads the code of the WebAssembly UDF by the tenant and the UDF name.
+pub type WasmCodeLoader = Box<dyn Fn(&str, &str) -> Result<Vec<u8>> + Send + Sync>;
+
+static WASM_CODE_LOADER: OnceLock<WasmCodeLoader> = OnceLock::new();
+
+/// Set the loader of the modules that are not cached, only the first loader is kept.
+pub fn set_wasm_code_loader(loader: WasmCodeLoader) {
+    let _ = WASM_CODE_LOADER.set(loader);
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `wasm_udf` is only built by the planner for the WebAssembly UDFs,
+    // it can't be called by name in SQL.
+    registry.register_internal_function_factory(WASM_UDF_FUNC_NAME, |params, args_type| {
+        let udf = Arc::new(WasmUDF::from_params(params, args_type)?);
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+
+        let f = Function {
+            signature: FunctionSignature {
+                name: WASM_UDF_FUNC_NAME.to_string(),
+                args_type: udf
+                    .arg_types
+                    .iter()
+                    .map(|ty| DataType::Number(*ty))
+                    .collect(),
+                return_type: DataType::Number(udf.return_type),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, ctx| udf.eval(args, ctx)),
+            },
+        };
+
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+}
+
+/// The params of the `wasm_udf` function call that invokes `handler` of the module,
+/// `code_hash` is returned by [`cache_wasm_module`].
+pub fn wasm_udf_params(
+    tenant: &str,
+    name: &str,
+    code_hash: &str,
+    handler: &str,
+    return_type: &DataType,
+) -> Vec<Scalar> {
+    vec![
+        Scalar::String(tenant.to_string()),
+        Scalar::String(name.to_string()),
+        Scalar::String(code_hash.to_string()),
+        Scalar::String(handler.to_string()),
+        Scalar::default_value(&return_type.remove_nullable()),
+    ]
+}
+
+/// Compile the module and put it into the cache, returns the hash of the code.
+pub fn cache_wasm_module(code: &[u8]) -> Result<String> {
+    let code_hash = blake3::hash(code).to_hex().to_string();
+    compile_module(&code_hash, code)?;
+    Ok(code_hash)
+}
+
+/// Check the module can be used as a UDF with the arguments and the return type.
+pub fn check_wasm_udf(
+    code: &[u8],
+    handler: &str,
+    arg_types: &[DataType],
+    return_type: &DataType,
+) -> Result<()> {
+    let arg_types = arg_types
+        .iter()
+        .map(number_type)
+        .collect::<Result<Vec<_>>>()?;
+    number_type(return_type)?;
+    let module = compile_module(blake3::hash(code).to_hex().as_str(), code)?;
+
+    match module.get_export("memory") {
+        Some(ExternType::Memory(_)) => {}
+        _ => {
+            return Err(ErrorCode::InvalidArgument(
+                "The WebAssembly module must export its memory as 'memory'",
+            ));
+        }
+    }
+    match module.get_export("alloc") {
+        Some(ExternType::Func(func))
+            if is_i32_params(func.params(), 1) && is_i32_params(func.results(), 1) => {}
+        _ => {
+            return Err(ErrorCode::InvalidArgument(
+                "The WebAssembly module must export the function 'alloc(len: i32) -> i32'",
+            ));
+        }
+    }
+    match module.get_export(handler) {
+        Some(ExternType::Func(func))
+            if is_i32_params(func.params(), arg_types.len() + 2) && func.results().len() == 0 => {}
+        _ => {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "The WebAssembly module must export the function '{handler}' with {} i32 parameters and no result",
+                arg_types.len() + 2
+            )));
+        }
+    }
+    Ok(())
+}
+
+struct WasmUDF {
+    handler: String,
+    /// The error of loading the module is reported when the function is evaluated.
+    module: Result<Module>,
+    arg_types: Vec<NumberDataType>,
+    return_type: NumberDataType,
+}
+
+struct WasmState {
+    limits: StoreLimits,
+}
+
+impl WasmUDF {
+    fn from_params(params: &[Scalar], args_type: &[DataType]) -> Option<Self> {
+        let [
+            Scalar::String(tenant),
+            Scalar::String(name),
+            Scalar::String(code_hash),
+            Scalar::String(handler),
+            return_type,
+        ] = params
+        else {
+            return None;
+        };
+        let arg_types = args_type
+            .iter()
+            .map(number_type)
+            .collect::<Result<Vec<_>>>()
+            .ok()?;
+        let return_type = number_type(&return_type.as_ref().infer_data_type()).ok()?;
+
+        Some(WasmUDF {
+            handler: handler.clone(),
+            module: load_module(tenant, name, code_hash),
+            arg_types,
+            return_type,
+        })
+    }
+
+    fn eval(&self, args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+        let all_scalars = !args.is_empty() && args.iter().all(|arg| arg.as_scalar().is_some());
+        let num_rows = if all_scalars { 1 } else { ctx.num_rows };
+        let validity = ctx
+            .validity
+            .as_ref()
+            .filter(|validity| !all_scalars && validity.unset_bits() > 0);
+
+        match self.call(args, num_rows, validity, ctx) {
+            Ok(column) if all_scalars => Value::Scalar(column.index(0).unwrap().to_owned()),
+            Ok(column) => Value::Column(column),
+            Err(err) => {
+                // The error of a null row is ignored, so it's set on the first valid row.
+                let row = ctx
+                    .validity
+                    .as_ref()
+                    .and_then(|validity| validity.iter().position(|valid| valid))
+                    .unwrap_or(0);
+                ctx.set_error(row, err.message());
+                let return_type = DataType::Number(self.return_type);
+                let default = Scalar::default_value(&return_type);
+                Value::Column(
+                    ColumnBuilder::repeat(&default.as_ref(), num_rows, &return_type).build(),
+                )
+            }
+        }
+    }
+
+    /// Call the handler on the valid rows, the results of the null rows are default values.
+    fn call(
+        &self,
+        args: &[ValueRef<AnyType>],
+        num_rows: usize,
+        validity: Option<&Bitmap>,
+        ctx: &EvalContext,
+    ) -> Result<Column> {
+        let num_valid_rows = validity.map_or(num_rows, |validity| num_rows - validity.unset_bits());
+
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(ctx.func_ctx.wasm_udf_max_memory as usize)
+            .instances(1)
+            .build();
+        let module = self.module.as_ref().map_err(|e| e.clone())?;
+        let mut store = Store::new(&WASM_ENGINE, WasmState { limits });
+        store.limiter(|state| &mut state.limits);
+        store
+            .set_fuel(ctx.func_ctx.wasm_udf_max_fuel)
+            .map_err(wasm_error)?;
+
+        let instance = Instance::new(&mut store, module, &[]).map_err(wasm_error)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| ErrorCode::InvalidArgument("'memory' is not exported"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, "alloc")
+            .map_err(wasm_error)?;
+        let handler = instance
+            .get_func(&mut store, &self.handler)
+            .ok_or_else(|| {
+                ErrorCode::InvalidArgument(format!("'{}' is not exported", self.handler))
+            })?;
+
+        let mut params = Vec::with_capacity(args.len() + 2);
+        params.push(Val::I32(num_valid_rows as i32));
+        for (arg, ty) in args.iter().zip(self.arg_types.iter()) {
+            let column = match arg {
+                ValueRef::Scalar(scalar) => {
+                    ColumnBuilder::repeat(scalar, num_valid_rows, &DataType::Number(*ty)).build()
+                }
+                ValueRef::Column(column) => match validity {
+                    Some(validity) => column.filter(validity),
+                    None => column.clone(),
+                },
+            };
+            let column = column.as_number().ok_or_else(|| {
+                ErrorCode::InvalidArgument("The arguments of WebAssembly UDF must be numbers")
+            })?;
+            let bytes = number_column_bytes(column);
+            let ptr = alloc
+                .call(&mut store, bytes.len() as i32)
+                .map_err(wasm_error)?;
+            memory
+                .write(&mut store, ptr as u32 as usize, bytes)
+                .map_err(wasm_error)?;
+            params.push(Val::I32(ptr));
+        }
+
+        let out_len = num_valid_rows * number_size(self.return_type);
+        let out_ptr = alloc.call(&mut store, out_len as i32).map_err(wasm_error)?;
+        params.push(Val::I32(out_ptr));
+        handler
+            .call(&mut store, &params, &mut [])
+            .map_err(wasm_error)?;
+
+        with_number_mapped_type!(|NUM_TYPE| match self.return_type {
+            NumberDataType::NUM_TYPE => {
+                let mut values = vec![NUM_TYPE::default(); num_valid_rows];
+                memory
+                    .read(&store, out_ptr as u32 as usize, as_bytes_mut(&mut values))
+                    .map_err(wasm_error)?;
+                let values = match validity {
+                    Some(validity) => {
+                        let mut valid_values = values.into_iter();
+                        validity
+                            .iter()
+                            .map(|valid| match valid {
+                                true => valid_values.next().unwrap_or_default(),
+                                false => NUM_TYPE::default(),
+                            })
+                            .collect()
+                    }
+                    None => values,
+                };
+                Ok(Column::Number(NUM_TYPE::upcast_column(values.into())))
+            }
+        })
+    }
+}
+
+fn cached_module(code_hash: &str) -> Option<Module> {
+    WASM_MODULES.lock().unwrap().get(code_hash).cloned()
+}
+
+fn load_module(tenant: &str, name: &str, code_hash: &str) -> Result<Module> {
+    if let Some(module) = cached_module(code_hash) {
+        return Ok(module);
+    }
+
+    let loader = WASM_CODE_LOADER.get().ok_or_else(|| {
+        ErrorCode::Internal(format!(
+            "The module of WebAssembly UDF '{name}' is not cached and can't be loaded"
+        ))
+    })?;
+    let code = loader(tenant, name)?;
+    if blake3::hash(&code).to_hex().as_str() != code_hash {
+        return Err(ErrorCode::InvalidArgument(format!(
+            "WebAssembly UDF '{name}' has been replaced, please run the query again"
+        )));
+    }
+    compile_module(code_hash, &code)
+}
+
+fn compile_module(code_hash: &str, code: &[u8]) -> Result<Module> {
+    if let Some(module) = cached_module(code_hash) {
+        return Ok(module);
+    }
+
+    // Compiled without holding the lock, which may take a while for a large module,
+    // the same module may be compiled more than once by concurrent queries.
+    let module = Module::new(&WASM_ENGINE, code)
+        .map_err(|e| ErrorCode::InvalidArgument(format!("Invalid WebAssembly module: {e}")))?;
+    let mut modules = WASM_MODULES.lock().unwrap();
+    if modules.len() >= MAX_CACHED_MODULES {
+        modules.clear();
+    }
+    modules.insert(code_hash.to_string(), module.clone());
+    Ok(module)
+}
+
+fn number_type(data_type: &DataType) -> Result<NumberDataType> {
+    match data_type.remove_nullable() {
+        DataType::Number(ty) => Ok(ty),
+        ty => Err(ErrorCode::InvalidArgument(format!(
+            "WebAssembly UDF only supports number types, but got {ty}"
+        ))),
+    }
+}
+
+fn number_size(ty: NumberDataType) -> usize {
+    with_number_mapped_type!(|NUM_TYPE| match ty {
+        NumberDataType::NUM_TYPE => std::mem::size_of::<NUM_TYPE>(),
+    })
+}
+
+fn is_i32_params(mut types: impl ExactSizeIterator<Item = ValType>, len: usize) -> bool {
+    types.len() == len && types.all(|ty| matches!(ty, ValType::I32))
+}
+
+fn number_column_bytes(column: &NumberColumn) -> &[u8] {
+    with_number_mapped_type!(|NUM_TYPE| match column {
+        NumberColumn::NUM_TYPE(buffer) => as_bytes(buffer.as_slice()),
+    })
+}
+
+fn as_bytes<T: Number>(values: &[T]) -> &[u8] {
+    // Safety: numbers are plain old data without padding.
+    unsafe {
+        std::slice::from_raw_parts(values.as_ptr() as *const u8, std::mem::size_of_val(values))
+    }
+}
+
+fn as_bytes_mut<T: Number>(values: &mut [T]) -> &mut [u8] {
+    // Safety: numbers are plain old data, any bytes are valid values.
+    unsafe {
+        std::slice::from_raw_parts_mut(
+            values.as_mut_ptr() as *mut u8,
+            std::mem::size_of_val(values),
+        )
+    }
+}
+
+fn wasm_error(e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::InvalidArgument(format!("WebAssembly UDF failed: {e:#}"))
+}
@@ -30,6 +30,10 @@ fn test_geometry() {
     test_st_makepoint(file);
     test_to_string(file);
     test_st_geometryfromwkt(file);
+    test_st_measurement(file);
+    test_st_spatial_predicates(file);
+    test_st_centroid(file);
+    test_st_aswkb(file);
     // test_st_transform(file);
 }
 
@@ -87,6 +91,65 @@ fn test_st_geometryfromwkt(file: &mut impl Write) {
     ]);
 }
 
+fn test_st_measurement(file: &mut impl Write) {
+    run_ast(file, "st_x(st_geometryfromwkt('POINT(7 8)'))", &[]);
+    run_ast(file, "st_y(st_geometryfromwkt('POINT(7 8)'))", &[]);
+    run_ast(
+        file,
+        "st_area(st_geometryfromwkt('POLYGON((0 0,4 0,4 3,0 3,0 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_length(st_geometryfromwkt('LINESTRING(0 0,3 4)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_distance(st_geometryfromwkt('POINT(0 0)'), st_geometryfromwkt('POINT(3 4)'))",
+        &[],
+    );
+}
+
+fn test_st_spatial_predicates(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_contains(st_geometryfromwkt('POLYGON((0 0,4 0,4 3,0 3,0 0))'), st_geometryfromwkt('POINT(1 1)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_intersects(st_geometryfromwkt('LINESTRING(0 0,2 2)'), st_geometryfromwkt('LINESTRING(0 2,2 0)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_within(st_geometryfromwkt('POINT(5 5)'), st_geometryfromwkt('POLYGON((0 0,4 0,4 3,0 3,0 0))'))",
+        &[],
+    );
+    // the bounding boxes are disjoint
+    run_ast(
+        file,
+        "st_intersects(st_makegeompoint(a, b), st_geometryfromwkt('POLYGON((10 10,11 10,11 11,10 11,10 10))'))",
+        &[
+            ("a", Float64Type::from_data(vec![1.0, 2.0, 3.0])),
+            ("b", Float64Type::from_data(vec![1.0, 2.0, 3.0])),
+        ],
+    );
+}
+
+fn test_st_centroid(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_centroid(st_geometryfromwkt('POLYGON((0 0,4 0,4 3,0 3,0 0))'))",
+        &[],
+    );
+}
+
+fn test_st_aswkb(file: &mut impl Write) {
+    run_ast(file, "st_aswkb(st_geometryfromwkt('POINT(1 2)'))", &[]);
+}
+
 // fn test_st_transform(file: &mut impl Write) {
 //     // just to_srid
 //     run_ast(
@@ -50,10 +50,12 @@ mod misc;
 mod other;
 pub(crate) mod parser;
 mod regexp;
+mod search;
 mod string;
 mod tuple;
 mod variant;
 mod vector;
+mod wasm_udf;
 
 pub fn run_ast(file: &mut impl Write, text: impl AsRef<str>, columns: &[(&str, Column)]) {
     let text = text.as_ref();
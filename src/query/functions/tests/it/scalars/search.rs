@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::types::StringType;
+use databend_common_expression::FromData;
+use goldenfile::Mint;
+
+use crate::scalars::run_ast;
+
+#[test]
+fn test_search() {
+    let mut mint = Mint::new("tests/it/scalars/testdata");
+    let file = &mut mint.new_goldenfile("search.txt").unwrap();
+
+    test_match(file);
+    test_score(file);
+}
+
+fn test_match(file: &mut impl Write) {
+    run_ast(
+        file,
+        "match('The quick brown fox', 'QUICK fox', 'standard')",
+        &[],
+    );
+    run_ast(
+        file,
+        "match('The quick brown fox', 'quick dog', 'english')",
+        &[],
+    );
+    run_ast(file, "match(a, 'the fox', 'english')", &[(
+        "a",
+        StringType::from_data(vec!["The quick fox", "a lazy dog", "Foxes and dogs"]),
+    )]);
+}
+
+fn test_score(file: &mut impl Write) {
+    run_ast(file, "score('The quick brown fox', 'fox', 'standard')", &[]);
+    run_ast(
+        file,
+        "score('The quick brown fox', 'quick fox', 'standard')",
+        &[],
+    );
+}
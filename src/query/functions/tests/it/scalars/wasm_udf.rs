@@ -0,0 +1,183 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_expression::types::*;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::FunctionID;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use databend_common_functions::scalars::cache_wasm_module;
+use databend_common_functions::scalars::check_wasm_udf;
+use databend_common_functions::scalars::wasm_udf_params;
+use databend_common_functions::scalars::WASM_UDF_FUNC_NAME;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+const MODULE: &str = r#"
+(module
+  (memory (export "memory") 1)
+  (global $next (mut i32) (i32.const 1024))
+  (func (export "alloc") (param $len i32) (result i32)
+    (local $ptr i32)
+    (local.set $ptr (global.get $next))
+    (global.set $next (i32.add (local.get $ptr) (local.get $len)))
+    (local.get $ptr))
+  (func (export "add") (param $n i32) (param $a i32) (param $b i32) (param $out i32)
+    (local $i i32)
+    (block $done
+      (loop $row
+        (br_if $done (i32.ge_u (local.get $i) (local.get $n)))
+        (i64.store
+          (i32.add (local.get $out) (i32.mul (local.get $i) (i32.const 8)))
+          (i64.add
+            (i64.extend_i32_s (i32.load (i32.add (local.get $a) (i32.mul (local.get $i) (i32.const 4)))))
+            (i64.extend_i32_s (i32.load (i32.add (local.get $b) (i32.mul (local.get $i) (i32.const 4)))))))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $row))))
+  (func (export "spin") (param $n i32) (param $a i32) (param $out i32)
+    (loop $forever (br $forever))))
+"#;
+
+#[test]
+fn test_wasm_udf() {
+    let code = MODULE.as_bytes();
+    let int32 = DataType::Number(NumberDataType::Int32);
+    let int64 = DataType::Number(NumberDataType::Int64);
+    let args_type = vec![int32.clone(), int32.clone()];
+
+    check_wasm_udf(code, "add", &args_type, &int64).unwrap();
+    check_wasm_udf(code, "spin", &args_type[..1], &int64).unwrap();
+    assert!(check_wasm_udf(code, "sub", &args_type, &int64).is_err());
+    assert!(check_wasm_udf(code, "add", &args_type[..1], &int64).is_err());
+    assert!(check_wasm_udf(code, "add", &args_type, &DataType::String).is_err());
+    assert!(check_wasm_udf(b"(module)", "add", &args_type, &int64).is_err());
+    assert!(cache_wasm_module(b"(invalid").is_err());
+
+    // `wasm_udf` can't be called by name in SQL.
+    assert!(!BUILTIN_FUNCTIONS.contains(WASM_UDF_FUNC_NAME));
+    let code_hash = cache_wasm_module(code).unwrap();
+
+    let func_ctx = FunctionContext {
+        wasm_udf_max_fuel: 1_000_000,
+        wasm_udf_max_memory: 1024 * 1024,
+        ..Default::default()
+    };
+
+    let func = BUILTIN_FUNCTIONS
+        .get(&FunctionID::Factory {
+            name: WASM_UDF_FUNC_NAME.to_string(),
+            id: 0,
+            params: wasm_udf_params("test", "add", &code_hash, "add", &int64),
+            args_type: args_type.clone(),
+        })
+        .unwrap();
+    assert_eq!(func.signature.return_type, int64);
+    let (_, eval) = func.eval.as_scalar().unwrap();
+    let a = Int32Type::from_data(vec![1, 2, 3]);
+    let b = Int32Type::from_data(vec![10, 20, 30]);
+    let mut ctx = EvalContext {
+        generics: &[],
+        num_rows: 3,
+        func_ctx: &func_ctx,
+        validity: None,
+        errors: None,
+    };
+    let result = eval(
+        &[
+            ValueRef::Column(a),
+            ValueRef::Scalar(ScalarRef::Number(NumberScalar::Int32(100))),
+        ],
+        &mut ctx,
+    );
+    assert!(ctx.errors.is_none());
+    assert_eq!(
+        result.into_column().unwrap(),
+        Int64Type::from_data(vec![101, 102, 103])
+    );
+    let result = eval(
+        &[ValueRef::Column(b.clone()), ValueRef::Column(b.clone())],
+        &mut ctx,
+    );
+    assert_eq!(
+        result.into_column().unwrap(),
+        Int64Type::from_data(vec![20, 40, 60])
+    );
+
+    // The null rows are not passed to the module.
+    let mut ctx = EvalContext {
+        generics: &[],
+        num_rows: 3,
+        func_ctx: &func_ctx,
+        validity: Some(Bitmap::from([true, false, true])),
+        errors: None,
+    };
+    let result = eval(
+        &[ValueRef::Column(b.clone()), ValueRef::Column(b)],
+        &mut ctx,
+    );
+    assert!(ctx.errors.is_none());
+    assert_eq!(
+        result.into_column().unwrap(),
+        Int64Type::from_data(vec![20, 0, 60])
+    );
+
+    // The module runs out of fuel.
+    let func = BUILTIN_FUNCTIONS
+        .get(&FunctionID::Factory {
+            name: WASM_UDF_FUNC_NAME.to_string(),
+            id: 0,
+            params: wasm_udf_params("test", "spin", &code_hash, "spin", &int64),
+            args_type: vec![int32],
+        })
+        .unwrap();
+    let (_, eval) = func.eval.as_scalar().unwrap();
+    let mut ctx = EvalContext {
+        generics: &[],
+        num_rows: 1,
+        func_ctx: &func_ctx,
+        validity: None,
+        errors: None,
+    };
+    let result = eval(&[ValueRef::Column(Int32Type::from_data(vec![1]))], &mut ctx);
+    assert!(matches!(result, Value::Column(_)));
+    assert!(ctx.errors.unwrap().1.contains("fuel"));
+
+    // The module is neither cached nor loadable, the error is reported on evaluation.
+    let func = BUILTIN_FUNCTIONS
+        .get(&FunctionID::Factory {
+            name: WASM_UDF_FUNC_NAME.to_string(),
+            id: 0,
+            params: wasm_udf_params("test", "missing", "0000", "add", &int64),
+            args_type: args_type.clone(),
+        })
+        .unwrap();
+    let (_, eval) = func.eval.as_scalar().unwrap();
+    let mut ctx = EvalContext {
+        generics: &[],
+        num_rows: 1,
+        func_ctx: &func_ctx,
+        validity: None,
+        errors: None,
+    };
+    eval(
+        &[
+            ValueRef::Column(Int32Type::from_data(vec![1])),
+            ValueRef::Column(Int32Type::from_data(vec![2])),
+        ],
+        &mut ctx,
+    );
+    assert!(ctx.errors.unwrap().1.contains("'missing'"));
+}
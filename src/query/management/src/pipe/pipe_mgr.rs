@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+
+use crate::pipe::pipe_api::PipeApi;
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+
+static PIPE_API_KEY_PREFIX: &str = "__fd_pipes";
+
+pub struct PipeMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    pipe_prefix: String,
+}
+
+impl PipeMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create pipe)",
+            ));
+        }
+
+        Ok(PipeMgr {
+            kv_api,
+            pipe_prefix: format!("{}/{}", PIPE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_pipe_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.pipe_prefix, escape_for_key(name)?))
+    }
+}
+
+#[async_trait::async_trait]
+impl PipeApi for PipeMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_pipe(&self, pipe: PipeInfo, create_option: &CreateOption) -> Result<()> {
+        let seq = MatchSeq::from(*create_option);
+        let key = self.make_pipe_key(pipe.name.as_str())?;
+        let value = Operation::Update(serialize_struct(&pipe, ErrorCode::IllegalPipe, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, None))
+            .await?;
+
+        if let CreateOption::CreateIfNotExists(false) = create_option {
+            if res.prev.is_some() {
+                return Err(ErrorCode::PipeAlreadyExists(format!(
+                    "Pipe '{}' already exists.",
+                    pipe.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_pipe(&self, pipe: PipeInfo, match_seq: MatchSeq) -> Result<u64> {
+        let key = self.make_pipe_key(pipe.name.as_str())?;
+        let value = Operation::Update(serialize_struct(&pipe, ErrorCode::IllegalPipe, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownPipe(format!(
+                "Pipe '{}' cannot be updated as it may not exist or the request is invalid.",
+                pipe.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_pipe(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_pipe_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownPipe(format!(
+                "Pipe '{}' does not exist.",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_pipe(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PipeInfo>> {
+        let key = self.make_pipe_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownPipe(format!("Pipe '{}' does not exist.", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalPipe, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownPipe(format!(
+                "Pipe '{}' does not exist.",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_pipes(&self) -> Result<Vec<PipeInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.pipe_prefix).await?;
+
+        let mut pipes = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let pipe = deserialize_struct(&value.data, ErrorCode::IllegalPipe, || "")?;
+            pipes.push(pipe);
+        }
+        Ok(pipes)
+    }
+}
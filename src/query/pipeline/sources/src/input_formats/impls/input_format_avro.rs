@@ -0,0 +1,535 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use apache_avro::schema::Name;
+use apache_avro::schema::ResolvedSchema;
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_formats::FieldDecoder;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::AvroFileFormatParams;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_storage::FileParseError;
+use opendal::Operator;
+use serde_json::Value as JsonValue;
+
+use crate::input_formats::error_utils::truncate_column_data;
+use crate::input_formats::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+type AvroNames<'a> = HashMap<Name, &'a Schema>;
+
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn read_row(
+        field_decoder: &FieldJsonAstDecoder,
+        record: Vec<(String, AvroValue)>,
+        record_schema: &HashMap<String, &Schema>,
+        names: &AvroNames,
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        default_values: &Option<Vec<Scalar>>,
+        missing_field_as: &NullAs,
+    ) -> std::result::Result<(), FileParseError> {
+        let mut values: HashMap<String, (AvroValue, &Schema)> = record
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let field_schema = *record_schema.get(&name)?;
+                let name = if field_decoder.ident_case_sensitive {
+                    name
+                } else {
+                    name.to_lowercase()
+                };
+                Some((name, (value, field_schema)))
+            })
+            .collect();
+
+        for ((column_index, field), column) in
+            schema.fields().iter().enumerate().zip(columns.iter_mut())
+        {
+            let field_name = if field_decoder.ident_case_sensitive {
+                field.name().to_owned()
+            } else {
+                field.name().to_lowercase()
+            };
+            match values.remove(&field_name) {
+                None => match missing_field_as {
+                    NullAs::Error => {
+                        return Err(FileParseError::ColumnMissingError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                        });
+                    }
+                    NullAs::Null => {
+                        if field.is_nullable_or_null() {
+                            column.push_default();
+                        } else {
+                            return Err(FileParseError::ColumnMissingError {
+                                column_index,
+                                column_name: field.name().to_owned(),
+                                column_type: field.data_type.to_string(),
+                            });
+                        }
+                    }
+                    NullAs::FieldDefault => {
+                        if let Some(values) = default_values {
+                            column.push(values[column_index].as_ref());
+                        } else {
+                            column.push_default();
+                        }
+                    }
+                },
+                Some((value, value_schema)) => {
+                    let decode_error =
+                        |e: ErrorCode, value: &AvroValue| FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.message(),
+                            column_data: truncate_column_data(format!("{:?}", value)),
+                        };
+                    read_avro_field(field_decoder, column, &value, value_schema, names)
+                        .map_err(|e| decode_error(e, &value))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(
+            options,
+            options.is_rounding_mode,
+        ))
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+        let avro_params = AvroFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
+
+        let reader = Reader::new(Cursor::new(&batch.data)).map_err(|e| avro_error(e, path))?;
+        let writer_schema = reader.writer_schema().clone();
+        let resolved = ResolvedSchema::try_from(&writer_schema).map_err(|e| avro_error(e, path))?;
+        let names = resolved.get_names().clone();
+        let record_schema = match &writer_schema {
+            Schema::Record(record) => record
+                .fields
+                .iter()
+                .map(|f| (f.name.clone(), &f.schema))
+                .collect::<HashMap<_, _>>(),
+            other => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "fail to parse Avro {}: the schema of rows must be a record, but got {:?}",
+                    path, other
+                )));
+            }
+        };
+
+        for (row, value) in reader.enumerate() {
+            let value = value.map_err(|e| avro_error(e, path))?;
+            let record = match value {
+                AvroValue::Record(record) => record,
+                other => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "fail to parse Avro {}:{} expect a record, but got {:?}",
+                        path,
+                        row + 1,
+                        other
+                    )));
+                }
+            };
+            if let Err(e) = Self::read_row(
+                field_decoder,
+                record,
+                &record_schema,
+                &names,
+                columns,
+                &builder.ctx.schema,
+                &builder.ctx.default_values,
+                &avro_params.missing_field_as,
+            ) {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    path,
+                    row,
+                )?
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The size of the first read of the file header, it's doubled until the header is complete.
+const AVRO_HEADER_READ_SIZE: u64 = 64 * 1024;
+
+/// Read the schema of an Avro object container file, and map it to a `TableSchema`.
+///
+/// Only the header of the file holding the schema is read.
+pub async fn read_avro_schema(
+    operator: &Operator,
+    path: &str,
+    file_size: Option<u64>,
+) -> Result<TableSchema> {
+    let file_size = match file_size {
+        Some(size) => size,
+        None => operator.stat(path).await?.content_length(),
+    };
+
+    let mut read_size = AVRO_HEADER_READ_SIZE.min(file_size);
+    loop {
+        let data = operator.read_with(path).range(0..read_size).await?;
+        match Reader::new(Cursor::new(&data)) {
+            Ok(reader) => return avro_schema_to_table_schema(reader.writer_schema()),
+            // The header may be truncated, read more of the file.
+            Err(_) if read_size < file_size => read_size = (read_size * 2).min(file_size),
+            Err(e) => return Err(avro_error(e, path)),
+        }
+    }
+}
+
+/// Map the schema of the rows in an Avro file to a `TableSchema`.
+///
+/// The top level schema must be a record, every field of it becomes a column.
+/// A union of `null` and another type is mapped to a nullable type,
+/// other unions are mapped to `Variant`.
+pub fn avro_schema_to_table_schema(schema: &Schema) -> Result<TableSchema> {
+    let resolved = ResolvedSchema::try_from(schema)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid Avro schema: {e}")))?;
+    let names = resolved.get_names();
+    match schema {
+        Schema::Record(record) => {
+            let fields = record
+                .fields
+                .iter()
+                .map(|f| {
+                    let ty = avro_type_to_table_type(&f.schema, names, false).map_err(|e| {
+                        ErrorCode::BadBytes(format!(
+                            "fail to map the Avro type of column {}: {}",
+                            f.name,
+                            e.message()
+                        ))
+                    })?;
+                    Ok(TableField::new(&f.name, ty))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TableSchema::new(fields))
+        }
+        other => Err(ErrorCode::BadBytes(format!(
+            "the schema of Avro rows must be a record, but got {:?}",
+            other
+        ))),
+    }
+}
+
+// Only top level `bytes` are mapped to `Binary`, nested ones are decoded as JSON and
+// mapped to `String`.
+fn avro_type_to_table_type(
+    schema: &Schema,
+    names: &AvroNames,
+    nested: bool,
+) -> Result<TableDataType> {
+    let ty = match schema {
+        Schema::Null => TableDataType::Null,
+        Schema::Boolean => TableDataType::Boolean,
+        Schema::Int | Schema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        Schema::Long | Schema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        Schema::Float => TableDataType::Number(NumberDataType::Float32),
+        Schema::Double => TableDataType::Number(NumberDataType::Float64),
+        Schema::Bytes | Schema::Fixed(_) if !nested => TableDataType::Binary,
+        Schema::Bytes | Schema::Fixed(_) | Schema::String | Schema::Enum(_) | Schema::Uuid => {
+            TableDataType::String
+        }
+        Schema::Date => TableDataType::Date,
+        Schema::TimestampMillis | Schema::TimestampMicros => TableDataType::Timestamp,
+        Schema::Decimal(decimal) => {
+            let size = DecimalSize {
+                precision: decimal.precision as u8,
+                scale: decimal.scale as u8,
+            };
+            TableDataType::Decimal(DecimalDataType::from_size(size)?)
+        }
+        Schema::Array(item) => {
+            TableDataType::Array(Box::new(avro_type_to_table_type(item, names, true)?))
+        }
+        Schema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![
+                TableDataType::String,
+                avro_type_to_table_type(value, names, true)?,
+            ],
+        })),
+        Schema::Record(record) => {
+            let mut fields_name = Vec::with_capacity(record.fields.len());
+            let mut fields_type = Vec::with_capacity(record.fields.len());
+            for field in record.fields.iter() {
+                fields_name.push(field.name.clone());
+                fields_type.push(avro_type_to_table_type(&field.schema, names, true)?);
+            }
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        Schema::Union(union) => {
+            let variants = union.variants();
+            let non_null = variants
+                .iter()
+                .filter(|s| !matches!(s, Schema::Null))
+                .collect::<Vec<_>>();
+            match non_null.as_slice() {
+                [] => TableDataType::Null,
+                [inner] => {
+                    let inner = avro_type_to_table_type(inner, names, nested)?;
+                    if non_null.len() < variants.len() {
+                        inner.wrap_nullable()
+                    } else {
+                        inner
+                    }
+                }
+                _ => {
+                    if non_null.len() < variants.len() {
+                        TableDataType::Variant.wrap_nullable()
+                    } else {
+                        TableDataType::Variant
+                    }
+                }
+            }
+        }
+        Schema::Ref { name } => match names.get(name) {
+            Some(schema) => avro_type_to_table_type(schema, names, nested)?,
+            None => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unresolved Avro schema reference {}",
+                    name
+                )));
+            }
+        },
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported Avro type {:?}",
+                other
+            )));
+        }
+    };
+    Ok(ty)
+}
+
+fn read_avro_field(
+    field_decoder: &FieldJsonAstDecoder,
+    column: &mut ColumnBuilder,
+    value: &AvroValue,
+    schema: &Schema,
+    names: &AvroNames,
+) -> Result<()> {
+    if column.data_type().remove_nullable() == DataType::Binary {
+        let value = match value {
+            AvroValue::Union(_, v) => v.as_ref(),
+            v => v,
+        };
+        match value {
+            AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => {
+                column.push(ScalarRef::Binary(v));
+                return Ok(());
+            }
+            AvroValue::String(v) => {
+                column.push(ScalarRef::Binary(v.as_bytes()));
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+    let value = avro_value_to_json(value, schema, names)?;
+    field_decoder.read_field(column, &value)
+}
+
+/// Convert an Avro value to JSON, so it can be read into any column by `FieldJsonAstDecoder`.
+///
+/// Logical types are converted to the representations accepted by the decoder:
+/// `date` to the number of days, `timestamp-*` to microseconds, and `decimal` to a string.
+fn avro_value_to_json(value: &AvroValue, schema: &Schema, names: &AvroNames) -> Result<JsonValue> {
+    let schema = match schema {
+        Schema::Ref { name } => names.get(name).copied().ok_or_else(|| {
+            ErrorCode::BadBytes(format!("unresolved Avro schema reference {}", name))
+        })?,
+        s => s,
+    };
+    let json = match (value, schema) {
+        (AvroValue::Union(idx, v), Schema::Union(union)) => {
+            let variant = union
+                .variants()
+                .get(*idx as usize)
+                .ok_or_else(|| ErrorCode::BadBytes(format!("invalid Avro union index {}", idx)))?;
+            avro_value_to_json(v, variant, names)?
+        }
+        (AvroValue::Null, _) => JsonValue::Null,
+        (AvroValue::Boolean(v), _) => JsonValue::Bool(*v),
+        (AvroValue::Int(v), _) | (AvroValue::TimeMillis(v), _) | (AvroValue::Date(v), _) => {
+            JsonValue::from(*v)
+        }
+        (AvroValue::Long(v), _) | (AvroValue::TimeMicros(v), _) => JsonValue::from(*v),
+        (AvroValue::TimestampMillis(v), _) => {
+            JsonValue::from(v.checked_mul(1000).ok_or_else(|| {
+                ErrorCode::BadBytes(format!("Avro timestamp-millis {} is out of range", v))
+            })?)
+        }
+        (AvroValue::TimestampMicros(v), _) => JsonValue::from(*v),
+        (AvroValue::Float(v), _) => JsonValue::from(*v),
+        (AvroValue::Double(v), _) => JsonValue::from(*v),
+        (AvroValue::Bytes(v), _) | (AvroValue::Fixed(_, v), _) => {
+            JsonValue::String(String::from_utf8_lossy(v).to_string())
+        }
+        (AvroValue::String(v), _) | (AvroValue::Enum(_, v), _) => JsonValue::String(v.clone()),
+        (AvroValue::Uuid(v), _) => JsonValue::String(v.to_string()),
+        (AvroValue::Decimal(v), Schema::Decimal(decimal)) => {
+            let bytes = Vec::<u8>::try_from(v)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid Avro decimal: {e}")))?;
+            JsonValue::String(decimal_to_string(&bytes, decimal.scale))
+        }
+        (AvroValue::Array(items), Schema::Array(item_schema)) => JsonValue::Array(
+            items
+                .iter()
+                .map(|v| avro_value_to_json(v, item_schema, names))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        (AvroValue::Map(entries), Schema::Map(value_schema)) => JsonValue::Object(
+            entries
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), avro_value_to_json(v, value_schema, names)?)))
+                .collect::<Result<_>>()?,
+        ),
+        (AvroValue::Record(fields), Schema::Record(record)) => JsonValue::Object(
+            fields
+                .iter()
+                .zip(record.fields.iter())
+                .map(|((k, v), f)| Ok((k.clone(), avro_value_to_json(v, &f.schema, names)?)))
+                .collect::<Result<_>>()?,
+        ),
+        (value, _) => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported Avro value {:?}",
+                value
+            )));
+        }
+    };
+    Ok(json)
+}
+
+// The unscaled value of an Avro decimal is a big-endian two's-complement integer of any width.
+fn decimal_to_string(bytes: &[u8], scale: usize) -> String {
+    let negative = bytes.first().is_some_and(|b| b & 0x80 != 0);
+    let mut magnitude = bytes.to_vec();
+    if negative {
+        for b in magnitude.iter_mut() {
+            *b = !*b;
+        }
+        for b in magnitude.iter_mut().rev() {
+            let (v, overflow) = b.overflowing_add(1);
+            *b = v;
+            if !overflow {
+                break;
+            }
+        }
+    }
+
+    // The decimal digits from the lowest, by dividing the magnitude by 10 repeatedly.
+    let mut digits = vec![];
+    while magnitude.iter().any(|b| *b != 0) {
+        let mut rem = 0u32;
+        for b in magnitude.iter_mut() {
+            let cur = (rem << 8) | *b as u32;
+            *b = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        digits.push(rem as u8);
+    }
+    let digits = digits
+        .iter()
+        .rev()
+        .map(|d| char::from(b'0' + d))
+        .collect::<String>();
+
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    let sign = if negative { "-" } else { "" };
+    if scale == 0 {
+        format!("{sign}{int_part}")
+    } else {
+        format!("{sign}{int_part}.{frac_part}")
+    }
+}
+
+fn avro_error(e: apache_avro::Error, path: &str) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to parse Avro {}: {}", path, e))
+}
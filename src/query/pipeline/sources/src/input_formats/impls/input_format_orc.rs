@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchemaRef;
+use databend_common_formats::FieldDecoder;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::OrcFileFormatParams;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_storage::orc::OrcFileMeta;
+use databend_common_storage::orc::OrcStripe;
+use databend_common_storage::FileParseError;
+
+use crate::input_formats::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatOrc {}
+
+impl InputFormatOrc {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    /// Decode all the columns of the table from a stripe.
+    ///
+    /// ORC is a columnar format, so a stripe is loaded or skipped as a whole.
+    fn read_stripe(
+        meta: &OrcFileMeta,
+        stripe: &OrcStripe,
+        schema: &TableSchemaRef,
+        default_values: &Option<Vec<Scalar>>,
+        missing_field_as: &NullAs,
+        ident_case_sensitive: bool,
+    ) -> std::result::Result<Vec<Column>, FileParseError> {
+        let num_rows = stripe.num_rows();
+        let mut columns = Vec::with_capacity(schema.num_fields());
+        for (column_index, field) in schema.fields().iter().enumerate() {
+            let data_type = field.data_type();
+            let missing_error = || FileParseError::ColumnMissingError {
+                column_index,
+                column_name: field.name().to_owned(),
+                column_type: data_type.to_string(),
+            };
+            let column = match meta.column_id(field.name(), ident_case_sensitive) {
+                Some(column_id) => {
+                    stripe
+                        .decode_column(meta, column_id, data_type)
+                        .map_err(|e| FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: data_type.to_string(),
+                            decode_error: e.message(),
+                            column_data: String::new(),
+                        })?
+                }
+                None => {
+                    let default = match missing_field_as {
+                        NullAs::Error => return Err(missing_error()),
+                        NullAs::Null if !field.is_nullable_or_null() => {
+                            return Err(missing_error());
+                        }
+                        NullAs::Null => Scalar::Null,
+                        NullAs::FieldDefault => match default_values {
+                            Some(values) => values[column_index].clone(),
+                            None => Scalar::default_value(&DataType::from(data_type)),
+                        },
+                    };
+                    let data_type = DataType::from(data_type);
+                    ColumnBuilder::repeat(&default.as_ref(), num_rows, &data_type).build()
+                }
+            };
+            columns.push(column);
+        }
+        Ok(columns)
+    }
+}
+
+impl InputFormatTextBase for InputFormatOrc {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Orc
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(
+            options,
+            options.is_rounding_mode,
+        ))
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let path = &batch.split_info.file.path;
+        let orc_params = OrcFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
+        let ident_case_sensitive = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success")
+            .ident_case_sensitive;
+
+        let meta = OrcFileMeta::try_from_tail(&batch.data)
+            .map_err(|e| e.add_message(format!("file: {path}")))?;
+        let mut start_row = 0;
+        for i in 0..meta.stripes().len() {
+            let stripe = OrcStripe::try_create(&meta, i, &batch.data)
+                .map_err(|e| e.add_message(format!("file: {path}")))?;
+            let num_rows = stripe.num_rows();
+            match Self::read_stripe(
+                &meta,
+                &stripe,
+                &builder.ctx.schema,
+                &builder.ctx.default_values,
+                &orc_params.missing_field_as,
+                ident_case_sensitive,
+            ) {
+                Ok(columns) => {
+                    for (column_builder, column) in
+                        builder.mutable_columns.iter_mut().zip(columns.iter())
+                    {
+                        column_builder.append_column(column);
+                    }
+                    builder.num_rows += num_rows;
+                    builder.file_status.num_rows_loaded += num_rows;
+                }
+                Err(e) => {
+                    builder
+                        .ctx
+                        .on_error(e, None, &mut builder.file_status, path, start_row)?;
+                }
+            }
+            start_row += num_rows;
+        }
+        Ok(())
+    }
+}
@@ -23,7 +23,10 @@ use databend_common_catalog::catalog::CatalogManager;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_functions::scalars::set_wasm_code_loader;
+use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_sharing::ShareEndpointManager;
 use databend_common_storage::DataOperator;
@@ -109,6 +112,7 @@ impl GlobalServices {
         .await?;
         RoleCacheManager::init()?;
         ShareEndpointManager::init()?;
+        set_wasm_code_loader(Box::new(load_wasm_udf_code));
 
         DataOperator::init(&config.storage).await?;
         ShareTableConfig::init(
@@ -125,3 +129,18 @@ impl GlobalServices {
         Ok(())
     }
 }
+
+/// Load the code of a WebAssembly UDF whose module is not cached on this node.
+fn load_wasm_udf_code(tenant: &str, name: &str) -> Result<Vec<u8>> {
+    let tenant = tenant.to_string();
+    let name = name.to_string();
+    GlobalIORuntime::instance().block_on(async move {
+        let udf = UserApiProvider::instance().get_udf(&tenant, &name).await?;
+        match udf.map(|udf| udf.definition) {
+            Some(UDFDefinition::UDFScript(script)) => Ok(script.code),
+            _ => Err(ErrorCode::UnknownFunction(format!(
+                "WebAssembly UDF '{name}' does not exist"
+            ))),
+        }
+    })
+}
@@ -81,6 +81,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
                 | Plan::CreateStream(_)
+                | Plan::AlterStream(_)
                 | Plan::DropStream(_)
 
                 // User.
@@ -22,10 +22,13 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::plans::RefreshIndexPlan;
+use databend_common_sql::plans::RefreshInvertedIndexPlan;
+use databend_common_sql::plans::RefreshVectorIndexPlan;
 use databend_common_sql::plans::RefreshVirtualColumnPlan;
 use databend_common_sql::BindContext;
 use databend_common_sql::Binder;
@@ -37,6 +40,8 @@ use parking_lot::RwLock;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::RefreshIndexInterpreter;
+use crate::interpreters::RefreshInvertedIndexInterpreter;
+use crate::interpreters::RefreshVectorIndexInterpreter;
 use crate::interpreters::RefreshVirtualColumnInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -100,6 +105,24 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         }
     }
 
+    // Generate sync inverted indexes.
+    if ctx
+        .get_settings()
+        .get_enable_refresh_inverted_index_after_write()?
+    {
+        let inverted_index_plans = generate_refresh_inverted_index_plan(ctx.clone(), &desc).await?;
+        plans.extend_from_slice(&inverted_index_plans);
+    }
+
+    // Generate sync vector indexes.
+    if ctx
+        .get_settings()
+        .get_enable_refresh_vector_index_after_write()?
+    {
+        let vector_index_plans = generate_refresh_vector_index_plan(ctx.clone(), &desc).await?;
+        plans.extend_from_slice(&vector_index_plans);
+    }
+
     let mut tasks = Vec::with_capacity(std::cmp::min(
         ctx.get_settings().get_max_threads()? as usize,
         plans.len(),
@@ -148,6 +171,34 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
                     }
                     Ok(())
                 }
+                Plan::RefreshInvertedIndex(inverted_index_plan) => {
+                    let refresh_inverted_index_interpreter =
+                        RefreshInvertedIndexInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *inverted_index_plan,
+                        )?;
+                    let build_res = refresh_inverted_index_interpreter.execute2().await?;
+                    if !build_res.main_pipeline.is_empty() {
+                        return Err(ErrorCode::Internal(
+                            "Logical error, refresh inverted index is an empty pipeline.",
+                        ));
+                    }
+                    Ok(())
+                }
+                Plan::RefreshVectorIndex(vector_index_plan) => {
+                    let refresh_vector_index_interpreter =
+                        RefreshVectorIndexInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *vector_index_plan,
+                        )?;
+                    let build_res = refresh_vector_index_interpreter.execute2().await?;
+                    if !build_res.main_pipeline.is_empty() {
+                        return Err(ErrorCode::Internal(
+                            "Logical error, refresh vector index is an empty pipeline.",
+                        ));
+                    }
+                    Ok(())
+                }
                 _ => unreachable!(),
             }
         });
@@ -253,3 +304,63 @@ async fn generate_refresh_virtual_column_plan(
 
     Ok(Some(Plan::RefreshVirtualColumn(Box::new(plan))))
 }
+
+async fn generate_refresh_inverted_index_plan(
+    ctx: Arc<QueryContext>,
+    desc: &RefreshDesc,
+) -> Result<Vec<Plan>> {
+    let segment_locs = ctx.get_segment_locations()?;
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+
+    let plans = table
+        .get_table_info()
+        .meta
+        .indexes
+        .values()
+        .filter(|index| index.sync_creation && index.index_type == TableIndexType::Inverted)
+        .map(|index| {
+            Plan::RefreshInvertedIndex(Box::new(RefreshInvertedIndexPlan {
+                catalog: desc.catalog.clone(),
+                database: desc.database.clone(),
+                table: desc.table.clone(),
+                index_name: index.name.clone(),
+                limit: None,
+                segment_locs: Some(segment_locs.clone()),
+            }))
+        })
+        .collect();
+
+    Ok(plans)
+}
+
+async fn generate_refresh_vector_index_plan(
+    ctx: Arc<QueryContext>,
+    desc: &RefreshDesc,
+) -> Result<Vec<Plan>> {
+    let segment_locs = ctx.get_segment_locations()?;
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+
+    let plans = table
+        .get_table_info()
+        .meta
+        .indexes
+        .values()
+        .filter(|index| index.sync_creation && index.index_type == TableIndexType::Vector)
+        .map(|index| {
+            Plan::RefreshVectorIndex(Box::new(RefreshVectorIndexPlan {
+                catalog: desc.catalog.clone(),
+                database: desc.database.clone(),
+                table: desc.table.clone(),
+                index_name: index.name.clone(),
+                limit: None,
+                segment_locs: Some(segment_locs.clone()),
+            }))
+        })
+        .collect();
+
+    Ok(plans)
+}
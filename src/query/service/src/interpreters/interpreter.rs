@@ -190,6 +190,11 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    // A failed statement aborts the explicit transaction.
+    if error.is_some() {
+        ctx.txn_mgr().lock().set_fail();
+    }
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
         error!("interpreter.finish.error: {:?}", error)
     }
@@ -64,10 +64,15 @@ impl Interpreter for ShowCreateCatalogInterpreter {
                     op.storage_params.unwrap_or(Box::new(StorageParams::None))
                 ),
             ),
-            CatalogOption::Iceberg(op) => (
-                String::from("iceberg"),
-                format!("STORAGE PARAMS\n{}", op.storage_params),
-            ),
+            CatalogOption::Iceberg(op) => (String::from("iceberg"), match op.rest {
+                Some(rest) => format!(
+                    "REST URI\n{}\nWAREHOUSE\n{}\nSTORAGE PARAMS\n{}",
+                    rest.uri,
+                    rest.warehouse.unwrap_or_default(),
+                    op.storage_params
+                ),
+                None => format!("STORAGE PARAMS\n{}", op.storage_params),
+            }),
         };
 
         let block = DataBlock::new(
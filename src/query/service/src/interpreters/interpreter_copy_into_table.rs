@@ -171,7 +171,7 @@ impl CopyIntoTableInterpreter {
             source,
         }));
         next_plan_id += 1;
-        if plan.enable_distributed {
+        if plan.enable_distributed && to_table.support_distributed_insert() {
             root = PhysicalPlan::Exchange(Exchange {
                 plan_id: next_plan_id,
                 input: Box::new(root),
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::ExplainKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use log::error;
 
@@ -48,6 +50,7 @@ use crate::interpreters::interpreter_task_describe::DescribeTaskInterpreter;
 use crate::interpreters::interpreter_task_drop::DropTaskInterpreter;
 use crate::interpreters::interpreter_task_execute::ExecuteTaskInterpreter;
 use crate::interpreters::interpreter_tasks_show::ShowTasksInterpreter;
+use crate::interpreters::AlterStreamInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
@@ -74,9 +77,52 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Only DML and the statements without side effects can be run in an explicit transaction.
+    ///
+    /// A statement rejected here leaves the transaction as it is, while a statement failed
+    /// in execution aborts the transaction.
+    fn check_txn(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let txn_mgr = txn_mgr.lock();
+        if txn_mgr.is_fail() && !matches!(plan, Plan::Commit | Plan::Abort) {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+        if txn_mgr.is_active()
+            && !matches!(
+                plan,
+                Plan::Query { .. }
+                    | Plan::Explain { .. }
+                    | Plan::ExplainAst { .. }
+                    | Plan::ExplainSyntax { .. }
+                    | Plan::CopyIntoTable(_)
+                    | Plan::Insert(_)
+                    | Plan::Replace(_)
+                    | Plan::Delete(_)
+                    | Plan::Update(_)
+                    | Plan::MergeInto(_)
+                    | Plan::UseDatabase(_)
+                    | Plan::ShowCreateTable(_)
+                    | Plan::DescribeTable(_)
+                    | Plan::SetVariable(_)
+                    | Plan::UnSetVariable(_)
+                    | Plan::Begin
+                    | Plan::Commit
+                    | Plan::Abort
+            )
+        {
+            return Err(ErrorCode::UnsupportedInTransaction(
+                "only DML and query statements are supported in an explicit transaction",
+            ));
+        }
+        Ok(())
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -246,6 +292,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_stream.clone(),
             )?)),
+            Plan::AlterStream(alter_stream) => Ok(Arc::new(AlterStreamInterpreter::try_create(
+                ctx,
+                *alter_stream.clone(),
+            )?)),
             Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
                 ctx,
                 *drop_stream.clone(),
@@ -265,6 +315,25 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::RefreshInvertedIndex(index) => Ok(Arc::new(
+                RefreshInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::CreateVectorIndex(index) => Ok(Arc::new(
+                CreateVectorIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropVectorIndex(index) => Ok(Arc::new(DropVectorIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::RefreshVectorIndex(index) => Ok(Arc::new(
+                RefreshVectorIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -443,6 +512,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::AddTablePartition(p) => Ok(Arc::new(AddTablePartitionInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropTablePartition(p) => Ok(Arc::new(DropTablePartitionInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::RecoverTablePartitions(p) => Ok(Arc::new(
+                RecoverTablePartitionsInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -505,6 +585,14 @@ impl InterpreterFactory {
             )?)),
             Plan::ShowTasks(p) => Ok(Arc::new(ShowTasksInterpreter::try_create(ctx, *p.clone())?)),
 
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
+
             Plan::CreateConnection(p) => Ok(Arc::new(CreateConnectionInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -518,6 +606,10 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
+
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
         }
     }
 }
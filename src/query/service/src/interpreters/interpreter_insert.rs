@@ -16,6 +16,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -70,6 +71,28 @@ impl InsertInterpreter {
         let cast_needed = select_schema != DataSchema::from(output_schema.as_ref()).into();
         Ok(cast_needed)
     }
+
+    /// The partition columns of `PARTITION (...)` are missing in the source, they are
+    /// filled like columns with default values, by taking the partition values as the
+    /// default expressions of the columns.
+    async fn fill_partition_values(&self, table: Arc<dyn Table>) -> Result<Arc<dyn Table>> {
+        if self.plan.partition.is_empty() {
+            return Ok(table);
+        }
+
+        let mut table_info = table.get_table_info().clone();
+        let mut schema = table_info.schema().as_ref().clone();
+        for field in schema.fields.iter_mut() {
+            if let Some((_, value)) = self.plan.partition.iter().find(|(c, _)| c == field.name()) {
+                *field = field.clone().with_default_expr(Some(value.clone()));
+            }
+        }
+        table_info.meta.schema = Arc::new(schema);
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        self.ctx
+            .build_table_by_table_info(&catalog.info(), &table_info, None)
+    }
 }
 
 #[async_trait::async_trait]
@@ -90,6 +113,7 @@ impl Interpreter for InsertInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        let table = self.fill_partition_values(table).await?;
 
         let mut build_res = PipelineBuildResult::create();
 
@@ -0,0 +1,222 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_sql::plans::AlterPipePlan;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use log::debug;
+use log::info;
+
+use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPipePlan,
+}
+
+impl AlterPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPipePlan) -> Result<Self> {
+        Ok(AlterPipeInterpreter { ctx, plan })
+    }
+
+    /// Parse the `COPY INTO <table>` statement saved in the pipe.
+    fn parse_copy_stmt(&self, pipe: &PipeInfo) -> Result<CopyIntoTableStmt> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(&pipe.copy_stmt)?;
+        match parse_sql(&tokens, sql_dialect)?.0 {
+            Statement::CopyIntoTable(stmt) => Ok(stmt),
+            _ => Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} is not defined by a COPY INTO <table> statement",
+                pipe.name
+            ))),
+        }
+    }
+
+    /// Load the files which are not loaded by the pipe yet.
+    ///
+    /// The files loaded before are filtered out by the copied files of the target
+    /// table, the same as `COPY INTO` without `FORCE`.
+    async fn refresh(
+        &self,
+        pipe: PipeInfo,
+        prefix: &Option<String>,
+        modified_after: &Option<String>,
+    ) -> Result<PipelineBuildResult> {
+        if pipe.execution_paused {
+            return Err(ErrorCode::BadArguments(format!(
+                "pipe {} is paused, set PIPE_EXECUTION_PAUSED = false to refresh it",
+                pipe.name
+            )));
+        }
+        let modified_after = modified_after
+            .as_ref()
+            .map(|s| parse_modified_after(s))
+            .transpose()?;
+
+        let mut stmt = self.parse_copy_stmt(&pipe)?;
+        match &mut stmt.src {
+            CopyIntoTableSource::Location(location) => {
+                if let Some(prefix) = prefix {
+                    append_prefix(location, prefix);
+                }
+            }
+            CopyIntoTableSource::Query(_) => {
+                if prefix.is_some() || modified_after.is_some() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "PREFIX and MODIFIED_AFTER are not supported by pipe {}, which loads from a query",
+                        pipe.name
+                    )));
+                }
+            }
+        }
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&stmt.to_string()).await?;
+        let Plan::CopyIntoTable(mut copy_plan) = plan else {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} is not defined by a COPY INTO <table> statement",
+                pipe.name
+            )));
+        };
+
+        if let Some(modified_after) = modified_after {
+            let files = copy_plan
+                .collect_files(self.ctx.as_ref())
+                .await?
+                .into_iter()
+                .filter(|file| file.last_modified > modified_after)
+                .collect::<Vec<_>>();
+            info!(
+                "refresh pipe {}: {} files modified after {}",
+                pipe.name,
+                files.len(),
+                modified_after
+            );
+            if files.is_empty() {
+                return Ok(PipelineBuildResult::create());
+            }
+
+            // Load exactly the files left. The listed paths are relative to the root of
+            // the stage, while the files to load are joined with the path of the location,
+            // so the path is cut to the directory containing all the files.
+            let files_info = &mut copy_plan.stage_table_info.files_info;
+            let dir_len = match files_info.path.rfind('/') {
+                Some(i) if files_info.path != "/" => i + 1,
+                _ => 0,
+            };
+            files_info.path.truncate(dir_len);
+            files_info.files = Some(
+                files
+                    .iter()
+                    .map(|file| file.path[dir_len..].to_string())
+                    .collect(),
+            );
+        }
+
+        CopyIntoTableInterpreter::try_create(self.ctx.clone(), *copy_plan)?
+            .execute2()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPipeInterpreter {
+    fn name(&self) -> &str {
+        "AlterPipeInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_pipe_execute");
+
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+        match &plan.options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => {
+                user_mgr
+                    .update_pipe(
+                        &plan.tenant,
+                        &plan.name,
+                        *execution_paused,
+                        comments.clone(),
+                        plan.if_exists,
+                    )
+                    .await?;
+                Ok(PipelineBuildResult::create())
+            }
+            AlterPipeOptions::Refresh {
+                prefix,
+                modified_after,
+            } => {
+                let pipe = match user_mgr.get_pipe(&plan.tenant, &plan.name).await {
+                    Ok(pipe) => pipe,
+                    Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_PIPE => {
+                        return Ok(PipelineBuildResult::create());
+                    }
+                    Err(e) => return Err(e.add_message_back(" (while refresh pipe)")),
+                };
+                self.refresh(pipe, prefix, modified_after).await
+            }
+        }
+    }
+}
+
+/// Load the files under `prefix` of the location only.
+fn append_prefix(location: &mut FileLocation, prefix: &str) {
+    let path = match location {
+        FileLocation::Stage(path) => path,
+        FileLocation::Uri(uri) => &mut uri.path,
+    };
+    *path = format!(
+        "{}/{}",
+        path.trim_end_matches('/'),
+        prefix.trim_start_matches('/')
+    );
+}
+
+/// Parse `MODIFIED_AFTER`, which is either RFC 3339 or `YYYY-MM-DD HH:MM:SS` in UTC.
+fn parse_modified_after(s: &str) -> Result<DateTime<Utc>> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(s) {
+        return Ok(t.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .map(|t| Utc.from_utc_datetime(&t))
+        .map_err(|e| ErrorCode::BadArguments(format!("invalid MODIFIED_AFTER '{}': {}", s, e)))
+}
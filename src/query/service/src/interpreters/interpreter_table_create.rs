@@ -58,6 +58,9 @@ use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_BLOOM_SIZE;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_SIZE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -210,6 +213,7 @@ impl CreateTableInterpreter {
             table: self.plan.table.clone(),
             table_id: table.get_id(),
             schema: self.plan.schema.clone(),
+            partition: vec![],
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
         };
@@ -344,7 +348,8 @@ impl CreateTableInterpreter {
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        is_valid_ngram_index(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
@@ -451,6 +456,9 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_SIZE);
+    r.insert(OPT_KEY_NGRAM_BLOOM_SIZE);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
@@ -520,6 +528,32 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_ngram_index(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_ngram_type)?;
+    }
+    if let Some(value) = options.get(OPT_KEY_NGRAM_SIZE) {
+        let ngram_size = value.parse::<u64>()?;
+        if ngram_size == 0 || ngram_size > 16 {
+            return Err(ErrorCode::TableOptionInvalid(
+                "invalid ngram_size option, must be between 1 and 16",
+            ));
+        }
+    }
+    if let Some(value) = options.get(OPT_KEY_NGRAM_BLOOM_SIZE) {
+        let bloom_size = value.parse::<u64>()?;
+        if !(64..=64 * 1024 * 1024).contains(&bloom_size) {
+            return Err(ErrorCode::TableOptionInvalid(
+                "invalid ngram_bloom_size option, must be between 64 and 67108864",
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_CHANGE_TRACKING) {
         value.to_lowercase().parse::<bool>()?;
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::RecoverTablePartitionsPlan;
+use databend_common_storages_hive::HiveTable;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RecoverTablePartitionsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RecoverTablePartitionsPlan,
+}
+
+impl RecoverTablePartitionsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RecoverTablePartitionsPlan) -> Result<Self> {
+        Ok(RecoverTablePartitionsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RecoverTablePartitionsInterpreter {
+    fn name(&self) -> &str {
+        "RecoverTablePartitionsInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+
+        let hive_table = HiveTable::try_from_table(table.as_ref())?;
+        let added = hive_table.recover_partitions(self.ctx.clone()).await?;
+        info!(
+            "recovered {} partitions of table {}.{}",
+            added, self.plan.database, self.plan.table
+        );
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct AbortInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl AbortInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(AbortInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AbortInterpreter {
+    fn name(&self) -> &str {
+        "AbortInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table_versions = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            let table_versions = txn_mgr.table_versions();
+            txn_mgr.clear();
+            table_versions
+        };
+        purge_uncommitted_files(&self.ctx, table_versions).await;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// Remove the files written by a rolled back transaction, given the versions of the
+/// tables it mutated.
+///
+/// It's done in the best effort, the files left behind are not referenced by any
+/// table meta and will be removed by VACUUM.
+#[async_backtrace::framed]
+pub async fn purge_uncommitted_files(ctx: &Arc<QueryContext>, table_versions: Vec<Vec<TableInfo>>) {
+    for versions in table_versions {
+        let Some((committed, uncommitted)) = versions.split_first() else {
+            continue;
+        };
+        if let Err(e) = purge_uncommitted_snapshots(ctx, committed, uncommitted).await {
+            warn!(
+                "failed to remove the uncommitted files of table {}: {}",
+                committed.desc, e
+            );
+        }
+    }
+}
+
+async fn purge_uncommitted_snapshots(
+    ctx: &Arc<QueryContext>,
+    committed: &TableInfo,
+    uncommitted: &[TableInfo],
+) -> Result<()> {
+    let table = ctx
+        .get_catalog(committed.catalog())
+        .await?
+        .get_table_by_info(committed)?;
+    let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+        return Ok(());
+    };
+
+    let committed_location = committed.options().get(OPT_KEY_SNAPSHOT_LOCATION);
+    let mut snapshot_locations = uncommitted
+        .iter()
+        .filter_map(|info| info.options().get(OPT_KEY_SNAPSHOT_LOCATION))
+        .filter(|location| Some(*location) != committed_location)
+        .cloned()
+        .collect::<Vec<_>>();
+    snapshot_locations.dedup();
+
+    fuse_table
+        .purge_uncommitted_snapshots(ctx.clone(), snapshot_locations)
+        .await
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use log::info;
+
+use crate::interpreters::interpreter_txn_abort::purge_uncommitted_files;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let (is_fail, req, table_versions) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            let is_fail = txn_mgr.is_fail();
+            let req = (txn_mgr.is_active() && !txn_mgr.is_empty()).then(|| txn_mgr.req());
+            let table_versions = txn_mgr.table_versions();
+            // The transaction ends whether the commit succeeds or not.
+            txn_mgr.clear();
+            (is_fail, req, table_versions)
+        };
+
+        if is_fail {
+            purge_uncommitted_files(&self.ctx, table_versions).await;
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, rolled back",
+            ));
+        }
+
+        if let Some(req) = req {
+            let table_ids = req
+                .update_table_metas
+                .iter()
+                .map(|r| r.table_id)
+                .collect::<Vec<_>>();
+            info!("committing transaction of tables {:?}", table_ids);
+            let catalog = self.ctx.get_default_catalog()?;
+            if let Err(e) = catalog.update_multi_table_meta(req).await {
+                // None of the table versions are committed, remove the files they wrote.
+                purge_uncommitted_files(&self.ctx, table_versions).await;
+                return Err(e);
+            }
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateVectorIndexPlan;
+use databend_common_storages_share::save_share_table_info;
+use uuid::Uuid;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateVectorIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateVectorIndexPlan,
+}
+
+impl CreateVectorIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateVectorIndexPlan) -> Result<Self> {
+        Ok(CreateVectorIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateVectorIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateVectorIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        if let Some(index) = new_table_meta.indexes.get(&self.plan.index_name) {
+            if index.index_type != TableIndexType::Vector {
+                return Err(ErrorCode::IndexAlreadyExists(format!(
+                    "{} index '{}' already exists on table '{}'.'{}'",
+                    index.index_type, self.plan.index_name, self.plan.database, self.plan.table
+                )));
+            }
+            match self.plan.create_option {
+                CreateOption::CreateIfNotExists(true) => {
+                    return Ok(PipelineBuildResult::create());
+                }
+                CreateOption::CreateIfNotExists(false) => {
+                    return Err(ErrorCode::IndexAlreadyExists(format!(
+                        "Vector index '{}' already exists on table '{}'.'{}'",
+                        self.plan.index_name, self.plan.database, self.plan.table
+                    )));
+                }
+                CreateOption::CreateOrReplace => {}
+            }
+        }
+
+        // A new version makes the index files of the replaced index unreachable.
+        let index = TableIndex {
+            index_type: TableIndexType::Vector,
+            name: self.plan.index_name.clone(),
+            column_ids: vec![self.plan.column_id],
+            sync_creation: self.plan.sync_creation,
+            version: Uuid::new_v4().simple().to_string(),
+            options: self.plan.index_options.clone(),
+        };
+        new_table_meta
+            .indexes
+            .insert(self.plan.index_name.clone(), index);
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        let res = catalog.update_table_meta(table_info, req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
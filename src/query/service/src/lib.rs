@@ -52,6 +52,7 @@ pub mod local;
 pub mod locks;
 pub mod metrics;
 pub mod pipelines;
+pub mod pipes;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
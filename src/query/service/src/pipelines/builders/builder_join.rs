@@ -21,6 +21,7 @@ use databend_common_pipeline_sinks::Sinker;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::MaterializedCte;
 use databend_common_sql::executor::physical_plans::RangeJoin;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::ColumnBinding;
 use databend_common_sql::IndexType;
@@ -35,6 +36,7 @@ use crate::pipelines::processors::transforms::HashJoinProbeState;
 use crate::pipelines::processors::transforms::MaterializedCteSink;
 use crate::pipelines::processors::transforms::MaterializedCteState;
 use crate::pipelines::processors::transforms::ProbeSpillState;
+use crate::pipelines::processors::transforms::RecursiveCteSource;
 use crate::pipelines::processors::transforms::TransformHashJoinBuild;
 use crate::pipelines::processors::transforms::TransformHashJoinProbe;
 use crate::pipelines::processors::HashJoinDesc;
@@ -257,6 +259,24 @@ impl PipelineBuilder {
         self.build_pipeline(&materialized_cte.right)
     }
 
+    pub(crate) fn build_recursive_cte(&mut self, recursive_cte: &RecursiveCte) -> Result<()> {
+        let scopes = self.main_pipeline.get_scopes();
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteSource::create(
+                    self.ctx.clone(),
+                    output,
+                    self.func_ctx.clone(),
+                    self.settings.clone(),
+                    scopes.clone(),
+                    self.cte_state.clone(),
+                    recursive_cte.clone(),
+                )
+            },
+            1,
+        )
+    }
+
     fn expand_left_side_pipeline(
         &mut self,
         left_side: &PhysicalPlan,
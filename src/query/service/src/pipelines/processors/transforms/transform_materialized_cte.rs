@@ -47,6 +47,17 @@ impl MaterializedCteState {
         }
     }
 
+    /// Create a state whose blocks have already been materialized, such as the working table
+    /// of a recursive cte.
+    pub fn finished(ctx: Arc<QueryContext>) -> Self {
+        MaterializedCteState {
+            ctx,
+            left_sinker_count: Arc::new(RwLock::new(0)),
+            sink_finished_notifier: Arc::new(Default::default()),
+            sink_finished: Arc::new(Mutex::new(true)),
+        }
+    }
+
     pub fn attach_sinker(&self) -> Result<()> {
         let mut left_sinker_count = self.left_sinker_count.write();
         *left_sinker_count += 1;
@@ -0,0 +1,225 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::PlanScope;
+use databend_common_pipeline_sinks::Sink;
+use databend_common_pipeline_sinks::Sinker;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_settings::Settings;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::ColumnBinding;
+use databend_common_sql::IndexType;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+
+/// Collect the output blocks of a sub pipeline.
+struct RecursiveCteCollectSink {
+    blocks: Arc<Mutex<Vec<DataBlock>>>,
+}
+
+impl Sink for RecursiveCteCollectSink {
+    const NAME: &'static str = "RecursiveCteCollectSink";
+
+    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        if data_block.num_rows() != 0 {
+            self.blocks.lock().push(data_block);
+        }
+        Ok(())
+    }
+}
+
+/// Evaluate a recursive cte to its fixpoint and output all the rows.
+///
+/// The anchor term is executed once, then the recursive term is rebuilt and executed
+/// with the rows produced by the previous iteration as the working table `(cte_idx, 0)`,
+/// until an iteration produces no new rows.
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    settings: Arc<Settings>,
+    scopes: Vec<PlanScope>,
+    // Materialized ctes which can be referenced by the anchor or recursive term.
+    cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    plan: RecursiveCte,
+
+    finished: bool,
+    output_blocks: VecDeque<DataBlock>,
+}
+
+impl RecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        settings: Arc<Settings>,
+        scopes: Vec<PlanScope>,
+        cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+        plan: RecursiveCte,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx.clone(), output_port, RecursiveCteSource {
+            ctx,
+            func_ctx,
+            settings,
+            scopes,
+            cte_state,
+            plan,
+            finished: false,
+            output_blocks: VecDeque::new(),
+        })
+    }
+
+    fn execute_plan(
+        &self,
+        plan: &PhysicalPlan,
+        output_columns: &[ColumnBinding],
+    ) -> Result<Vec<DataBlock>> {
+        let mut builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            QueryContext::create_from(self.ctx.clone()),
+            self.scopes.clone(),
+        );
+        builder.cte_state = self.cte_state.clone();
+        builder.cte_state.insert(
+            self.plan.cte_idx,
+            Arc::new(MaterializedCteState::finished(self.ctx.clone())),
+        );
+        let mut build_res = builder.finalize(plan)?;
+
+        PipelineBuilder::build_result_projection(
+            &self.func_ctx,
+            plan.output_schema()?,
+            output_columns,
+            &mut build_res.main_pipeline,
+            false,
+        )?;
+
+        let blocks = Arc::new(Mutex::new(vec![]));
+        build_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(Sinker::create(
+                input,
+                RecursiveCteCollectSink {
+                    blocks: blocks.clone(),
+                },
+            )))
+        })?;
+
+        let executor_settings = ExecutorSettings::try_create(&self.settings, self.ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        executor.execute()?;
+        drop(executor);
+
+        let blocks = std::mem::take(&mut *blocks.lock());
+        Ok(blocks)
+    }
+
+    // Remove the rows which have been seen before, only used by `UNION`.
+    fn distinct_blocks(
+        blocks: Vec<DataBlock>,
+        seen: &mut HashSet<Vec<Scalar>>,
+    ) -> Result<Vec<DataBlock>> {
+        let mut result = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let mut bitmap = MutableBitmap::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let values = block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.value.index(row).unwrap().to_owned())
+                    .collect::<Vec<_>>();
+                bitmap.push(seen.insert(values));
+            }
+            let block = block.filter_with_bitmap(&bitmap.into())?;
+            if block.num_rows() != 0 {
+                result.push(block);
+            }
+        }
+        Ok(result)
+    }
+
+    fn compute_fixpoint(&mut self) -> Result<()> {
+        let max_depth = self.settings.get_max_cte_recursion_depth()?;
+        let mut seen = HashSet::new();
+
+        let mut working_blocks = self.execute_plan(&self.plan.anchor, &self.plan.output_columns)?;
+        if self.plan.distinct {
+            working_blocks = Self::distinct_blocks(working_blocks, &mut seen)?;
+        }
+
+        let mut depth = 0;
+        while !working_blocks.is_empty() {
+            self.output_blocks.extend(working_blocks.iter().cloned());
+
+            depth += 1;
+            if depth > max_depth {
+                return Err(ErrorCode::Overflow(format!(
+                    "Recursive cte exceeds the maximum recursion depth {}, \
+                    please check the termination condition or increase the setting `max_cte_recursion_depth`",
+                    max_depth
+                )));
+            }
+
+            self.ctx.set_materialized_cte(
+                (self.plan.cte_idx, 0),
+                Arc::new(RwLock::new(working_blocks)),
+            )?;
+            working_blocks =
+                self.execute_plan(&self.plan.recursive, &self.plan.recursive_output_columns)?;
+            if self.plan.distinct {
+                working_blocks = Self::distinct_blocks(working_blocks, &mut seen)?;
+            }
+        }
+
+        self.ctx
+            .set_materialized_cte((self.plan.cte_idx, 0), Arc::new(RwLock::new(vec![])))?;
+        Ok(())
+    }
+}
+
+impl SyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if !self.finished {
+            self.finished = true;
+            self.compute_fixpoint()?;
+        }
+        Ok(self.output_blocks.pop_front())
+    }
+}
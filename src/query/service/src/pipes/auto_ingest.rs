@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures_util::StreamExt;
+use log::info;
+use log::warn;
+
+use crate::clusters::ClusterDiscovery;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// How often the pipes with `AUTO_INGEST = TRUE` look for new files.
+pub const AUTO_INGEST_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Runs the pipes with `AUTO_INGEST = TRUE` in the background.
+///
+/// Every [`AUTO_INGEST_INTERVAL`] each pipe which is not paused is refreshed, the same as
+/// `ALTER PIPE ... REFRESH`, so the files already loaded are skipped by the copied files
+/// of the target table and only the new files are loaded.
+pub struct PipeAutoIngest;
+
+impl PipeAutoIngest {
+    pub fn start(conf: &InnerConfig) {
+        let conf = conf.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            loop {
+                sleep(AUTO_INGEST_INTERVAL).await;
+                if let Err(e) = Self::ingest(&conf).await {
+                    warn!("auto ingest of pipes failed: {:?}", e);
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn ingest(conf: &InnerConfig) -> Result<()> {
+        // Only the node with the smallest id runs the pipes, other nodes would load the
+        // same files concurrently and fail to commit the copied files.
+        let cluster = ClusterDiscovery::instance().discover(conf).await?;
+        if cluster.nodes.iter().any(|node| node.id < cluster.local_id) {
+            return Ok(());
+        }
+
+        let pipes = UserApiProvider::instance()
+            .get_pipes(&conf.query.tenant_id)
+            .await?;
+        for pipe in pipes {
+            if !pipe.auto_ingest || pipe.execution_paused {
+                continue;
+            }
+            if let Err(e) = Self::refresh(conf, &pipe).await {
+                warn!("auto ingest of pipe {} failed: {:?}", pipe.name, e);
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh(conf: &InnerConfig, pipe: &PipeInfo) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let user = UserInfo::new_no_auth(
+            format!(
+                "{}-{}-pipe-ingest",
+                conf.query.tenant_id, conf.query.cluster_id
+            )
+            .as_str(),
+            "0.0.0.0",
+        );
+        session
+            .set_authed_user(user, Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()))
+            .await?;
+        let ctx = session.create_query_context().await?;
+
+        // The name is saved as it is written in `CREATE PIPE`, quotes included.
+        let sql = format!("ALTER PIPE {} REFRESH", pipe.name);
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(&sql).await?;
+        ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx.clone()).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+
+        info!(
+            "auto ingest of pipe {}: {} rows loaded",
+            pipe.name,
+            ctx.get_write_progress_value().rows
+        );
+        Ok(())
+    }
+}
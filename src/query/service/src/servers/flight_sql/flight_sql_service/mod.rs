@@ -27,7 +27,6 @@ use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
 use dashmap::DashMap;
 use databend_common_sql::plans::Plan;
-use databend_common_sql::PlanExtras;
 use futures::Stream;
 use parking_lot::Mutex;
 use sql_info::SqlInfoProvider;
@@ -51,7 +50,12 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    /// The prepared plans and their query text.
+    statements: Arc<DashMap<Uuid, (Plan, String)>>,
+    /// The plans of the substrait queries to be fetched by `do_get`, they are removed once fetched.
+    substrait_queries: Arc<DashMap<Uuid, (Plan, String)>>,
+    /// The id of the query running for each fetched handle, used to cancel it.
+    running_queries: Arc<DashMap<Uuid, String>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -60,6 +64,8 @@ impl FlightSqlServiceImpl {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
             statements: Arc::new(Default::default()),
+            substrait_queries: Arc::new(Default::default()),
+            running_queries: Arc::new(Default::default()),
         }
     }
 }
@@ -16,6 +16,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use arrow_flight::sql::SubstraitPlan;
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
@@ -29,12 +30,14 @@ use databend_common_expression::DataSchema;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
+use databend_common_sql::SubstraitPlanner;
 use databend_common_storages_fuse::TableContext;
 use futures::Stream;
 use futures::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
 use super::DoGetStream;
@@ -46,6 +49,9 @@ use crate::sessions::Session;
 /// A app_metakey which indicates the data is a progress type
 static H_PROGRESS: u8 = 0x01;
 
+/// Substrait plans have no query text, this is shown in the query log instead.
+pub(super) const SUBSTRAIT_QUERY: &str = "<substrait plan>";
+
 impl FlightSqlServiceImpl {
     pub(crate) fn schema_to_flight_data(data_schema: DataSchema) -> FlightData {
         let arrow_schema = ArrowSchema::from(&data_schema);
@@ -83,19 +89,36 @@ impl FlightSqlServiceImpl {
         planner.plan_sql(query).await
     }
 
+    #[async_backtrace::framed]
+    pub async fn plan_substrait(
+        &self,
+        session: &Arc<Session>,
+        plan: Option<SubstraitPlan>,
+    ) -> Result<Plan> {
+        let plan = plan.ok_or_else(|| ErrorCode::BadArguments("Substrait plan is required"))?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        SubstraitPlanner::try_create(context)?
+            .plan(&plan.plan)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn execute_update(
         &self,
         session: Arc<Session>,
         plan: &Plan,
-        plan_extras: &PlanExtras,
+        query: &str,
     ) -> Result<i64> {
         let context = session
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
-        context.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+        context.attach_query_str(plan.kind(), query.to_string());
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let mut blocks = interpreter.execute(context.clone()).await?;
@@ -110,8 +133,9 @@ impl FlightSqlServiceImpl {
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
+        handle: Uuid,
         plan: &Plan,
-        plan_extras: &PlanExtras,
+        query: &str,
     ) -> Result<DoGetStream> {
         let is_native_client = session.get_status().read().is_native_client;
 
@@ -120,12 +144,15 @@ impl FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
-        context.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+        context.attach_query_str(plan.kind(), query.to_string());
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let data_schema = plan.schema();
         let data_stream = interpreter.execute(context.clone()).await?;
 
+        let query_id = context.get_id();
+        self.running_queries.insert(handle, query_id.clone());
+
         let is_finished = Arc::new(AtomicBool::new(false));
         let is_finished_clone = is_finished.clone();
         let (sender, receiver) = tokio::sync::mpsc::channel(2);
@@ -134,6 +161,7 @@ impl FlightSqlServiceImpl {
             .await;
 
         let s1 = sender.clone();
+        let running_queries = self.running_queries.clone();
         databend_common_base::runtime::spawn(async move {
             let mut data_stream = data_stream;
 
@@ -156,6 +184,7 @@ impl FlightSqlServiceImpl {
                     }
                 }
             }
+            running_queries.remove_if(&handle, |_, id| *id == query_id);
             is_finished_clone.store(true, Ordering::SeqCst);
         });
 
@@ -33,6 +33,7 @@ use arrow_flight::sql::ActionCreatePreparedSubstraitPlanRequest;
 use arrow_flight::sql::ActionEndSavepointRequest;
 use arrow_flight::sql::ActionEndTransactionRequest;
 use arrow_flight::sql::Any;
+use arrow_flight::sql::CancelResult;
 use arrow_flight::sql::CommandGetCatalogs;
 use arrow_flight::sql::CommandGetCrossReference;
 use arrow_flight::sql::CommandGetDbSchemas;
@@ -49,6 +50,7 @@ use arrow_flight::sql::CommandStatementQuery;
 use arrow_flight::sql::CommandStatementSubstraitPlan;
 use arrow_flight::sql::CommandStatementUpdate;
 use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::EndTransaction;
 use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::sql::TicketStatementQuery;
@@ -64,9 +66,13 @@ use arrow_flight::PutResult;
 use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::Schema as ArrowSchema;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
+use databend_common_sql::plans::Plan;
+use databend_storages_common_txn::TxnState;
 use futures::Stream;
 use log::info;
 use prost::Message;
@@ -77,6 +83,7 @@ use tonic::Response;
 use tonic::Status;
 use tonic::Streaming;
 
+use super::query::SUBSTRAIT_QUERY;
 use super::status;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 
@@ -122,6 +129,67 @@ fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     Response::new(info)
 }
 
+/// The flight info to fetch the results of a prepared plan with [`FetchResults`].
+fn fetch_flight_info(handle: &Uuid, schema: &ArrowSchema) -> Result<Response<FlightInfo>, Status> {
+    let loc = Location {
+        uri: "grpc+tcp://127.0.0.1".to_string(),
+    };
+    let fetch = FetchResults {
+        handle: handle.to_string(),
+    };
+    let buf = fetch.as_any().encode_to_vec().into();
+    let ticket = Ticket { ticket: buf };
+    let endpoint = FlightEndpoint {
+        ticket: Some(ticket),
+        location: vec![loc],
+    };
+    let endpoints = vec![endpoint];
+
+    let message = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let IpcMessage(schema_bytes) = message;
+
+    let flight_desc = FlightDescriptor {
+        r#type: DescriptorType::Cmd.into(),
+        cmd: Default::default(),
+        path: vec![],
+    };
+    let info = FlightInfo {
+        schema: schema_bytes,
+        flight_descriptor: Some(flight_desc),
+        endpoint: endpoints,
+        total_records: -1,
+        total_bytes: -1,
+        ordered: false,
+    };
+    let resp = Response::new(info);
+    Ok(resp)
+}
+
+fn prepared_statement_result(
+    handle: &Uuid,
+    plan: &Plan,
+) -> Result<ActionCreatePreparedStatementResult, Status> {
+    // JDBC client use call put when schema.fields == 0
+    let data_schema = if plan.has_result_set() {
+        plan.schema()
+    } else {
+        Arc::new(DataSchema::empty())
+    };
+    info!("prepared statement with handler={handle}, return schema={data_schema:?}");
+    let schema = (&*data_schema).into();
+    let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let IpcMessage(schema_bytes) = message;
+    Ok(ActionCreatePreparedStatementResult {
+        prepared_statement_handle: handle.as_bytes().to_vec().into(),
+        dataset_schema: schema_bytes,
+        parameter_schema: Default::default(), // TODO: parameters
+    })
+}
+
 impl NamedService for FlightSqlServiceImpl {
     const NAME: &'static str = "FlightSqlService";
 }
@@ -192,9 +260,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, query) = match self.substrait_queries.remove(&handle) {
+            Some((_, handle_plan)) => handle_plan,
+            None => self
+                .statements
+                .get(&handle)
+                .map(|handle_plan| handle_plan.value().clone())
+                .ok_or_else(|| Status::not_found(format!("unknown handle {handle}")))?,
+        };
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(session, handle, &plan, &query)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -226,40 +301,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         let handle_plan_ref = self.statements.get(&handle).unwrap();
         let schema = handle_plan_ref.value().0.schema().as_ref().into();
-        let loc = Location {
-            uri: "grpc+tcp://127.0.0.1".to_string(),
-        };
-        let fetch = FetchResults {
-            handle: handle.to_string(),
-        };
-        let buf = fetch.as_any().encode_to_vec().into();
-        let ticket = Ticket { ticket: buf };
-        let endpoint = FlightEndpoint {
-            ticket: Some(ticket),
-            location: vec![loc],
-        };
-        let endpoints = vec![endpoint];
-
-        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
-            .try_into()
-            .map_err(|e| status!("Unable to serialize schema", e))?;
-        let IpcMessage(schema_bytes) = message;
-
-        let flight_desc = FlightDescriptor {
-            r#type: DescriptorType::Cmd.into(),
-            cmd: Default::default(),
-            path: vec![],
-        };
-        let info = FlightInfo {
-            schema: schema_bytes,
-            flight_descriptor: Some(flight_desc),
-            endpoint: endpoints,
-            total_records: -1,
-            total_bytes: -1,
-            ordered: false,
-        };
-        let resp = Response::new(info);
-        Ok(resp)
+        fetch_flight_info(&handle, &schema)
     }
 
     #[async_backtrace::framed]
@@ -321,48 +363,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        info!("get_flight_info_primary_keys({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     // do_get
@@ -450,21 +488,25 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys()?
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -472,11 +514,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -484,11 +527,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -508,7 +552,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
         let res = self
-            .execute_update(session, &plan, &plan_extras)
+            .execute_update(session, &plan, &plan_extras.statement.to_mask_sql())
             .await
             .map_err(|e| status!("fail to execute", e))?;
         Ok(res)
@@ -571,7 +615,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let session = self.get_session(&request)?;
         let sql = query.query.clone();
         let handle = Uuid::new_v4();
-        let plan = self
+        let (plan, plan_extras) = self
             .plan_sql(&session, &sql)
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
@@ -579,27 +623,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
             "do_action_create_prepared_statement with handler={handle} query={:?}",
             query.query
         );
-        // JDBC client use call put when schema.fields == 0
-        let data_schema = if plan.0.has_result_set() {
-            plan.0.schema()
-        } else {
-            Arc::new(DataSchema::empty())
-        };
-        info!(
-            "do_action_create_prepared_statement with handler={handle}, query={:?}, return schema={data_schema:?}",
-            query.query
-        );
-        let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
-        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
-            .try_into()
-            .map_err(|e| status!("Unable to serialize schema", e))?;
-        let IpcMessage(schema_bytes) = message;
-        let res = ActionCreatePreparedStatementResult {
-            prepared_statement_handle: handle.as_bytes().to_vec().into(),
-            dataset_schema: schema_bytes,
-            parameter_schema: Default::default(), // TODO: parameters
-        };
+        let res = prepared_statement_result(&handle, &plan)?;
+        self.statements
+            .insert(handle, (plan, plan_extras.statement.to_mask_sql()));
         Ok(res)
     }
 
@@ -654,44 +680,110 @@ impl FlightSqlService for FlightSqlServiceImpl {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
     async fn get_flight_info_substrait_plan(
         &self,
-        _query: CommandStatementSubstraitPlan,
-        _request: Request<FlightDescriptor>,
-    ) -> std::result::Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        query: CommandStatementSubstraitPlan,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let session = self.get_session(&request)?;
+        let plan = self
+            .plan_substrait(&session, query.plan)
+            .await
+            .map_err(|e| status!("Error planning substrait plan", e))?;
+        let handle = Uuid::new_v4();
+        info!("get_flight_info_substrait_plan with handle={handle}");
+
+        let schema = plan.schema().as_ref().into();
+        self.substrait_queries
+            .insert(handle, (plan, SUBSTRAIT_QUERY.to_string()));
+        fetch_flight_info(&handle, &schema)
     }
 
+    #[async_backtrace::framed]
     async fn do_put_substrait_plan(
         &self,
-        _query: CommandStatementSubstraitPlan,
-        _request: Request<PeekableFlightDataStream>,
-    ) -> std::result::Result<i64, Status> {
-        unimplemented!()
+        query: CommandStatementSubstraitPlan,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        let session = self.get_session(&request)?;
+        let plan = self
+            .plan_substrait(&session, query.plan)
+            .await
+            .map_err(|e| status!("Error planning substrait plan", e))?;
+        self.execute_update(session, &plan, SUBSTRAIT_QUERY)
+            .await
+            .map_err(|e| status!("fail to execute", e))
     }
 
+    #[async_backtrace::framed]
     async fn do_action_create_prepared_substrait_plan(
         &self,
-        _query: ActionCreatePreparedSubstraitPlanRequest,
-        _request: Request<Action>,
-    ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
-        unimplemented!()
+        query: ActionCreatePreparedSubstraitPlanRequest,
+        request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let session = self.get_session(&request)?;
+        let plan = self
+            .plan_substrait(&session, query.plan)
+            .await
+            .map_err(|e| status!("Error planning substrait plan", e))?;
+        let handle = Uuid::new_v4();
+        info!("do_action_create_prepared_substrait_plan with handler={handle}");
+
+        let res = prepared_statement_result(&handle, &plan)?;
+        self.statements
+            .insert(handle, (plan, SUBSTRAIT_QUERY.to_string()));
+        Ok(res)
     }
 
+    // A session has at most one explicit transaction, so the session id is used as
+    // the transaction id.
+    #[async_backtrace::framed]
     async fn do_action_begin_transaction(
         &self,
         _query: ActionBeginTransactionRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        info!("do_action_begin_transaction");
+        if session.txn_mgr().lock().state() != TxnState::AutoCommit {
+            return Err(Status::failed_precondition(
+                "there is already a transaction in progress",
+            ));
+        }
+        let transaction_id = session.get_id().into_bytes();
+        self.execute_update(session, &Plan::Begin, "BEGIN")
+            .await
+            .map_err(|e| status!("fail to begin transaction", e))?;
+        Ok(ActionBeginTransactionResult {
+            transaction_id: transaction_id.into(),
+        })
     }
 
+    #[async_backtrace::framed]
     async fn do_action_end_transaction(
         &self,
-        _query: ActionEndTransactionRequest,
-        _request: Request<Action>,
+        query: ActionEndTransactionRequest,
+        request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        info!("do_action_end_transaction({:?})", query.action());
+        if query.transaction_id.as_ref() != session.get_id().as_bytes() {
+            return Err(Status::not_found("transaction not found"));
+        }
+        let (plan, query) = match query.action() {
+            EndTransaction::Commit => (Plan::Commit, "COMMIT"),
+            EndTransaction::Rollback => (Plan::Abort, "ROLLBACK"),
+            EndTransaction::Unspecified => {
+                return Err(Status::invalid_argument(
+                    "the action of ending a transaction is unspecified",
+                ));
+            }
+        };
+        self.execute_update(session, &plan, query)
+            .await
+            .map_err(|e| status!("fail to end transaction", e))?;
+        Ok(())
     }
 
     async fn do_action_begin_savepoint(
@@ -710,12 +802,47 @@ impl FlightSqlService for FlightSqlServiceImpl {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
     async fn do_action_cancel_query(
         &self,
-        _query: ActionCancelQueryRequest,
-        _request: Request<Action>,
+        query: ActionCancelQueryRequest,
+        request: Request<Action>,
     ) -> std::result::Result<ActionCancelQueryResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let flight_info = FlightInfo::decode(query.info)
+            .map_err(|e| Status::invalid_argument(format!("Error decoding flight info: {e}")))?;
+        info!("do_action_cancel_query({flight_info:?})");
+
+        // Same as `KILL QUERY`, but only the queries fetching the tickets of the flight info
+        // are aborted, the session may have moved on to another query.
+        let mut result = CancelResult::NotCancellable;
+        for endpoint in &flight_info.endpoint {
+            let Some(ticket) = &endpoint.ticket else {
+                continue;
+            };
+            let Ok(message) = Any::decode(ticket.ticket.clone()) else {
+                continue;
+            };
+            let Ok(Some(fetch_results)) = message.unpack::<FetchResults>() else {
+                continue;
+            };
+            let Ok(handle) = Uuid::try_parse(&fetch_results.handle) else {
+                continue;
+            };
+            let Some((_, query_id)) = self.running_queries.remove(&handle) else {
+                continue;
+            };
+            if session.get_current_query_id().as_ref() == Some(&query_id) {
+                session.force_kill_query(ErrorCode::AbortedQuery(
+                    "Aborted query, because the query was cancelled by the client",
+                ));
+                result = CancelResult::Cancelled;
+            }
+        }
+
+        Ok(ActionCancelQueryResult {
+            result: result.into(),
+        })
     }
 }
 
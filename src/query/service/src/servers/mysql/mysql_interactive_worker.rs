@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_ast::ast::Statement;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
@@ -48,6 +50,9 @@ use rand::RngCore;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_prepared_statement::BoundStatement;
+use crate::servers::mysql::mysql_prepared_statement::PreparedStatement;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -59,8 +64,13 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+// The default of `max_prepared_stmt_count` in MySQL.
+const MAX_PREPARED_STATEMENTS: usize = 16382;
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -282,36 +292,117 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        match self.prepare_statement(query).await {
+            Ok(id) => {
+                let statement = &self.prepared_statements[&id];
+                writer
+                    .reply(id, &statement.params, &statement.columns)
+                    .await?;
+            }
+            Err(error) => {
+                error!("OnPrepare Error: {:?}", error);
+                writer
+                    .error(
+                        ErrorKind::ER_UNKNOWN_ERROR,
+                        error.display_with_sql(query).to_string().as_bytes(),
+                    )
+                    .await?;
+            }
+        }
         Ok(())
     }
 
     #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
+    async fn prepare_statement(&mut self, query: &str) -> Result<u32> {
+        if self.prepared_statements.len() >= MAX_PREPARED_STATEMENTS {
+            return Err(ErrorCode::BadArguments(format!(
+                "Can't create more than {} prepared statements",
+                MAX_PREPARED_STATEMENTS
+            )));
+        }
+
+        let mut statement = PreparedStatement::try_create(query)?;
+        // Describe the result set by planning the statement with NULL parameters. A
+        // statement may not be planned without the values of the parameters, e.g.
+        // `LIMIT ?`, then the columns are only reported with the result set.
+        if self.federated_server_command_check(query).is_none() {
+            let context = self.session.create_query_context().await?;
+            statement.parse(context.get_settings().get_sql_dialect()?);
+            statement.describe_params(&context).await?;
+            let bound = statement.bind_nulls();
+            let mut planner = Planner::new(context);
+            let plan = match bound.statement {
+                Some(stmt) => planner.plan_stmt(stmt).await,
+                None => planner.plan_sql(&bound.sql).await,
+            };
+            match plan {
+                Ok((plan, _)) if plan.has_result_set() => {
+                    statement.columns = convert_schema(&plan.schema())?;
+                }
+                Ok(_) => {}
+                Err(e) if e.code() == ErrorCode::SYNTAX_EXCEPTION => return Err(e),
+                Err(e) => info!("Can't describe the prepared statement: {}", e),
+            }
+        }
+
+        // The id wraps around after a long session, skip the ids still in use.
+        let mut id = self.next_statement_id;
+        while self.prepared_statements.contains_key(&id) {
+            id = id.wrapping_add(1).max(1);
+        }
+        self.next_statement_id = id.wrapping_add(1).max(1);
+        self.prepared_statements.insert(id, statement);
+        Ok(id)
+    }
+
+    #[async_backtrace::framed]
+    async fn do_execute<W: AsyncWrite + Send + Unpin>(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let instant = Instant::now();
+        let bound = match self.prepared_statements.get(&id) {
+            Some(statement) => statement.bind(params),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement handler ({id}) given to mysqld_stmt_execute"
+            ))),
+        };
+        let query_result = match bound {
+            Ok(BoundStatement {
+                sql,
+                statement: Some(stmt),
+            }) => self
+                .run_query(&sql, Some(stmt))
+                .await
+                .map_err(|err| err.display_with_sql(&sql)),
+            Ok(BoundStatement {
+                sql,
+                statement: None,
+            }) => self
+                .do_query(&sql)
+                .await
+                .map_err(|err| err.display_with_sql(&sql)),
+            Err(e) => Err(e),
+        };
+
+        let format = self.session.get_format_settings();
+        let write_result = writer.write(query_result, &format).await;
+        observe_mysql_process_request_duration(instant.elapsed());
+
+        write_result
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -350,40 +441,50 @@ impl InteractiveWorkerBase {
                     None,
                 ))
             }
-            None => {
-                info!("Normal query: {}", query);
-                let context = self.session.create_query_context().await?;
-
-                let mut planner = Planner::new(context.clone());
-                let (plan, extras) = planner.plan_sql(query).await?;
-
-                context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = plan.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+            None => self.run_query(query, None).await,
+        }
+    }
+
+    /// Plan and execute a query, the statement is planned directly if it's already parsed.
+    #[async_backtrace::framed]
+    async fn run_query(
+        &mut self,
+        query: &str,
+        statement: Option<Statement>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = match statement {
+            Some(stmt) => planner.plan_stmt(stmt).await?,
+            None => planner.plan_sql(query).await?,
+        };
+
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = plan.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -457,7 +558,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 1,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
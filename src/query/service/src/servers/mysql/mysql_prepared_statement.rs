@@ -0,0 +1,632 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::ReplaceStmt;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::ast::UpdateStmt;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::walk_statement_mut;
+use databend_common_ast::Dialect;
+use databend_common_ast::VisitorMut;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::resolve_type_name;
+use databend_common_sql::NameResolutionContext;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
+use opensrv_mysql::ParamValue;
+use opensrv_mysql::ValueInner;
+
+use crate::servers::mysql::writers::make_column_from_field;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The placeholders are parsed as the columns named by this prefix and the index of the parameter.
+const PARAM_PREFIX: &str = "__mysql_param_";
+
+/// A statement prepared by COM_STMT_PREPARE.
+///
+/// The statement is parsed once, and planned again on each COM_STMT_EXECUTE with the
+/// parameters bound as typed constants, since a plan holds the snapshots of the tables it reads.
+pub struct PreparedStatement {
+    /// The SQL text around the placeholders, there is one more fragment than placeholders.
+    fragments: Vec<String>,
+    /// The SQL the statement is parsed from, with the placeholders replaced by the markers.
+    marked_sql: String,
+    /// The spans of the markers in `marked_sql`.
+    markers: Vec<Range<usize>>,
+    /// The parsed statement, `None` if the markers can't all be parsed as expressions,
+    /// e.g. `SHOW TABLES LIKE ?`, then the parameters are bound into the SQL text instead.
+    statement: Option<Statement>,
+    pub params: Vec<Column>,
+    pub columns: Vec<Column>,
+}
+
+/// Where the type of a parameter is taken from.
+enum ParamTypeHint {
+    /// The parameter is cast to the type, e.g. `CAST(? AS INT)`.
+    Type(TypeName),
+    /// The parameter is compared with or assigned to the column of the table.
+    Column(Identifier),
+    /// The parameter is inserted into the column at the position of the inserted columns.
+    Position(usize),
+}
+
+/// The table that the column hints of the parameters refer to.
+struct ParamTable {
+    catalog: Option<Identifier>,
+    database: Option<Identifier>,
+    table: Identifier,
+    /// The columns listed by `INSERT INTO t (c1, c2, ...)`.
+    columns: Vec<Identifier>,
+}
+
+/// A prepared statement with the parameters bound.
+pub struct BoundStatement {
+    /// The SQL to execute if `statement` is `None`, or to report the errors with.
+    pub sql: String,
+    pub statement: Option<Statement>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str) -> Result<PreparedStatement> {
+        let tokens = tokenize_sql(query)?;
+        let mut fragments = vec![];
+        let mut marked_sql = String::with_capacity(query.len());
+        let mut markers = vec![];
+        let mut start = 0;
+        let mut prev_kind = None;
+        for token in tokens.iter() {
+            if token.kind == TokenKind::Placeholder && !follows_operand(prev_kind) {
+                let fragment = &query[start..token.span.start()];
+                marked_sql.push_str(fragment);
+                fragments.push(fragment.to_string());
+
+                let marker = marker_name(markers.len());
+                markers.push(marked_sql.len()..marked_sql.len() + marker.len());
+                marked_sql.push_str(&marker);
+                start = token.span.end();
+            }
+            prev_kind = Some(token.kind);
+        }
+        marked_sql.push_str(&query[start..]);
+        fragments.push(query[start..].to_string());
+
+        let params = (1..fragments.len())
+            .map(|i| Column {
+                table: "".to_string(),
+                column: format!("?{i}"),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect();
+        Ok(PreparedStatement {
+            fragments,
+            marked_sql,
+            markers,
+            statement: None,
+            params,
+            columns: vec![],
+        })
+    }
+
+    /// Parse the statement once, so that it's not parsed again on each execution.
+    ///
+    /// The statement is kept only if all the placeholders are parsed as expressions.
+    pub fn parse(&mut self, dialect: Dialect) {
+        let Ok(tokens) = tokenize_sql(&self.marked_sql) else {
+            return;
+        };
+        let Ok((statement, _)) = parse_sql(&tokens, dialect) else {
+            return;
+        };
+        let nulls = self.null_params();
+        let mut binder = ParamBinder::new(&nulls, &self.markers);
+        binder.visit_statement(&mut statement.clone());
+        if binder.num_bound == self.markers.len() {
+            self.statement = Some(statement);
+        }
+    }
+
+    /// Advertise the types of the parameters by the columns they're compared with or inserted
+    /// into, or the types they're cast to. The other parameters are advertised as strings,
+    /// which are cast to the expected types when the statement is executed.
+    pub async fn describe_params(&mut self, ctx: &Arc<QueryContext>) -> Result<()> {
+        let Some(statement) = &self.statement else {
+            return Ok(());
+        };
+        let mut collector = ParamTypeCollector {
+            hints: (0..self.params.len()).map(|_| None).collect(),
+            markers: &self.markers,
+        };
+        collector.visit_statement(&mut statement.clone());
+        let hints = collector.hints;
+
+        let name_resolution_ctx = NameResolutionContext::try_from(ctx.get_settings().as_ref())?;
+        let normalize = |ident: &Identifier| normalize_identifier(ident, &name_resolution_ctx).name;
+        let (table, columns) = match param_table(statement) {
+            Some(param_table) => {
+                let catalog = param_table
+                    .catalog
+                    .as_ref()
+                    .map_or_else(|| ctx.get_current_catalog(), normalize);
+                let database = param_table
+                    .database
+                    .as_ref()
+                    .map_or_else(|| ctx.get_current_database(), normalize);
+                let table = normalize(&param_table.table);
+                // The statement reports the errors of the table when it's executed.
+                let table = ctx.get_table(&catalog, &database, &table).await.ok();
+                (table, param_table.columns)
+            }
+            None => (None, vec![]),
+        };
+        let schema = table.map(|table| table.schema().remove_computed_fields());
+
+        for (i, hint) in hints.into_iter().enumerate() {
+            let data_type = match (hint, &schema) {
+                (Some(ParamTypeHint::Type(type_name)), _) => resolve_type_name(&type_name, true)
+                    .ok()
+                    .map(|ty| DataType::from(&ty)),
+                (Some(ParamTypeHint::Column(column)), Some(schema)) => schema
+                    .field_with_name(&normalize(&column))
+                    .ok()
+                    .map(|field| DataType::from(field.data_type())),
+                (Some(ParamTypeHint::Position(position)), Some(schema)) => {
+                    let field = match columns.get(position) {
+                        Some(column) => schema.field_with_name(&normalize(column)).ok(),
+                        None if columns.is_empty() => schema.fields().get(position),
+                        None => None,
+                    };
+                    field.map(|field| DataType::from(field.data_type()))
+                }
+                _ => None,
+            };
+            if let Some(data_type) = data_type {
+                let field = DataField::new(&format!("?{}", i + 1), data_type);
+                if let Ok(column) = make_column_from_field(&field) {
+                    self.params[i] = column;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The statement with all the parameters bound to NULL, which is used to describe the result.
+    pub fn bind_nulls(&self) -> BoundStatement {
+        self.bind_exprs(&self.null_params())
+    }
+
+    /// The statement with the placeholders replaced by the constants of the parameters.
+    pub fn bind<'a>(
+        &self,
+        params: impl IntoIterator<Item = ParamValue<'a>>,
+    ) -> Result<BoundStatement> {
+        let params = params
+            .into_iter()
+            .map(param_to_expr)
+            .collect::<Result<Vec<_>>>()?;
+        if params.len() != self.params.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "prepared statement expects {} parameters, got {}",
+                self.params.len(),
+                params.len()
+            )));
+        }
+        Ok(self.bind_exprs(&params))
+    }
+
+    fn bind_exprs(&self, params: &[Expr]) -> BoundStatement {
+        match &self.statement {
+            Some(statement) => {
+                let mut statement = statement.clone();
+                ParamBinder::new(params, &self.markers).visit_statement(&mut statement);
+                BoundStatement {
+                    sql: self.marked_sql.clone(),
+                    statement: Some(statement),
+                }
+            }
+            None => {
+                let mut sql = self.fragments[0].clone();
+                for (param, fragment) in params.iter().zip(self.fragments[1..].iter()) {
+                    sql.push_str(&param.to_string());
+                    sql.push_str(fragment);
+                }
+                BoundStatement {
+                    sql,
+                    statement: None,
+                }
+            }
+        }
+    }
+
+    fn null_params(&self) -> Vec<Expr> {
+        vec![literal(Literal::Null); self.params.len()]
+    }
+}
+
+fn marker_name(index: usize) -> String {
+    format!("{PARAM_PREFIX}{index}")
+}
+
+/// Replace the markers of the placeholders with the parameters.
+struct ParamBinder<'a> {
+    params: &'a [Expr],
+    markers: &'a [Range<usize>],
+    num_bound: usize,
+}
+
+impl<'a> ParamBinder<'a> {
+    fn new(params: &'a [Expr], markers: &'a [Range<usize>]) -> Self {
+        ParamBinder {
+            params,
+            markers,
+            num_bound: 0,
+        }
+    }
+
+    /// The rows of `INSERT ... VALUES` are kept as text and parsed while inserting,
+    /// so the parameters are bound into the text, from the last one to keep the offsets.
+    fn bind_values(&mut self, rest_str: &mut String, start: usize) {
+        for (i, marker) in self.markers.iter().enumerate().rev() {
+            if marker.start < start {
+                continue;
+            }
+            let range = marker.start - start..marker.end - start;
+            if rest_str.get(range.clone()) == Some(marker_name(i).as_str()) {
+                rest_str.replace_range(range, &self.params[i].to_string());
+                self.num_bound += 1;
+            }
+        }
+    }
+}
+
+impl VisitorMut for ParamBinder<'_> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Some(param) = param_index(expr).and_then(|index| self.params.get(index)) {
+            *expr = param.clone();
+            self.num_bound += 1;
+            return;
+        }
+        walk_expr_mut(self, expr);
+    }
+
+    fn visit_insert(&mut self, insert: &mut InsertStmt) {
+        self.visit_insert_source(&mut insert.source);
+    }
+
+    fn visit_replace(&mut self, replace: &mut ReplaceStmt) {
+        self.visit_insert_source(&mut replace.source);
+    }
+
+    fn visit_insert_source(&mut self, insert_source: &mut InsertSource) {
+        match insert_source {
+            InsertSource::Select { query } => self.visit_query(query),
+            InsertSource::Values { rest_str, start } => self.bind_values(rest_str, *start),
+            _ => {}
+        }
+    }
+}
+
+/// Collect the hints of the parameter types from the expressions around the parameters.
+struct ParamTypeCollector<'a> {
+    hints: Vec<Option<ParamTypeHint>>,
+    markers: &'a [Range<usize>],
+}
+
+impl ParamTypeCollector<'_> {
+    fn set_hint(&mut self, index: usize, hint: ParamTypeHint) {
+        if let Some(slot @ None) = self.hints.get_mut(index) {
+            *slot = Some(hint);
+        }
+    }
+
+    /// The parameter is compared with the column, e.g. `c = ?` or `c IN (?, ?)`.
+    fn compare_with_column(&mut self, param: &Expr, column: &Expr) {
+        if let (
+            Some(index),
+            Expr::ColumnRef {
+                column: ColumnID::Name(ident),
+                ..
+            },
+        ) = (param_index(param), column)
+        {
+            self.set_hint(index, ParamTypeHint::Column(ident.clone()));
+        }
+    }
+
+    /// The parameters of the rows in `INSERT ... VALUES` are inserted into the columns
+    /// at their positions, unless they're part of expressions.
+    fn collect_values(&mut self, rest_str: &str, start: usize) {
+        let Ok(tokens) = tokenize_sql(rest_str) else {
+            return;
+        };
+        let mut depth = 0;
+        let mut position = 0;
+        for (i, token) in tokens.iter().enumerate() {
+            match token.kind {
+                TokenKind::LParen => {
+                    depth += 1;
+                    if depth == 1 {
+                        position = 0;
+                    }
+                }
+                TokenKind::RParen => depth -= 1,
+                TokenKind::Comma if depth == 1 => position += 1,
+                TokenKind::Ident if depth == 1 => {
+                    let alone = matches!(tokens[i - 1].kind, TokenKind::LParen | TokenKind::Comma)
+                        && matches!(
+                            tokens.get(i + 1).map(|token| token.kind),
+                            Some(TokenKind::Comma | TokenKind::RParen)
+                        );
+                    let span = start + token.span.start()..start + token.span.end();
+                    if let Some(index) = self.markers.iter().position(|marker| *marker == span) {
+                        if alone {
+                            self.set_hint(index, ParamTypeHint::Position(position));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl VisitorMut for ParamTypeCollector<'_> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Cast {
+                expr: param,
+                target_type,
+                ..
+            }
+            | Expr::TryCast {
+                expr: param,
+                target_type,
+                ..
+            } => {
+                if let Some(index) = param_index(param) {
+                    self.set_hint(index, ParamTypeHint::Type(target_type.clone()));
+                }
+            }
+            Expr::BinaryOp { left, right, .. } => {
+                self.compare_with_column(left, right);
+                self.compare_with_column(right, left);
+            }
+            Expr::Between {
+                expr: column,
+                low,
+                high,
+                ..
+            } => {
+                self.compare_with_column(low, column);
+                self.compare_with_column(high, column);
+            }
+            Expr::InList {
+                expr: column, list, ..
+            } => {
+                for item in list.iter() {
+                    self.compare_with_column(item, column);
+                }
+            }
+            _ => {}
+        }
+        walk_expr_mut(self, expr);
+    }
+
+    fn visit_insert(&mut self, insert: &mut InsertStmt) {
+        self.visit_insert_source(&mut insert.source);
+    }
+
+    fn visit_replace(&mut self, replace: &mut ReplaceStmt) {
+        self.visit_insert_source(&mut replace.source);
+    }
+
+    fn visit_insert_source(&mut self, insert_source: &mut InsertSource) {
+        match insert_source {
+            InsertSource::Select { query } => self.visit_query(query),
+            InsertSource::Values { rest_str, start } => self.collect_values(rest_str, *start),
+            _ => {}
+        }
+    }
+
+    fn visit_update(&mut self, update: &mut UpdateStmt) {
+        for update_expr in update.update_list.iter_mut() {
+            if let Some(index) = param_index(&update_expr.expr) {
+                self.set_hint(index, ParamTypeHint::Column(update_expr.name.clone()));
+            }
+            self.visit_expr(&mut update_expr.expr);
+        }
+        if let Some(selection) = &mut update.selection {
+            self.visit_expr(selection);
+        }
+    }
+}
+
+/// The table that the columns around the parameters are resolved in, the columns of a
+/// query are only resolved if it reads a single table.
+fn param_table(statement: &Statement) -> Option<ParamTable> {
+    let table_reference = |table_reference: &TableReference| match table_reference {
+        TableReference::Table {
+            catalog,
+            database,
+            table,
+            ..
+        } => Some(ParamTable {
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table.clone(),
+            columns: vec![],
+        }),
+        _ => None,
+    };
+    match statement {
+        Statement::Insert(insert) => Some(ParamTable {
+            catalog: insert.catalog.clone(),
+            database: insert.database.clone(),
+            table: insert.table.clone(),
+            columns: insert.columns.clone(),
+        }),
+        Statement::Replace(replace) => Some(ParamTable {
+            catalog: replace.catalog.clone(),
+            database: replace.database.clone(),
+            table: replace.table.clone(),
+            columns: replace.columns.clone(),
+        }),
+        Statement::Update(update) => table_reference(&update.table),
+        Statement::Delete(delete) => table_reference(&delete.table),
+        Statement::Query(query) => match &query.body {
+            SetExpr::Select(select) if select.from.len() == 1 => table_reference(&select.from[0]),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The index of the parameter if the expression is the marker of a placeholder.
+fn param_index(expr: &Expr) -> Option<usize> {
+    match expr {
+        Expr::ColumnRef {
+            database: None,
+            table: None,
+            column: ColumnID::Name(ident),
+            ..
+        } if ident.quote.is_none() => ident.name.strip_prefix(PARAM_PREFIX)?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// `?` is also a JSON operator, which follows an operand like `v ? 'k'`.
+fn follows_operand(prev_kind: Option<TokenKind>) -> bool {
+    match prev_kind {
+        Some(kind) => {
+            kind.is_literal()
+                || matches!(
+                    kind,
+                    TokenKind::Ident | TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace
+                )
+        }
+        None => false,
+    }
+}
+
+fn literal(lit: Literal) -> Expr {
+    Expr::Literal { span: None, lit }
+}
+
+fn cast(value: String, target_type: TypeName) -> Expr {
+    Expr::Cast {
+        span: None,
+        expr: Box::new(literal(Literal::String(value))),
+        target_type,
+        pg_style: false,
+    }
+}
+
+fn param_to_expr(param: ParamValue) -> Result<Expr> {
+    let expr = match param.value.into_inner() {
+        ValueInner::NULL => literal(Literal::Null),
+        ValueInner::Int(v) if v < 0 => Expr::UnaryOp {
+            span: None,
+            op: UnaryOperator::Minus,
+            expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+        },
+        ValueInner::Int(v) => literal(Literal::UInt64(v as u64)),
+        ValueInner::UInt(v) => literal(Literal::UInt64(v)),
+        ValueInner::Double(v) if v.is_finite() => literal(Literal::Float64(v)),
+        ValueInner::Double(v) => cast(v.to_string(), TypeName::Float64),
+        ValueInner::Bytes(v) => match std::str::from_utf8(v) {
+            Ok(s) => literal(Literal::String(s.to_string())),
+            // Binary data is passed in hex, and decoded to a binary value.
+            Err(_) => Expr::FunctionCall {
+                span: None,
+                distinct: false,
+                name: Identifier::from_name("from_hex"),
+                args: vec![literal(Literal::String(hex::encode(v)))],
+                params: vec![],
+                window: None,
+                lambda: None,
+            },
+        },
+        ValueInner::Date(v) => cast(decode_datetime(v)?, TypeName::Date),
+        ValueInner::Datetime(v) => cast(decode_datetime(v)?, TypeName::Timestamp),
+        ValueInner::Time(v) => literal(Literal::String(decode_time(v)?)),
+    };
+    Ok(expr)
+}
+
+/// Decode `DATE` and `DATETIME` in the binary protocol, which are encoded as
+/// `year(2) month(1) day(1) [hour(1) minute(1) second(1) [microsecond(4)]]`.
+fn decode_datetime(v: &[u8]) -> Result<String> {
+    match v.len() {
+        0 => Ok("0000-00-00 00:00:00".to_string()),
+        4 | 7 | 11 => {
+            let year = u16::from_le_bytes([v[0], v[1]]);
+            let mut s = format!("{:04}-{:02}-{:02}", year, v[2], v[3]);
+            if v.len() >= 7 {
+                s.push_str(&format!(" {:02}:{:02}:{:02}", v[4], v[5], v[6]));
+            }
+            if v.len() == 11 {
+                let micros = u32::from_le_bytes([v[7], v[8], v[9], v[10]]);
+                s.push_str(&format!(".{:06}", micros));
+            }
+            Ok(s)
+        }
+        len => Err(ErrorCode::BadArguments(format!(
+            "invalid datetime parameter of length {len}"
+        ))),
+    }
+}
+
+/// Decode `TIME` in the binary protocol, which is encoded as
+/// `is_negative(1) days(4) hour(1) minute(1) second(1) [microsecond(4)]`.
+fn decode_time(v: &[u8]) -> Result<String> {
+    match v.len() {
+        0 => Ok("00:00:00".to_string()),
+        8 | 12 => {
+            let sign = if v[0] == 1 { "-" } else { "" };
+            let days = u32::from_le_bytes([v[1], v[2], v[3], v[4]]);
+            let hours = days * 24 + v[5] as u32;
+            let mut s = format!("{sign}{:02}:{:02}:{:02}", hours, v[6], v[7]);
+            if v.len() == 12 {
+                let micros = u32::from_le_bytes([v[8], v[9], v[10], v[11]]);
+                s.push_str(&format!(".{:06}", micros));
+            }
+            Ok(s)
+        }
+        len => Err(ErrorCode::BadArguments(format!(
+            "invalid time parameter of length {len}"
+        ))),
+    }
+}
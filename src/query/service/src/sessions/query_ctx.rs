@@ -91,6 +91,7 @@ use databend_common_storages_stage::StageTable;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
 use log::info;
 use parking_lot::RwLock;
@@ -596,6 +597,8 @@ impl TableContext for QueryContext {
         let external_server_request_timeout_secs = self
             .get_settings()
             .get_external_server_request_timeout_secs()?;
+        let wasm_udf_max_fuel = self.get_settings().get_wasm_udf_max_fuel()?;
+        let wasm_udf_max_memory = self.get_settings().get_wasm_udf_max_memory()?;
 
         let tz = self.get_settings().get_timezone()?;
         let tz = TzFactory::instance().get_by_name(&tz)?;
@@ -619,6 +622,9 @@ impl TableContext for QueryContext {
 
             external_server_connect_timeout_secs,
             external_server_request_timeout_secs,
+
+            wasm_udf_max_fuel,
+            wasm_udf_max_memory,
         })
     }
 
@@ -748,6 +754,14 @@ impl TableContext for QueryContext {
         table: &str,
     ) -> Result<Arc<dyn Table>> {
         let table = self.shared.get_table(catalog, database, table).await?;
+        // Read the uncommitted table meta if the table is mutated in the current transaction.
+        let buffered = self.txn_mgr().lock().get_table_from_buffer(table.get_id());
+        let table = match buffered {
+            Some(info) if info.catalog() == table.get_table_info().catalog() => {
+                self.get_catalog(catalog).await?.get_table_by_info(&info)?
+            }
+            _ => table,
+        };
         // the better place to do this is in the QueryContextShared::get_table_to_cache() method,
         // but there is no way to access dyn TableContext.
         let table: Arc<dyn Table> = if table.engine() == "ICEBERG" {
@@ -994,6 +1008,10 @@ impl TableContext for QueryContext {
         }
         false
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
 }
 
 impl TrySpawn for QueryContext {
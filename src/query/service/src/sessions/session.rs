@@ -26,6 +26,7 @@ use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_settings::Settings;
 use databend_common_users::GrantObjectVisibilityChecker;
+use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
 use parking_lot::RwLock;
 
@@ -291,6 +292,10 @@ impl Session {
         self.session_ctx.get_settings()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         // TODO(winter): use thread memory tracker
         0
@@ -29,10 +29,12 @@ use databend_common_expression::TableSchema;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::input_formats::read_avro_schema;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_orc_schema;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
@@ -145,9 +147,13 @@ impl AsyncSource for ParquetInferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Avro => {
+                read_avro_schema(&operator, &first_file.path, Some(first_file.size)).await?
+            }
+            StageFileFormatType::Orc => read_orc_schema(&operator, &first_file.path).await?,
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, Avro and ORC",
                 ));
             }
         };
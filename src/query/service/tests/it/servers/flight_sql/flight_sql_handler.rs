@@ -17,11 +17,26 @@
 use std::fs;
 use std::io::Write;
 
+use arrow_array::RecordBatch;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::ActionCancelQueryRequest;
+use arrow_flight::sql::ActionCancelQueryResult;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::CancelResult;
+use arrow_flight::sql::CommandGetCrossReference;
+use arrow_flight::sql::CommandGetExportedKeys;
+use arrow_flight::sql::CommandGetImportedKeys;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandStatementSubstraitPlan;
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::sql::SubstraitPlan;
 use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::Action;
 use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
 use databend_common_base::base::tokio;
 use databend_common_config::InnerConfig;
@@ -34,6 +49,35 @@ use databend_query::test_kits::TestFixture;
 use futures::TryStreamExt;
 use goldenfile::Mint;
 use log::debug;
+use prost::Message;
+use substrait::proto::expression::field_reference;
+use substrait::proto::expression::literal::LiteralType;
+use substrait::proto::expression::reference_segment;
+use substrait::proto::expression::FieldReference;
+use substrait::proto::expression::Literal;
+use substrait::proto::expression::ReferenceSegment;
+use substrait::proto::expression::RexType;
+use substrait::proto::expression::ScalarFunction;
+use substrait::proto::extensions::simple_extension_declaration::ExtensionFunction;
+use substrait::proto::extensions::simple_extension_declaration::MappingType;
+use substrait::proto::extensions::SimpleExtensionDeclaration;
+use substrait::proto::function_argument::ArgType;
+use substrait::proto::plan_rel;
+use substrait::proto::read_rel;
+use substrait::proto::rel::RelType;
+use substrait::proto::rel_common::Emit;
+use substrait::proto::rel_common::EmitKind;
+use substrait::proto::Expression;
+use substrait::proto::FilterRel;
+use substrait::proto::FunctionArgument;
+use substrait::proto::NamedStruct;
+use substrait::proto::Plan;
+use substrait::proto::PlanRel;
+use substrait::proto::ProjectRel;
+use substrait::proto::ReadRel;
+use substrait::proto::Rel;
+use substrait::proto::RelCommon;
+use substrait::proto::RelRoot;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -75,6 +119,16 @@ async fn run_query(
     Ok(res)
 }
 
+async fn fetch_batches(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<Vec<RecordBatch>, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    flight_data_to_batches(&flight_data)
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -148,3 +202,288 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_metadata_keys() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        run_query(&mut client, "create table test_keys(a int)")
+            .await
+            .unwrap();
+
+        let flight_info = client
+            .get_primary_keys(CommandGetPrimaryKeys {
+                catalog: None,
+                db_schema: None,
+                table: "test_keys".to_string(),
+            })
+            .await
+            .unwrap();
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+        let schema = batches[0].schema();
+        assert_eq!(schema.field(2).name(), "table_name");
+        assert_eq!(schema.field(5).name(), "key_sequence");
+
+        let infos = vec![
+            client
+                .get_exported_keys(CommandGetExportedKeys {
+                    catalog: None,
+                    db_schema: None,
+                    table: "test_keys".to_string(),
+                })
+                .await
+                .unwrap(),
+            client
+                .get_imported_keys(CommandGetImportedKeys {
+                    catalog: None,
+                    db_schema: None,
+                    table: "test_keys".to_string(),
+                })
+                .await
+                .unwrap(),
+            client
+                .get_cross_reference(CommandGetCrossReference {
+                    pk_catalog: None,
+                    pk_db_schema: None,
+                    pk_table: "test_keys".to_string(),
+                    fk_catalog: None,
+                    fk_db_schema: None,
+                    fk_table: "test_keys".to_string(),
+                })
+                .await
+                .unwrap(),
+        ];
+        for flight_info in infos {
+            let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+            assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+            let schema = batches[0].schema();
+            assert_eq!(schema.fields().len(), 13);
+            assert_eq!(schema.field(0).name(), "pk_catalog_name");
+            assert_eq!(schema.field(12).name(), "delete_rule");
+        }
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
+
+fn substrait_field(field: i32) -> Expression {
+    Expression {
+        rex_type: Some(RexType::Selection(Box::new(FieldReference {
+            reference_type: Some(field_reference::ReferenceType::DirectReference(
+                ReferenceSegment {
+                    reference_type: Some(reference_segment::ReferenceType::StructField(Box::new(
+                        reference_segment::StructField { field, child: None },
+                    ))),
+                },
+            )),
+            root_type: Some(field_reference::RootType::RootReference(
+                field_reference::RootReference {},
+            )),
+        }))),
+    }
+}
+
+// select b from test_substrait where a > 1
+fn substrait_plan() -> Vec<u8> {
+    let read = Rel {
+        rel_type: Some(RelType::Read(Box::new(ReadRel {
+            base_schema: Some(NamedStruct {
+                names: vec!["a".to_string(), "b".to_string()],
+                r#struct: None,
+            }),
+            read_type: Some(read_rel::ReadType::NamedTable(read_rel::NamedTable {
+                names: vec!["test_substrait".to_string()],
+                advanced_extension: None,
+            })),
+            ..Default::default()
+        }))),
+    };
+    let condition = Expression {
+        rex_type: Some(RexType::ScalarFunction(ScalarFunction {
+            function_reference: 1,
+            arguments: vec![
+                FunctionArgument {
+                    arg_type: Some(ArgType::Value(substrait_field(0))),
+                },
+                FunctionArgument {
+                    arg_type: Some(ArgType::Value(Expression {
+                        rex_type: Some(RexType::Literal(Literal {
+                            nullable: false,
+                            type_variation_reference: 0,
+                            literal_type: Some(LiteralType::I32(1)),
+                        })),
+                    })),
+                },
+            ],
+            ..Default::default()
+        })),
+    };
+    let filter = Rel {
+        rel_type: Some(RelType::Filter(Box::new(FilterRel {
+            input: Some(Box::new(read)),
+            condition: Some(Box::new(condition)),
+            ..Default::default()
+        }))),
+    };
+    let project = Rel {
+        rel_type: Some(RelType::Project(Box::new(ProjectRel {
+            common: Some(RelCommon {
+                emit_kind: Some(EmitKind::Emit(Emit {
+                    output_mapping: vec![1],
+                })),
+                ..Default::default()
+            }),
+            input: Some(Box::new(filter)),
+            expressions: vec![],
+            advanced_extension: None,
+        }))),
+    };
+    let plan = Plan {
+        extensions: vec![SimpleExtensionDeclaration {
+            mapping_type: Some(MappingType::ExtensionFunction(ExtensionFunction {
+                extension_uri_reference: 0,
+                function_anchor: 1,
+                name: "gt:i32_i32".to_string(),
+            })),
+        }],
+        relations: vec![PlanRel {
+            rel_type: Some(plan_rel::RelType::Root(RelRoot {
+                input: Some(project),
+                names: vec!["b".to_string()],
+            })),
+        }],
+        ..Default::default()
+    };
+    plan.encode_to_vec()
+}
+
+#[tokio::test]
+async fn test_substrait_plan() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+        for sql in [
+            "create table test_substrait(a int, b string)",
+            "insert into table test_substrait(a, b) values (1, 'x'), (2, 'y'), (3, 'z')",
+        ] {
+            run_query(&mut client, sql).await.unwrap();
+        }
+
+        let cmd = CommandStatementSubstraitPlan {
+            plan: Some(SubstraitPlan {
+                plan: substrait_plan().into(),
+                version: "0.19.0".to_string(),
+            }),
+            transaction_id: None,
+        };
+        let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
+        let mut request = tonic::Request::new(descriptor);
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        let flight_info = client
+            .inner_mut()
+            .get_flight_info(request)
+            .await
+            .unwrap()
+            .into_inner();
+        let batches = fetch_batches(&mut client, flight_info.clone())
+            .await
+            .unwrap();
+        let res = pretty_format_batches(batches.as_slice())
+            .unwrap()
+            .to_string();
+        let mut lines = res.lines().skip(3).collect::<Vec<_>>();
+        lines.sort();
+        assert_eq!(lines, vec!["+---+", "| y |", "| z |"]);
+
+        // the plan is dropped once it has been fetched
+        assert!(
+            fetch_batches(&mut client, flight_info.clone())
+                .await
+                .is_err()
+        );
+
+        // the query has finished, there is nothing left to cancel
+        let cancel = ActionCancelQueryRequest {
+            info: flight_info.encode_to_vec().into(),
+        };
+        let mut request = tonic::Request::new(Action {
+            r#type: "CancelQuery".to_string(),
+            body: cancel.as_any().encode_to_vec().into(),
+        });
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        let results: Vec<_> = client
+            .inner_mut()
+            .do_action(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .try_collect()
+            .await
+            .unwrap();
+        let result: ActionCancelQueryResult = Any::decode(results[0].body.clone())
+            .unwrap()
+            .unpack()
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.result(), CancelResult::NotCancellable);
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
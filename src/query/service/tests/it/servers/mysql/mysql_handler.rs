@@ -28,11 +28,14 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
 use mysql_async::Row;
 use mysql_async::SslOpts;
+use mysql_async::Statement;
+use mysql_async::Value;
 use tokio::sync::Barrier;
 
 use crate::tests::tls_constants::*;
@@ -54,6 +57,106 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let stmt = connection
+        .prep("SELECT number + ?, ? FROM numbers(5) WHERE number = ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "prepare")?;
+    assert_eq!(stmt.num_params(), 3);
+    assert_eq!(stmt.num_columns(), 2);
+
+    for i in 0..3u64 {
+        let row: Option<(u64, String)> = connection
+            .exec_first(&stmt, (10u64, "it's", i))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "execute")?;
+        assert_eq!(row, Some((10 + i, "it's".to_string())));
+    }
+
+    let row: Option<(u8,)> = connection
+        .exec_first("SELECT ? IS NULL", (None::<u64>,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "execute")?;
+    assert_eq!(row, Some((1,)));
+
+    // The parameters are bound as typed constants, and the values are returned in their types.
+    let row: Option<(i64, Vec<u8>, Value, u64)> = connection
+        .exec_first(
+            "SELECT ?, ?, ?, ?::UINT64",
+            (
+                -3i64,
+                vec![0xffu8, 0x00],
+                Value::Date(2024, 1, 2, 3, 4, 5, 0),
+                u64::MAX,
+            ),
+        )
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "execute")?;
+    assert_eq!(
+        row,
+        Some((
+            -3,
+            vec![0xff, 0x00],
+            Value::Date(2024, 1, 2, 3, 4, 5, 0),
+            u64::MAX
+        ))
+    );
+
+    // The parameters must match the placeholders.
+    let result: std::result::Result<Option<(u64,)>, _> =
+        connection.exec_first(&stmt, (1u64,)).await;
+    assert!(result.is_err());
+
+    connection
+        .close(stmt)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "close")?;
+
+    // The parameter types are taken from the columns and the casts around them.
+    connection
+        .query_drop("CREATE TABLE test_prepared(a INT, b VARCHAR, c DATE)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "create table")?;
+    let param_types = |stmt: &Statement| {
+        stmt.params()
+            .iter()
+            .map(|param| param.column_type())
+            .collect::<Vec<_>>()
+    };
+    let stmt = connection
+        .prep("INSERT INTO test_prepared (c, a) VALUES (?, ?), (?, ? + 1)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "prepare")?;
+    assert_eq!(param_types(&stmt), vec![
+        ColumnType::MYSQL_TYPE_DATE,
+        ColumnType::MYSQL_TYPE_LONG,
+        ColumnType::MYSQL_TYPE_DATE,
+        ColumnType::MYSQL_TYPE_VAR_STRING,
+    ]);
+    let stmt = connection
+        .prep("SELECT a FROM test_prepared WHERE a = ? AND b IN (?) AND c > ?::TIMESTAMP AND ? > 0")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "prepare")?;
+    assert_eq!(param_types(&stmt), vec![
+        ColumnType::MYSQL_TYPE_LONG,
+        ColumnType::MYSQL_TYPE_VARCHAR,
+        ColumnType::MYSQL_TYPE_DATETIME,
+        ColumnType::MYSQL_TYPE_VAR_STRING,
+    ]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
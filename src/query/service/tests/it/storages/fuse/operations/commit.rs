@@ -127,6 +127,7 @@ use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_txn::TxnManagerRef;
 use futures::TryStreamExt;
 use parking_lot::RwLock;
 use uuid::Uuid;
@@ -732,6 +733,10 @@ impl TableContext for CtxDelegation {
     fn has_bloom_runtime_filters(&self, _id: usize) -> bool {
         todo!()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
     fn get_data_cache_metrics(&self) -> &DataCacheMetrics {
         todo!()
     }
@@ -355,3 +355,33 @@ async fn test_fuse_purge_older_version() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_purge_rolled_back_txn() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    // ingests some test data
+    append_sample_data(1, &fixture).await?;
+
+    // the data appended in the transaction is removed on rollback
+    fixture.execute_command("BEGIN").await?;
+    append_sample_data(2, &fixture).await?;
+    fixture.execute_command("ROLLBACK").await?;
+
+    check_data_dir(
+        &fixture,
+        "rollback: there should be 1 snapshot, 1 segment/block",
+        1, // 1 snapshot
+        0, // 0 snapshot statistic
+        1, // 1 segments
+        1, // 1 blocks
+        1, // 1 index
+        Some(()),
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -164,6 +164,51 @@ async fn test_table_update_analyze_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histogram_statistics() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture
+        .execute_command("create table t(c int, s string)")
+        .await?;
+    // 900 of the 1000 rows are 1.
+    fixture
+        .execute_command(
+            "insert into t select if(number < 900, 1, number), to_string(number) from numbers(1000)",
+        )
+        .await?;
+    ctx.evict_table_from_cache("default", "default", "t")?;
+    fixture.execute_command("analyze table default.t").await?;
+
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let provider = table.column_statistics_provider(ctx.clone()).await?;
+
+    let histogram = provider
+        .column_statistics(0)
+        .and_then(|stat| stat.histogram.as_ref())
+        .unwrap();
+    assert_eq!(histogram.num_values(), 1000.0);
+    assert_eq!(histogram.most_common_values, vec![(
+        Scalar::Number(NumberScalar::Int32(1)),
+        900.0
+    )]);
+    let first = histogram.buckets.first().unwrap();
+    assert_eq!(first.lower_bound, Scalar::Number(NumberScalar::Int32(1)));
+    assert_eq!(first.upper_bound, Scalar::Number(NumberScalar::Int32(1)));
+    let last = histogram.buckets.last().unwrap();
+    assert_eq!(last.upper_bound, Scalar::Number(NumberScalar::Int32(999)));
+
+    // No histogram for string columns.
+    let stat = provider.column_statistics(1).unwrap();
+    assert!(stat.histogram.is_none());
+
+    Ok(())
+}
+
 async fn check_column_ndv_statistics(
     ctx: Arc<dyn TableContext>,
     table: Arc<dyn Table>,
@@ -38,8 +38,10 @@ use databend_common_expression::TableSchema;
 use databend_common_functions::aggregates::eval_aggr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::evaluator::BlockOperator;
+use databend_common_storages_fuse::statistics::build_column_histogram;
 use databend_common_storages_fuse::statistics::reducers::reduce_block_metas;
 use databend_common_storages_fuse::statistics::Trim;
+use databend_common_storages_fuse::statistics::HISTOGRAM_BUCKETS;
 use databend_common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
 use databend_common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
 use databend_common_storages_fuse::FuseStorageFormat;
@@ -51,6 +53,7 @@ use databend_query::storages::fuse::statistics::StatisticsAccumulator;
 use databend_query::test_kits::*;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::Compression;
 use databend_storages_common_table_meta::meta::Statistics;
@@ -639,3 +642,61 @@ fn test_reduce_block_meta() -> databend_common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_ft_stats_column_histogram() -> databend_common_exception::Result<()> {
+    let int = |v: i32| Scalar::Number(NumberScalar::Int32(v));
+
+    // 500 samples of 7, and the values 0..500 once.
+    let mut samples = vec![int(7); 500];
+    samples.extend((0..500).map(int));
+    // scaled to 10 times of the samples
+    let histogram = build_column_histogram(samples, 10000, 5000).unwrap();
+
+    assert_eq!(histogram.most_common_values, vec![(int(7), 5010.0)]);
+    assert!(histogram.buckets.len() <= HISTOGRAM_BUCKETS);
+    assert_eq!(histogram.num_values(), 10000.0);
+    assert_eq!(histogram.buckets.first().unwrap().lower_bound, int(0));
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, int(499));
+    // The bounds are ordered and never overlap.
+    for pair in histogram.buckets.windows(2) {
+        assert!(pair[0].lower_bound <= pair[0].upper_bound);
+        assert!(pair[0].upper_bound < pair[1].lower_bound);
+    }
+    // The repeated value falls into a single bucket.
+    let bucket = histogram
+        .buckets
+        .iter()
+        .find(|bucket| bucket.lower_bound <= int(7) && int(7) <= bucket.upper_bound)
+        .unwrap();
+    assert!(bucket.num_values >= 5010.0);
+
+    // Uniformly distributed values have no most common values.
+    let samples = (0..1000).map(int).collect();
+    let histogram = build_column_histogram(samples, 1000, 1000).unwrap();
+    assert!(histogram.most_common_values.is_empty());
+    assert_eq!(histogram.buckets.len(), 100);
+    assert_eq!(histogram.buckets[0].num_values, 10.0);
+    assert_eq!(histogram.buckets[0].num_distinct, 10.0);
+
+    assert!(build_column_histogram(vec![], 1000, 1000).is_none());
+
+    // NaN and infinities are left out, so the histogram can be serialized.
+    let float = |v: f64| Scalar::Number(NumberScalar::Float64(v.into()));
+    let mut samples = (0..100).map(|v| float(v as f64)).collect::<Vec<_>>();
+    samples.extend(vec![float(f64::NAN); 50]);
+    samples.extend(vec![float(f64::INFINITY); 25]);
+    samples.extend(vec![float(f64::NEG_INFINITY); 25]);
+    let histogram = build_column_histogram(samples, 200, 103).unwrap();
+    assert!(histogram.most_common_values.is_empty());
+    assert_eq!(histogram.num_values(), 100.0);
+    assert_eq!(histogram.buckets.first().unwrap().lower_bound, float(0.0));
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, float(99.0));
+    let json = serde_json::to_vec(&histogram)?;
+    assert_eq!(serde_json::from_slice::<ColumnHistogram>(&json)?, histogram);
+
+    let samples = vec![float(f64::NAN), float(f64::INFINITY)];
+    assert!(build_column_histogram(samples, 1000, 2).is_none());
+
+    Ok(())
+}
@@ -244,6 +244,10 @@ impl Settings {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_cte_recursion_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursion_depth")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -547,6 +551,14 @@ impl Settings {
         self.try_get_u64("external_server_request_timeout_secs")
     }
 
+    pub fn get_wasm_udf_max_fuel(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_max_fuel")
+    }
+
+    pub fn get_wasm_udf_max_memory(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_max_memory")
+    }
+
     pub fn get_create_query_flight_client_with_current_rt(&self) -> Result<bool> {
         Ok(self.try_get_u64("create_query_flight_client_with_current_rt")? != 0)
     }
@@ -583,6 +595,22 @@ impl Settings {
         )
     }
 
+    pub fn get_enable_refresh_inverted_index_after_write(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_refresh_inverted_index_after_write")? != 0)
+    }
+
+    pub fn set_enable_refresh_inverted_index_after_write(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_refresh_inverted_index_after_write", u64::from(val))
+    }
+
+    pub fn get_enable_refresh_vector_index_after_write(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_refresh_vector_index_after_write")? != 0)
+    }
+
+    pub fn set_enable_refresh_vector_index_after_write(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_refresh_vector_index_after_write", u64::from(val))
+    }
+
     pub fn get_disable_variant_check(&self) -> Result<bool> {
         Ok(self.try_get_u64("disable_variant_check")? != 0)
     }
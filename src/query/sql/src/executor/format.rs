@@ -47,6 +47,7 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::ReclusterSink;
+use crate::executor::physical_plans::RecursiveCte;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
@@ -234,6 +235,7 @@ fn to_format_tree(
         PhysicalPlan::MaterializedCte(plan) => {
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
+        PhysicalPlan::RecursiveCte(plan) => recursive_cte_to_format_tree(plan, metadata, profs),
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
     }
 }
@@ -310,6 +312,12 @@ fn table_scan_to_format_tree(
         .as_ref()
         .and_then(|extras| extras.agg_index.as_ref());
 
+    let vector_index = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_index.as_ref());
+
     let mut children = vec![
         FormatTreeNode::new(format!("table: {table_name}")),
         FormatTreeNode::new(format!(
@@ -332,6 +340,13 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    // Vector index
+    if let Some(vector_index) = vector_index {
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [name: {}, limit: {}]",
+            vector_index.index_name, vector_index.limit
+        )));
+    }
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -1183,6 +1198,29 @@ fn materialized_cte_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "union type: {}",
+            if plan.distinct { "UNION" } else { "UNION ALL" }
+        )),
+        to_format_tree(&plan.anchor, metadata, profs)?,
+        to_format_tree(&plan.recursive, metadata, profs)?,
+    ];
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCTE".to_string(),
+        children,
+    ))
+}
+
 fn format_output_columns(
     output_schema: DataSchemaRef,
     metadata: &Metadata,
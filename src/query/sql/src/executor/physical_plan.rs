@@ -48,6 +48,7 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCte;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -80,6 +81,7 @@ pub enum PhysicalPlan {
     UnionAll(UnionAll),
     CteScan(CteScan),
     MaterializedCte(MaterializedCte),
+    RecursiveCte(RecursiveCte),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
 
@@ -217,6 +219,12 @@ impl PhysicalPlan {
                 plan.plan_id = *next_id;
                 *next_id += 1;
             }
+            PhysicalPlan::RecursiveCte(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.anchor.adjust_plan_id(next_id);
+                plan.recursive.adjust_plan_id(next_id);
+            }
             PhysicalPlan::ConstantTableScan(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -330,6 +338,7 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSink(v) => v.plan_id,
             PhysicalPlan::CteScan(v) => v.plan_id,
             PhysicalPlan::MaterializedCte(v) => v.plan_id,
+            PhysicalPlan::RecursiveCte(v) => v.plan_id,
             PhysicalPlan::ConstantTableScan(v) => v.plan_id,
             PhysicalPlan::Udf(v) => v.plan_id,
             PhysicalPlan::DeleteSource(v) => v.plan_id,
@@ -372,6 +381,7 @@ impl PhysicalPlan {
             PhysicalPlan::CopyIntoTable(plan) => plan.output_schema(),
             PhysicalPlan::CteScan(plan) => plan.output_schema(),
             PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
             PhysicalPlan::ConstantTableScan(plan) => plan.output_schema(),
             PhysicalPlan::Udf(plan) => plan.output_schema(),
             PhysicalPlan::MergeIntoSource(plan) => plan.input.output_schema(),
@@ -424,6 +434,7 @@ impl PhysicalPlan {
             PhysicalPlan::MergeIntoAppendNotMatched(_) => "MergeIntoAppendNotMatched".to_string(),
             PhysicalPlan::CteScan(_) => "PhysicalCteScan".to_string(),
             PhysicalPlan::MaterializedCte(_) => "PhysicalMaterializedCte".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "PhysicalRecursiveCte".to_string(),
             PhysicalPlan::ConstantTableScan(_) => "PhysicalConstantTableScan".to_string(),
             PhysicalPlan::MergeIntoAddRowNumber(_) => "AddRowNumber".to_string(),
             PhysicalPlan::ReclusterSource(_) => "ReclusterSource".to_string(),
@@ -486,6 +497,10 @@ impl PhysicalPlan {
             PhysicalPlan::MaterializedCte(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
             PhysicalPlan::ReclusterSink(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Udf(plan) => Box::new(std::iter::once(plan.input.as_ref())),
         }
@@ -512,6 +527,7 @@ impl PhysicalPlan {
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
             | PhysicalPlan::MaterializedCte(_)
+            | PhysicalPlan::RecursiveCte(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
             | PhysicalPlan::AggregatePartial(_)
@@ -688,6 +704,13 @@ impl PhysicalPlan {
             PhysicalPlan::CteScan(v) => {
                 format!("CTE index: {}, sub index: {}", v.cte_idx.0, v.cte_idx.1)
             }
+            PhysicalPlan::RecursiveCte(v) => {
+                format!(
+                    "CTE index: {}, {}",
+                    v.cte_idx,
+                    if v.distinct { "UNION" } else { "UNION ALL" }
+                )
+            }
             PhysicalPlan::UnionAll(v) => v
                 .pairs
                 .iter()
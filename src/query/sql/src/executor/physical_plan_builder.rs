@@ -109,6 +109,7 @@ impl PhysicalPlanBuilder {
             RelOperator::MaterializedCte(cte) => {
                 self.build_materialized_cte(s_expr, cte, required).await
             }
+            RelOperator::RecursiveCte(cte) => self.build_recursive_cte(s_expr, cte, required).await,
             RelOperator::ConstantTableScan(scan) => {
                 self.build_constant_table_scan(scan, required).await
             }
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::ColumnBinding;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub anchor: Box<PhysicalPlan>,
+    // The recursive term, it will be rebuilt and executed in every iteration.
+    pub recursive: Box<PhysicalPlan>,
+    pub cte_idx: IndexType,
+    pub distinct: bool,
+    pub output_columns: Vec<ColumnBinding>,
+    pub recursive_output_columns: Vec<ColumnBinding>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let fields = self
+            .output_columns
+            .iter()
+            .map(|column| DataField::new(&column.index.to_string(), *column.data_type.clone()))
+            .collect::<Vec<_>>();
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte(
+        &mut self,
+        s_expr: &SExpr,
+        cte: &crate::plans::RecursiveCte,
+        _required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // 1. Every column of the working table may be referenced by the recursive term,
+        // so the output columns can't be pruned.
+        let anchor_required = cte
+            .output_columns
+            .iter()
+            .map(|column| column.index)
+            .collect::<ColumnSet>();
+        let recursive_required = cte
+            .recursive_output_columns
+            .iter()
+            .map(|column| column.index)
+            .collect::<ColumnSet>();
+
+        // 2. Build physical plan.
+        let anchor = self.build(s_expr.child(0)?, anchor_required).await?;
+
+        // The working table scan in the recursive term reads the full rows of the cte,
+        // instead of the pruned columns required by the `MaterializedCte`.
+        let pruned_output_columns = self
+            .cte_output_columns
+            .insert(cte.cte_idx, cte.output_columns.clone());
+        let recursive = self.build(s_expr.child(1)?, recursive_required).await;
+        match pruned_output_columns {
+            Some(columns) => {
+                self.cte_output_columns.insert(cte.cte_idx, columns);
+            }
+            None => {
+                self.cte_output_columns.remove(&cte.cte_idx);
+            }
+        }
+
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id: 0,
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive?),
+            cte_idx: cte.cte_idx,
+            distinct: cte.distinct,
+            output_columns: cte.output_columns.clone(),
+            recursive_output_columns: cte.recursive_output_columns.clone(),
+        }))
+    }
+}
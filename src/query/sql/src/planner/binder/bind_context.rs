@@ -18,6 +18,7 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use dashmap::DashMap;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::TableAlias;
@@ -132,6 +133,11 @@ pub struct BindContext {
     /// It's used to check if the view has a loop dependency.
     pub view_info: Option<(String, String)>,
 
+    /// Names of the SQL table functions being expanded, from the outermost one.
+    ///
+    /// It's used to reject the recursive calls of table functions.
+    pub udtf_names: Vec<String>,
+
     /// Set-returning functions in current context.
     /// The key is the `Expr::to_string` of the function.
     pub srfs: DashMap<String, ScalarExpr>,
@@ -144,6 +150,10 @@ pub struct BindContext {
     pub planning_agg_index: bool,
 
     pub window_definitions: DashMap<String, WindowSpec>,
+
+    /// The column and query of `match(column, query)` in the WHERE clause,
+    /// it's used to resolve `score()` in the select list.
+    pub inverted_index_match: Option<(Expr, Expr)>,
 }
 
 #[derive(Clone, Debug)]
@@ -151,6 +161,8 @@ pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
     pub materialized: bool,
+    // If cte is defined in `WITH RECURSIVE` and references itself
+    pub recursive: bool,
     pub cte_idx: IndexType,
     // Record how many times this cte is used
     pub used_count: usize,
@@ -173,10 +185,12 @@ impl BindContext {
             allow_internal_columns: true,
             in_grouping: false,
             view_info: None,
+            udtf_names: vec![],
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
             window_definitions: DashMap::new(),
+            inverted_index_match: None,
         }
     }
 
@@ -192,10 +206,12 @@ impl BindContext {
             allow_internal_columns: parent.allow_internal_columns,
             in_grouping: false,
             view_info: None,
+            udtf_names: parent.udtf_names.clone(),
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
             window_definitions: DashMap::new(),
+            inverted_index_match: None,
         }
     }
 
@@ -205,6 +221,7 @@ impl BindContext {
         bind_context.parent = self.parent.clone();
         bind_context.cte_name = self.cte_name.clone();
         bind_context.cte_map_ref = self.cte_map_ref.clone();
+        bind_context.udtf_names = self.udtf_names.clone();
         bind_context
     }
 
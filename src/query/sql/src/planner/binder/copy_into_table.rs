@@ -493,7 +493,7 @@ impl<'a> Binder {
 // we can avoid this by specializing the parser.
 // make parse a little more complex, now it is COPY ~ INTO ~ #copy_unit ~ FROM ~ #copy_unit
 // also check_query here may give a more friendly error msg.
-fn check_transform_query(
+pub(in crate::planner::binder) fn check_transform_query(
     query: &Query,
 ) -> Result<(&Vec<SelectTarget>, &FileLocation, &Option<TableAlias>)> {
     if query.offset.is_none()
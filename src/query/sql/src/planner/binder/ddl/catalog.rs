@@ -34,6 +34,7 @@ use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 
 use crate::binder::parse_storage_params_from_uri;
@@ -160,7 +161,30 @@ impl Binder {
                 })
             }
             CatalogType::Iceberg => {
-                let sp = parse_catalog_url(ctx,options.clone()).await?.ok_or_else(|| {
+                let mut options = options.clone();
+
+                // Remove the REST catalog options to avoid unexpected field error in uri location.
+                let rest = match options.remove("type").map(|v| v.to_lowercase()).as_deref() {
+                    None | Some("storage") => None,
+                    Some("rest") => {
+                        let uri = options
+                            .remove("uri")
+                            .ok_or_else(|| ErrorCode::InvalidArgument("expected field: URI"))?;
+                        Some(IcebergRestCatalogOption {
+                            uri,
+                            warehouse: options.remove("warehouse"),
+                            token: options.remove("token"),
+                            credential: options.remove("credential"),
+                        })
+                    }
+                    Some(v) => {
+                        return Err(ErrorCode::InvalidArgument(format!(
+                            "invalid iceberg catalog type '{v}', expected 'storage' or 'rest'"
+                        )));
+                    }
+                };
+
+                let sp = parse_catalog_url(ctx, options).await?.ok_or_else(|| {
                     ErrorCode::InvalidArgument(
                         "expect storage connection but failed to find, seems the url is missing",
                     )
@@ -168,6 +192,7 @@ impl Binder {
 
                 let opt = IcebergCatalogOption {
                     storage_params: Box::new(sp),
+                    rest,
                 };
                 CatalogOption::Iceberg(opt)
             }
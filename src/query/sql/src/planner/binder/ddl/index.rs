@@ -13,11 +13,17 @@
 // limitations under the License.
 
 use databend_common_ast::ast::CreateIndexStmt;
+use databend_common_ast::ast::CreateInvertedIndexStmt;
+use databend_common_ast::ast::CreateVectorIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
+use databend_common_ast::ast::DropInvertedIndexStmt;
+use databend_common_ast::ast::DropVectorIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
+use databend_common_ast::ast::RefreshInvertedIndexStmt;
+use databend_common_ast::ast::RefreshVectorIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
@@ -28,20 +34,35 @@ use databend_common_ast::Visitor;
 use databend_common_ast::VisitorMut;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_functions::scalars::Tokenizer;
+use databend_common_functions::scalars::TOKENIZER_OPTION;
 use databend_common_license::license::Feature::AggregateIndex;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_common_vector::HnswOptions;
+use databend_common_vector::VECTOR_EF_CONSTRUCTION_OPTION;
+use databend_common_vector::VECTOR_METRIC_OPTION;
+use databend_common_vector::VECTOR_M_OPTION;
 use databend_storages_common_table_meta::meta::Location;
 
 use crate::binder::Binder;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::CreateVectorIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
+use crate::plans::DropVectorIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshInvertedIndexPlan;
+use crate::plans::RefreshVectorIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
@@ -346,4 +367,281 @@ impl Binder {
             }
         }
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            create_option,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            sync_creation,
+            index_options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Inverted index only support FUSE engine, but got {}",
+                table_info.engine()
+            )));
+        }
+
+        let schema = table_info.schema();
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column_name = self.normalize_object_identifier(column);
+            let field = schema.field_with_name(&column_name)?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted index only support String type, but the type of column {} is {}",
+                    column_name,
+                    field.data_type()
+                )));
+            }
+            if column_ids.contains(&field.column_id()) {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted index column {} is duplicated",
+                    column_name
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        if let Some(key) = index_options.keys().find(|key| *key != TOKENIZER_OPTION) {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Unknown inverted index option {key}"
+            )));
+        }
+        // Make sure the tokenizer is valid.
+        Tokenizer::from_options(index_options)?;
+
+        let plan = CreateInvertedIndexPlan {
+            create_option: *create_option,
+            catalog,
+            database,
+            table,
+            index_name,
+            column_ids,
+            sync_creation: *sync_creation,
+            index_options: index_options.clone(),
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+        &mut self,
+        stmt: &RefreshInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshInvertedIndexStmt {
+            index_name,
+            catalog,
+            database,
+            table,
+            limit,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table_info
+            .get_table_info()
+            .meta
+            .indexes
+            .get(&index_name)
+            .is_some_and(|index| index.index_type == TableIndexType::Inverted)
+        {
+            return Err(ErrorCode::UnknownIndex(format!(
+                "Inverted index {} does not exist on table {}.{}",
+                index_name, database, table
+            )));
+        }
+
+        let plan = RefreshInvertedIndexPlan {
+            catalog,
+            database,
+            table,
+            index_name,
+            limit: *limit,
+            segment_locs: None,
+        };
+        Ok(Plan::RefreshInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_vector_index(
+        &mut self,
+        stmt: &CreateVectorIndexStmt,
+    ) -> Result<Plan> {
+        let CreateVectorIndexStmt {
+            create_option,
+            index_name,
+            catalog,
+            database,
+            table,
+            column,
+            sync_creation,
+            index_options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index only support FUSE engine, but got {}",
+                table_info.engine()
+            )));
+        }
+
+        let schema = table_info.schema();
+        let column_name = self.normalize_object_identifier(column);
+        let field = schema.field_with_name(&column_name)?;
+        let vector_type =
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)));
+        if field.data_type().remove_nullable() != vector_type {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index only support Array(Float32) type, but the type of column {} is {}",
+                column_name,
+                field.data_type()
+            )));
+        }
+
+        if let Some(key) = index_options.keys().find(|key| {
+            ![
+                VECTOR_METRIC_OPTION,
+                VECTOR_M_OPTION,
+                VECTOR_EF_CONSTRUCTION_OPTION,
+            ]
+            .contains(&key.as_str())
+        }) {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Unknown vector index option {key}"
+            )));
+        }
+        // Make sure the options are valid.
+        HnswOptions::from_options(index_options)?;
+
+        let plan = CreateVectorIndexPlan {
+            create_option: *create_option,
+            catalog,
+            database,
+            table,
+            index_name,
+            column_id: field.column_id(),
+            sync_creation: *sync_creation,
+            index_options: index_options.clone(),
+        };
+        Ok(Plan::CreateVectorIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_vector_index(
+        &mut self,
+        stmt: &DropVectorIndexStmt,
+    ) -> Result<Plan> {
+        let DropVectorIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = DropVectorIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropVectorIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_vector_index(
+        &mut self,
+        stmt: &RefreshVectorIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshVectorIndexStmt {
+            index_name,
+            catalog,
+            database,
+            table,
+            limit,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table_info
+            .get_table_info()
+            .meta
+            .indexes
+            .get(&index_name)
+            .is_some_and(|index| index.index_type == TableIndexType::Vector)
+        {
+            return Err(ErrorCode::UnknownIndex(format!(
+                "Vector index {} does not exist on table {}.{}",
+                index_name, database, table
+            )));
+        }
+
+        let plan = RefreshVectorIndexPlan {
+            catalog,
+            database,
+            table,
+            index_name,
+            limit: *limit,
+            segment_locs: None,
+        };
+        Ok(Plan::RefreshVectorIndex(Box::new(plan)))
+    }
 }
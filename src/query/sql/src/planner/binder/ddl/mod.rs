@@ -21,6 +21,7 @@ mod database;
 mod index;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod role;
 mod share;
 mod stage;
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+
+use crate::binder::copy_into_table::check_transform_query;
+use crate::binder::resolve_file_location;
+use crate::binder::Binder;
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        let (catalog_name, database_name, table_name) = self.normalize_object_identifier_triple(
+            &copy_stmt.dst.catalog,
+            &copy_stmt.dst.database,
+            &copy_stmt.dst.table,
+        );
+        // Check the target table and the source location up front, the files are
+        // only listed when the pipe runs.
+        self.ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let location = match &copy_stmt.src {
+            CopyIntoTableSource::Location(location) => location,
+            CopyIntoTableSource::Query(query) => check_transform_query(query)?.1,
+        };
+        resolve_file_location(self.ctx.as_ref(), location).await?;
+
+        if copy_stmt.force {
+            return Err(ErrorCode::SemanticError(
+                "pipe can not be created with FORCE = TRUE, which would load the same files repeatedly",
+            ));
+        }
+
+        // The pipe may be run from another session, so the current catalog and
+        // database are saved with the statement.
+        let mut copy_stmt = copy_stmt.clone();
+        let dst = &mut copy_stmt.dst;
+        dst.catalog
+            .get_or_insert_with(|| Identifier::from_name(catalog_name));
+        dst.database
+            .get_or_insert_with(|| Identifier::from_name(database_name));
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreatePipePlan {
+            create_option: CreateOption::CreateIfNotExists(*if_not_exists),
+            tenant,
+            name: name.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.clone(),
+            copy_stmt: copy_stmt.to_string(),
+        };
+        Ok(Plan::CreatePipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            options: options.clone(),
+        };
+        Ok(Plan::AlterPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+
+        let plan = DescPipePlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPipe(Box::new(plan)))
+    }
+}
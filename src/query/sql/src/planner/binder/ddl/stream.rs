@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::AlterStreamStmt;
 use databend_common_ast::ast::CreateStreamStmt;
 use databend_common_ast::ast::DescribeStreamStmt;
 use databend_common_ast::ast::DropStreamStmt;
@@ -25,6 +26,7 @@ use log::debug;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
+use crate::plans::AlterStreamPlan;
 use crate::plans::CreateStreamPlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::Plan;
@@ -37,6 +39,7 @@ impl Binder {
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_stream(
         &mut self,
+        bind_context: &mut BindContext,
         stmt: &CreateStreamStmt,
     ) -> Result<Plan> {
         let CreateStreamStmt {
@@ -61,16 +64,10 @@ impl Binder {
             .unwrap_or_else(|| self.ctx.get_current_database());
         let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
 
-        let navigation = stream_point.as_ref().map(|point| match point {
-            StreamPoint::AtStream { database, name } => {
-                let database = database
-                    .as_ref()
-                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
-                    .unwrap_or_else(|| self.ctx.get_current_database());
-                let name = normalize_identifier(name, &self.name_resolution_ctx).name;
-                StreamNavigation::AtStream { database, name }
-            }
-        });
+        let navigation = match stream_point {
+            Some(stream_point) => Some(self.bind_stream_point(bind_context, stream_point).await?),
+            None => None,
+        };
 
         let plan = CreateStreamPlan {
             create_option: *create_option,
@@ -87,6 +84,58 @@ impl Binder {
         Ok(Plan::CreateStream(plan.into()))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_stream(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &AlterStreamStmt,
+    ) -> Result<Plan> {
+        let AlterStreamStmt {
+            if_exists,
+            catalog,
+            database,
+            stream,
+            stream_point,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, stream_name) =
+            self.normalize_object_identifier_triple(catalog, database, stream);
+        let navigation = self.bind_stream_point(bind_context, stream_point).await?;
+
+        let plan = AlterStreamPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            stream_name,
+            navigation,
+        };
+        Ok(Plan::AlterStream(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    async fn bind_stream_point(
+        &mut self,
+        bind_context: &mut BindContext,
+        stream_point: &StreamPoint,
+    ) -> Result<StreamNavigation> {
+        match stream_point {
+            StreamPoint::AtStream { database, name } => {
+                let database = database
+                    .as_ref()
+                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_database());
+                let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                Ok(StreamNavigation::AtStream { database, name })
+            }
+            StreamPoint::AtPoint(point) => {
+                let point = self.resolve_data_travel_point(bind_context, point).await?;
+                Ok(StreamNavigation::AtPoint(point))
+            }
+        }
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_stream(
         &mut self,
@@ -32,9 +32,11 @@ use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::ModifyColumnAction;
 use databend_common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use databend_common_ast::ast::OptimizeTableStmt;
+use databend_common_ast::ast::PartitionSpec;
 use databend_common_ast::ast::RenameTableStmt;
 use databend_common_ast::ast::ShowCreateTableStmt;
 use databend_common_ast::ast::ShowDropTablesStmt;
@@ -53,6 +55,7 @@ use databend_common_ast::ast::VacuumTemporaryFiles;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::walk_expr_mut;
+use databend_common_catalog::table::Table;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -99,12 +102,14 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTablePartitionPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
@@ -113,6 +118,7 @@ use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RecoverTablePartitionsPlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
@@ -1009,9 +1015,95 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddPartition {
+                if_not_exists,
+                partition,
+                location,
+            } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let partition = self
+                    .bind_partition_spec(tbl.as_ref(), partition)?
+                    .into_iter()
+                    .map(|(column, value)| Ok((column, partition_value_to_string(value)?)))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(Plan::AddTablePartition(Box::new(AddTablePartitionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    if_not_exists: *if_not_exists,
+                    partition,
+                    location: location.clone(),
+                })))
+            }
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let partition = self
+                    .bind_partition_spec(tbl.as_ref(), partition)?
+                    .into_iter()
+                    .map(|(column, value)| Ok((column, partition_value_to_string(value)?)))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    if_exists: *if_exists,
+                    partition,
+                })))
+            }
+            AlterTableAction::RecoverPartitions => Ok(Plan::RecoverTablePartitions(Box::new(
+                RecoverTablePartitionsPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                },
+            ))),
         }
     }
 
+    /// Check the columns of `PARTITION (...)` are the partition columns of the table,
+    /// and normalize the column names.
+    pub(in crate::planner::binder) fn bind_partition_spec<'a>(
+        &self,
+        table: &dyn Table,
+        spec: &'a PartitionSpec,
+    ) -> Result<Vec<(String, &'a Literal)>> {
+        let partition_keys = table.partition_keys();
+        if partition_keys.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table {} is not partitioned",
+                table.name()
+            )));
+        }
+
+        let mut values = Vec::with_capacity(spec.values.len());
+        for (column, value) in spec.values.iter() {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if !partition_keys.contains(&column) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column {} is not a partition column of table {}",
+                    column,
+                    table.name()
+                )));
+            }
+            if values.iter().any(|(c, _)| c == &column) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "partition column {} is specified more than once",
+                    column
+                )));
+            }
+            values.push((column, value));
+        }
+        Ok(values)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_rename_table(
         &mut self,
@@ -1549,3 +1641,15 @@ impl Binder {
             .unwrap_or(true)
     }
 }
+
+/// The raw value of a partition column, as written in the partition directory name.
+fn partition_value_to_string(value: &Literal) -> Result<String> {
+    match value {
+        Literal::String(v) => Ok(v.clone()),
+        Literal::Boolean(v) => Ok(v.to_string()),
+        Literal::Null => Err(ErrorCode::SemanticError(
+            "partition value can not be NULL".to_string(),
+        )),
+        other => Ok(other.to_string()),
+    }
+}
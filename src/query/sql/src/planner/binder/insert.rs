@@ -79,6 +79,7 @@ impl Binder {
             catalog,
             database,
             table,
+            partition,
             columns,
             source,
             overwrite,
@@ -91,7 +92,39 @@ impl Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
         let table_id = table.get_id();
-        let schema = self.schema_project(&table.schema(), columns)?;
+        let mut schema = self.schema_project(&table.schema(), columns)?;
+
+        // The partition columns of `PARTITION (...)` are not provided by the source.
+        let partition = match partition {
+            Some(partition) => {
+                let partition = self
+                    .bind_partition_spec(table.as_ref(), partition)?
+                    .into_iter()
+                    .map(|(column, value)| (column, value.to_string()))
+                    .collect::<Vec<_>>();
+                if !columns.is_empty() {
+                    if let Some(field) = schema
+                        .fields()
+                        .iter()
+                        .find(|f| partition.iter().any(|(c, _)| c == f.name()))
+                    {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "partition column {} can not be in the column list of insert",
+                            field.name()
+                        )));
+                    }
+                }
+                let fields = schema
+                    .fields()
+                    .iter()
+                    .filter(|f| partition.iter().all(|(c, _)| c != f.name()))
+                    .cloned()
+                    .collect();
+                schema = TableSchemaRefExt::create(fields);
+                partition
+            }
+            None => vec![],
+        };
 
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Streaming {
@@ -125,6 +158,11 @@ impl Binder {
                 let values_str = rest_str.trim_end_matches(';').trim_start().to_owned();
                 match self.ctx.get_stage_attachment() {
                     Some(attachment) => {
+                        if !partition.is_empty() {
+                            return Err(ErrorCode::Unimplemented(
+                                "Cannot insert into a partition with stage attachment",
+                            ));
+                        }
                         return self
                             .bind_copy_from_attachment(
                                 bind_context,
@@ -150,7 +188,9 @@ impl Binder {
                 let statement = Statement::Query(query);
                 let select_plan = self.bind_statement(bind_context, &statement).await?;
                 let opt_ctx = OptimizerContext::new(self.ctx.clone(), self.metadata.clone())
-                    .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty());
+                    .with_enable_distributed_optimization(
+                        !self.ctx.get_cluster().is_empty() && table.support_distributed_insert(),
+                    );
 
                 if let Plan::Query { s_expr, .. } = &select_plan {
                     if !self.check_sexpr_top(s_expr)? {
@@ -171,6 +211,7 @@ impl Binder {
             table: table_name,
             table_id,
             schema,
+            partition,
             overwrite: *overwrite,
             source: input_source?,
         };
@@ -37,6 +37,7 @@ mod presign;
 mod project;
 mod project_set;
 mod qualify;
+mod recursive_cte;
 mod replace;
 mod scalar;
 mod scalar_common;
@@ -0,0 +1,402 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperator;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::walk_table_reference;
+use databend_common_ast::Visitor;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use parking_lot::RwLock;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::CteInfo;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::CteScan;
+use crate::plans::EvalScalar;
+use crate::plans::RecursiveCte;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::NameResolutionContext;
+
+// The recursive term is re-bound when its output types are wider than the working table,
+// this is the maximum number of attempts before giving up.
+const MAX_RECURSIVE_CTE_TYPE_COERCION: usize = 8;
+
+/// Count the references of a cte in a query.
+pub struct CteReferenceCounter<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    pub count: usize,
+}
+
+impl<'a> CteReferenceCounter<'a> {
+    pub fn new(cte_name: &'a str, name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            cte_name,
+            name_resolution_ctx,
+            count: 0,
+        }
+    }
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceCounter<'a> {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table
+        {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.count += 1;
+            }
+        }
+        walk_table_reference(self, table);
+    }
+}
+
+impl Binder {
+    /// Check if the cte defined in `WITH RECURSIVE` references itself.
+    pub(crate) fn is_recursive_cte(&self, cte_name: &str, query: &Query) -> bool {
+        let mut counter = CteReferenceCounter::new(cte_name, &self.name_resolution_ctx);
+        counter.visit_query(query);
+        counter.count > 0
+    }
+
+    /// Bind a recursive cte, which must be in the form of
+    /// `anchor UNION [ALL] recursive`, and only the recursive term references the cte itself.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let (anchor, recursive, all) = match &query.body {
+            SetExpr::SetOperation(set_operation)
+                if set_operation.op == SetOperator::Union
+                    && query.with.is_none()
+                    && query.order_by.is_empty()
+                    && query.limit.is_empty()
+                    && query.offset.is_none() =>
+            {
+                (
+                    set_operation.left.as_ref(),
+                    set_operation.right.as_ref(),
+                    set_operation.all,
+                )
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Recursive CTE '{table_name}' must be in the form of `anchor UNION [ALL] recursive term` without WITH, ORDER BY or LIMIT"
+                ))
+                .set_span(span));
+            }
+        };
+
+        let mut anchor_counter = CteReferenceCounter::new(table_name, &self.name_resolution_ctx);
+        anchor_counter.visit_set_expr(anchor);
+        let mut recursive_counter = CteReferenceCounter::new(table_name, &self.name_resolution_ctx);
+        recursive_counter.visit_set_expr(recursive);
+        if anchor_counter.count != 0 || recursive_counter.count != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "Recursive CTE '{table_name}' must reference itself exactly once in the recursive term, and not in the anchor term"
+            ))
+            .set_span(span));
+        }
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        new_bind_context.cte_name = Some(table_name.to_string());
+        new_bind_context.cte_map_ref = Box::default();
+
+        // 1. Bind the anchor term.
+        let (anchor_expr, anchor_ctx) = self
+            .bind_set_expr(&mut new_bind_context.clone(), anchor, &[], 0)
+            .await?;
+        let mut cols_alias = cte_info.columns_alias.clone();
+        if cols_alias.len() > anchor_ctx.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "The CTE '{}' has {} columns, but {} aliases were provided. Ensure the number of aliases matches the number of columns in the CTE.",
+                table_name,
+                anchor_ctx.columns.len(),
+                cols_alias.len()
+            ))
+            .set_span(span));
+        }
+        for column in anchor_ctx.columns.iter().skip(cols_alias.len()) {
+            cols_alias.push(column.column_name.clone());
+        }
+        let anchor_stat_info = RelExpr::with_s_expr(&anchor_expr).derive_cardinality()?;
+
+        // 2. Bind the recursive term with the working table, the columns of the working table
+        // start with the widened types of the anchor term, and are widened again until the
+        // output types of the recursive term fit into them.
+        let mut data_types = anchor_ctx
+            .columns
+            .iter()
+            .map(|column| widen_recursive_type(&column.data_type))
+            .collect::<Vec<_>>();
+        let mut attempts = 0;
+        let (output_columns, recursive_expr, recursive_ctx) = loop {
+            attempts += 1;
+            let output_columns = cols_alias
+                .iter()
+                .zip(data_types.iter())
+                .map(|(name, data_type)| {
+                    let index = self
+                        .metadata
+                        .write()
+                        .add_derived_column(name.clone(), data_type.clone());
+                    ColumnBindingBuilder::new(
+                        name.clone(),
+                        index,
+                        Box::new(data_type.clone()),
+                        Visibility::Visible,
+                    )
+                    .table_name(Some(table_name.to_string()))
+                    .build()
+                })
+                .collect::<Vec<_>>();
+            self.ctes_map
+                .entry(table_name.to_string())
+                .and_modify(|cte_info| {
+                    cte_info.stat_info = Some(anchor_stat_info.clone());
+                    cte_info.columns = output_columns.clone();
+                });
+
+            let (recursive_expr, recursive_ctx) = self
+                .bind_set_expr(&mut new_bind_context.clone(), recursive, &[], 0)
+                .await?;
+            if recursive_ctx.columns.len() != output_columns.len() {
+                return Err(ErrorCode::SemanticError(
+                    "SetOperation must have the same number of columns",
+                )
+                .set_span(recursive.span()));
+            }
+
+            let mut coercion_types = Vec::with_capacity(data_types.len());
+            for (output_col, recursive_col) in
+                output_columns.iter().zip(recursive_ctx.columns.iter())
+            {
+                let data_type = common_super_type(
+                    *output_col.data_type.clone(),
+                    *recursive_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "Recursive CTE's types cannot be matched, anchor column {:?}, type: {:?}, recursive column {:?}, type: {:?}",
+                        output_col.column_name,
+                        output_col.data_type,
+                        recursive_col.column_name,
+                        recursive_col.data_type
+                    ))
+                    .set_span(recursive.span())
+                })?;
+                coercion_types.push(data_type);
+            }
+            if coercion_types == data_types {
+                break (output_columns, recursive_expr, recursive_ctx);
+            }
+            if attempts >= MAX_RECURSIVE_CTE_TYPE_COERCION {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Cannot determine the column types of recursive CTE '{table_name}'"
+                ))
+                .set_span(span));
+            }
+            data_types = coercion_types;
+        };
+
+        // 3. Project the anchor term to the output columns,
+        // and the recursive term to new columns with the same types.
+        let anchor_items = anchor_ctx
+            .columns
+            .iter()
+            .zip(output_columns.iter())
+            .map(|(column, output_column)| ScalarItem {
+                scalar: wrap_cast_column(span, column, &output_column.data_type),
+                index: output_column.index,
+            })
+            .collect::<Vec<_>>();
+        let mut recursive_items = Vec::with_capacity(output_columns.len());
+        let mut recursive_output_columns = Vec::with_capacity(output_columns.len());
+        for (column, output_column) in recursive_ctx.columns.iter().zip(output_columns.iter()) {
+            let index = self.metadata.write().add_derived_column(
+                output_column.column_name.clone(),
+                *output_column.data_type.clone(),
+            );
+            recursive_items.push(ScalarItem {
+                scalar: wrap_cast_column(span, column, &output_column.data_type),
+                index,
+            });
+            recursive_output_columns.push(
+                ColumnBindingBuilder::new(
+                    output_column.column_name.clone(),
+                    index,
+                    output_column.data_type.clone(),
+                    Visibility::Visible,
+                )
+                .build(),
+            );
+        }
+        let anchor_expr = SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: anchor_items,
+                }
+                .into(),
+            ),
+            Arc::new(anchor_expr),
+        );
+        let recursive_expr = SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: recursive_items,
+                }
+                .into(),
+            ),
+            Arc::new(recursive_expr),
+        );
+
+        let s_expr = SExpr::create_binary(
+            Arc::new(
+                RecursiveCte {
+                    cte_idx: cte_info.cte_idx,
+                    distinct: !all,
+                    output_columns: output_columns.clone(),
+                    recursive_output_columns,
+                }
+                .into(),
+            ),
+            Arc::new(anchor_expr),
+            Arc::new(recursive_expr),
+        );
+
+        // 4. Resolve the alias of the cte reference.
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let mut res_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        for (idx, mut column) in output_columns.into_iter().enumerate() {
+            if let Some(col_alias) = alias.as_ref().and_then(|alias| alias.columns.get(idx)) {
+                column.column_name = col_alias.name.clone();
+            }
+            column.database_name = None;
+            column.table_name = Some(alias_table_name.clone());
+            res_bind_context.add_column_binding(column);
+        }
+        Ok((s_expr, res_bind_context))
+    }
+
+    /// Bind the reference to a recursive cte inside its recursive term,
+    /// which reads the rows produced by the previous iteration.
+    pub(crate) fn bind_recursive_cte_working_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        cte_info: &CteInfo,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let blocks = Arc::new(RwLock::new(vec![]));
+        self.ctx
+            .set_materialized_cte((cte_info.cte_idx, 0), blocks)?;
+
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut fields = vec![];
+        let mut offsets = vec![];
+        for (idx, column) in cte_info.columns.iter().enumerate() {
+            fields.push(DataField::new(
+                column.index.to_string().as_str(),
+                *column.data_type.clone(),
+            ));
+            offsets.push(idx);
+            let mut column = column.clone();
+            column.database_name = None;
+            column.table_name = Some(alias_table_name.clone());
+            new_bind_context.add_column_binding(column);
+        }
+        let cte_scan = SExpr::create_leaf(Arc::new(
+            CteScan {
+                cte_idx: (cte_info.cte_idx, 0),
+                fields,
+                offsets,
+                // It is safe to unwrap here because the stat info is set before binding the recursive term.
+                stat: cte_info.stat_info.clone().unwrap(),
+            }
+            .into(),
+        ));
+        Ok((cte_scan, new_bind_context))
+    }
+}
+
+// Widen the numeric types of the anchor term, so that expressions like `n + 1`
+// in the recursive term don't change the type of the working table in every iteration.
+fn widen_recursive_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Nullable(ty) => DataType::Nullable(Box::new(widen_recursive_type(ty))),
+        DataType::Number(ty) if ty.is_float() => DataType::Number(NumberDataType::Float64),
+        DataType::Number(ty) if ty.is_signed() => DataType::Number(NumberDataType::Int64),
+        DataType::Number(_) => DataType::Number(NumberDataType::UInt64),
+        _ => data_type.clone(),
+    }
+}
+
+fn wrap_cast_column(span: Span, column: &ColumnBinding, target_type: &DataType) -> ScalarExpr {
+    let column_ref: ScalarExpr = BoundColumnRef {
+        span,
+        column: column.clone(),
+    }
+    .into();
+    if column.data_type.as_ref() == target_type {
+        column_ref
+    } else {
+        CastExpr {
+            span,
+            is_try: false,
+            argument: Box::new(column_ref),
+            target_type: Box::new(target_type.clone()),
+        }
+        .into()
+    }
+}
@@ -27,6 +27,7 @@ use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Join;
 use databend_common_ast::ast::JoinCondition;
 use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Lambda;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::OrderByExpr;
 use databend_common_ast::ast::Query;
@@ -35,6 +36,8 @@ use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::SetOperator;
 use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::Window as ASTWindow;
+use databend_common_ast::walk_expr;
 use databend_common_ast::Visitor;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -69,6 +72,11 @@ use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::IndexType;
@@ -161,6 +169,13 @@ impl Binder {
         // This operation should be before `normalize_select_list` because window functions can be used in select list.
         self.analyze_window_definition(&mut from_context, &stmt.window_list)?;
 
+        // `score()` in select list refers to the `match` in WHERE clause.
+        if let Some(selection) = &stmt.selection {
+            let mut inverted_index_match = InvertedIndexMatch::default();
+            inverted_index_match.visit_expr(selection);
+            from_context.inverted_index_match = inverted_index_match.column_and_query;
+        }
+
         // Generate a analyzed select list with from context
         let mut select_list = self
             .normalize_select_list(&mut from_context, &stmt.select_list)
@@ -356,6 +371,7 @@ impl Binder {
                         "duplicate cte {table_name}"
                     )));
                 }
+                let recursive = with.recursive && self.is_recursive_cte(&table_name, &cte.query);
                 let cte_info = CteInfo {
                     columns_alias: cte
                         .alias
@@ -364,7 +380,9 @@ impl Binder {
                         .map(|c| normalize_identifier(c, &self.name_resolution_ctx).name)
                         .collect(),
                     query: *cte.query.clone(),
-                    materialized: cte.materialized,
+                    // Recursive cte is always materialized, and evaluated iteratively.
+                    materialized: cte.materialized || recursive,
+                    recursive,
                     cte_idx: idx,
                     used_count: 0,
                     stat_info: None,
@@ -483,7 +501,7 @@ impl Binder {
         }
 
         match (op, all) {
-            (SetOperator::Intersect, false) => {
+            (SetOperator::Intersect, _) => {
                 // Transfer Intersect to Semi join
                 self.bind_intersect(
                     left.span(),
@@ -492,9 +510,10 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !all,
                 )
             }
-            (SetOperator::Except, false) => {
+            (SetOperator::Except, _) => {
                 // Transfer Except to Anti join
                 self.bind_except(
                     left.span(),
@@ -503,6 +522,7 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !all,
                 )
             }
             (SetOperator::Union, true) => self.bind_union(
@@ -523,9 +543,6 @@ impl Binder {
                 right_expr,
                 true,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
@@ -596,6 +613,7 @@ impl Binder {
         Ok((new_expr, new_bind_context))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect(
         &mut self,
         left_span: Span,
@@ -604,6 +622,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -613,9 +632,11 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftSemi,
+            distinct,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_except(
         &mut self,
         left_span: Span,
@@ -624,6 +645,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -633,6 +655,7 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftAnti,
+            distinct,
         )
     }
 
@@ -646,16 +669,44 @@ impl Binder {
         left_expr: SExpr,
         right_expr: SExpr,
         join_type: JoinType,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let left_expr = self.bind_distinct(
-            left_span,
-            &left_context,
-            left_context.all_column_bindings(),
-            &mut HashMap::new(),
-            left_expr,
-        )?;
-        let mut left_conditions = Vec::with_capacity(left_context.columns.len());
-        let mut right_conditions = Vec::with_capacity(right_context.columns.len());
+        let mut left_conditions = Vec::with_capacity(left_context.columns.len() + 1);
+        let mut right_conditions = Vec::with_capacity(right_context.columns.len() + 1);
+        let (left_expr, right_expr) = if distinct {
+            let left_expr = self.bind_distinct(
+                left_span,
+                &left_context,
+                left_context.all_column_bindings(),
+                &mut HashMap::new(),
+                left_expr,
+            )?;
+            (left_expr, right_expr)
+        } else {
+            // Number the duplicates of each row on both sides, then the n-th duplicate on
+            // the left side only matches the n-th duplicate on the right side. So a row
+            // appearing m times on the left and n times on the right is returned min(m, n)
+            // times by INTERSECT ALL and max(m - n, 0) times by EXCEPT ALL.
+            let (left_expr, left_row_number) =
+                self.bind_duplicate_row_number(left_span, &left_context, left_expr)?;
+            let (right_expr, right_row_number) =
+                self.bind_duplicate_row_number(right_span, &right_context, right_expr)?;
+            left_conditions.push(
+                BoundColumnRef {
+                    span: left_span,
+                    column: left_row_number,
+                }
+                .into(),
+            );
+            right_conditions.push(
+                BoundColumnRef {
+                    span: right_span,
+                    column: right_row_number,
+                }
+                .into(),
+            );
+            (left_expr, right_expr)
+        };
         assert_eq!(left_context.columns.len(), right_context.columns.len());
         for (left_column, right_column) in left_context
             .columns
@@ -687,6 +738,58 @@ impl Binder {
         Ok((s_expr, left_context))
     }
 
+    /// Add `row_number() OVER (PARTITION BY <all columns>)` to `s_expr`, which numbers
+    /// the duplicates of each row.
+    fn bind_duplicate_row_number(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        s_expr: SExpr,
+    ) -> Result<(SExpr, ColumnBinding)> {
+        let func = WindowFuncType::RowNumber;
+        let data_type = func.return_type();
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column("_row_number".to_string(), data_type.clone());
+        let column = ColumnBindingBuilder::new(
+            "_row_number".to_string(),
+            index,
+            Box::new(data_type),
+            Visibility::InVisible,
+        )
+        .build();
+
+        let partition_by = bind_context
+            .columns
+            .iter()
+            .map(|column| ScalarItem {
+                scalar: BoundColumnRef {
+                    span,
+                    column: column.clone(),
+                }
+                .into(),
+                index: column.index,
+            })
+            .collect();
+        let window = Window {
+            span,
+            index,
+            function: func,
+            arguments: vec![],
+            partition_by,
+            order_by: vec![],
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::CurrentRow,
+            },
+            limit: None,
+        };
+        let s_expr = SExpr::create_unary(Arc::new(window.into()), Arc::new(s_expr));
+        Ok((s_expr, column))
+    }
+
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
     fn coercion_union_type(
@@ -1175,3 +1278,34 @@ impl<'a> Visitor<'a> for MaxColumnPosition {
         }
     }
 }
+
+/// Find the first `match(column, query)` in an expression.
+#[derive(Default)]
+pub struct InvertedIndexMatch {
+    pub column_and_query: Option<(Expr, Expr)>,
+}
+
+impl<'a> Visitor<'a> for InvertedIndexMatch {
+    #[allow(clippy::too_many_arguments)]
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        name: &'a Identifier,
+        args: &'a [Expr],
+        _params: &'a [Expr],
+        _over: &'a Option<ASTWindow>,
+        _lambda: &'a Option<Lambda>,
+    ) {
+        if self.column_and_query.is_some() {
+            return;
+        }
+        if name.name.eq_ignore_ascii_case("match") && args.len() == 2 {
+            self.column_and_query = Some((args[0].clone(), args[1].clone()));
+            return;
+        }
+        for arg in args {
+            walk_expr(self, arg);
+        }
+    }
+}
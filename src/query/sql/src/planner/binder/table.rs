@@ -69,6 +69,9 @@ use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_types::MetaId;
+use databend_common_pipeline_sources::input_formats::read_avro_schema;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_orc_schema;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
@@ -194,6 +197,18 @@ impl Binder {
         // Check and bind common table expression
         let ctes_map = self.ctes_map.clone();
         if let Some(cte_info) = ctes_map.get(&table_name) {
+            // The recursive cte is being bound, so the reference is to its working table.
+            if cte_info.recursive
+                && !cte_info.columns.is_empty()
+                && !self.m_cte_bound_ctx.contains_key(&cte_info.cte_idx)
+            {
+                return self.bind_recursive_cte_working_table(
+                    bind_context,
+                    cte_info,
+                    &table_name,
+                    alias,
+                );
+            }
             if bind_cte {
                 return if !cte_info.materialized {
                     self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
@@ -365,7 +380,7 @@ impl Binder {
                                     'INSERT' as change$action, \
                                     false as change$is_update, \
                                     if(is_not_null(_origin_block_id), \
-                                       concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
+                                       concat(replace(to_string(to_uuid(_origin_block_id)), '-', ''), lpad(hex(_origin_block_row_num), 6, '0')), \
                                        {append_alias}._base_row_id \
                                     ) as change$row_id \
                              from {database}.{table_name} as {append_alias} \
@@ -375,6 +390,12 @@ impl Binder {
                         )
                     }
                     StreamMode::Standard => {
+                        // The scans aliased by `_change_insert` and `_change_delete` only read
+                        // the blocks added and removed since the offset of the stream, see
+                        // `StreamTable::collect_incremental_blocks`. A block rewritten by a
+                        // mutation also moves its untouched rows, the join pairs them by their
+                        // origin row ids so that only the rows with a newer version are reported
+                        // as changes.
                         let a_table_alias = format!("_change_insert${}", suffix);
                         let a_cols = cols.join(", ");
 
@@ -385,6 +406,8 @@ impl Binder {
                             .collect::<Vec<_>>()
                             .join(", ");
 
+                        // The rows never rewritten (`_origin_block_id` is null) can't match any
+                        // deleted row, so only the rewritten rows are joined with the deleted rows.
                         format!(
                             "with _change({a_cols}, change$action, change$row_id, \
                                           {d_cols}, d_change$action, d_change$row_id) as materialized \
@@ -394,18 +417,16 @@ impl Binder {
                                     select *, \
                                            _row_version, \
                                            'INSERT' as change$action, \
-                                           if(is_not_null(_origin_block_id), \
-                                              concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
-                                              {a_table_alias}._base_row_id \
-                                           ) as change$row_id \
+                                           concat(replace(to_string(to_uuid(_origin_block_id)), '-', ''), lpad(hex(_origin_block_row_num), 6, '0')) as change$row_id \
                                     from {database}.{table_name} as {a_table_alias} \
+                                    where is_not_null(_origin_block_id) \
                                 ) as A \
                                 FULL OUTER JOIN ( \
                                     select *, \
                                            _row_version, \
                                            'DELETE' as change$action, \
                                            if(is_not_null(_origin_block_id), \
-                                              concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
+                                              concat(replace(to_string(to_uuid(_origin_block_id)), '-', ''), lpad(hex(_origin_block_row_num), 6, '0')), \
                                               {d_table_alias}._base_row_id \
                                            ) as change$row_id \
                                     from {database}.{table_name} as {d_table_alias} \
@@ -425,7 +446,14 @@ impl Binder {
                                    d_change$row_id, \
                                    change$action is not null as change$is_update \
                             from _change \
-                            where d_change$action is not null",
+                            where d_change$action is not null \
+                            union all \
+                            select {a_cols}, \
+                                   'INSERT' as change$action, \
+                                   {a_table_alias}._base_row_id as change$row_id, \
+                                   false as change$is_update \
+                            from {database}.{table_name} as {a_table_alias} \
+                            where not(is_not_null(_origin_block_id))",
                         )
                     }
                 };
@@ -732,6 +760,13 @@ impl Binder {
                 .await;
         }
 
+        if let Some(subquery) = self
+            .rewrite_udtf_call(bind_context, span, false, name, params, named_params, alias)
+            .await?
+        {
+            return self.bind_udtf_subquery(bind_context, name, &subquery).await;
+        }
+
         let mut scalar_binder = ScalarBinder::new(
             bind_context,
             self.ctx.clone(),
@@ -852,6 +887,7 @@ impl Binder {
                     .clone()
                     .unwrap_or_else(|| Box::new(BindContext::new())),
             );
+            new_bind_context.udtf_names = bind_context.udtf_names.clone();
             self.bind_query(&mut new_bind_context, subquery).await?
         };
 
@@ -1044,6 +1080,26 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
+            FileFormatParams::Avro(..) | FileFormatParams::Orc(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = files_info.first_file(&operator).await?;
+                let schema = match stage_info.file_format_params {
+                    FileFormatParams::Avro(..) => {
+                        read_avro_schema(&operator, &first_file.path, Some(first_file.size)).await?
+                    }
+                    _ => read_orc_schema(&operator, &first_file.path).await?,
+                };
+                let schema = Arc::new(schema);
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
             FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
                 let max_column_position = self.metadata.read().get_max_column_position();
                 if max_column_position == 0 {
@@ -1080,7 +1136,7 @@ impl Binder {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, Avro, ORC, CSV, and TSV. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
@@ -1166,7 +1222,30 @@ impl Binder {
                     result_ctx = ctx;
                 }
                 _ => {
-                    if join.right.is_lateral_table_function() {
+                    // A lateral SQL table function is bound as a lateral subquery.
+                    let udtf_ref = match &*join.right {
+                        TableReference::TableFunction {
+                            span,
+                            lateral: true,
+                            name,
+                            params,
+                            named_params,
+                            alias,
+                        } => self
+                            .rewrite_udtf_call(
+                                &result_ctx,
+                                span,
+                                true,
+                                name,
+                                params,
+                                named_params,
+                                alias,
+                            )
+                            .await?
+                            .map(|subquery| (name, subquery)),
+                        _ => None,
+                    };
+                    if udtf_ref.is_none() && join.right.is_lateral_table_function() {
                         let (expr, ctx) = self
                             .bind_lateral_table_function(
                                 &mut result_ctx,
@@ -1177,8 +1256,13 @@ impl Binder {
                         result_expr = expr;
                         result_ctx = ctx;
                     } else {
-                        let (right_expr, right_ctx) =
-                            self.bind_single_table(&mut result_ctx, &join.right).await?;
+                        let (right_expr, right_ctx) = match &udtf_ref {
+                            Some((name, subquery)) => {
+                                self.bind_udtf_subquery(&mut result_ctx, name, subquery)
+                                    .await?
+                            }
+                            None => self.bind_single_table(&mut result_ctx, &join.right).await?,
+                        };
                         let (join_expr, ctx) = self
                             .bind_join(
                                 current_ctx,
@@ -1245,11 +1329,13 @@ impl Binder {
             cte_map_ref: Box::default(),
             in_grouping: false,
             view_info: None,
+            udtf_names: bind_context.udtf_names.clone(),
             srfs: Default::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
             allow_internal_columns: true,
             window_definitions: DashMap::new(),
+            inverted_index_match: None,
         };
 
         let (s_expr, mut res_bind_context) = self
@@ -1300,9 +1386,13 @@ impl Binder {
         span: &Span,
     ) -> Result<(SExpr, BindContext)> {
         let new_bind_context = if cte_info.used_count == 0 {
-            let (cte_s_expr, cte_bind_ctx) = self
-                .bind_cte(*span, bind_context, table_name, alias, cte_info)
-                .await?;
+            let (cte_s_expr, cte_bind_ctx) = if cte_info.recursive {
+                self.bind_recursive_cte(*span, bind_context, table_name, alias, cte_info)
+                    .await?
+            } else {
+                self.bind_cte(*span, bind_context, table_name, alias, cte_info)
+                    .await?
+            };
             let stat_info = RelExpr::with_s_expr(&cte_s_expr).derive_cardinality()?;
             self.ctes_map
                 .entry(table_name.clone())
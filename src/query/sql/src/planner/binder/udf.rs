@@ -14,25 +14,57 @@
 
 use chrono::Utc;
 use databend_common_ast::ast::AlterUDFStmt;
+use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::CreateUDFStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UDFDefinition;
+use databend_common_ast::parser::expr::type_name;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::run_parser;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::walk_table_reference_mut;
+use databend_common_ast::Dialect;
+use databend_common_ast::VisitorMut;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_exception::Span;
 use databend_common_expression::types::DataType;
 use databend_common_expression::udf_client::UDFFlightClient;
+use databend_common_expression::FunctionKind;
+use databend_common_functions::scalars::check_wasm_udf;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::UDAFServer;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
+use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
 use databend_common_meta_app::principal::UserDefinedFunction;
+use databend_common_meta_app::principal::UDTF;
+use databend_common_storage::init_stage_operator;
+use databend_common_users::UserApiProvider;
 
+use crate::binder::resolve_stage_location;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
 use crate::planner::resolve_type_name;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::Plan;
+use crate::BindContext;
 use crate::Binder;
+use crate::NameResolutionContext;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_udf_definition(
@@ -68,6 +100,13 @@ impl Binder {
                 address,
                 handler,
                 language,
+            }
+            | UDFDefinition::UDAFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language,
             } => {
                 if !GlobalConfig::instance().query.enable_udf_server {
                     return Err(ErrorCode::Unimplemented(
@@ -105,15 +144,97 @@ impl Binder {
                     .check_schema(handler, &arg_datatypes, &return_type)
                     .await?;
 
-                Ok(UserDefinedFunction {
-                    name: udf_name.to_string(),
-                    description: udf_description.clone().unwrap_or_default(),
-                    definition: PlanUDFDefinition::UDFServer(UDFServer {
+                let definition = if let UDFDefinition::UDAFServer { .. } = udf_definition {
+                    PlanUDFDefinition::UDAFServer(UDAFServer {
+                        address: address.clone(),
+                        arg_types: arg_datatypes,
+                        return_type,
+                        handler: handler.clone(),
+                        language: language.clone(),
+                    })
+                } else {
+                    PlanUDFDefinition::UDFServer(UDFServer {
                         address: address.clone(),
                         arg_types: arg_datatypes,
                         return_type,
                         handler: handler.clone(),
                         language: language.clone(),
+                    })
+                };
+
+                Ok(UserDefinedFunction {
+                    name: udf_name.to_string(),
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition,
+                    created_on: Utc::now(),
+                })
+            }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language,
+            } => {
+                if !language.eq_ignore_ascii_case("wasm") {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "Unsupported UDF language '{language}', only 'wasm' is supported"
+                    )));
+                }
+
+                let mut arg_datatypes = Vec::with_capacity(arg_types.len());
+                for arg_type in arg_types {
+                    arg_datatypes.push(DataType::from(&resolve_type_name(arg_type, true)?));
+                }
+                let return_type = DataType::from(&resolve_type_name(return_type, true)?);
+
+                // The module is loaded from a stage file, or written inline in text format.
+                let code = if let Some(location) = code.strip_prefix('@') {
+                    let (stage_info, path) =
+                        resolve_stage_location(self.ctx.as_ref(), location).await?;
+                    let operator = init_stage_operator(&stage_info)?;
+                    operator.read(&path).await?
+                } else {
+                    code.as_bytes().to_vec()
+                };
+                check_wasm_udf(&code, handler, &arg_datatypes, &return_type)?;
+
+                Ok(UserDefinedFunction {
+                    name: udf_name.to_string(),
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::UDFScript(UDFScript {
+                        code,
+                        handler: handler.clone(),
+                        language: language.to_lowercase(),
+                        arg_types: arg_datatypes,
+                        return_type,
+                    }),
+                    created_on: Utc::now(),
+                })
+            }
+            UDFDefinition::UDTF {
+                arg_types,
+                return_types,
+                sql,
+            } => {
+                let arg_types = self.resolve_udtf_columns(arg_types)?;
+                let return_types = self.resolve_udtf_columns(return_types)?;
+
+                let tokens = tokenize_sql(sql)?;
+                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                if !matches!(stmt, Statement::Query(_)) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "The definition of table function '{udf_name}' must be a query"
+                    )));
+                }
+
+                Ok(UserDefinedFunction {
+                    name: udf_name.to_string(),
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::UDTF(UDTF {
+                        arg_types,
+                        return_types,
+                        sql: sql.clone(),
                     }),
                     created_on: Utc::now(),
                 })
@@ -121,6 +242,213 @@ impl Binder {
         }
     }
 
+    fn resolve_udtf_columns(
+        &self,
+        columns: &[(Identifier, TypeName)],
+    ) -> Result<Vec<(String, DataType)>> {
+        let mut resolved: Vec<(String, DataType)> = Vec::with_capacity(columns.len());
+        for (ident, ty) in columns {
+            let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+            if resolved.iter().any(|(other, _)| *other == name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicated column name '{name}' in table function"
+                ))
+                .set_span(ident.span));
+            }
+            resolved.push((name, DataType::from(&resolve_type_name(ty, true)?)));
+        }
+        Ok(resolved)
+    }
+
+    /// Rewrites a call of a SQL table function into a subquery.
+    ///
+    /// The arguments are cast to the parameter types and selected by a one-row derived table
+    /// whose columns are named after the parameters, the query is joined to it laterally, so
+    /// the parameters are resolved like outer columns: the columns of the tables in the query
+    /// shadow them, and the arguments can't be captured by those tables. Then the columns of
+    /// the query are cast to the declared return types.
+    /// Returns `None` if there is no SQL table function of the name, or the name is taken
+    /// by a built-in table function.
+    #[allow(clippy::too_many_arguments)]
+    pub(in crate::planner::binder) async fn rewrite_udtf_call(
+        &self,
+        bind_context: &BindContext,
+        span: &Span,
+        lateral: bool,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<Option<TableReference>> {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        if self.is_builtin_table_function(&func_name)? {
+            return Ok(None);
+        }
+
+        let udf = UserApiProvider::instance()
+            .get_udf(self.ctx.get_tenant().as_str(), &func_name)
+            .await?;
+        let Some(UserDefinedFunction {
+            definition: PlanUDFDefinition::UDTF(udtf),
+            ..
+        }) = udf
+        else {
+            return Ok(None);
+        };
+
+        if bind_context.udtf_names.contains(&func_name) {
+            let calls = bind_context
+                .udtf_names
+                .iter()
+                .chain(std::iter::once(&func_name))
+                .map(|name| format!("'{name}'"))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(ErrorCode::SemanticError(format!(
+                "Recursive call of table function '{func_name}' is not allowed: {calls}"
+            ))
+            .set_span(*span));
+        }
+
+        if params.len() + named_params.len() != udtf.arg_types.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Table function '{func_name}' expects {} arguments, but got {}",
+                udtf.arg_types.len(),
+                params.len() + named_params.len()
+            ))
+            .set_span(*span));
+        }
+        let mut args = Vec::with_capacity(udtf.arg_types.len());
+        for (i, (param, ty)) in udtf.arg_types.iter().enumerate() {
+            let arg = if i < params.len() {
+                params[i].clone()
+            } else {
+                named_params
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(param))
+                    .map(|(_, arg)| arg.clone())
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "Missing argument '{param}' of table function '{func_name}'"
+                        ))
+                        .set_span(*span)
+                    })?
+            };
+            args.push((param.clone(), Expr::Cast {
+                span: arg.span(),
+                expr: Box::new(arg),
+                target_type: udtf_type_name(ty)?,
+                pg_style: false,
+            }));
+        }
+
+        let tokens = tokenize_sql(&udtf.sql)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(mut query) = stmt else {
+            return Err(
+                ErrorCode::Internal(format!("Invalid table function: {func_name}")).set_span(*span),
+            );
+        };
+        let mut replacer = UDTFArgReplacer {
+            args: &args,
+            name_resolution_ctx: &self.name_resolution_ctx,
+            in_table_function: false,
+        };
+        replacer.visit_query(&mut query);
+
+        let alias_name = Identifier {
+            span: name.span,
+            name: func_name,
+            quote: None,
+        };
+        let mut columns = Vec::with_capacity(udtf.return_types.len());
+        let mut select_list = Vec::with_capacity(udtf.return_types.len());
+        for (column, ty) in udtf.return_types.iter() {
+            let column = Identifier::from_name_with_quoted(column, Some('"'));
+            select_list.push(SelectTarget::AliasedExpr {
+                expr: Box::new(Expr::Cast {
+                    span: *span,
+                    expr: Box::new(Expr::ColumnRef {
+                        span: *span,
+                        database: None,
+                        table: Some(alias_name.clone()),
+                        column: ColumnID::Name(column.clone()),
+                    }),
+                    target_type: udtf_type_name(ty)?,
+                    pg_style: false,
+                }),
+                alias: Some(column.clone()),
+            });
+            columns.push(column);
+        }
+        let mut from = Vec::with_capacity(2);
+        if !args.is_empty() {
+            let args_list = args
+                .into_iter()
+                .map(|(param, arg)| SelectTarget::AliasedExpr {
+                    expr: Box::new(arg),
+                    alias: Some(Identifier::from_name_with_quoted(param, Some('"'))),
+                })
+                .collect();
+            from.push(TableReference::Subquery {
+                span: *span,
+                lateral,
+                subquery: Box::new(udtf_select_query(*span, args_list, vec![])),
+                alias: Some(TableAlias {
+                    name: Identifier::from_name(UDTF_ARGS_TABLE_NAME),
+                    columns: vec![],
+                }),
+            });
+        }
+        from.push(TableReference::Subquery {
+            span: *span,
+            lateral: lateral || !from.is_empty(),
+            subquery: query,
+            alias: Some(TableAlias {
+                name: alias_name.clone(),
+                columns,
+            }),
+        });
+
+        Ok(Some(TableReference::Subquery {
+            span: *span,
+            lateral,
+            subquery: Box::new(udtf_select_query(*span, select_list, from)),
+            alias: alias.clone().or(Some(TableAlias {
+                name: alias_name,
+                columns: vec![],
+            })),
+        }))
+    }
+
+    /// Binds the subquery rewritten from a call of the SQL table function `name`, the function
+    /// is recorded in the context while its query is bound, to detect the recursive calls.
+    pub(in crate::planner::binder) async fn bind_udtf_subquery(
+        &mut self,
+        bind_context: &mut BindContext,
+        name: &Identifier,
+        subquery: &TableReference,
+    ) -> Result<(SExpr, BindContext)> {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        bind_context.udtf_names.push(func_name);
+        let result = self.bind_single_table(bind_context, subquery).await;
+        bind_context.udtf_names.pop();
+        result
+    }
+
+    /// Built-in table functions take precedence over the SQL table functions of the same name.
+    fn is_builtin_table_function(&self, func_name: &str) -> Result<bool> {
+        let is_srf = BUILTIN_FUNCTIONS
+            .get_property(func_name)
+            .is_some_and(|p| p.kind == FunctionKind::SRF);
+        Ok(is_srf
+            || func_name.eq_ignore_ascii_case("result_scan")
+            || self
+                .catalogs
+                .get_default_catalog()?
+                .exists_table_function(func_name))
+    }
+
     pub(in crate::planner::binder) async fn bind_create_udf(
         &mut self,
         stmt: &CreateUDFStmt,
@@ -144,3 +472,89 @@ impl Binder {
         Ok(Plan::AlterUDF(Box::new(AlterUDFPlan { udf })))
     }
 }
+
+/// Parses the type name back from a resolved type, the display of types is a valid type name.
+fn udtf_type_name(data_type: &DataType) -> Result<TypeName> {
+    let tokens = tokenize_sql(&data_type.to_string())?;
+    run_parser(&tokens, Dialect::default(), false, type_name)
+}
+
+/// The alias of the derived table which selects the arguments of a SQL table function.
+const UDTF_ARGS_TABLE_NAME: &str = "_udtf_args";
+
+fn udtf_select_query(
+    span: Span,
+    select_list: Vec<SelectTarget>,
+    from: Vec<TableReference>,
+) -> Query {
+    Query {
+        span,
+        with: None,
+        body: SetExpr::Select(Box::new(SelectStmt {
+            span,
+            hints: None,
+            distinct: false,
+            select_list,
+            from,
+            selection: None,
+            group_by: None,
+            having: None,
+            window_list: None,
+            qualify: None,
+        })),
+        order_by: vec![],
+        limit: vec![],
+        offset: None,
+        ignore_result: false,
+    }
+}
+
+/// Substitutes the arguments for the parameters passed to the table functions called by the
+/// query of a SQL table function. These must be constants, so they can't refer to the derived
+/// table of the arguments, and there is nothing the substituted arguments could be captured by.
+struct UDTFArgReplacer<'a> {
+    args: &'a [(String, Expr)],
+    name_resolution_ctx: &'a NameResolutionContext,
+    in_table_function: bool,
+}
+
+impl VisitorMut for UDTFArgReplacer<'_> {
+    fn visit_table_reference(&mut self, table: &mut TableReference) {
+        if let TableReference::TableFunction {
+            params,
+            named_params,
+            ..
+        } = table
+        {
+            let in_table_function = std::mem::replace(&mut self.in_table_function, true);
+            for param in params.iter_mut() {
+                self.visit_expr(param);
+            }
+            for (_, param) in named_params.iter_mut() {
+                self.visit_expr(param);
+            }
+            self.in_table_function = in_table_function;
+            return;
+        }
+        walk_table_reference_mut(self, table);
+    }
+
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::ColumnRef {
+            database: None,
+            table: None,
+            column: ColumnID::Name(ident),
+            ..
+        } = expr
+        {
+            if self.in_table_function {
+                let name = normalize_identifier(ident, self.name_resolution_ctx).name;
+                if let Some((_, arg)) = self.args.iter().find(|(param, _)| *param == name) {
+                    *expr = arg.clone();
+                    return;
+                }
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
+}
@@ -442,6 +442,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -456,6 +457,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
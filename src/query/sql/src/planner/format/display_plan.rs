@@ -101,12 +101,19 @@ impl Plan {
 
             // Streams
             Plan::CreateStream(_) => Ok("CreateStream".to_string()),
+            Plan::AlterStream(_) => Ok("AlterStream".to_string()),
             Plan::DropStream(_) => Ok("DropStream".to_string()),
 
             // Indexes
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
             Plan::DropIndex(_) => Ok("DropIndex".to_string()),
             Plan::RefreshIndex(_) => Ok("RefreshIndex".to_string()),
+            Plan::CreateInvertedIndex(_) => Ok("CreateInvertedIndex".to_string()),
+            Plan::DropInvertedIndex(_) => Ok("DropInvertedIndex".to_string()),
+            Plan::RefreshInvertedIndex(_) => Ok("RefreshInvertedIndex".to_string()),
+            Plan::CreateVectorIndex(_) => Ok("CreateVectorIndex".to_string()),
+            Plan::DropVectorIndex(_) => Ok("DropVectorIndex".to_string()),
+            Plan::RefreshVectorIndex(_) => Ok("RefreshVectorIndex".to_string()),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(_) => Ok("CreateVirtualColumn".to_string()),
@@ -168,6 +175,9 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(_) => Ok("ShowObjectGrantPrivileges".to_string()),
             Plan::ShowGrantTenantsOfShare(_) => Ok("ShowGrantTenantsOfShare".to_string()),
             Plan::RevertTable(_) => Ok("RevertTable".to_string()),
+            Plan::AddTablePartition(_) => Ok("AddTablePartition".to_string()),
+            Plan::DropTablePartition(_) => Ok("DropTablePartition".to_string()),
+            Plan::RecoverTablePartitions(_) => Ok("RecoverTablePartitions".to_string()),
 
             // data mask
             Plan::CreateDatamaskPolicy(_) => Ok("CreateDatamaskPolicy".to_string()),
@@ -195,11 +205,22 @@ impl Plan {
             Plan::ExecuteTask(_) => Ok("ExecuteTask".to_string()),
             Plan::ShowTasks(_) => Ok("ShowTasks".to_string()),
 
+            // pipe
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescPipe(_) => Ok("DescPipe".to_string()),
+
             // task
             Plan::CreateConnection(_) => Ok("CreateConnection".to_string()),
             Plan::DescConnection(_) => Ok("DescConnection".to_string()),
             Plan::DropConnection(_) => Ok("DropConnection".to_string()),
             Plan::ShowConnections(_) => Ok("ShowConnections".to_string()),
+
+            // transaction
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
         }
     }
 }
@@ -246,6 +267,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             prewhere: None,
             agg_index: None,
             change_type: None,
+            vector_index: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
@@ -25,6 +25,7 @@ mod expression_parser;
 pub mod optimizer;
 pub mod plans;
 mod stream_column;
+mod substrait;
 mod udf_validator;
 
 pub use binder::parse_result_scan_args;
@@ -48,3 +49,5 @@ pub use plans::INSERT_NAME;
 pub use plans::UPDATE_NAME;
 pub use semantic::*;
 pub use stream_column::*;
+
+pub use self::substrait::SubstraitPlanner;
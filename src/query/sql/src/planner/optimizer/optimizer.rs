@@ -37,6 +37,7 @@ use crate::optimizer::filter::PullUpFilterOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_recursive_cte;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -229,7 +230,8 @@ pub fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
 
 pub fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SExpr> {
     let enable_distributed_query = opt_ctx.enable_distributed_optimization
-        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata);
+        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata)
+        && !contains_recursive_cte(&s_expr);
 
     // Decorrelate subqueries, after this step, there should be no subquery in the expression.
     if s_expr.contain_subquery() {
@@ -317,7 +319,8 @@ pub fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SE
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<Memo> {
     let enable_distributed_query = opt_ctx.enable_distributed_optimization
-        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata);
+        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata)
+        && !contains_recursive_cte(&s_expr);
 
     // Decorrelate subqueries, after this step, there should be no subquery in the expression.
     if s_expr.contain_subquery() {
@@ -436,6 +439,7 @@ fn optimize_merge_into(opt_ctx: OptimizerContext, plan: Box<MergeInto>) -> Resul
     // - distributed merge-into is enabled
     if opt_ctx.enable_distributed_optimization
         && !contains_local_table_scan(&join_sexpr, &opt_ctx.metadata)
+        && !contains_recursive_cte(&join_sexpr)
         && opt_ctx
             .table_ctx
             .get_settings()
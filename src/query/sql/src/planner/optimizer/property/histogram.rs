@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use databend_common_exception::Result;
 use databend_common_expression::arithmetics_type::ResultTypeOfUnary;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -33,18 +34,24 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// If the table is analyzed, the histogram is built from the sampled values
+/// of the column by `ANALYZE TABLE`, along with the most common values.
+/// Otherwise, the histogram is constructed from NDV(number of distinct values)
+/// and the total number of rows, which brings the assumption that the data is
+/// uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// The most common values and their estimated number of occurrences.
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            most_common_values: vec![],
+        }
     }
 
     /// Get number of buckets
@@ -71,6 +78,46 @@ impl Histogram {
     pub fn buckets_iter(&self) -> impl DoubleEndedIterator<Item = &HistogramBucket> {
         self.buckets.iter()
     }
+
+    /// Get the estimated number of occurrences of a value if it's one of the most common values.
+    pub fn most_common_value_count(&self, value: &Datum) -> Option<f64> {
+        self.most_common_values
+            .iter()
+            .find(|(mcv, _)| {
+                mcv.type_comparable(value) && matches!(mcv.compare(value), Ok(Ordering::Equal))
+            })
+            .map(|(_, count)| *count)
+    }
+}
+
+/// Construct a histogram from the histogram collected by `ANALYZE TABLE`.
+///
+/// Returns `None` if any bound of the buckets can't be converted to `Datum`.
+pub fn histogram_from_column_histogram(histogram: &ColumnHistogram) -> Option<Histogram> {
+    let first = histogram.buckets.first()?;
+    let mut buckets = Vec::with_capacity(histogram.buckets.len() + 1);
+    // The first bucket is a dummy bucket which is used to record the min value of the column
+    buckets.push(HistogramBucket {
+        upper_bound: Datum::from_scalar(first.lower_bound.clone())?,
+        num_values: 0.0,
+        num_distinct: 0.0,
+    });
+    for bucket in histogram.buckets.iter() {
+        buckets.push(HistogramBucket {
+            upper_bound: Datum::from_scalar(bucket.upper_bound.clone())?,
+            num_values: bucket.num_values,
+            num_distinct: bucket.num_distinct,
+        });
+    }
+    let most_common_values = histogram
+        .most_common_values
+        .iter()
+        .map(|(value, count)| Some((Datum::from_scalar(value.clone())?, *count)))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Histogram {
+        buckets,
+        most_common_values,
+    })
 }
 
 /// Construct a histogram from NDV and total number of rows.
@@ -93,7 +140,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -148,7 +195,7 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
 }
 
 #[derive(Debug, Clone)]
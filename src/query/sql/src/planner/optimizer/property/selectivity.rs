@@ -144,7 +144,8 @@ impl<'a> SelectivityEstimator<'a> {
                     ComparisonOp::Equal => {
                         // For equal predicate, we just use cardinality of a single
                         // value to estimate the selectivity. This assumes that
-                        // the column is in a uniform distribution.
+                        // the column is in a uniform distribution, unless the
+                        // most common values are collected by `ANALYZE TABLE`.
                         let selectivity = evaluate_equal(column_stat, constant);
                         if update {
                             update_statistic(
@@ -328,6 +329,15 @@ impl<'a> SelectivityEstimator<'a> {
                         for bucket in histogram.buckets.iter_mut() {
                             bucket.update(selectivity);
                         }
+                        // The most common values are scaled along with the buckets, and
+                        // dropped once the scaled NDV can't hold them.
+                        if new_ndv <= histogram.most_common_values.len() as f64 {
+                            histogram.most_common_values.clear();
+                        } else {
+                            for (_, count) in histogram.most_common_values.iter_mut() {
+                                *count *= selectivity;
+                            }
+                        }
                     }
                 }
             }
@@ -388,6 +398,10 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+        if let Some(selectivity) = evaluate_equal_by_most_common_values(constant_datum, column_stat)
+        {
+            return selectivity;
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -397,6 +411,31 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
     }
 }
 
+// Use the most common values collected by `ANALYZE TABLE` to estimate the selectivity,
+// the values that are not the most common values are assumed to be uniformly distributed.
+fn evaluate_equal_by_most_common_values(datum: &Datum, column_stat: &ColumnStat) -> Option<f64> {
+    let histogram = column_stat.histogram.as_ref()?;
+    if histogram.most_common_values.is_empty() {
+        return None;
+    }
+    let num_values = histogram.num_values();
+    if num_values <= 0.0 {
+        return None;
+    }
+    if let Some(count) = histogram.most_common_value_count(datum) {
+        return Some((count / num_values).min(1.0));
+    }
+    let common_values = histogram
+        .most_common_values
+        .iter()
+        .fold(0.0, |acc, (_, count)| acc + count);
+    let rest_ndv = column_stat.ndv - histogram.most_common_values.len() as f64;
+    if rest_ndv < 1.0 {
+        return Some(0.0);
+    }
+    Some(((num_values - common_values).max(0.0) / rest_ndv / num_values).min(1.0))
+}
+
 fn update_statistic(
     column_stat: &mut ColumnStat,
     mut new_min: Datum,
@@ -40,6 +40,7 @@ mod rule_push_down_limit_union;
 mod rule_push_down_limit_window;
 mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
+mod rule_push_down_sort_vector_index;
 mod rule_semi_to_inner_join;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
@@ -71,6 +72,7 @@ pub use rule_push_down_limit_union::RulePushDownLimitUnion;
 pub use rule_push_down_limit_window::RulePushDownLimitWindow;
 pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
+pub use rule_push_down_sort_vector_index::RulePushDownSortVectorIndex;
 pub use rule_semi_to_inner_join::RuleSemiToInnerJoin;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::Scalar;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_common_vector::HnswOptions;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::MetadataRef;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet(padding vector_index)
+///
+/// Applies if the first sort item is an ascending distance between an indexed vector
+/// column and a constant vector, such as
+/// `ORDER BY cosine_distance(embedding, [0.1, 0.2]) LIMIT 10`, and the metric of the
+/// vector index is the same distance. Only the blocks containing the nearest vectors
+/// will be read.
+pub struct RulePushDownSortVectorIndex {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    metadata: MetadataRef,
+}
+
+impl RulePushDownSortVectorIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::PushDownSortVectorIndex,
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Sort,
+                children: vec![Matcher::MatchOp {
+                    op_type: RelOp::EvalScalar,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Scan,
+                        children: vec![],
+                    }],
+                }],
+            }],
+            metadata,
+        }
+    }
+
+    fn find_vector_index(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Option<VectorIndexInfo> {
+        let limit = sort.limit?;
+        let item = sort.items.first()?;
+        // The NULL values are not indexed, they must be sorted last.
+        if !item.asc
+            || item.nulls_first
+            || scan.push_down_predicates.is_some()
+            || scan.change_type.is_some()
+            || scan.vector_index.is_some()
+        {
+            return None;
+        }
+        let ScalarExpr::FunctionCall(func) = &eval_scalar
+            .items
+            .iter()
+            .find(|scalar_item| scalar_item.index == item.index)?
+            .scalar
+        else {
+            return None;
+        };
+        let (column, query) = match func.arguments.as_slice() {
+            [
+                ScalarExpr::BoundColumnRef(column),
+                ScalarExpr::ConstantExpr(query),
+            ]
+            | [
+                ScalarExpr::ConstantExpr(query),
+                ScalarExpr::BoundColumnRef(column),
+            ] => (column, query),
+            _ => return None,
+        };
+        if column.column.table_index != Some(scan.table_index) {
+            return None;
+        }
+        let Scalar::Array(query) = &query.value else {
+            return None;
+        };
+        let query = Float32Type::try_downcast_column(query)?;
+
+        let metadata = self.metadata.read();
+        let table = metadata.table(scan.table_index).table();
+        let column_id = table
+            .schema()
+            .field_with_name(&column.column.column_name)
+            .ok()?
+            .column_id();
+        let index = table.get_table_info().meta.indexes.values().find(|index| {
+            index.index_type == TableIndexType::Vector
+                && index.column_ids.contains(&column_id)
+                && HnswOptions::from_options(&index.options).is_ok_and(|options| {
+                    options.distance.function_name() == func.func_name.as_str()
+                })
+        })?;
+
+        Some(VectorIndexInfo {
+            index_name: index.name.clone(),
+            index_version: index.version.clone(),
+            column_id,
+            query: query.to_vec(),
+            limit,
+        })
+    }
+}
+
+impl Rule for RulePushDownSortVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut scan: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+
+        let Some(vector_index) = self.find_vector_index(&sort, &eval_scalar, &scan) else {
+            return Ok(());
+        };
+        scan.vector_index = Some(vector_index);
+
+        let scan = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        let eval_scalar = eval_scalar_expr.replace_children(vec![Arc::new(scan)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
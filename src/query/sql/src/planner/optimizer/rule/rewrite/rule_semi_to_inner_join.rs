@@ -125,6 +125,7 @@ fn find_group_by_keys(child: &SExpr, group_by_keys: &mut HashSet<IndexType>) ->
         | RelOperator::DummyTableScan(_)
         | RelOperator::ProjectSet(_)
         | RelOperator::MaterializedCte(_)
+        | RelOperator::RecursiveCte(_)
         | RelOperator::ConstantTableScan(_)
         | RelOperator::Udf(_)
         | RelOperator::Scan(_)
@@ -38,6 +38,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownLimitUnion,
         RuleID::PushDownLimitEvalScalar,
         RuleID::PushDownLimitSort,
+        RuleID::PushDownSortVectorIndex,
         RuleID::PushDownLimitWindow,
         RuleID::PushDownLimitAggregate,
         RuleID::PushDownLimitOuterJoin,
@@ -93,6 +94,7 @@ pub enum RuleID {
     PushDownLimitAggregate,
     PushDownLimitScan,
     PushDownSortScan,
+    PushDownSortVectorIndex,
     SemiToInnerJoin,
     EliminateEvalScalar,
     EliminateFilter,
@@ -128,6 +130,7 @@ impl Display for RuleID {
             RuleID::PushDownFilterAggregate => write!(f, "PushDownFilterAggregate"),
             RuleID::PushDownLimitScan => write!(f, "PushDownLimitScan"),
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
+            RuleID::PushDownSortVectorIndex => write!(f, "PushDownSortVectorIndex"),
             RuleID::PushDownLimitWindow => write!(f, "PushDownLimitWindow"),
             RuleID::PushDownFilterWindow => write!(f, "PushDownFilterWindow"),
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
@@ -318,6 +318,7 @@ impl SExpr {
             | RelOperator::CteScan(_)
             | RelOperator::AddRowNumber(_)
             | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_)
             | RelOperator::ConstantTableScan(_) => {}
         };
         for child in &self.children {
@@ -397,6 +398,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::CteScan(_)
         | RelOperator::AddRowNumber(_)
         | RelOperator::MaterializedCte(_)
+        | RelOperator::RecursiveCte(_)
         | RelOperator::ConstantTableScan(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
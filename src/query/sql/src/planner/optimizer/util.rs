@@ -27,3 +27,9 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
             false
         }
 }
+
+/// Check if a query contains recursive cte, which can only be evaluated in the local node.
+pub fn contains_recursive_cte(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::RecursiveCte(_))
+        || s_expr.children().any(contains_recursive_cte)
+}
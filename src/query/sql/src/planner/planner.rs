@@ -91,41 +91,10 @@ impl Planner {
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
-                let (mut stmt, format) = parse_sql(&tokens, sql_dialect)?;
-
-                if matches!(stmt, Statement::CopyIntoLocation(_)) {
-                    // Indicate binder there is no need to collect column statistics for the binding table.
-                    self.ctx
-                        .attach_query_str(QueryKind::CopyIntoTable, String::new());
-                }
-
-                self.replace_stmt(&mut stmt, sql_dialect);
-
-                // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-                let plan = binder.bind(&stmt).await?;
-
-                // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
-                    .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
-                    .with_enable_join_reorder(unsafe {
-                        !self.ctx.get_settings().get_disable_join_reorder()?
-                    })
-                    .with_enable_dphyp(self.ctx.get_settings().get_enable_dphyp()?);
-
-                let optimized_plan = optimize(opt_ctx, plan)?;
-                Ok((optimized_plan, PlanExtras {
-                    metadata,
-                    format,
-                    statement: stmt,
-                }))
+                let (stmt, format) = parse_sql(&tokens, sql_dialect)?;
+                let (plan, mut extras) = self.plan_stmt(stmt).await?;
+                extras.format = format;
+                Ok((plan, extras))
             }
             .await;
 
@@ -169,6 +138,48 @@ impl Planner {
         }
     }
 
+    /// Plan a parsed statement, the statements prepared by the clients are parsed once
+    /// and planned on each execution with the parameters bound.
+    #[async_backtrace::framed]
+    pub async fn plan_stmt(&mut self, mut stmt: Statement) -> Result<(Plan, PlanExtras)> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+
+        if matches!(stmt, Statement::CopyIntoLocation(_)) {
+            // Indicate binder there is no need to collect column statistics for the binding table.
+            self.ctx
+                .attach_query_str(QueryKind::CopyIntoTable, String::new());
+        }
+
+        self.replace_stmt(&mut stmt, sql_dialect);
+
+        // Step 3: Bind AST with catalog, and generate a pure logical SExpr
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+        let plan = binder.bind(&stmt).await?;
+
+        // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+        let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
+            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
+            .with_enable_join_reorder(unsafe {
+                !self.ctx.get_settings().get_disable_join_reorder()?
+            })
+            .with_enable_dphyp(self.ctx.get_settings().get_enable_dphyp()?);
+
+        let optimized_plan = optimize(opt_ctx, plan)?;
+        Ok((optimized_plan, PlanExtras {
+            metadata,
+            format: None,
+            statement: stmt,
+        }))
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -18,6 +18,7 @@ mod connection;
 mod database;
 mod file_format;
 mod index;
+mod pipe;
 mod stage;
 mod stream;
 mod table;
@@ -32,6 +33,7 @@ pub use connection::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use pipe::*;
 pub use stage::*;
 pub use stream::*;
 pub use table::*;
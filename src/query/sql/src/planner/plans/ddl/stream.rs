@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_meta_app::schema::CreateOption;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum StreamNavigation {
     AtStream { database: String, name: String },
+    AtPoint(NavigationPoint),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -33,6 +35,16 @@ pub struct CreateStreamPlan {
     pub comment: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterStreamPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub stream_name: String,
+    pub navigation: StreamNavigation,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropStreamPlan {
     pub if_exists: bool,
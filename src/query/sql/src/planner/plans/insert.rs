@@ -58,6 +58,9 @@ pub struct Insert {
     pub table: String,
     pub table_id: MetaId,
     pub schema: TableSchemaRef,
+    /// (column, value) of `PARTITION (...)`, the values are SQL literals.
+    /// The partition columns are not in `schema`, but filled with these values.
+    pub partition: Vec<(String, String)>,
     pub overwrite: bool,
     pub source: InsertInputSource,
 }
@@ -88,6 +91,7 @@ impl std::fmt::Debug for Insert {
             .field("database", &self.database)
             .field("table", &self.table)
             .field("schema", &self.schema)
+            .field("partition", &self.partition)
             .field("overwrite", &self.overwrite)
             .finish()
     }
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
@@ -26,6 +27,7 @@ use databend_storages_common_table_meta::table::ChangeType;
 use itertools::Itertools;
 
 use super::ScalarItem;
+use crate::optimizer::histogram_from_column_histogram;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -97,6 +99,7 @@ pub struct Scan {
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
     pub change_type: Option<ChangeType>,
+    pub vector_index: Option<VectorIndexInfo>,
 
     pub statistics: Statistics,
 }
@@ -124,6 +127,7 @@ impl Scan {
             prewhere,
             agg_index: self.agg_index.clone(),
             change_type: self.change_type.clone(),
+            vector_index: self.vector_index.clone(),
         }
     }
 
@@ -208,13 +212,20 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                // Prefer the histogram collected by `ANALYZE TABLE`.
+                let histogram = col_stat
+                    .histogram
+                    .as_ref()
+                    .and_then(histogram_from_column_histogram)
+                    .or_else(|| {
+                        histogram_from_ndv(
+                            ndv,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok()
+                    });
                 let column_stat = ColumnStat {
                     min,
                     max,
@@ -68,14 +68,23 @@ use databend_common_expression::FunctionKind;
 use databend_common_expression::RawExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
+use databend_common_functions::aggregates::udaf_server_params;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::aggregates::UDAF_SERVER_FUNC_NAME;
 use databend_common_functions::is_builtin_function;
+use databend_common_functions::scalars::cache_wasm_module;
+use databend_common_functions::scalars::wasm_udf_params;
+use databend_common_functions::scalars::Tokenizer;
+use databend_common_functions::scalars::WASM_UDF_FUNC_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
 use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::UDAFServer;
 use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_users::UserApiProvider;
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -2748,6 +2757,20 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_cast_to_variant(span, &data_type, &scalar, true)
                     .await
             }
+            (name @ ("match" | "score"), &[column, query]) => Some(
+                self.resolve_search_function(span, name, column, query)
+                    .await,
+            ),
+            ("score", &[]) => match self.bind_context.inverted_index_match.clone() {
+                Some((column, query)) => Some(
+                    self.resolve_search_function(span, "score", &column, &query)
+                        .await,
+                ),
+                None => Some(Err(ErrorCode::SemanticError(
+                    "score() requires a match(column, query) predicate in the WHERE clause",
+                )
+                .set_span(span))),
+            },
             ("greatest", args) => {
                 let (array, _) = *self
                     .resolve_function(span, "array", vec![], args)
@@ -2766,6 +2789,63 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve `match(column, query)` and `score(column, query)` with the tokenizer of the
+    /// inverted index on the column.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_search_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        column: &Expr,
+        query: &Expr,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let box (column_scalar, _) = self.resolve(column).await?;
+        let tokenizer = match &column_scalar {
+            ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }) => {
+                self.inverted_index_tokenizer(column)?
+            }
+            _ => None,
+        };
+        let Some(tokenizer) = tokenizer else {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name}() requires a column with inverted index, but got {column}"
+            ))
+            .set_span(span));
+        };
+        let box (query_scalar, _) = self.resolve(query).await?;
+        let tokenizer_scalar = ConstantExpr {
+            span,
+            value: databend_common_expression::Scalar::String(tokenizer.to_string()),
+        }
+        .into();
+
+        self.resolve_scalar_function_call(span, func_name, vec![], vec![
+            column_scalar,
+            query_scalar,
+            tokenizer_scalar,
+        ])
+    }
+
+    /// Get the tokenizer of the inverted index which contains the column.
+    fn inverted_index_tokenizer(&self, column: &ColumnBinding) -> Result<Option<Tokenizer>> {
+        let Some(table_index) = column.table_index else {
+            return Ok(None);
+        };
+        let table = self.metadata.read().table(table_index).table();
+        let Ok(field) = table.schema().field_with_name(&column.column_name) else {
+            return Ok(None);
+        };
+        for index in table.get_table_info().meta.indexes.values() {
+            if index.index_type == TableIndexType::Inverted
+                && index.column_ids.contains(&field.column_id())
+            {
+                return Ok(Some(Tokenizer::from_options(&index.options)?));
+            }
+        }
+        Ok(None)
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_trim_function(
@@ -2954,7 +3034,145 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_udf_server(span, name, arguments, udf_def)
                     .await?,
             )),
+            UDFDefinition::UDFScript(udf_def) => Ok(Some(
+                self.resolve_udf_script(span, name, arguments, udf_def)
+                    .await?,
+            )),
+            UDFDefinition::UDAFServer(udf_def) => Ok(Some(
+                self.resolve_udaf_server(span, name, arguments, udf_def)
+                    .await?,
+            )),
+            UDFDefinition::UDTF(_) => Err(ErrorCode::SemanticError(format!(
+                "Table function '{name}' can only be used in FROM clause"
+            ))
+            .set_span(span)),
+        }
+    }
+
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_udaf_server(
+        &mut self,
+        span: Span,
+        name: String,
+        arguments: &[Expr],
+        udf_definition: UDAFServer,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if !GlobalConfig::instance().query.enable_udf_server {
+            return Err(ErrorCode::Unimplemented(
+                "UDF server is not allowed, you can enable it by setting 'enable_udf_server = true' in query node config",
+            ));
         }
+
+        let udf_server_allow_list = &GlobalConfig::instance().query.udf_server_allow_list;
+        let address = &udf_definition.address;
+        if udf_server_allow_list
+            .iter()
+            .all(|addr| addr.trim_end_matches('/') != address.trim_end_matches('/'))
+        {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Unallowed UDF server address, '{address}' is not in udf_server_allow_list"
+            )));
+        }
+
+        if arguments.len() != udf_definition.arg_types.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Require {} parameters, but got: {}",
+                udf_definition.arg_types.len(),
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        if self.in_aggregate_function {
+            return Err(ErrorCode::SemanticError(
+                "aggregate function calls cannot be nested".to_string(),
+            )
+            .set_span(span));
+        }
+
+        self.in_aggregate_function = true;
+        let mut args = Vec::with_capacity(arguments.len());
+        for (argument, dest_type) in arguments.iter().zip(udf_definition.arg_types.iter()) {
+            let box (arg, ty) = self.resolve(argument).await?;
+            if ty != *dest_type {
+                args.push(wrap_cast(&arg, dest_type));
+            } else {
+                args.push(arg);
+            }
+        }
+        self.in_aggregate_function = false;
+
+        // The states are kept by the builtin aggregate function `udaf_server`,
+        // which takes the address, the handler and the return type as params.
+        let settings = self.ctx.get_settings();
+        let params = udaf_server_params(
+            &udf_definition.address,
+            &udf_definition.handler,
+            &udf_definition.return_type,
+            settings.get_external_server_connect_timeout_secs()?,
+            settings.get_external_server_request_timeout_secs()?,
+        );
+        let arg_types = udf_definition.arg_types.clone();
+        let agg_func = AggregateFunctionFactory::instance()
+            .get(UDAF_SERVER_FUNC_NAME, params.clone(), arg_types)
+            .map_err(|e| e.set_span(span))?;
+        let data_type = agg_func.return_type()?;
+
+        let arg_names = arguments.iter().map(|arg| format!("{}", arg)).join(", ");
+        let display_name = format!("{}({})", name, arg_names);
+
+        self.ctx.set_cacheable(false);
+        let new_agg_func = AggregateFunction {
+            display_name,
+            func_name: UDAF_SERVER_FUNC_NAME.to_string(),
+            distinct: false,
+            params,
+            args,
+            return_type: Box::new(data_type.clone()),
+        };
+        Ok(Box::new((new_agg_func.into(), data_type)))
+    }
+
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_udf_script(
+        &mut self,
+        span: Span,
+        name: String,
+        arguments: &[Expr],
+        udf_definition: UDFScript,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if arguments.len() != udf_definition.arg_types.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Require {} parameters, but got: {}",
+                udf_definition.arg_types.len(),
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut args = Vec::with_capacity(arguments.len());
+        for (argument, dest_type) in arguments.iter().zip(udf_definition.arg_types.iter()) {
+            let box (arg, ty) = self.resolve(argument).await?;
+            if ty != *dest_type {
+                args.push(wrap_cast(&arg, dest_type));
+            } else {
+                args.push(arg);
+            }
+        }
+
+        // The module is executed by the builtin `wasm_udf` function, which takes the
+        // UDF name, the hash of the cached module, the handler and the return type as params.
+        let code_hash = cache_wasm_module(&udf_definition.code).map_err(|e| e.set_span(span))?;
+        let params = wasm_udf_params(
+            self.ctx.get_tenant().as_str(),
+            &name,
+            &code_hash,
+            &udf_definition.handler,
+            &udf_definition.return_type,
+        );
+        self.resolve_scalar_function_call(span, WASM_UDF_FUNC_NAME, params, args)
     }
 
     #[async_recursion::async_recursion]
@@ -3879,6 +4097,9 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         }
         TypeName::Variant => TableDataType::Variant,
         TypeName::Geometry => TableDataType::Geometry,
+        TypeName::Interval => TableDataType::Interval,
+        TypeName::Uuid => TableDataType::Uuid,
+        TypeName::Inet => TableDataType::Inet,
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
@@ -0,0 +1,685 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::TableReference;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use itertools::Itertools;
+use parking_lot::RwLock;
+use prost::Message;
+use substrait::proto::aggregate_function::AggregationInvocation;
+use substrait::proto::extensions::simple_extension_declaration::MappingType;
+use substrait::proto::join_rel;
+use substrait::proto::plan_rel;
+use substrait::proto::read_rel::ReadType;
+use substrait::proto::rel::RelType;
+use substrait::proto::rel_common::EmitKind;
+use substrait::proto::sort_field::SortDirection;
+use substrait::proto::sort_field::SortKind;
+use substrait::proto::r#type::Kind;
+use substrait::proto::AggregateRel;
+use substrait::proto::JoinRel;
+use substrait::proto::NamedStruct;
+use substrait::proto::ReadRel;
+use substrait::proto::Rel;
+use substrait::proto::RelCommon;
+use substrait::proto::SortRel;
+use substrait::proto::Type;
+
+use super::scalar::invalid_plan;
+use super::scalar::required;
+use crate::binder::split_conjunctions;
+use crate::binder::split_equivalent_predicate;
+use crate::format_scalar;
+use crate::optimizer::optimize;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::OptimizerContext;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::Limit;
+use crate::plans::Plan;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::ColumnBindingBuilder;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::NameResolutionContext;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Translate a [Substrait](https://substrait.io) plan to a query plan.
+///
+/// The read (named tables only), filter, project, aggregate, join, cross, sort and fetch
+/// relations are supported. Each relation is translated to a `SExpr` together with its
+/// output columns, the field references of the parent relation are resolved by position.
+pub struct SubstraitPlanner {
+    ctx: Arc<dyn TableContext>,
+    binder: Binder,
+    metadata: MetadataRef,
+    /// The function names declared in the extensions of the plan, keyed by the anchor.
+    pub(super) functions: HashMap<u32, String>,
+}
+
+impl SubstraitPlanner {
+    pub fn try_create(ctx: Arc<dyn TableContext>) -> Result<Self> {
+        let settings = ctx.get_settings();
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+        Ok(SubstraitPlanner {
+            ctx,
+            binder,
+            metadata,
+            functions: HashMap::new(),
+        })
+    }
+
+    /// Plan the protobuf encoded Substrait plan.
+    #[async_backtrace::framed]
+    pub async fn plan(mut self, plan: &[u8]) -> Result<Plan> {
+        let plan = substrait::proto::Plan::decode(plan)
+            .map_err(|e| ErrorCode::BadArguments(format!("Invalid substrait plan: {e}")))?;
+
+        for extension in plan.extensions.iter() {
+            if let Some(MappingType::ExtensionFunction(func)) = &extension.mapping_type {
+                // The name may be a compound name with the signature, like `add:i64_i64`.
+                let name = func.name.split(':').next().unwrap_or_default();
+                self.functions
+                    .insert(func.function_anchor, name.to_ascii_lowercase());
+            }
+        }
+
+        let [relation] = plan.relations.as_slice() else {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Substrait plan should have exactly one relation, but got {}",
+                plan.relations.len()
+            )));
+        };
+        let (rel, names) = match required(relation.rel_type.as_ref(), "relation")? {
+            plan_rel::RelType::Root(root) => {
+                let input = required(root.input.as_ref(), "root input")?;
+                (input, root.names.as_slice())
+            }
+            plan_rel::RelType::Rel(rel) => (rel, &[][..]),
+        };
+
+        let (s_expr, mut columns) = self.translate_rel(rel).await?;
+        if names.len() == columns.len() {
+            for (column, name) in columns.iter_mut().zip(names.iter()) {
+                column.column_name = name.clone();
+            }
+        }
+
+        let mut bind_context = BindContext::new();
+        for column in columns {
+            bind_context.add_column_binding(column);
+        }
+        let plan = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata: self.metadata.clone(),
+            bind_context: Box::new(bind_context),
+            rewrite_kind: None,
+            ignore_result: false,
+            formatted_ast: None,
+        };
+
+        let settings = self.ctx.get_settings();
+        let opt_ctx = OptimizerContext::new(self.ctx.clone(), self.metadata.clone())
+            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
+            .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
+            .with_enable_dphyp(settings.get_enable_dphyp()?);
+        optimize(opt_ctx, plan)
+    }
+
+    #[async_recursion::async_recursion]
+    async fn translate_rel(&mut self, rel: &Rel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let (s_expr, columns, common) = match required(rel.rel_type.as_ref(), "relation")? {
+            RelType::Read(read) => {
+                let (s_expr, columns) = self.translate_read(read).await?;
+                (s_expr, columns, read.common.as_ref())
+            }
+            RelType::Filter(filter) => {
+                let input = required(filter.input.as_deref(), "filter input")?;
+                let (s_expr, columns) = self.translate_rel(input).await?;
+                let condition = required(filter.condition.as_deref(), "filter condition")?;
+                let predicate = self.translate_expr(condition, &columns)?;
+                (
+                    filter_expr(s_expr, predicate),
+                    columns,
+                    filter.common.as_ref(),
+                )
+            }
+            RelType::Project(project) => {
+                let input = required(project.input.as_deref(), "project input")?;
+                let (mut s_expr, mut columns) = self.translate_rel(input).await?;
+                let mut items = vec![];
+                for expr in project.expressions.iter() {
+                    let scalar = self.translate_expr(expr, &columns)?;
+                    columns.push(self.derive_column(scalar, &mut items)?);
+                }
+                if !items.is_empty() {
+                    s_expr = eval_scalar_expr(s_expr, items);
+                }
+                (s_expr, columns, project.common.as_ref())
+            }
+            RelType::Aggregate(aggregate) => {
+                let input = required(aggregate.input.as_deref(), "aggregate input")?;
+                let (s_expr, columns) = self.translate_rel(input).await?;
+                let (s_expr, columns) = self.translate_aggregate(aggregate, s_expr, columns)?;
+                (s_expr, columns, aggregate.common.as_ref())
+            }
+            RelType::Join(join) => {
+                let left = required(join.left.as_deref(), "join left input")?;
+                let right = required(join.right.as_deref(), "join right input")?;
+                let left = self.translate_rel(left).await?;
+                let right = self.translate_rel(right).await?;
+                let (s_expr, columns) = self.translate_join(join, left, right)?;
+                (s_expr, columns, join.common.as_ref())
+            }
+            RelType::Cross(cross) => {
+                let left = required(cross.left.as_deref(), "cross left input")?;
+                let right = required(cross.right.as_deref(), "cross right input")?;
+                let (left, mut columns) = self.translate_rel(left).await?;
+                let (right, right_columns) = self.translate_rel(right).await?;
+                columns.extend(right_columns);
+                let join = Join {
+                    join_type: JoinType::Cross,
+                    ..Default::default()
+                };
+                let s_expr =
+                    SExpr::create_binary(Arc::new(join.into()), Arc::new(left), Arc::new(right));
+                (s_expr, columns, cross.common.as_ref())
+            }
+            RelType::Sort(sort) => {
+                let input = required(sort.input.as_deref(), "sort input")?;
+                let (s_expr, columns) = self.translate_rel(input).await?;
+                let s_expr = self.translate_sort(sort, s_expr, &columns)?;
+                (s_expr, columns, sort.common.as_ref())
+            }
+            RelType::Fetch(fetch) => {
+                let input = required(fetch.input.as_deref(), "fetch input")?;
+                let (s_expr, columns) = self.translate_rel(input).await?;
+                let offset = usize::try_from(fetch.offset)
+                    .map_err(|_| invalid_plan(format!("negative offset {}", fetch.offset)))?;
+                // A negative count means all the remaining rows.
+                let limit = Limit {
+                    before_exchange: false,
+                    limit: usize::try_from(fetch.count).ok(),
+                    offset,
+                };
+                let s_expr = SExpr::create_unary(Arc::new(limit.into()), Arc::new(s_expr));
+                (s_expr, columns, fetch.common.as_ref())
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "Only read, filter, project, aggregate, join, cross, sort and fetch relations are supported in substrait plans",
+                ));
+            }
+        };
+        Ok((s_expr, emit_columns(common, columns)?))
+    }
+
+    #[async_backtrace::framed]
+    async fn translate_read(&mut self, read: &ReadRel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let Some(ReadType::NamedTable(named_table)) = &read.read_type else {
+            return Err(ErrorCode::Unimplemented(
+                "Only named tables are supported in the read relations of substrait plans",
+            ));
+        };
+        let (catalog, database, table) = match named_table.names.as_slice() {
+            [table] => (None, None, table),
+            [database, table] => (None, Some(database), table),
+            [catalog, database, table] => (Some(catalog), Some(database), table),
+            names => {
+                return Err(invalid_plan(format!("invalid table name {names:?}")));
+            }
+        };
+        let table_ref = TableReference::Table {
+            span: None,
+            catalog: catalog.map(Identifier::from_name),
+            database: database.map(Identifier::from_name),
+            table: Identifier::from_name(table),
+            alias: None,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        };
+        let (mut s_expr, bind_context) = self
+            .binder
+            .bind_table_reference(&mut BindContext::new(), &table_ref)
+            .await?;
+        let table_columns = bind_context
+            .columns
+            .into_iter()
+            .filter(|column| column.visibility == Visibility::Visible)
+            .collect::<Vec<_>>();
+
+        // The columns are read in the order of the base schema, which may be a subset of the table.
+        let mut columns = match &read.base_schema {
+            Some(schema) => top_level_names(schema)?
+                .into_iter()
+                .map(|name| {
+                    table_columns
+                        .iter()
+                        .find(|column| column.column_name.eq_ignore_ascii_case(&name))
+                        .cloned()
+                        .ok_or_else(|| {
+                            ErrorCode::UnknownColumn(format!(
+                                "Unknown column '{name}' in table {}",
+                                named_table.names.join(".")
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => table_columns,
+        };
+
+        if let Some(filter) = read.filter.as_deref() {
+            let predicate = self.translate_expr(filter, &columns)?;
+            s_expr = filter_expr(s_expr, predicate);
+        }
+        if let Some(select) = read.projection.as_ref().and_then(|p| p.select.as_ref()) {
+            columns = select
+                .struct_items
+                .iter()
+                .map(|item| field_column(&columns, item.field).cloned())
+                .collect::<Result<Vec<_>>>()?;
+        }
+        Ok((s_expr, columns))
+    }
+
+    /// The output columns are the grouping expressions followed by the measures.
+    fn translate_aggregate(
+        &self,
+        aggregate: &AggregateRel,
+        mut s_expr: SExpr,
+        input_columns: Vec<ColumnBinding>,
+    ) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let grouping_expressions = match aggregate.groupings.as_slice() {
+            [] => &[][..],
+            [grouping] => grouping.grouping_expressions.as_slice(),
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "Multiple groupings are not supported in substrait plans",
+                ));
+            }
+        };
+
+        // The group items and the arguments of aggregate functions are evaluated before aggregating.
+        let mut scalar_items = vec![];
+        let mut group_items = Vec::with_capacity(grouping_expressions.len());
+        let mut columns = Vec::with_capacity(grouping_expressions.len() + aggregate.measures.len());
+        for expr in grouping_expressions {
+            let scalar = self.translate_expr(expr, &input_columns)?;
+            let column = self.derive_column(scalar, &mut scalar_items)?;
+            group_items.push(ScalarItem {
+                scalar: column_ref(&column),
+                index: column.index,
+            });
+            columns.push(column);
+        }
+
+        let mut aggregate_functions = Vec::with_capacity(aggregate.measures.len());
+        for measure in aggregate.measures.iter() {
+            let func = required(measure.measure.as_ref(), "aggregate function")?;
+            let name = self.function_name(func.function_reference)?;
+            let mut func_name = match name {
+                "any_value" => "any",
+                "std_dev" => "stddev_samp",
+                _ => name,
+            }
+            .to_string();
+            let distinct = func.invocation() == AggregationInvocation::Distinct;
+            if distinct {
+                if measure.filter.is_some() {
+                    return Err(ErrorCode::Unimplemented(
+                        "Distinct aggregate functions with filter are not supported in substrait plans",
+                    ));
+                }
+                func_name = format!("{func_name}_distinct");
+            }
+
+            let mut args = vec![];
+            for arg in self.translate_arguments(&func.arguments, &input_columns)?.1 {
+                args.push(column_ref(&self.derive_column(arg, &mut scalar_items)?));
+            }
+            if let Some(filter) = &measure.filter {
+                let predicate = self.translate_expr(filter, &input_columns)?;
+                args.push(column_ref(
+                    &self.derive_column(predicate, &mut scalar_items)?,
+                ));
+                func_name = format!("{func_name}_if");
+            }
+
+            let arg_types = args
+                .iter()
+                .map(|arg| arg.data_type())
+                .collect::<Result<Vec<_>>>()?;
+            let return_type = AggregateFunctionFactory::instance()
+                .get(&func_name, vec![], arg_types)?
+                .return_type()?;
+            let display_name =
+                format!("{func_name}({})", args.iter().map(format_scalar).join(", "));
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(display_name.clone(), return_type.clone());
+            aggregate_functions.push(ScalarItem {
+                scalar: AggregateFunction {
+                    func_name,
+                    distinct: false,
+                    params: vec![],
+                    args,
+                    return_type: Box::new(return_type.clone()),
+                    display_name: display_name.clone(),
+                }
+                .into(),
+                index,
+            });
+            columns.push(
+                ColumnBindingBuilder::new(
+                    display_name,
+                    index,
+                    Box::new(return_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            );
+        }
+
+        if !scalar_items.is_empty() {
+            s_expr = eval_scalar_expr(s_expr, scalar_items);
+        }
+        let aggregate_plan = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items,
+            aggregate_functions,
+            from_distinct: false,
+            limit: None,
+            grouping_sets: None,
+        };
+        let s_expr = SExpr::create_unary(Arc::new(aggregate_plan.into()), Arc::new(s_expr));
+        Ok((s_expr, columns))
+    }
+
+    /// The join expression and the post join filter refer to the columns of both inputs.
+    fn translate_join(
+        &self,
+        join: &JoinRel,
+        (left, left_columns): (SExpr, Vec<ColumnBinding>),
+        (right, right_columns): (SExpr, Vec<ColumnBinding>),
+    ) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let join_type = match join.r#type() {
+            join_rel::JoinType::Inner => JoinType::Inner,
+            join_rel::JoinType::Outer => JoinType::Full,
+            join_rel::JoinType::Left => JoinType::Left,
+            join_rel::JoinType::Right => JoinType::Right,
+            join_rel::JoinType::Semi => JoinType::LeftSemi,
+            join_rel::JoinType::Anti => JoinType::LeftAnti,
+            join_rel::JoinType::Single => JoinType::LeftSingle,
+            join_rel::JoinType::Unspecified => {
+                return Err(invalid_plan("unspecified join type"));
+            }
+        };
+
+        let mut all_columns = left_columns.clone();
+        all_columns.extend(right_columns.iter().cloned());
+        let left_set: ColumnSet = left_columns.iter().map(|column| column.index).collect();
+        let right_set: ColumnSet = right_columns.iter().map(|column| column.index).collect();
+
+        let mut join_plan = Join {
+            join_type: join_type.clone(),
+            ..Default::default()
+        };
+        if let Some(expr) = join.expression.as_deref() {
+            let condition = self.translate_expr(expr, &all_columns)?;
+            for predicate in split_conjunctions(&condition) {
+                match split_equivalent_predicate(&predicate) {
+                    Some((l, r))
+                        if l.used_columns().is_subset(&left_set)
+                            && r.used_columns().is_subset(&right_set) =>
+                    {
+                        join_plan.left_conditions.push(l);
+                        join_plan.right_conditions.push(r);
+                    }
+                    Some((l, r))
+                        if l.used_columns().is_subset(&right_set)
+                            && r.used_columns().is_subset(&left_set) =>
+                    {
+                        join_plan.left_conditions.push(r);
+                        join_plan.right_conditions.push(l);
+                    }
+                    _ => join_plan.non_equi_conditions.push(predicate),
+                }
+            }
+        }
+        if join_plan.join_type == JoinType::Inner
+            && join_plan.left_conditions.is_empty()
+            && join_plan.non_equi_conditions.is_empty()
+        {
+            join_plan.join_type = JoinType::Cross;
+        }
+
+        let nullable = |columns: Vec<ColumnBinding>| {
+            columns
+                .into_iter()
+                .map(|mut column| {
+                    column.data_type = Box::new(column.data_type.wrap_nullable());
+                    column
+                })
+                .collect::<Vec<_>>()
+        };
+        let columns = match join_type {
+            JoinType::Left | JoinType::LeftSingle => {
+                let mut columns = left_columns;
+                columns.extend(nullable(right_columns));
+                columns
+            }
+            JoinType::Right => {
+                let mut columns = nullable(left_columns);
+                columns.extend(right_columns);
+                columns
+            }
+            JoinType::Full => {
+                let mut columns = nullable(left_columns);
+                columns.extend(nullable(right_columns));
+                columns
+            }
+            JoinType::LeftSemi | JoinType::LeftAnti => left_columns,
+            _ => all_columns,
+        };
+
+        let mut s_expr =
+            SExpr::create_binary(Arc::new(join_plan.into()), Arc::new(left), Arc::new(right));
+        if let Some(filter) = join.post_join_filter.as_deref() {
+            let predicate = self.translate_expr(filter, &columns)?;
+            s_expr = filter_expr(s_expr, predicate);
+        }
+        Ok((s_expr, columns))
+    }
+
+    fn translate_sort(
+        &self,
+        sort: &SortRel,
+        mut s_expr: SExpr,
+        columns: &[ColumnBinding],
+    ) -> Result<SExpr> {
+        let mut scalar_items = vec![];
+        let mut items = Vec::with_capacity(sort.sorts.len());
+        for field in sort.sorts.iter() {
+            let expr = required(field.expr.as_ref(), "sort expression")?;
+            let scalar = self.translate_expr(expr, columns)?;
+            let column = self.derive_column(scalar, &mut scalar_items)?;
+            let direction = match &field.sort_kind {
+                Some(SortKind::Direction(direction)) => SortDirection::try_from(*direction).ok(),
+                _ => None,
+            };
+            let (asc, nulls_first) = match direction {
+                Some(SortDirection::AscNullsFirst) => (true, true),
+                Some(SortDirection::AscNullsLast) => (true, false),
+                Some(SortDirection::DescNullsFirst) => (false, true),
+                Some(SortDirection::DescNullsLast) => (false, false),
+                _ => {
+                    return Err(ErrorCode::Unimplemented(
+                        "Only ascending and descending sort directions are supported in substrait plans",
+                    ));
+                }
+            };
+            items.push(SortItem {
+                index: column.index,
+                asc,
+                nulls_first,
+            });
+        }
+
+        if !scalar_items.is_empty() {
+            s_expr = eval_scalar_expr(s_expr, scalar_items);
+        }
+        let sort_plan = Sort {
+            items,
+            limit: None,
+            after_exchange: None,
+            pre_projection: None,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(sort_plan.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// Get the column of the scalar, a derived column evaluated by `scalar_items` is added
+    /// if the scalar is not a column reference.
+    fn derive_column(
+        &self,
+        scalar: ScalarExpr,
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> Result<ColumnBinding> {
+        if let ScalarExpr::BoundColumnRef(column_ref) = &scalar {
+            return Ok(column_ref.column.clone());
+        }
+        let data_type = scalar.data_type()?;
+        let name = format_scalar(&scalar);
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(name.clone(), data_type.clone());
+        scalar_items.push(ScalarItem { scalar, index });
+        Ok(
+            ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible)
+                .build(),
+        )
+    }
+}
+
+/// Get the column at `field` of the input columns.
+pub(super) fn field_column(columns: &[ColumnBinding], field: i32) -> Result<&ColumnBinding> {
+    usize::try_from(field)
+        .ok()
+        .and_then(|field| columns.get(field))
+        .ok_or_else(|| {
+            invalid_plan(format!(
+                "field {field} is out of range, the input has {} columns",
+                columns.len()
+            ))
+        })
+}
+
+fn column_ref(column: &ColumnBinding) -> ScalarExpr {
+    BoundColumnRef {
+        span: None,
+        column: column.clone(),
+    }
+    .into()
+}
+
+fn filter_expr(s_expr: SExpr, predicate: ScalarExpr) -> SExpr {
+    let filter = Filter {
+        predicates: split_conjunctions(&predicate),
+    };
+    SExpr::create_unary(Arc::new(filter.into()), Arc::new(s_expr))
+}
+
+fn eval_scalar_expr(s_expr: SExpr, items: Vec<ScalarItem>) -> SExpr {
+    let eval_scalar = EvalScalar { items };
+    SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr))
+}
+
+/// Reorder the output columns of a relation by its emit mapping.
+fn emit_columns(
+    common: Option<&RelCommon>,
+    columns: Vec<ColumnBinding>,
+) -> Result<Vec<ColumnBinding>> {
+    match common.and_then(|common| common.emit_kind.as_ref()) {
+        Some(EmitKind::Emit(emit)) => emit
+            .output_mapping
+            .iter()
+            .map(|field| field_column(&columns, *field).cloned())
+            .collect(),
+        _ => Ok(columns),
+    }
+}
+
+/// The names of a `NamedStruct` are flattened in depth-first order, including the names
+/// of the nested struct fields. Returns the names of the top level fields.
+fn top_level_names(schema: &NamedStruct) -> Result<Vec<String>> {
+    let Some(r#struct) = &schema.r#struct else {
+        return Ok(schema.names.clone());
+    };
+    let mut names = Vec::with_capacity(r#struct.types.len());
+    let mut position = 0;
+    for ty in r#struct.types.iter() {
+        let name = schema
+            .names
+            .get(position)
+            .ok_or_else(|| invalid_plan("the names of base schema don't match the types"))?;
+        names.push(name.clone());
+        position += 1 + nested_name_count(ty);
+    }
+    Ok(names)
+}
+
+fn nested_name_count(ty: &Type) -> usize {
+    match &ty.kind {
+        Some(Kind::Struct(s)) => s.types.iter().map(|ty| 1 + nested_name_count(ty)).sum(),
+        Some(Kind::List(list)) => list.r#type.as_deref().map_or(0, nested_name_count),
+        Some(Kind::Map(map)) => {
+            map.key.as_deref().map_or(0, nested_name_count)
+                + map.value.as_deref().map_or(0, nested_name_count)
+        }
+        _ => 0,
+    }
+}
@@ -0,0 +1,411 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::number::F32;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Scalar;
+use substrait::proto::expression::cast::FailureBehavior;
+use substrait::proto::expression::field_reference::ReferenceType;
+use substrait::proto::expression::field_reference::RootType;
+use substrait::proto::expression::literal::LiteralType;
+use substrait::proto::expression::reference_segment;
+use substrait::proto::expression::FieldReference;
+use substrait::proto::expression::Literal;
+use substrait::proto::expression::RexType;
+use substrait::proto::expression::ScalarFunction;
+use substrait::proto::function_argument::ArgType;
+use substrait::proto::r#type::Kind;
+use substrait::proto::r#type::Nullability;
+use substrait::proto::Expression;
+use substrait::proto::FunctionArgument;
+use substrait::proto::Type;
+
+use super::relation::field_column;
+use super::SubstraitPlanner;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::FunctionCall;
+use crate::ColumnBinding;
+use crate::ScalarExpr;
+
+pub(super) fn invalid_plan(msg: impl Display) -> ErrorCode {
+    ErrorCode::BadArguments(format!("Invalid substrait plan: {msg}"))
+}
+
+pub(super) fn required<'a, T>(value: Option<&'a T>, name: &str) -> Result<&'a T> {
+    value.ok_or_else(|| invalid_plan(format!("{name} is missing")))
+}
+
+impl SubstraitPlanner {
+    pub(super) fn function_name(&self, anchor: u32) -> Result<&str> {
+        self.functions
+            .get(&anchor)
+            .map(|name| name.as_str())
+            .ok_or_else(|| invalid_plan(format!("function anchor {anchor} is not declared")))
+    }
+
+    /// Translate the expression, the field references refer to `columns`.
+    pub(super) fn translate_expr(
+        &self,
+        expr: &Expression,
+        columns: &[ColumnBinding],
+    ) -> Result<ScalarExpr> {
+        match required(expr.rex_type.as_ref(), "expression")? {
+            RexType::Literal(literal) => translate_literal(literal),
+            RexType::Selection(field) => Ok(BoundColumnRef {
+                span: None,
+                column: field_reference_column(field, columns)?.clone(),
+            }
+            .into()),
+            RexType::ScalarFunction(func) => self.translate_scalar_function(func, columns),
+            RexType::Cast(cast) => {
+                let input = required(cast.input.as_deref(), "cast input")?;
+                let target_type = required(cast.r#type.as_ref(), "cast type")?;
+                Ok(CastExpr {
+                    span: None,
+                    is_try: cast.failure_behavior() == FailureBehavior::ReturnNull,
+                    argument: Box::new(self.translate_expr(input, columns)?),
+                    target_type: Box::new(translate_type(target_type)?),
+                }
+                .into())
+            }
+            RexType::IfThen(if_then) => {
+                // Translate to `if(cond1, then1, cond2, then2, ..., else)`
+                let mut args = Vec::with_capacity(if_then.ifs.len() * 2 + 1);
+                for clause in if_then.ifs.iter() {
+                    let cond = required(clause.r#if.as_ref(), "if condition")?;
+                    let then = required(clause.then.as_ref(), "then expression")?;
+                    args.push(self.translate_expr(cond, columns)?);
+                    args.push(self.translate_expr(then, columns)?);
+                }
+                match if_then.r#else.as_deref() {
+                    Some(r#else) => args.push(self.translate_expr(r#else, columns)?),
+                    None => args.push(null_constant()),
+                }
+                function_call("if", args)
+            }
+            RexType::SingularOrList(list) => {
+                // Translate `x IN (a, b)` to `x = a OR x = b`
+                let value = required(list.value.as_deref(), "IN list value")?;
+                let value = self.translate_expr(value, columns)?;
+                let mut result = None;
+                for option in list.options.iter() {
+                    let option = self.translate_expr(option, columns)?;
+                    let eq = function_call("eq", vec![value.clone(), option])?;
+                    result = Some(match result {
+                        Some(prev) => function_call("or", vec![prev, eq])?,
+                        None => eq,
+                    });
+                }
+                result.ok_or_else(|| invalid_plan("IN list without options"))
+            }
+            _ => Err(ErrorCode::Unimplemented(
+                "Only literal, field reference, scalar function, cast, if-then and IN list expressions are supported in substrait plans",
+            )),
+        }
+    }
+
+    /// Returns the enum arguments and the value arguments.
+    pub(super) fn translate_arguments(
+        &self,
+        arguments: &[FunctionArgument],
+        columns: &[ColumnBinding],
+    ) -> Result<(Vec<String>, Vec<ScalarExpr>)> {
+        let mut enums = vec![];
+        let mut values = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            match required(argument.arg_type.as_ref(), "function argument")? {
+                ArgType::Value(expr) => values.push(self.translate_expr(expr, columns)?),
+                ArgType::Enum(value) => enums.push(value.to_ascii_uppercase()),
+                // The type arguments only describe the output type.
+                ArgType::Type(_) => {}
+            }
+        }
+        Ok((enums, values))
+    }
+
+    fn translate_scalar_function(
+        &self,
+        func: &ScalarFunction,
+        columns: &[ColumnBinding],
+    ) -> Result<ScalarExpr> {
+        let name = self.function_name(func.function_reference)?;
+        let (enums, mut args) = self.translate_arguments(&func.arguments, columns)?;
+        match (name, args.len()) {
+            ("is_null", 1) => {
+                // Rewrite is_null(x) to not(is_not_null(x))
+                let is_not_null = function_call("is_not_null", args)?;
+                function_call("not", vec![is_not_null])
+            }
+            ("between", 3) => {
+                // Rewrite between(x, low, high) to x >= low and x <= high
+                let (high, low, x) = (
+                    args.pop().unwrap(),
+                    args.pop().unwrap(),
+                    args.pop().unwrap(),
+                );
+                let gte = function_call("gte", vec![x.clone(), low])?;
+                let lte = function_call("lte", vec![x, high])?;
+                function_call("and", vec![gte, lte])
+            }
+            ("and" | "or", n) if n > 2 => {
+                // The Substrait boolean functions are variadic, fold them into binary calls.
+                let mut args = args.into_iter();
+                let mut result = args.next().unwrap();
+                for arg in args {
+                    result = function_call(name, vec![result, arg])?;
+                }
+                Ok(result)
+            }
+            ("divide", 2) => {
+                // Substrait `divide` truncates on integers, which is `div` in Databend.
+                let mut is_integer = true;
+                for arg in args.iter() {
+                    is_integer &= arg.data_type()?.remove_nullable().is_integer();
+                }
+                let func_name = if is_integer { "div" } else { "divide" };
+                function_call(func_name, args)
+            }
+            ("and_not", 2) => {
+                let b = function_call("not", vec![args.pop().unwrap()])?;
+                function_call("and", vec![args.pop().unwrap(), b])
+            }
+            ("coalesce", n) if n > 0 => {
+                // Rewrite coalesce(a, b, c) to if(is_not_null(a), a, if(is_not_null(b), b, c))
+                let mut result = args.pop().unwrap();
+                while let Some(arg) = args.pop() {
+                    let is_not_null = function_call("is_not_null", vec![arg.clone()])?;
+                    result = function_call("if", vec![is_not_null, arg, result])?;
+                }
+                Ok(result)
+            }
+            ("starts_with" | "ends_with", 2) => {
+                // Rewrite starts_with(s, p) to left(s, length(p)) = p
+                let pattern = args.pop().unwrap();
+                let len = function_call("character_length", vec![pattern.clone()])?;
+                let func_name = if name == "starts_with" {
+                    "left"
+                } else {
+                    "right"
+                };
+                let prefix = function_call(func_name, vec![args.pop().unwrap(), len])?;
+                function_call("eq", vec![prefix, pattern])
+            }
+            ("contains", 2) => {
+                // Rewrite contains(s, p) to position(p, s) > 0
+                args.swap(0, 1);
+                let position = function_call("position", args)?;
+                function_call("gt", vec![
+                    position,
+                    ConstantExpr {
+                        span: None,
+                        value: Scalar::Number(NumberScalar::UInt64(0)),
+                    }
+                    .into(),
+                ])
+            }
+            ("strpos", 2) => {
+                args.swap(0, 1);
+                function_call("position", args)
+            }
+            ("extract", 1) => {
+                let component = enums.first().map(|s| s.as_str()).unwrap_or_default();
+                let func_name = match component {
+                    "YEAR" => "to_year",
+                    "QUARTER" => "to_quarter",
+                    "MONTH" => "to_month",
+                    "DAY" => "to_day_of_month",
+                    "DAY_OF_YEAR" => "to_day_of_year",
+                    "DAY_OF_WEEK" => "to_day_of_week",
+                    "HOUR" => "to_hour",
+                    "MINUTE" => "to_minute",
+                    "SECOND" => "to_second",
+                    _ => {
+                        return Err(ErrorCode::Unimplemented(format!(
+                            "Unsupported component {component:?} of extract in substrait plans"
+                        )));
+                    }
+                };
+                function_call(func_name, args)
+            }
+            _ => function_call(scalar_function_name(name), args),
+        }
+    }
+}
+
+/// Map the names of the Substrait standard functions to builtin functions,
+/// the functions with the same names are kept as they are.
+fn scalar_function_name(name: &str) -> &str {
+    match name {
+        "add" => "plus",
+        "subtract" | "negate" => "minus",
+        "modulus" => "modulo",
+        "power" => "pow",
+        "equal" => "eq",
+        "not_equal" => "noteq",
+        "char_length" => "character_length",
+        "substring" => "substr",
+        _ => name,
+    }
+}
+
+fn function_call(func_name: &str, arguments: Vec<ScalarExpr>) -> Result<ScalarExpr> {
+    let scalar: ScalarExpr = FunctionCall {
+        span: None,
+        func_name: func_name.to_string(),
+        params: vec![],
+        arguments,
+    }
+    .into();
+    // Check the function signature eagerly to report the unsupported functions on planning.
+    scalar.data_type()?;
+    Ok(scalar)
+}
+
+fn null_constant() -> ScalarExpr {
+    ConstantExpr {
+        span: None,
+        value: Scalar::Null,
+    }
+    .into()
+}
+
+fn field_reference_column<'a>(
+    field: &FieldReference,
+    columns: &'a [ColumnBinding],
+) -> Result<&'a ColumnBinding> {
+    if !matches!(field.root_type, None | Some(RootType::RootReference(_))) {
+        return Err(ErrorCode::Unimplemented(
+            "Outer references are not supported in substrait plans",
+        ));
+    }
+    match &field.reference_type {
+        Some(ReferenceType::DirectReference(segment)) => match &segment.reference_type {
+            Some(reference_segment::ReferenceType::StructField(struct_field))
+                if struct_field.child.is_none() =>
+            {
+                field_column(columns, struct_field.field)
+            }
+            _ => Err(ErrorCode::Unimplemented(
+                "Only references to top level fields are supported in substrait plans",
+            )),
+        },
+        _ => Err(ErrorCode::Unimplemented(
+            "Masked references are not supported in substrait plans",
+        )),
+    }
+}
+
+fn translate_literal(literal: &Literal) -> Result<ScalarExpr> {
+    let value = match required(literal.literal_type.as_ref(), "literal")? {
+        LiteralType::Boolean(v) => Scalar::Boolean(*v),
+        LiteralType::I8(v) => Scalar::Number(NumberScalar::Int8(*v as i8)),
+        LiteralType::I16(v) => Scalar::Number(NumberScalar::Int16(*v as i16)),
+        LiteralType::I32(v) => Scalar::Number(NumberScalar::Int32(*v)),
+        LiteralType::I64(v) => Scalar::Number(NumberScalar::Int64(*v)),
+        LiteralType::Fp32(v) => Scalar::Number(NumberScalar::Float32(F32::from(*v))),
+        LiteralType::Fp64(v) => Scalar::Number(NumberScalar::Float64(F64::from(*v))),
+        LiteralType::String(v) | LiteralType::FixedChar(v) => Scalar::String(v.clone()),
+        LiteralType::VarChar(v) => Scalar::String(v.value.clone()),
+        LiteralType::Binary(v) | LiteralType::FixedBinary(v) => Scalar::Binary(v.clone()),
+        LiteralType::Date(v) => Scalar::Date(*v),
+        LiteralType::Timestamp(v) | LiteralType::TimestampTz(v) => Scalar::Timestamp(*v),
+        LiteralType::Decimal(v) => {
+            // The value is a 16 bytes little-endian two's complement integer.
+            let bytes: [u8; 16] = v
+                .value
+                .as_slice()
+                .try_into()
+                .map_err(|_| invalid_plan("decimal literal should have 16 bytes"))?;
+            let size = DecimalSize {
+                precision: v.precision as u8,
+                scale: v.scale as u8,
+            };
+            Scalar::Decimal(DecimalScalar::Decimal128(i128::from_le_bytes(bytes), size))
+        }
+        LiteralType::Null(ty) => {
+            return Ok(CastExpr {
+                span: None,
+                is_try: false,
+                argument: Box::new(null_constant()),
+                target_type: Box::new(translate_type(ty)?.wrap_nullable()),
+            }
+            .into());
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(
+                "Unsupported literal type in substrait plans",
+            ));
+        }
+    };
+    Ok(ConstantExpr { span: None, value }.into())
+}
+
+fn translate_type(ty: &Type) -> Result<DataType> {
+    let (data_type, nullability) = match required(ty.kind.as_ref(), "type kind")? {
+        Kind::Bool(t) => (DataType::Boolean, t.nullability),
+        Kind::I8(t) => (DataType::Number(NumberDataType::Int8), t.nullability),
+        Kind::I16(t) => (DataType::Number(NumberDataType::Int16), t.nullability),
+        Kind::I32(t) => (DataType::Number(NumberDataType::Int32), t.nullability),
+        Kind::I64(t) => (DataType::Number(NumberDataType::Int64), t.nullability),
+        Kind::Fp32(t) => (DataType::Number(NumberDataType::Float32), t.nullability),
+        Kind::Fp64(t) => (DataType::Number(NumberDataType::Float64), t.nullability),
+        Kind::String(t) => (DataType::String, t.nullability),
+        Kind::Varchar(t) => (DataType::String, t.nullability),
+        Kind::FixedChar(t) => (DataType::String, t.nullability),
+        Kind::Binary(t) => (DataType::Binary, t.nullability),
+        Kind::FixedBinary(t) => (DataType::Binary, t.nullability),
+        Kind::Date(t) => (DataType::Date, t.nullability),
+        Kind::Timestamp(t) => (DataType::Timestamp, t.nullability),
+        Kind::TimestampTz(t) => (DataType::Timestamp, t.nullability),
+        Kind::Decimal(t) => {
+            let size = DecimalSize {
+                precision: t.precision as u8,
+                scale: t.scale as u8,
+            };
+            (
+                DataType::Decimal(DecimalDataType::from_size(size)?),
+                t.nullability,
+            )
+        }
+        Kind::List(t) => {
+            let inner = required(t.r#type.as_deref(), "list element type")?;
+            (
+                DataType::Array(Box::new(translate_type(inner)?)),
+                t.nullability,
+            )
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(
+                "Unsupported data type in substrait plans",
+            ));
+        }
+    };
+    if nullability == Nullability::Nullable as i32 {
+        Ok(data_type.wrap_nullable())
+    } else {
+        Ok(data_type)
+    }
+}
@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_sql::optimizer::histogram_from_column_histogram;
 use databend_common_sql::optimizer::Histogram;
 use databend_common_sql::optimizer::HistogramBucket;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::HistogramBucket as ColumnHistogramBucket;
 
 #[test]
 fn test_histogram() {
@@ -28,3 +33,45 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_column_histogram() {
+    let histogram = ColumnHistogram {
+        buckets: vec![
+            ColumnHistogramBucket {
+                lower_bound: Scalar::Number(NumberScalar::Int32(1)),
+                upper_bound: Scalar::Number(NumberScalar::Int32(1)),
+                num_values: 80.0,
+                num_distinct: 1.0,
+            },
+            ColumnHistogramBucket {
+                lower_bound: Scalar::Number(NumberScalar::Int32(2)),
+                upper_bound: Scalar::Number(NumberScalar::Int32(100)),
+                num_values: 20.0,
+                num_distinct: 20.0,
+            },
+        ],
+        most_common_values: vec![(Scalar::Number(NumberScalar::Int32(1)), 80.0)],
+    };
+
+    let histogram = histogram_from_column_histogram(&histogram).unwrap();
+    // The first bucket is a dummy bucket of the min value.
+    assert_eq!(histogram.num_buckets(), 3);
+    assert_eq!(histogram.buckets[0].upper_bound(), &Datum::Int(1));
+    assert_eq!(histogram.num_values(), 100.0);
+    assert_eq!(
+        histogram.most_common_value_count(&Datum::Int(1)),
+        Some(80.0)
+    );
+    assert_eq!(
+        histogram.most_common_value_count(&Datum::UInt(1)),
+        Some(80.0)
+    );
+    assert_eq!(histogram.most_common_value_count(&Datum::Int(2)), None);
+
+    let empty = ColumnHistogram {
+        buckets: vec![],
+        most_common_values: vec![],
+    };
+    assert!(histogram_from_column_histogram(&empty).is_none());
+}
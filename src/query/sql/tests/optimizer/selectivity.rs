@@ -0,0 +1,113 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_sql::optimizer::ColumnStat;
+use databend_common_sql::optimizer::Histogram;
+use databend_common_sql::optimizer::HistogramBucket;
+use databend_common_sql::optimizer::SelectivityEstimator;
+use databend_common_sql::optimizer::Statistics;
+use databend_common_sql::optimizer::MAX_SELECTIVITY;
+use databend_common_sql::plans::BoundColumnRef;
+use databend_common_sql::plans::ConstantExpr;
+use databend_common_sql::plans::FunctionCall;
+use databend_common_sql::plans::ScalarExpr;
+use databend_common_sql::ColumnBindingBuilder;
+use databend_common_sql::Visibility;
+use databend_common_storage::Datum;
+
+// 900 of the 1000 values are 1, the others are 2..=100.
+fn skewed_column_stat() -> ColumnStat {
+    let mut histogram = Histogram::new(vec![
+        HistogramBucket::new(Datum::Int(1), 900.0, 1.0),
+        HistogramBucket::new(Datum::Int(100), 100.0, 99.0),
+    ]);
+    histogram.most_common_values = vec![(Datum::Int(1), 900.0)];
+    ColumnStat {
+        min: Datum::Int(1),
+        max: Datum::Int(100),
+        ndv: 100.0,
+        null_count: 0,
+        histogram: Some(histogram),
+    }
+}
+
+fn equal(index: usize, value: i32) -> ScalarExpr {
+    let column = ColumnBindingBuilder::new(
+        format!("c{index}"),
+        index,
+        Box::new(DataType::Number(NumberDataType::Int32)),
+        Visibility::Visible,
+    )
+    .build();
+    ScalarExpr::FunctionCall(FunctionCall {
+        span: None,
+        func_name: "eq".to_string(),
+        params: vec![],
+        arguments: vec![
+            ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
+            ScalarExpr::ConstantExpr(ConstantExpr {
+                span: None,
+                value: Scalar::Number(NumberScalar::Int32(value)),
+            }),
+        ],
+    })
+}
+
+// Estimates the selectivity of the conjunct predicates the way a filter does.
+fn filter_selectivity(statistics: &mut Statistics, predicates: &[ScalarExpr]) -> Result<f64> {
+    let mut sb = SelectivityEstimator::new(statistics, HashSet::new());
+    let mut selectivity = MAX_SELECTIVITY;
+    for pred in predicates.iter() {
+        selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
+    }
+    sb.update_other_statistic_by_selectivity(selectivity);
+    Ok(selectivity)
+}
+
+#[test]
+fn test_most_common_values_selectivity() -> Result<()> {
+    let mut statistics = Statistics {
+        precise_cardinality: Some(1000),
+        column_stats: HashMap::from([
+            (0, skewed_column_stat()),
+            (1, skewed_column_stat()),
+            (2, skewed_column_stat()),
+        ]),
+    };
+
+    // c0 = 1 AND c1 = 1
+    let selectivity = filter_selectivity(&mut statistics, &[equal(0, 1), equal(1, 1)])?;
+    assert!((selectivity - 0.9).abs() < 1e-9);
+
+    // The statistics of c2 are scaled by the filter, the share of its most common
+    // value is kept.
+    let histogram = statistics.column_stats[&2].histogram.as_ref().unwrap();
+    assert!((histogram.num_values() - 900.0).abs() < 1e-9);
+    assert_eq!(
+        histogram.most_common_value_count(&Datum::Int(1)),
+        Some(810.0)
+    );
+
+    let selectivity = filter_selectivity(&mut statistics, &[equal(2, 1)])?;
+    assert!((selectivity - 0.9).abs() < 1e-9);
+    Ok(())
+}
@@ -44,7 +44,7 @@ pub fn blocks_to_parquet(
         .build();
     let batches = blocks
         .into_iter()
-        .map(|block| block.to_record_batch(&schema.into()))
+        .map(|block| block.to_storage_record_batch(&schema.into()))
         .collect::<Result<Vec<_>>>()?;
     let arrow_schema = Arc::new(table_schema_to_arrow_schema_ignore_inside_nullable(schema));
     let mut writer = ArrowWriter::try_new(write_buffer, arrow_schema, Some(props))?;
@@ -26,7 +26,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::InMemoryItemCacheHolder;
 use databend_storages_common_cache::NamedCache;
-use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::filters::FilterImpl;
 use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::SegmentInfo;
@@ -45,9 +45,9 @@ pub type TableSnapshotCache = NamedCache<InMemoryItemCacheHolder<TableSnapshot>>
 /// In memory object cache of TableSnapshotStatistics
 pub type TableSnapshotStatisticCache = NamedCache<InMemoryItemCacheHolder<TableSnapshotStatistics>>;
 /// In memory object cache of bloom filter.
-/// For each indexed data block, the bloom xor8 filter of column is cached individually
+/// For each indexed data block, the bloom filter of column is cached individually
 pub type BloomIndexFilterCache =
-    NamedCache<InMemoryItemCacheHolder<Xor8Filter, DefaultHashBuilder, BloomIndexFilterMeter>>;
+    NamedCache<InMemoryItemCacheHolder<FilterImpl, DefaultHashBuilder, BloomIndexFilterMeter>>;
 /// In memory object cache of parquet FileMetaData of bloom index data
 pub type BloomIndexMetaCache = NamedCache<InMemoryItemCacheHolder<BloomIndexMeta>>;
 /// In memory object cache of parquet FileMetaData of external parquet files
@@ -122,7 +122,7 @@ impl CachedObject<(PartStatistics, Partitions)> for (PartStatistics, Partitions)
     }
 }
 
-impl CachedObject<Xor8Filter, DefaultHashBuilder, BloomIndexFilterMeter> for Xor8Filter {
+impl CachedObject<FilterImpl, DefaultHashBuilder, BloomIndexFilterMeter> for FilterImpl {
     type Cache = BloomIndexFilterCache;
     fn cache() -> Option<Self::Cache> {
         CacheManager::instance().get_bloom_index_filter_cache()
@@ -158,10 +158,10 @@ impl Meter<String, Arc<CompactSegmentInfo>> for CompactSegmentInfoMeter {
 
 pub struct BloomIndexFilterMeter;
 
-impl Meter<String, Arc<Xor8Filter>> for BloomIndexFilterMeter {
+impl Meter<String, Arc<FilterImpl>> for BloomIndexFilterMeter {
     type Measure = usize;
 
-    fn measure<Q: ?Sized>(&self, _: &Q, value: &Arc<Xor8Filter>) -> Self::Measure {
-        std::mem::size_of::<Xor8Filter>() + value.filter.finger_prints.len()
+    fn measure<Q: ?Sized>(&self, _: &Q, value: &Arc<FilterImpl>) -> Self::Measure {
+        std::mem::size_of::<FilterImpl>() + value.mem_bytes()
     }
 }
@@ -41,6 +41,7 @@ use databend_common_expression::Expr;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
@@ -53,6 +54,8 @@ use databend_storages_common_table_meta::meta::Versioned;
 use crate::filters::BlockBloomFilterIndexVersion;
 use crate::filters::Filter;
 use crate::filters::FilterBuilder;
+use crate::filters::FilterImpl;
+use crate::filters::NgramBuilder;
 use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
@@ -123,6 +126,9 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// The string columns may also have a filter of their n-grams, stored with field name
+/// 'Ngram(column_id)', which is used to prune the blocks by `LIKE` and `regexp` patterns.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -134,12 +140,24 @@ pub struct BloomIndex {
     pub version: u64,
 
     ///  filters.
-    pub filters: Vec<Arc<Xor8Filter>>,
+    pub filters: Vec<Arc<FilterImpl>>,
 
     /// Approximate distinct count of columns generated by xor hash function.
     pub column_distinct_count: HashMap<FieldIndex, usize>,
 }
 
+/// The arguments to build the n-gram filter of a string column.
+#[derive(Clone, Debug)]
+pub struct NgramArgs {
+    /// The index of the column in the data block.
+    pub index: FieldIndex,
+    pub field: TableField,
+    /// The number of characters of a n-gram.
+    pub gram_size: usize,
+    /// The max size of the filter in bytes.
+    pub bloom_size: u64,
+}
+
 /// FilterExprEvalResult represents the evaluation result of an expression by a filter.
 ///
 /// For example, expression of 'age = 12' should return false is the filter are sure
@@ -158,7 +176,7 @@ impl BloomIndex {
     pub fn from_filter_block(
         func_ctx: FunctionContext,
         filter_schema: TableSchemaRef,
-        filters: Vec<Arc<Xor8Filter>>,
+        filters: Vec<Arc<FilterImpl>>,
         version: u64,
     ) -> Result<Self> {
         Ok(Self {
@@ -178,6 +196,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -264,7 +283,31 @@ impl BloomIndex {
 
             let filter_name = Self::build_filter_column_name(version, &field)?;
             filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
-            filters.push(Arc::new(filter));
+            filters.push(Arc::new(FilterImpl::from(filter)));
+        }
+
+        for arg in ngram_args {
+            let field_type = &data_blocks_tobe_indexed[0]
+                .get_by_offset(arg.index)
+                .data_type;
+            if !Self::supported_ngram_data_type(field_type) {
+                continue;
+            }
+            let mut filter_builder = NgramBuilder::create(arg.gram_size, arg.bloom_size);
+            for block in data_blocks_tobe_indexed {
+                let value = &block.get_by_offset(arg.index).value;
+                let column = value.convert_to_full_column(field_type, block.num_rows());
+                for scalar in column.iter() {
+                    if let ScalarRef::String(text) = scalar {
+                        filter_builder.add_text(text);
+                    }
+                }
+            }
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_ngram_column_name(&arg.field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
+            filters.push(Arc::new(FilterImpl::from(filter)));
         }
 
         if filter_fields.is_empty() {
@@ -327,6 +370,22 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_pattern_match(&mut expr, &mut |span, col_name, fragments, return_type| {
+            let filter_column =
+                &Self::build_ngram_column_name(data_schema.field_with_name(col_name)?);
+
+            // If the column doesn't contain all the fragments, we rewrite the expression to `false`.
+            if self.find_ngrams(filter_column, fragments)? == FilterEvalResult::MustFalse {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -423,6 +482,24 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the pattern of `col LIKE <constant>` or
+    /// `regexp_like(col, <constant>, 'c')` in the expression, and have literal fragments to search.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        fields: &[TableField],
+    ) -> Result<Vec<TableField>> {
+        let mut cols: Vec<TableField> = Vec::new();
+        visit_expr_pattern_match(&mut expr.clone(), &mut |_, col_name, fragments, _| {
+            if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                if !fragments.is_empty() && !cols.iter().any(|c| c.column_id() == v.column_id()) {
+                    cols.push(v.clone());
+                }
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -438,6 +515,16 @@ impl BloomIndex {
         }
     }
 
+    /// The n-gram filter of a string column is stored with field name 'Ngram(column_id)'.
+    pub fn build_ngram_column_name(field: &TableField) -> String {
+        format!("Ngram({})", field.column_id())
+    }
+
+    /// Whether the filter column stores a n-gram filter.
+    pub fn is_ngram_column_name(filter_column: &str) -> bool {
+        filter_column.starts_with("Ngram(")
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -472,6 +559,36 @@ impl BloomIndex {
         }
     }
 
+    fn find_ngrams(&self, filter_column: &str, fragments: &[String]) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a n-gram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let FilterImpl::Ngram(filter) = self.filters[idx].as_ref() else {
+            return Ok(FilterEvalResult::Uncertain);
+        };
+
+        if fragments
+            .iter()
+            .all(|fragment| filter.contains_text(fragment))
+        {
+            Ok(FilterEvalResult::Uncertain)
+        } else {
+            Ok(FilterEvalResult::MustFalse)
+        }
+    }
+
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        let data_type = DataType::from(data_type);
+        Self::supported_ngram_data_type(&data_type)
+    }
+
+    fn supported_ngram_data_type(data_type: &DataType) -> bool {
+        data_type.remove_nullable() == DataType::String
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let data_type = DataType::from(data_type);
         Self::supported_data_type(&data_type)
@@ -612,3 +729,172 @@ fn visit_map_column(
     }
     Ok(None)
 }
+
+/// Find patterns like `like(Column, <constant>)` or `regexp_like(Column, <constant>, <match type>)`,
+/// the visitor is called with the literal fragments that the matched values must contain.
+///
+/// `regexp(Column, <constant>)` and `regexp_like` without a case-sensitive match type are
+/// case-insensitive, the n-grams of the fragments can't be used to prune the blocks.
+fn visit_expr_pattern_match(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[String], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if let [
+            Expr::ColumnRef { id, data_type, .. },
+            Expr::Constant {
+                scalar: Scalar::String(pattern),
+                ..
+            },
+            match_type @ ..,
+        ] = args.as_slice()
+        {
+            let fragments = match (function.signature.name.as_str(), match_type) {
+                ("like", []) => Some(like_fragments(pattern)),
+                (
+                    "regexp_like",
+                    [
+                        Expr::Constant {
+                            scalar: Scalar::String(match_type),
+                            ..
+                        },
+                    ],
+                ) if is_case_sensitive_match_type(match_type) => regexp_fragments(pattern),
+                _ => None,
+            };
+            if let Some(fragments) = fragments {
+                if data_type.remove_nullable() == DataType::String {
+                    if let Some(new_expr) = visitor(*span, id, &fragments, return_type)? {
+                        *expr = new_expr;
+                    }
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_pattern_match(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_pattern_match(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Split the `LIKE` pattern by the wildcards `%` and `_`, a backslash escapes the next character.
+fn like_fragments(pattern: &str) -> Vec<String> {
+    let mut fragments = Vec::new();
+    let mut fragment = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' | '_' => take_fragment(&mut fragment, &mut fragments),
+            '\\' => fragment.push(chars.next().unwrap_or('\\')),
+            c => fragment.push(c),
+        }
+    }
+    take_fragment(&mut fragment, &mut fragments);
+    fragments
+}
+
+/// The match type of `regexp_like` is case-insensitive by default, the last `c` or `i` wins.
+fn is_case_sensitive_match_type(match_type: &str) -> bool {
+    match_type.chars().rev().find(|c| matches!(c, 'c' | 'i')) == Some('c')
+}
+
+/// Extract the literal fragments that a value must contain to match the regular expression,
+/// returns `None` if the fragments can't be extracted.
+///
+/// Alternations and groups may make any fragment optional, and escapes like `\x41`, `\pL` or
+/// `\d` don't match themselves, so nothing is extracted from them.
+fn regexp_fragments(pattern: &str) -> Option<Vec<String>> {
+    if pattern.contains(['|', '(']) {
+        return None;
+    }
+    let mut fragments = Vec::new();
+    let mut fragment = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(c) if is_regexp_meta_character(c) => fragment.push(c),
+                _ => return None,
+            },
+            // The quantified character may not appear.
+            '?' | '*' => {
+                fragment.pop();
+                take_fragment(&mut fragment, &mut fragments);
+            }
+            '{' => {
+                fragment.pop();
+                take_fragment(&mut fragment, &mut fragments);
+                if !chars.any(|c| c == '}') {
+                    return None;
+                }
+            }
+            '[' => {
+                take_fragment(&mut fragment, &mut fragments);
+                if !skip_regexp_class(&mut chars) {
+                    return None;
+                }
+            }
+            '+' | '.' | '^' | '$' => take_fragment(&mut fragment, &mut fragments),
+            c => fragment.push(c),
+        }
+    }
+    take_fragment(&mut fragment, &mut fragments);
+    Some(fragments)
+}
+
+/// The characters that match themselves when they are escaped.
+fn is_regexp_meta_character(c: char) -> bool {
+    r"\.+*?()|[]{}^$#&-~".contains(c)
+}
+
+/// Skip a character class whose opening `[` is consumed, returns false if it's not closed.
+fn skip_regexp_class(chars: &mut std::str::Chars) -> bool {
+    let mut class = String::new();
+    let mut depth = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+                class.push(c);
+            }
+            // A `]` at the beginning of the class is a literal.
+            ']' if class.is_empty() || class == "^" => class.push(c),
+            ']' if depth == 0 => return true,
+            ']' => {
+                depth -= 1;
+                class.push(c);
+            }
+            '[' => {
+                depth += 1;
+                class.push(c);
+            }
+            c => class.push(c),
+        }
+    }
+    false
+}
+
+fn take_fragment(fragment: &mut String, fragments: &mut Vec<String>) {
+    if !fragment.is_empty() {
+        fragments.push(std::mem::take(fragment));
+    }
+}
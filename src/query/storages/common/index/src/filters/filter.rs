@@ -14,6 +14,11 @@
 
 use std::hash::Hash;
 
+use databend_common_exception::Result;
+
+use crate::filters::NgramFilter;
+use crate::filters::Xor8Filter;
+
 // `len()` returns an Option thus `is_empty()` can not be provided.
 #[allow(clippy::len_without_is_empty)]
 pub trait Filter: Sized {
@@ -59,3 +64,54 @@ pub trait FilterBuilder {
     /// Build the filter with added keys.
     fn build(&mut self) -> Result<Self::Filter, Self::Error>;
 }
+
+/// The filters stored in the columns of a bloom index.
+///
+/// They are encoded as is, the kind of a filter is told by the name of its column.
+pub enum FilterImpl {
+    Xor(Xor8Filter),
+    Ngram(NgramFilter),
+}
+
+impl FilterImpl {
+    pub fn contains<K: ?Sized + Hash>(&self, key: &K) -> bool {
+        match self {
+            FilterImpl::Xor(filter) => filter.contains(key),
+            FilterImpl::Ngram(filter) => filter.contains(key),
+        }
+    }
+
+    pub fn contains_digest(&self, digest: u64) -> bool {
+        match self {
+            FilterImpl::Xor(filter) => filter.contains_digest(digest),
+            FilterImpl::Ngram(filter) => filter.contains_digest(digest),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(match self {
+            FilterImpl::Xor(filter) => filter.to_bytes()?,
+            FilterImpl::Ngram(filter) => filter.to_bytes()?,
+        })
+    }
+
+    /// The approximate memory size of the filter.
+    pub fn mem_bytes(&self) -> usize {
+        match self {
+            FilterImpl::Xor(filter) => filter.filter.finger_prints.len(),
+            FilterImpl::Ngram(filter) => filter.mem_bytes(),
+        }
+    }
+}
+
+impl From<Xor8Filter> for FilterImpl {
+    fn from(filter: Xor8Filter) -> Self {
+        FilterImpl::Xor(filter)
+    }
+}
+
+impl From<NgramFilter> for FilterImpl {
+    fn from(filter: NgramFilter) -> Self {
+        FilterImpl::Ngram(filter)
+    }
+}
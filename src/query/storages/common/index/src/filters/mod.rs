@@ -15,10 +15,16 @@
 //! Probabilistic filters
 
 mod filter;
+mod ngram_filter;
 mod xor8;
 
 pub use filter::Filter;
 pub use filter::FilterBuilder;
+pub use filter::FilterImpl;
+pub use ngram_filter::ngram_digests;
+pub use ngram_filter::NgramBuilder;
+pub use ngram_filter::NgramFilter;
+pub use ngram_filter::NgramFilterError;
 pub use xor8::BlockBloomFilterIndexVersion;
 pub use xor8::BlockFilter;
 pub use xor8::V2BloomBlock;
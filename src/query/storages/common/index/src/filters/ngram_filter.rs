@@ -0,0 +1,239 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use databend_common_exception::ErrorCode;
+use siphasher::sip::SipHasher24;
+
+use crate::filters::Filter;
+use crate::filters::FilterBuilder;
+
+/// Bits allocated for each distinct n-gram, gives a false positive rate about 1%.
+const BITS_PER_KEY: u64 = 10;
+
+const MAX_NUM_HASHES: u32 = 16;
+
+/// The keys of the hasher, the filter is persisted so the digests must never change.
+const HASH_KEYS: (u64, u64) = (0x6e67_7261_6d5f_6b30, 0x6e67_7261_6d5f_6b31);
+
+/// A bloom filter of the n-grams of a string column, used to prune the blocks by
+/// `LIKE` and regular expression patterns.
+///
+/// The size of the n-grams is stored with the filter, so a pattern can be split
+/// the same way as the values were.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NgramFilter {
+    gram_size: usize,
+    num_hashes: u32,
+    words: Vec<u64>,
+}
+
+/// A builder that builds a n-gram filter.
+///
+/// The filter is sized by the number of distinct n-grams, but never exceeds `bloom_size` bytes.
+pub struct NgramBuilder {
+    gram_size: usize,
+    bloom_size: u64,
+    digests: HashSet<u64>,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{msg}")]
+pub struct NgramFilterError {
+    msg: String,
+}
+
+impl NgramBuilder {
+    pub fn create(gram_size: usize, bloom_size: u64) -> Self {
+        NgramBuilder {
+            gram_size,
+            bloom_size,
+            digests: HashSet::new(),
+        }
+    }
+
+    /// Add the n-grams of a string.
+    pub fn add_text(&mut self, text: &str) {
+        self.digests.extend(ngram_digests(text, self.gram_size));
+    }
+}
+
+impl FilterBuilder for NgramBuilder {
+    type Filter = NgramFilter;
+    type Error = NgramFilterError;
+
+    fn add_key<K: Hash>(&mut self, key: &K) {
+        self.digests.insert(digest(key));
+    }
+
+    fn add_keys<K: Hash>(&mut self, keys: &[K]) {
+        self.digests.extend(keys.iter().map(digest::<K>));
+    }
+
+    fn add_digests<'i, I: IntoIterator<Item = &'i u64>>(&mut self, digests: I) {
+        self.digests.extend(digests);
+    }
+
+    fn build(&mut self) -> Result<Self::Filter, Self::Error> {
+        if self.gram_size == 0 || self.bloom_size == 0 {
+            return Err(NgramFilterError::new(format!(
+                "invalid n-gram filter, gram size: {}, bloom size: {}",
+                self.gram_size, self.bloom_size
+            )));
+        }
+        let num_keys = self.digests.len().max(1) as u64;
+        let num_bits = (num_keys * BITS_PER_KEY).min(self.bloom_size * 8);
+        let num_words = num_bits.div_ceil(64) as usize;
+        let bits_per_key = (num_words * 64) as f64 / num_keys as f64;
+        let num_hashes =
+            ((bits_per_key * std::f64::consts::LN_2).round() as u32).clamp(1, MAX_NUM_HASHES);
+
+        let mut filter = NgramFilter {
+            gram_size: self.gram_size,
+            num_hashes,
+            words: vec![0; num_words],
+        };
+        for digest in self.digests.drain() {
+            filter.insert_digest(digest);
+        }
+        Ok(filter)
+    }
+}
+
+impl NgramFilter {
+    /// The number of characters of a n-gram.
+    pub fn gram_size(&self) -> usize {
+        self.gram_size
+    }
+
+    /// The size of the bits in bytes.
+    pub fn mem_bytes(&self) -> usize {
+        self.words.len() * 8
+    }
+
+    /// Check if all the n-grams of the string are in the filter.
+    ///
+    /// Always returns `true` if the string is shorter than a n-gram.
+    pub fn contains_text(&self, text: &str) -> bool {
+        ngram_digests(text, self.gram_size).all(|digest| self.contains_digest(digest))
+    }
+
+    fn insert_digest(&mut self, digest: u64) {
+        let num_bits = self.words.len() as u64 * 64;
+        for bit in probes(digest, self.num_hashes, num_bits) {
+            self.words[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+}
+
+impl Filter for NgramFilter {
+    type CodecError = NgramFilterError;
+
+    fn contains<K: ?Sized + Hash>(&self, key: &K) -> bool {
+        self.contains_digest(digest(key))
+    }
+
+    fn contains_digest(&self, digest: u64) -> bool {
+        let num_bits = self.words.len() as u64 * 64;
+        probes(digest, self.num_hashes, num_bits)
+            .all(|bit| self.words[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Serialized as `gram_size: u32`, `num_hashes: u32`, then the words, all in little endian.
+    fn to_bytes(&self) -> Result<Vec<u8>, NgramFilterError> {
+        let mut buf = Vec::with_capacity(8 + self.mem_bytes());
+        buf.extend_from_slice(&(self.gram_size as u32).to_le_bytes());
+        buf.extend_from_slice(&self.num_hashes.to_le_bytes());
+        for word in self.words.iter() {
+            buf.extend_from_slice(&word.to_le_bytes());
+        }
+        Ok(buf)
+    }
+
+    fn from_bytes(buf: &[u8]) -> Result<(Self, usize), NgramFilterError> {
+        if buf.len() <= 8 || (buf.len() - 8) % 8 != 0 {
+            return Err(NgramFilterError::new(format!(
+                "invalid n-gram filter of {} bytes",
+                buf.len()
+            )));
+        }
+        let gram_size = u32::from_le_bytes(buf[0..4].try_into().unwrap()) as usize;
+        let num_hashes = u32::from_le_bytes(buf[4..8].try_into().unwrap());
+        let words = buf[8..]
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        Ok((
+            Self {
+                gram_size,
+                num_hashes,
+                words,
+            },
+            buf.len(),
+        ))
+    }
+}
+
+/// Returns the digests of the n-grams of the string, n-grams are counted in characters.
+pub fn ngram_digests(text: &str, gram_size: usize) -> impl Iterator<Item = u64> + '_ {
+    let offsets = text
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(text.len()))
+        .collect::<Vec<_>>();
+    let num_grams = if gram_size == 0 {
+        0
+    } else {
+        offsets.len().saturating_sub(gram_size)
+    };
+    (0..num_grams).map(move |i| {
+        let mut hasher = hasher();
+        hasher.write(text[offsets[i]..offsets[i + gram_size]].as_bytes());
+        hasher.finish()
+    })
+}
+
+fn digest<K: ?Sized + Hash>(key: &K) -> u64 {
+    let mut hasher = hasher();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hasher() -> SipHasher24 {
+    SipHasher24::new_with_keys(HASH_KEYS.0, HASH_KEYS.1)
+}
+
+/// The bits to set or check for a digest, by double hashing.
+fn probes(digest: u64, num_hashes: u32, num_bits: u64) -> impl Iterator<Item = u64> {
+    let h1 = digest & 0xFFFF_FFFF;
+    let h2 = digest >> 32;
+    (0..num_hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+}
+
+impl NgramFilterError {
+    pub fn new(msg: impl ToString) -> Self {
+        Self {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+impl From<NgramFilterError> for ErrorCode {
+    fn from(e: NgramFilterError) -> Self {
+        ErrorCode::Internal(e.to_string())
+    }
+}
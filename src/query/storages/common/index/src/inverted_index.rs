@@ -0,0 +1,294 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnId;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::scalars::Tokenizer;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::FilterEvalResult;
+
+/// The inverted index of a block, maps the terms of the indexed columns to the rows
+/// containing them.
+///
+/// It is stored as a parquet file alongside the block, one row per term:
+/// ```text
+///         +--column_id--+--term--+--row_ids--+
+///         |      1      |  "fox" |   [0, 3]  |
+///         +-------------+--------+-----------+
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    postings: BTreeMap<ColumnId, BTreeMap<String, Vec<u32>>>,
+}
+
+impl InvertedIndex {
+    /// The schema of the index file.
+    pub fn schema() -> TableSchemaRef {
+        Arc::new(TableSchema::new(vec![
+            TableField::new("column_id", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new("term", TableDataType::String),
+            TableField::new(
+                "row_ids",
+                TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+        ]))
+    }
+
+    /// Create the index from the string columns of a block, NULL values are not indexed.
+    pub fn try_create(tokenizer: Tokenizer, columns: &[(ColumnId, Column)]) -> Result<Self> {
+        let mut postings = BTreeMap::new();
+        for (column_id, column) in columns {
+            let column_postings: &mut BTreeMap<String, Vec<u32>> =
+                postings.entry(*column_id).or_default();
+            for (row, value) in column.iter().enumerate() {
+                let text = match value {
+                    ScalarRef::String(text) => text,
+                    ScalarRef::Null => continue,
+                    _ => {
+                        return Err(ErrorCode::UnsupportedIndex(format!(
+                            "Inverted index only support String type, but got {}",
+                            column.data_type()
+                        )));
+                    }
+                };
+                for term in tokenizer.tokenize(text) {
+                    let row_ids = column_postings.entry(term).or_default();
+                    if row_ids.last() != Some(&(row as u32)) {
+                        row_ids.push(row as u32);
+                    }
+                }
+            }
+        }
+        Ok(Self { postings })
+    }
+
+    pub fn serialize_to_data_block(&self) -> DataBlock {
+        let mut column_ids = Vec::new();
+        let mut terms = Vec::new();
+        let mut row_ids = Vec::new();
+        let mut offsets = vec![0];
+        for (column_id, column_postings) in self.postings.iter() {
+            for (term, term_row_ids) in column_postings.iter() {
+                column_ids.push(*column_id);
+                terms.push(term.clone());
+                row_ids.extend_from_slice(term_row_ids);
+                offsets.push(row_ids.len() as u64);
+            }
+        }
+        let row_ids = ArrayType::<UInt32Type>::upcast_column(ArrayColumn {
+            values: row_ids.into(),
+            offsets: offsets.into(),
+        });
+        DataBlock::new_from_columns(vec![
+            UInt32Type::from_data(column_ids),
+            StringType::from_data(terms),
+            row_ids,
+        ])
+    }
+
+    pub fn from_data_block(block: &DataBlock) -> Result<Self> {
+        let columns = (0..3)
+            .map(|i| {
+                let entry = block.get_by_offset(i);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows())
+            })
+            .collect::<Vec<_>>();
+        let invalid = || ErrorCode::StorageOther("invalid inverted index file");
+        let column_ids = UInt32Type::try_downcast_column(&columns[0]).ok_or_else(invalid)?;
+        let terms = StringType::try_downcast_column(&columns[1]).ok_or_else(invalid)?;
+        let row_ids =
+            ArrayType::<UInt32Type>::try_downcast_column(&columns[2]).ok_or_else(invalid)?;
+
+        let mut postings: BTreeMap<ColumnId, BTreeMap<String, Vec<u32>>> = BTreeMap::new();
+        for ((column_id, term), term_row_ids) in
+            column_ids.iter().zip(terms.iter()).zip(row_ids.iter())
+        {
+            postings
+                .entry(*column_id)
+                .or_default()
+                .insert(term.to_string(), term_row_ids.to_vec());
+        }
+        Ok(Self { postings })
+    }
+
+    /// Returns the rows of the column which contain the term.
+    pub fn row_ids(&self, column_id: ColumnId, term: &str) -> Option<&[u32]> {
+        self.postings
+            .get(&column_id)?
+            .get(term)
+            .map(|row_ids| row_ids.as_slice())
+    }
+
+    /// Whether the column is indexed.
+    pub fn contains_column(&self, column_id: ColumnId) -> bool {
+        self.postings.contains_key(&column_id)
+    }
+
+    /// Returns `MustFalse` if the predicate can't be true for any row of the block,
+    /// e.g. a `match` call whose terms are not all contained by a row.
+    ///
+    /// `tokenizer` is the one the index is built with, the `match` calls tokenizing the
+    /// query with another tokenizer are not evaluated by the index.
+    #[minitrace::trace]
+    pub fn apply(
+        &self,
+        func_ctx: &FunctionContext,
+        mut expr: Expr<String>,
+        data_schema: &TableSchema,
+        tokenizer: Tokenizer,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_match(&mut expr, &mut |column_name, query, query_tokenizer| {
+            let column_id = data_schema.field_with_name(column_name)?.column_id();
+            if !self.contains_column(column_id)
+                || query_tokenizer.parse::<Tokenizer>()? != tokenizer
+            {
+                return Ok(true);
+            }
+            Ok(self.may_match(column_id, &tokenizer.tokenize(query)))
+        })?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Whether some rows of the column may contain all the terms.
+    pub fn may_match(&self, column_id: ColumnId, terms: &[String]) -> bool {
+        let mut rows: Option<Vec<u32>> = None;
+        for term in terms {
+            let Some(row_ids) = self.row_ids(column_id, term) else {
+                return false;
+            };
+            rows = Some(match rows {
+                None => row_ids.to_vec(),
+                Some(rows) => rows
+                    .into_iter()
+                    .filter(|row| row_ids.binary_search(row).is_ok())
+                    .collect(),
+            });
+            if rows.as_ref().is_some_and(|rows| rows.is_empty()) {
+                return false;
+            }
+        }
+        !terms.is_empty()
+    }
+}
+
+/// Returns the names of the columns searched by `match` in the expression.
+pub fn match_columns(expr: &Expr<String>) -> Vec<String> {
+    let mut columns = Vec::new();
+    let mut expr = expr.clone();
+    let _ = visit_expr_match(&mut expr, &mut |column_name, _, _| {
+        if !columns.iter().any(|c| c == column_name) {
+            columns.push(column_name.to_string());
+        }
+        Ok(true)
+    });
+    columns
+}
+
+fn constant_string(expr: &Expr<String>) -> Option<&str> {
+    match expr {
+        Expr::Constant {
+            scalar: Scalar::String(s),
+            ..
+        } => Some(s.as_str()),
+        Expr::Cast { expr, .. } => constant_string(expr),
+        _ => None,
+    }
+}
+
+/// Find the patterns like `match(Column, <query>, <tokenizer>)` and rewrite them to
+/// `false` if the visitor returns false.
+fn visit_expr_match(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &str, &str) -> Result<bool>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "match" && args.len() == 3 {
+            let column = match &args[0] {
+                Expr::ColumnRef { id, .. } => Some(id.as_str()),
+                Expr::Cast {
+                    expr: box Expr::ColumnRef { id, .. },
+                    ..
+                } => Some(id.as_str()),
+                _ => None,
+            };
+            if let (Some(column), Some(query), Some(tokenizer)) =
+                (column, constant_string(&args[1]), constant_string(&args[2]))
+            {
+                if !visitor(column, query, tokenizer)? {
+                    *expr = Expr::Constant {
+                        span: *span,
+                        scalar: Scalar::Boolean(false),
+                        data_type: return_type.clone(),
+                    };
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_match(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_match(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
@@ -21,14 +21,18 @@ use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::decimal::Decimal256Type;
 use databend_common_expression::types::decimal::DecimalDataType;
 use databend_common_expression::types::decimal::DecimalDomain;
+use databend_common_expression::types::geometry::GeometryDomain;
+use databend_common_expression::types::geometry::GeometryType;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::string::StringDomain;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
+use databend_common_expression::types::InetType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UuidType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnId;
@@ -242,6 +246,17 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                         *sz,
                     )),
                 },
+                DataType::Uuid => UuidType::upcast_domain(SimpleDomain {
+                    min: UuidType::try_downcast_scalar(&min.as_ref()).unwrap(),
+                    max: UuidType::try_downcast_scalar(&max.as_ref()).unwrap(),
+                }),
+                DataType::Inet => InetType::upcast_domain(SimpleDomain {
+                    min: InetType::try_downcast_scalar(&min.as_ref()).unwrap(),
+                    max: InetType::try_downcast_scalar(&max.as_ref()).unwrap(),
+                }),
+                DataType::Geometry => GeometryDomain::from_min_max(min, max)
+                    .map(GeometryType::upcast_domain)
+                    .unwrap_or_else(|| Domain::full(data_type)),
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
@@ -249,4 +264,20 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
     }
 }
 
-impl Index for RangeIndex {}
+impl Index for RangeIndex {
+    fn supported_type(data_type: &DataType) -> bool {
+        // the min and max of geometries are the corners of their bounding box.
+        let inner_type = data_type.remove_nullable();
+        matches!(
+            inner_type,
+            DataType::Number(_)
+                | DataType::Date
+                | DataType::Timestamp
+                | DataType::String
+                | DataType::Decimal(_)
+                | DataType::Geometry
+                | DataType::Uuid
+                | DataType::Inet
+        )
+    }
+}
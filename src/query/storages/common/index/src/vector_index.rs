@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_vector::HnswIndex;
+use databend_common_vector::HnswOptions;
+use databend_common_vector::VectorDistance;
+
+/// The vector index of a block, a HNSW graph of the vectors of the indexed column.
+///
+/// It is stored as a parquet file alongside the block, one row per node of the graph.
+/// The neighbors of all the layers of a node are concatenated, `neighbor_counts` keeps
+/// the number of neighbors of each layer:
+/// ```text
+///         +--row_id--+--vector--+--neighbors--+--neighbor_counts--+
+///         |    0     | [0.1, 1] |  [2, 5, 2]  |      [2, 1]       |
+///         +----------+----------+-------------+-------------------+
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorIndex {
+    /// the rows of the nodes in the block, NULL values are not indexed
+    row_ids: Vec<u32>,
+    graph: HnswIndex,
+}
+
+impl VectorIndex {
+    /// The schema of the index file.
+    pub fn schema() -> TableSchemaRef {
+        let uint32_array =
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt32)));
+        Arc::new(TableSchema::new(vec![
+            TableField::new("row_id", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "vector",
+                TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32))),
+            ),
+            TableField::new("neighbors", uint32_array.clone()),
+            TableField::new("neighbor_counts", uint32_array),
+        ]))
+    }
+
+    /// Create the index from the `Array(Float32)` column of a block, NULL values are not indexed.
+    pub fn try_create(options: HnswOptions, column: &Column) -> Result<Self> {
+        let mut row_ids = Vec::new();
+        let mut vectors = Vec::new();
+        for (row, value) in column.iter().enumerate() {
+            let vector = match value {
+                ScalarRef::Array(array) => Float32Type::try_downcast_column(&array),
+                ScalarRef::Null => continue,
+                _ => None,
+            };
+            let Some(vector) = vector else {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Vector index only support Array(Float32) type, but got {}",
+                    column.data_type()
+                )));
+            };
+            row_ids.push(row as u32);
+            vectors.push(vector.iter().map(|v| v.0).collect());
+        }
+        let graph = HnswIndex::build(
+            options.distance,
+            options.m,
+            options.ef_construction,
+            vectors,
+        )?;
+        Ok(Self { row_ids, graph })
+    }
+
+    pub fn serialize_to_data_block(&self) -> DataBlock {
+        let mut vector_values = Vec::new();
+        let mut vector_offsets = vec![0];
+        let mut neighbors = Vec::new();
+        let mut neighbor_offsets = vec![0];
+        let mut neighbor_counts = Vec::new();
+        let mut count_offsets = vec![0];
+        for (vector, layers) in self.graph.vectors().iter().zip(self.graph.neighbors()) {
+            vector_values.extend(vector.iter().map(|v| F32::from(*v)));
+            vector_offsets.push(vector_values.len() as u64);
+            for layer in layers {
+                neighbors.extend_from_slice(layer);
+                neighbor_counts.push(layer.len() as u32);
+            }
+            neighbor_offsets.push(neighbors.len() as u64);
+            count_offsets.push(neighbor_counts.len() as u64);
+        }
+        DataBlock::new_from_columns(vec![
+            UInt32Type::from_data(self.row_ids.clone()),
+            ArrayType::<Float32Type>::upcast_column(ArrayColumn {
+                values: vector_values.into(),
+                offsets: vector_offsets.into(),
+            }),
+            ArrayType::<UInt32Type>::upcast_column(ArrayColumn {
+                values: neighbors.into(),
+                offsets: neighbor_offsets.into(),
+            }),
+            ArrayType::<UInt32Type>::upcast_column(ArrayColumn {
+                values: neighbor_counts.into(),
+                offsets: count_offsets.into(),
+            }),
+        ])
+    }
+
+    /// Restore the index from the data block of the index file, the distance comes from
+    /// the options of the index.
+    pub fn from_data_block(block: &DataBlock, distance: VectorDistance) -> Result<Self> {
+        let columns = (0..4)
+            .map(|i| {
+                let entry = block.get_by_offset(i);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows())
+            })
+            .collect::<Vec<_>>();
+        let invalid = || ErrorCode::StorageOther("invalid vector index file");
+        let row_ids = UInt32Type::try_downcast_column(&columns[0]).ok_or_else(invalid)?;
+        let vectors =
+            ArrayType::<Float32Type>::try_downcast_column(&columns[1]).ok_or_else(invalid)?;
+        let neighbors =
+            ArrayType::<UInt32Type>::try_downcast_column(&columns[2]).ok_or_else(invalid)?;
+        let neighbor_counts =
+            ArrayType::<UInt32Type>::try_downcast_column(&columns[3]).ok_or_else(invalid)?;
+
+        let mut node_vectors = Vec::with_capacity(block.num_rows());
+        let mut node_neighbors = Vec::with_capacity(block.num_rows());
+        for ((vector, neighbors), counts) in vectors
+            .iter()
+            .zip(neighbors.iter())
+            .zip(neighbor_counts.iter())
+        {
+            node_vectors.push(vector.iter().map(|v| v.0).collect());
+            let mut layers = Vec::with_capacity(counts.len());
+            let mut start = 0;
+            for count in counts.iter() {
+                let end = start + *count as usize;
+                if end > neighbors.len() {
+                    return Err(invalid());
+                }
+                layers.push(neighbors[start..end].to_vec());
+                start = end;
+            }
+            node_neighbors.push(layers);
+        }
+        let graph = HnswIndex::from_parts(distance, node_vectors, node_neighbors)?;
+        Ok(Self {
+            row_ids: row_ids.to_vec(),
+            graph,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.row_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.row_ids.is_empty()
+    }
+
+    /// Returns the rows of the `k` vectors closest to the query and their distances,
+    /// the closest first.
+    pub fn search(&self, query: &[f32], k: usize, ef: usize) -> Result<Vec<(u32, f32)>> {
+        let result = self.graph.search(query, k, ef)?;
+        Ok(result
+            .into_iter()
+            .map(|(node, distance)| (self.row_ids[node as usize], distance))
+            .collect())
+    }
+}
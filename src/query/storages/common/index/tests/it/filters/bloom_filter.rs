@@ -48,6 +48,7 @@ use databend_storages_common_index::filters::Xor8Filter;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_index::FilterEvalResult;
 use databend_storages_common_index::Index;
+use databend_storages_common_index::NgramArgs;
 use databend_storages_common_table_meta::meta::Versioned;
 
 #[test]
@@ -118,6 +119,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -251,6 +253,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -291,6 +294,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -309,6 +313,157 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new(
+            "1",
+            TableDataType::Nullable(Box::new(TableDataType::String)),
+        ),
+    ]));
+
+    let blocks = [DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2, 3]),
+        StringType::from_data_with_validity(
+            vec!["connection refused", "disk error: no space left", ""],
+            vec![true, true, false],
+        ),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let ngram_args = vec![NgramArgs {
+        index: 1,
+        field: schema.field(1).clone(),
+        gram_size: 3,
+        bloom_size: 1024,
+    }];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        &ngram_args,
+    )?
+    .unwrap();
+    let fields = vec![schema.field(1).clone()];
+
+    for (func_name, pattern, match_type, expected) in [
+        ("like", "%error%", None, FilterEvalResult::Uncertain),
+        ("like", "connection%", None, FilterEvalResult::Uncertain),
+        ("like", "%no_space%", None, FilterEvalResult::Uncertain),
+        ("like", "%timeout%", None, FilterEvalResult::MustFalse),
+        ("like", "%refused\\_%", None, FilterEvalResult::MustFalse),
+        // Too short to have a n-gram.
+        ("like", "%xy%", None, FilterEvalResult::Uncertain),
+        // Case-insensitive by default.
+        ("regexp", "ERROR", None, FilterEvalResult::Uncertain),
+        ("regexp", "timeout", None, FilterEvalResult::Uncertain),
+        ("regexp_like", "timeout", None, FilterEvalResult::Uncertain),
+        (
+            "regexp_like",
+            "timeout",
+            Some("ci"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "ERROR",
+            Some("c"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            "regexp_like",
+            "ERROR",
+            Some("ic"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            "regexp_like",
+            "disk (error|full)",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "^disk error",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "^disk full",
+            Some("c"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            "regexp_like",
+            "refused?d",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "err[aeiou]r",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "[[:alpha:]]timeout",
+            Some("c"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            "regexp_like",
+            "refused\\.",
+            Some("c"),
+            FilterEvalResult::MustFalse,
+        ),
+        // Escapes that don't match themselves.
+        (
+            "regexp_like",
+            "connection\\s+refused",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "connection\\x20refused",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "\\u0064isk error",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "disk\\pLerror",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+    ] {
+        assert_eq!(
+            expected,
+            eval_ngram_index(
+                &index,
+                "1",
+                fields.clone(),
+                schema.clone(),
+                func_name,
+                pattern,
+                match_type,
+            ),
+            "{func_name}('{pattern}', {match_type:?})"
+        );
+    }
+
+    Ok(())
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -352,6 +507,44 @@ fn eval_index(
     index.apply(expr, &scalar_map, schema).unwrap()
 }
 
+fn eval_ngram_index(
+    index: &BloomIndex,
+    col_name: &str,
+    fields: Vec<TableField>,
+    schema: Arc<TableSchema>,
+    func_name: &str,
+    pattern: &str,
+    match_type: Option<&str>,
+) -> FilterEvalResult {
+    let ty = DataType::from(schema.field_with_name(col_name).unwrap().data_type());
+    let mut args = vec![
+        Expr::ColumnRef {
+            span: None,
+            id: col_name.to_string(),
+            data_type: ty.clone(),
+            display_name: col_name.to_string(),
+        },
+        Expr::Constant {
+            span: None,
+            scalar: Scalar::String(pattern.to_string()),
+            data_type: DataType::String,
+        },
+    ];
+    if let Some(match_type) = match_type {
+        args.push(Expr::Constant {
+            span: None,
+            scalar: Scalar::String(match_type.to_string()),
+            data_type: DataType::String,
+        });
+    }
+    let expr = check_function(None, func_name, &[], &args, &BUILTIN_FUNCTIONS).unwrap();
+
+    let ngram_cols = BloomIndex::find_ngram_columns(&expr, &fields).unwrap();
+    assert!(ngram_cols.len() <= 1);
+
+    index.apply(expr, &HashMap::new(), schema).unwrap()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn eval_map_index(
     index: &BloomIndex,
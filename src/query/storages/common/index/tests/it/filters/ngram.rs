@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_storages_common_index::filters::ngram_digests;
+use databend_storages_common_index::filters::Filter;
+use databend_storages_common_index::filters::FilterBuilder;
+use databend_storages_common_index::filters::NgramBuilder;
+use databend_storages_common_index::filters::NgramFilter;
+
+#[test]
+fn test_ngram_filter() -> Result<()> {
+    let mut builder = NgramBuilder::create(3, 1024 * 1024);
+    builder.add_text("connection refused");
+    builder.add_text("disk error");
+    builder.add_text("数据库错误");
+    let filter = builder.build()?;
+
+    assert_eq!(filter.gram_size(), 3);
+    assert!(filter.contains_text("refused"));
+    assert!(filter.contains_text("error"));
+    assert!(filter.contains_text("k err"));
+    assert!(filter.contains_text("库错误"));
+    // Shorter than a n-gram, can't be checked.
+    assert!(filter.contains_text("zz"));
+    assert!(!filter.contains_text("timeout"));
+    assert!(!filter.contains_text("errors"));
+
+    let val = filter.to_bytes()?;
+    let (decoded, n) = NgramFilter::from_bytes(&val)?;
+    assert_eq!(n, val.len());
+    assert_eq!(decoded, filter);
+
+    Ok(())
+}
+
+#[test]
+fn test_ngram_filter_size() -> Result<()> {
+    let mut builder = NgramBuilder::create(3, 64);
+    for i in 0..10000 {
+        builder.add_text(&format!("value {i}"));
+    }
+    let filter = builder.build()?;
+
+    // The filter never exceeds the bloom size, even if it's saturated.
+    assert_eq!(filter.mem_bytes(), 64);
+    assert!(filter.contains_text("value 9999"));
+
+    assert!(NgramFilter::from_bytes(&[0; 12]).is_err());
+    assert!(NgramBuilder::create(0, 64).build().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_ngram_digests_are_stable() {
+    // The filters are persisted, the digests must never change.
+    let digests = ngram_digests("error", 3).collect::<Vec<_>>();
+    assert_eq!(digests, vec![
+        16804873318746008753,
+        7469918550479180817,
+        2304587192431664924
+    ]);
+}
@@ -83,6 +83,12 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// The table version (seq of the table meta) this snapshot is committed upon.
+    ///
+    /// Filled in by the committers, `None` for the snapshots written by older versions.
+    #[serde(default)]
+    pub prev_table_seq: Option<u64>,
 }
 
 impl TableSnapshot {
@@ -114,6 +120,7 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            prev_table_seq: None,
         }
     }
 
@@ -221,6 +228,7 @@ impl From<v2::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            prev_table_seq: None,
         }
     }
 }
@@ -242,6 +250,7 @@ where T: Into<v3::TableSnapshot>
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            prev_table_seq: None,
         }
     }
 }
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnState {
+    /// Each statement is committed by itself.
+    AutoCommit,
+    /// In an explicit transaction, the mutations are buffered until COMMIT.
+    Active,
+    /// A statement failed in the transaction, only COMMIT or ROLLBACK are accepted,
+    /// and both of them discard the buffered mutations.
+    Fail,
+}
+
+/// The uncommitted table metas of a transaction.
+#[derive(Debug, Clone, Default)]
+struct TxnBuffer {
+    /// The request to update the table meta and the latest table info, keyed by table id.
+    ///
+    /// The version in the request is the one the transaction read at first, so the commit
+    /// fails if the table is changed by others in the meantime.
+    mutated_tables: BTreeMap<u64, (UpdateTableMetaReq, TableInfo)>,
+    /// All the versions of the mutated tables, starting from the committed one the
+    /// transaction read at first, keyed by table id.
+    ///
+    /// The files written by the uncommitted versions are removed on rollback.
+    table_versions: BTreeMap<u64, Vec<TableInfo>>,
+}
+
+impl TxnBuffer {
+    fn update_table_meta(&mut self, req: UpdateTableMetaReq, table_info: &TableInfo) {
+        // The table of the first mutation is read from the committed version.
+        let versions = self
+            .table_versions
+            .entry(req.table_id)
+            .or_insert_with(|| vec![table_info.clone()]);
+        let mut table_info = table_info.clone();
+        table_info.meta = req.new_table_meta.clone();
+        versions.push(table_info.clone());
+
+        let Some((buffered, buffered_info)) = self.mutated_tables.get_mut(&req.table_id) else {
+            self.mutated_tables.insert(req.table_id, (req, table_info));
+            return;
+        };
+        buffered.new_table_meta = req.new_table_meta;
+        match (&mut buffered.copied_files, req.copied_files) {
+            (Some(files), Some(copied_files)) => {
+                files.file_info.extend(copied_files.file_info);
+                files.fail_if_duplicated |= copied_files.fail_if_duplicated;
+                files.expire_at = copied_files.expire_at.or(files.expire_at);
+            }
+            (files, copied_files @ Some(_)) => *files = copied_files,
+            _ => {}
+        }
+        for stream in req.update_stream_meta {
+            // Keep the first version of the stream, and the latest options.
+            match buffered
+                .update_stream_meta
+                .iter_mut()
+                .find(|s| s.stream_id == stream.stream_id)
+            {
+                Some(s) => s.options = stream.options,
+                None => buffered.update_stream_meta.push(stream),
+            }
+        }
+        if req.deduplicated_label.is_some() {
+            buffered.deduplicated_label = req.deduplicated_label;
+        }
+        *buffered_info = table_info;
+    }
+}
+
+/// The state of the explicit transaction of a session.
+#[derive(Debug, Clone)]
+pub struct TxnManager {
+    state: TxnState,
+    txn_buffer: TxnBuffer,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_buffer: TxnBuffer::default(),
+        }))
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    /// End the transaction and discard the buffered mutations.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_buffer = TxnBuffer::default();
+    }
+
+    /// Buffer the update of a table meta instead of committing it to the meta service.
+    pub fn update_table_meta(&mut self, req: UpdateTableMetaReq, table_info: &TableInfo) {
+        self.txn_buffer.update_table_meta(req, table_info);
+    }
+
+    /// Get the uncommitted table info if the table is mutated in the transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.txn_buffer
+            .mutated_tables
+            .get(&table_id)
+            .map(|(_, table_info)| table_info.clone())
+    }
+
+    /// The versions of each mutated table, the first one is the committed version before
+    /// the transaction, and the others are written by the transaction.
+    pub fn table_versions(&self) -> Vec<Vec<TableInfo>> {
+        self.txn_buffer.table_versions.values().cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txn_buffer.mutated_tables.is_empty()
+    }
+
+    /// The request to commit all the buffered mutations atomically.
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        UpdateMultiTableMetaReq {
+            update_table_metas: self
+                .txn_buffer
+                .mutated_tables
+                .values()
+                .map(|(req, _)| req.clone())
+                .collect(),
+        }
+    }
+}
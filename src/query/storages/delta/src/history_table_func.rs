@@ -0,0 +1,284 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+
+use crate::DeltaTable;
+
+const DELTA_HISTORY: &str = "delta_history";
+
+/// `delta_history([<catalog>,] <database>, <table>)` lists the versions of a delta table,
+/// the versions could be used to query the table with `AT (SNAPSHOT => '<version>')`.
+pub struct DeltaHistoryTable {
+    table_info: TableInfo,
+    arg_catalog_name: Option<String>,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl DeltaHistoryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(DELTA_HISTORY, None)?;
+        let args = args.iter().map(string_value).collect::<Result<Vec<_>>>()?;
+        let (arg_catalog_name, arg_database_name, arg_table_name) = match args.as_slice() {
+            [db, tbl] => (None, db.clone(), tbl.clone()),
+            [ctl, db, tbl] => (Some(ctl.clone()), db.clone(), tbl.clone()),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "expecting [<catalog>,] <database> and <table_name> (as string literals), but got {:?}",
+                    args
+                )));
+            }
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: schema(),
+                engine: DELTA_HISTORY.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(DeltaHistoryTable {
+            table_info,
+            arg_catalog_name,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for DeltaHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        let mut args = vec![];
+        if let Some(catalog) = &self.arg_catalog_name {
+            args.push(Scalar::String(catalog.clone()));
+        }
+        args.push(Scalar::String(self.arg_database_name.clone()));
+        args.push(Scalar::String(self.arg_table_name.clone()));
+        Some(TableArgs::new_positioned(args))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        let catalog_name = self
+            .arg_catalog_name
+            .clone()
+            .unwrap_or_else(|| ctx.get_current_catalog());
+        pipeline.add_source(
+            |output| {
+                DeltaHistorySource::create(
+                    ctx.clone(),
+                    output,
+                    catalog_name.clone(),
+                    self.arg_database_name.clone(),
+                    self.arg_table_name.clone(),
+                    plan.push_downs.as_ref().and_then(|extras| extras.limit),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for DeltaHistoryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct DeltaHistorySource {
+    ctx: Arc<dyn TableContext>,
+    finish: bool,
+    catalog_name: String,
+    database_name: String,
+    table_name: String,
+    limit: Option<usize>,
+}
+
+impl DeltaHistorySource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        catalog_name: String,
+        database_name: String,
+        table_name: String,
+        limit: Option<usize>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, DeltaHistorySource {
+            ctx,
+            finish: false,
+            catalog_name,
+            database_name,
+            table_name,
+            limit,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for DeltaHistorySource {
+    const NAME: &'static str = DELTA_HISTORY;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tenant_id = self.ctx.get_tenant();
+        let tbl = self
+            .ctx
+            .get_catalog(&self.catalog_name)
+            .await?
+            .get_table(tenant_id.as_str(), &self.database_name, &self.table_name)
+            .await?;
+
+        let tbl = DeltaTable::try_from_table(tbl.as_ref())?;
+        let history = tbl.history(self.limit).await?;
+
+        let len = history.len();
+        let mut versions = Vec::with_capacity(len);
+        let mut timestamps = Vec::with_capacity(len);
+        let mut operations = Vec::with_capacity(len);
+        let mut operation_parameters = Vec::with_capacity(len);
+        let mut read_versions = Vec::with_capacity(len);
+        let mut is_blind_appends = Vec::with_capacity(len);
+        let mut engine_infos = Vec::with_capacity(len);
+        for (version, commit_info) in history {
+            let commit_info = commit_info.unwrap_or_default();
+            versions.push(version);
+            timestamps.push(commit_info.timestamp.map(|ts| ts * 1000));
+            operations.push(commit_info.operation);
+            operation_parameters.push(
+                commit_info
+                    .operation_parameters
+                    .map(|params| serde_json::to_string(&params))
+                    .transpose()?,
+            );
+            read_versions.push(commit_info.read_version);
+            is_blind_appends.push(commit_info.is_blind_append);
+            engine_infos.push(commit_info.engine_info);
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int64Type::from_data(versions),
+            TimestampType::from_opt_data(timestamps),
+            StringType::from_opt_data(operations),
+            StringType::from_opt_data(operation_parameters),
+            Int64Type::from_opt_data(read_versions),
+            BooleanType::from_opt_data(is_blind_appends),
+            StringType::from_opt_data(engine_infos),
+        ])))
+    }
+}
+
+fn string_value(value: &Scalar) -> Result<String> {
+    match value {
+        Scalar::String(val) => Ok(val.clone()),
+        _ => Err(ErrorCode::BadArguments("invalid string.")),
+    }
+}
+
+fn schema() -> Arc<TableSchema> {
+    TableSchemaRefExt::create(vec![
+        TableField::new("version", TableDataType::Number(NumberDataType::Int64)),
+        TableField::new("timestamp", TableDataType::Timestamp.wrap_nullable()),
+        TableField::new("operation", TableDataType::String.wrap_nullable()),
+        TableField::new(
+            "operation_parameters",
+            TableDataType::String.wrap_nullable(),
+        ),
+        TableField::new(
+            "read_version",
+            TableDataType::Number(NumberDataType::Int64).wrap_nullable(),
+        ),
+        TableField::new("is_blind_append", TableDataType::Boolean.wrap_nullable()),
+        TableField::new("engine_info", TableDataType::String.wrap_nullable()),
+    ])
+}
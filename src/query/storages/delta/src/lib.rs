@@ -17,9 +17,12 @@
 #![allow(clippy::diverging_sub_expression)]
 
 mod dal;
+mod history_table_func;
 mod partition;
 mod partition_columns;
+mod stats;
 mod table;
 mod table_source;
 
+pub use history_table_func::DeltaHistoryTable;
 pub use table::DeltaTable;
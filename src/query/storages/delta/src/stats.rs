@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use databend_common_expression::types::Number;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use deltalake::protocol::Stats;
+
+/// Strings in the file stats are truncated to this length by the writers (`dataSkippingStringPrefixLength`),
+/// a value of this length may not be the real max value.
+const STRING_PREFIX_LENGTH: usize = 32;
+
+/// Convert the statistics of a data file to [`StatisticsOfColumns`].
+///
+/// - partition columns: the partition value is both the min and max value.
+/// - other columns: the `minValues`, `maxValues` and `nullCount` of the file stats.
+pub fn get_stats_of_file(
+    schema: &TableSchema,
+    stats: &Stats,
+    partition_fields: &[&TableField],
+    partition_values: &[Scalar],
+) -> StatisticsOfColumns {
+    let num_records = stats.num_records as u64;
+    let mut res: HashMap<u32, ColumnStatistics> = HashMap::with_capacity(schema.num_fields());
+    for (field, value) in partition_fields.iter().zip(partition_values) {
+        let null_count = if value.is_null() { num_records } else { 0 };
+        res.insert(
+            field.column_id,
+            ColumnStatistics::new(value.clone(), value.clone(), null_count, 0, None),
+        );
+    }
+
+    for field in schema.fields() {
+        if res.contains_key(&field.column_id) {
+            continue;
+        }
+        if let Some(stat) = get_column_stats(field, stats) {
+            res.insert(field.column_id, stat);
+        }
+    }
+    res
+}
+
+/// Try get [`ColumnStatistics`] for one column, stats of nested columns are not used.
+fn get_column_stats(field: &TableField, stats: &Stats) -> Option<ColumnStatistics> {
+    let min = stats.min_values.get(&field.name)?.as_value()?;
+    let max = stats.max_values.get(&field.name)?.as_value()?;
+    let null_count = stats.null_count.get(&field.name)?.as_value()?;
+
+    let ty = field.data_type().remove_nullable();
+    let min = parse_json_value(&ty, min, false)?;
+    let max = parse_json_value(&ty, max, true)?;
+    Some(ColumnStatistics::new(min, max, null_count as u64, 0, None))
+}
+
+/// Deserialize the json value of the file stats to [`Scalar`].
+fn parse_json_value(ty: &TableDataType, value: &serde_json::Value, is_max: bool) -> Option<Scalar> {
+    match ty {
+        TableDataType::Boolean => Some(Scalar::Boolean(value.as_bool()?)),
+        TableDataType::Number(ty) => with_integer_mapped_type!(|NUM_TYPE| match ty {
+            NumberDataType::NUM_TYPE => {
+                let v = NUM_TYPE::try_from(value.as_i64()?).ok()?;
+                Some(Scalar::Number(NUM_TYPE::upcast_scalar(v)))
+            }
+            // NaN is not counted in the stats, but it's the largest float in databend.
+            NumberDataType::Float32 | NumberDataType::Float64 => None,
+        }),
+        TableDataType::Date => {
+            let date = NaiveDate::parse_from_str(value.as_str()?, "%Y-%m-%d").ok()?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+            Some(Scalar::Date((date - epoch).num_days() as i32))
+        }
+        TableDataType::Timestamp => {
+            let ts = DateTime::parse_from_rfc3339(value.as_str()?).ok()?;
+            // timestamps in the stats are in milliseconds, the sub-millisecond part is truncated.
+            let ts = ts.timestamp_micros();
+            Some(Scalar::Timestamp(if is_max { ts + 999 } else { ts }))
+        }
+        TableDataType::String => {
+            let v = value.as_str()?;
+            if v.chars().count() >= STRING_PREFIX_LENGTH {
+                return None;
+            }
+            Some(Scalar::String(v.to_string()))
+        }
+        // TODO: support Decimal.
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_expression::types::NumberScalar;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_json_value() {
+        let int_ty = TableDataType::Number(NumberDataType::Int32);
+        assert_eq!(
+            parse_json_value(&int_ty, &json!(42), false),
+            Some(Scalar::Number(NumberScalar::Int32(42)))
+        );
+        assert_eq!(parse_json_value(&int_ty, &json!(i64::MAX), false), None);
+
+        let float_ty = TableDataType::Number(NumberDataType::Float64);
+        assert_eq!(parse_json_value(&float_ty, &json!(1.5), false), None);
+
+        assert_eq!(
+            parse_json_value(&TableDataType::Date, &json!("1970-01-11"), false),
+            Some(Scalar::Date(10))
+        );
+
+        let ts = json!("1970-01-01T00:00:01.001Z");
+        assert_eq!(
+            parse_json_value(&TableDataType::Timestamp, &ts, false),
+            Some(Scalar::Timestamp(1_001_000))
+        );
+        assert_eq!(
+            parse_json_value(&TableDataType::Timestamp, &ts, true),
+            Some(Scalar::Timestamp(1_001_999))
+        );
+
+        assert_eq!(
+            parse_json_value(&TableDataType::String, &json!("abc"), true),
+            Some(Scalar::String("abc".to_string()))
+        );
+        let truncated = json!("a".repeat(STRING_PREFIX_LENGTH));
+        assert_eq!(
+            parse_json_value(&TableDataType::String, &truncated, true),
+            None
+        );
+    }
+}
@@ -26,6 +26,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -35,6 +36,7 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_pipeline_core::Pipeline;
@@ -43,8 +45,9 @@ use databend_common_storages_parquet::ParquetFilesPart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
+use databend_storages_common_pruner::RangePrunerCreator;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
-use deltalake::kernel::Add;
+use deltalake::kernel::CommitInfo;
 use deltalake::logstore::default_logstore::DefaultLogStore;
 use deltalake::logstore::LogStoreConfig;
 use deltalake::DeltaTableConfig;
@@ -58,6 +61,7 @@ use crate::dal::OpendalStore;
 use crate::partition::DeltaPartInfo;
 use crate::partition_columns::get_partition_values;
 use crate::partition_columns::get_pushdown_without_partition_columns;
+use crate::stats::get_stats_of_file;
 use crate::table_source::DeltaTableSource;
 
 pub const DELTA_ENGINE: &str = "DELTA";
@@ -71,6 +75,9 @@ pub struct DeltaTable {
 #[derive(Serialize, Deserialize)]
 pub struct DeltaTableMeta {
     partition_columns: Vec<String>,
+    /// the version the table is navigated to by time travel, the latest version is read if it's absent.
+    #[serde(default)]
+    version: Option<i64>,
 }
 
 /// In a delta table, partition columns are not stored in parquet file.
@@ -130,8 +137,27 @@ impl DeltaTable {
             .collect()
     }
 
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&DeltaTable> {
+        tbl.as_any().downcast_ref::<DeltaTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine DELTA, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
     #[async_backtrace::framed]
     pub async fn get_meta(table: &deltalake::table::DeltaTable) -> Result<(TableSchema, String)> {
+        let (schema, meta) = Self::get_delta_meta(table)?;
+        let meta = serde_json::to_string(&meta).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
+        })?;
+        Ok((schema, meta))
+    }
+
+    fn get_delta_meta(
+        table: &deltalake::table::DeltaTable,
+    ) -> Result<(TableSchema, DeltaTableMeta)> {
         let delta_meta = table.get_schema().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
         })?;
@@ -146,17 +172,14 @@ impl DeltaTable {
         })?;
         let meta = DeltaTableMeta {
             partition_columns: state.partition_columns.clone(),
+            version: None,
         };
-        let meta = serde_json::to_string(&meta).map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
-        })?;
 
         let schema = TableSchema::try_from(&arrow_schema)?;
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    fn new_table(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op));
         let config = DeltaTableConfig::default();
@@ -164,23 +187,99 @@ impl DeltaTable {
             location: Url::from_directory_path("/").unwrap(),
             options: HashMap::new().into(),
         }));
-        let mut table = deltalake::table::DeltaTable::new(log_store, config);
+        Ok(deltalake::table::DeltaTable::new(log_store, config))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::new_table(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
         Ok(table)
     }
 
+    /// Load the table at the given version, or the latest version committed at or before the time point.
+    #[async_backtrace::framed]
+    async fn load_at(
+        sp: &StorageParams,
+        instant: &NavigationPoint,
+    ) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::new_table(sp)?;
+        let res = match instant {
+            NavigationPoint::SnapshotID(version) => {
+                let version = version.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid delta table version '{version}', expecting a 64-bit integer"
+                    ))
+                })?;
+                table.load_version(version).await
+            }
+            NavigationPoint::TimePoint(point) => table.load_with_datetime(*point).await,
+        };
+        res.map_err(|err| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point: {err:?}"
+            ))
+        })?;
+        Ok(table)
+    }
+
     #[async_backtrace::framed]
     async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
-                Self::load(sp).await
+                match self.meta.version {
+                    Some(version) => {
+                        Self::load_at(sp, &NavigationPoint::SnapshotID(version.to_string())).await
+                    }
+                    None => Self::load(sp).await,
+                }
             })
             .await
     }
 
+    /// The commit info of each version, latest version first.
+    ///
+    /// Versions whose log entries are already cleaned up are not included.
+    #[async_backtrace::framed]
+    pub async fn history(&self, limit: Option<usize>) -> Result<Vec<(i64, Option<CommitInfo>)>> {
+        let table = self.table().await?;
+        let log_store = table.log_store();
+
+        let mut history = vec![];
+        for version in (0..=table.version()).rev() {
+            if limit.is_some_and(|limit| history.len() >= limit) {
+                break;
+            }
+
+            let entry = log_store.read_commit_entry(version).await.map_err(|err| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Cannot read delta log of version {version}: {err:?}"
+                ))
+            })?;
+            let Some(entry) = entry else {
+                break;
+            };
+
+            // each line of the log entry is an action, find out the `commitInfo` one.
+            let commit_info = entry
+                .split(|b| *b == b'\n')
+                .filter_map(|line| serde_json::from_slice::<serde_json::Value>(line).ok())
+                .find_map(|mut action| action.get_mut("commitInfo").map(|v| v.take()))
+                .map(serde_json::from_value::<CommitInfo>)
+                .transpose()
+                .map_err(|err| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "Invalid commit info of delta log version {version}: {err:?}"
+                    ))
+                })?;
+            history.push((version, commit_info));
+        }
+        Ok(history)
+    }
+
     pub fn do_read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -271,8 +370,8 @@ impl DeltaTable {
     #[async_backtrace::framed]
     async fn do_read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
 
@@ -282,32 +381,47 @@ impl DeltaTable {
         let partition_fields = self.get_partition_fields()?;
         let adds = table.get_state().files();
         let total_files = adds.len();
-        let parts = adds.iter()
-            .map(|add: &Add| {
-                let stats = add
-                    .get_stats()
-                    .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot get stats: {e:?}")))?
-                    .ok_or_else(|| {
-                        ErrorCode::ReadTableDataError(format!(
-                            "Current DeltaTable assuming Add contains Stats, but found in {}.",
-                            add.path
-                        ))
-                    })?;
-                read_rows += stats.num_records as usize;
-                read_bytes += add.size as usize;
-                let partition_values = get_partition_values(add, &partition_fields[..])?;
-                Ok(Arc::new(
-                    Box::new(DeltaPartInfo{
-                        partition_values,
-                        data: ParquetPart::ParquetFiles(
-                            ParquetFilesPart {
-                            files: vec![(add.path.clone(), add.size as u64)],
-                            estimated_uncompressed_size: add.size as u64, // This field is not used here.
-                        },
-                    )}) as Box<dyn PartInfo>
-                ))
-            })
-            .collect::<Result<Vec<_>>>()?;
+
+        let filter = push_downs.as_ref().and_then(|extra| {
+            extra
+                .filters
+                .as_ref()
+                .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
+        });
+        let schema = self.schema();
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
+
+        let mut parts = Vec::with_capacity(total_files);
+        for add in adds.iter() {
+            let stats = add
+                .get_stats()
+                .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot get stats: {e:?}")))?
+                .ok_or_else(|| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "Current DeltaTable assuming Add contains Stats, but found in {}.",
+                        add.path
+                    ))
+                })?;
+            let partition_values = get_partition_values(add, &partition_fields[..])?;
+
+            // prune the files by the partition values and the min/max of columns.
+            let stats_of_columns =
+                get_stats_of_file(&schema, &stats, &partition_fields, &partition_values);
+            if !pruner.should_keep(&stats_of_columns, None) {
+                continue;
+            }
+
+            read_rows += stats.num_records as usize;
+            read_bytes += add.size as usize;
+            parts.push(Arc::new(Box::new(DeltaPartInfo {
+                partition_values,
+                data: ParquetPart::ParquetFiles(ParquetFilesPart {
+                    files: vec![(add.path.clone(), add.size as u64)],
+                    estimated_uncompressed_size: add.size as u64, // This field is not used here.
+                }),
+            }) as Box<dyn PartInfo>));
+        }
 
         Ok((
             PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), total_files),
@@ -355,6 +469,29 @@ impl Table for DeltaTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let sp = self.get_storage_params()?;
+        let table = Self::load_at(sp, instant).await?;
+        let (schema, mut meta) = Self::get_delta_meta(&table)?;
+        meta.version = Some(table.version());
+
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(schema);
+        info.meta.engine_options.insert(
+            OPT_KEY_ENGINE_META.to_string(),
+            serde_json::to_string(&meta).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
+            })?,
+        );
+
+        Ok(Arc::new(DeltaTable {
+            info,
+            table: OnceCell::new_with(Some(table)),
+            meta,
+        }))
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -19,6 +19,7 @@ use databend_common_catalog::table::ColumnStatisticsProvider;
 use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
+use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 
 /// A column statistics provider for fuse table.
 #[derive(Default)]
@@ -29,20 +30,27 @@ pub struct FuseTableColumnStatisticsProvider {
 impl FuseTableColumnStatisticsProvider {
     pub fn new(
         column_stats: HashMap<ColumnId, FuseColumnStatistics>,
-        column_distinct_values: Option<HashMap<ColumnId, u64>>,
+        table_statistics: Option<&TableSnapshotStatistics>,
         row_count: u64,
     ) -> Self {
         let column_stats = column_stats
             .into_iter()
             .map(|(column_id, stat)| {
-                let ndv = column_distinct_values
-                    .as_ref()
-                    .map_or(row_count, |map| map.get(&column_id).map_or(0, |v| *v));
+                let ndv = table_statistics.map_or(row_count, |table_statistics| {
+                    table_statistics
+                        .column_distinct_values
+                        .get(&column_id)
+                        .map_or(0, |v| *v)
+                });
+                let histogram = table_statistics
+                    .and_then(|table_statistics| table_statistics.histograms.get(&column_id))
+                    .cloned();
                 let stat = BasicColumnStatistics {
                     min: Datum::from_scalar(stat.min),
                     max: Datum::from_scalar(stat.max),
                     ndv: Some(ndv),
                     null_count: stat.null_count,
+                    histogram,
                 };
                 (column_id, stat.get_useful_stat(row_count))
             })
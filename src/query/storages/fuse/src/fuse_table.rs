@@ -42,6 +42,8 @@ use databend_common_expression::SNAPSHOT_NAME_COLUMN_ID;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -56,6 +58,8 @@ use databend_common_storage::ShareTableConfig;
 use databend_common_storage::StorageMetrics;
 use databend_common_storage::StorageMetricsLayer;
 use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::NgramArgs;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::Statistics as FuseStatistics;
@@ -68,6 +72,9 @@ use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_BLOOM_SIZE;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_SIZE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -90,6 +97,8 @@ use crate::NavigationPoint;
 use crate::Table;
 use crate::TableStatistics;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::DEFAULT_NGRAM_BLOOM_SIZE;
+use crate::DEFAULT_NGRAM_SIZE;
 use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
@@ -294,7 +303,7 @@ impl FuseTable {
     }
 
     pub fn table_snapshot_statistics_format_version(&self, location: &String) -> u64 {
-        TableMetaLocationGenerator::snapshot_version(location)
+        TableMetaLocationGenerator::snapshot_statistics_version(location)
     }
 
     #[minitrace::trace]
@@ -435,6 +444,53 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn inverted_indexes(&self) -> Vec<TableIndex> {
+        self.table_indexes(TableIndexType::Inverted)
+    }
+
+    pub fn vector_indexes(&self) -> Vec<TableIndex> {
+        self.table_indexes(TableIndexType::Vector)
+    }
+
+    fn table_indexes(&self, index_type: TableIndexType) -> Vec<TableIndex> {
+        self.table_info
+            .meta
+            .indexes
+            .values()
+            .filter(|index| index.index_type == index_type)
+            .cloned()
+            .collect()
+    }
+
+    /// The string columns that have n-gram filters in the bloom index, specified by the
+    /// table option `ngram_index_columns`.
+    pub fn ngram_args(&self) -> Result<Vec<NgramArgs>> {
+        let options = self.table_info.options();
+        let Some(ngram_index_cols) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) else {
+            return Ok(vec![]);
+        };
+        let ngram_index_cols = ngram_index_cols.parse::<BloomIndexColumns>()?;
+        let gram_size = match options.get(OPT_KEY_NGRAM_SIZE) {
+            Some(v) => v.parse::<usize>()?,
+            None => DEFAULT_NGRAM_SIZE,
+        };
+        let bloom_size = match options.get(OPT_KEY_NGRAM_BLOOM_SIZE) {
+            Some(v) => v.parse::<u64>()?,
+            None => DEFAULT_NGRAM_BLOOM_SIZE,
+        };
+        let fields =
+            ngram_index_cols.bloom_index_fields(self.schema(), BloomIndex::supported_ngram_type)?;
+        Ok(fields
+            .into_iter()
+            .map(|(index, field)| NgramArgs {
+                index,
+                field,
+                gram_size,
+                bloom_size,
+            })
+            .collect())
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -770,19 +826,11 @@ impl Table for FuseTable {
         let provider = if let Some(snapshot) = self.read_table_snapshot().await? {
             let stats = &snapshot.summary.col_stats;
             let table_statistics = self.read_table_snapshot_statistics(Some(&snapshot)).await?;
-            if let Some(table_statistics) = table_statistics {
-                FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
-                    Some(table_statistics.column_distinct_values.clone()),
-                    snapshot.summary.row_count,
-                )
-            } else {
-                FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
-                    None,
-                    snapshot.summary.row_count,
-                )
-            }
+            FuseTableColumnStatisticsProvider::new(
+                stats.clone(),
+                table_statistics.as_deref(),
+                snapshot.summary.row_count,
+            )
         } else {
             FuseTableColumnStatisticsProvider::default()
         };
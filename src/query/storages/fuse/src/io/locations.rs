@@ -30,7 +30,9 @@ use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -41,6 +43,8 @@ static SNAPSHOT_V4: SnapshotVersion = SnapshotVersion::V4(PhantomData);
 
 static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
+static SNAPSHOT_STATISTICS_V1: TableSnapshotStatisticsVersion =
+    TableSnapshotStatisticsVersion::V1(PhantomData);
 
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
@@ -135,8 +139,15 @@ impl TableMetaLocationGenerator {
         Ok(statistics_version.create(id, &self.prefix))
     }
 
-    pub fn snapshot_statistics_version(_location: impl AsRef<str>) -> u64 {
-        SNAPSHOT_STATISTICS_V0.version()
+    pub fn snapshot_statistics_version(location: impl AsRef<str>) -> u64 {
+        if location
+            .as_ref()
+            .ends_with(SNAPSHOT_STATISTICS_V1.suffix().as_str())
+        {
+            SNAPSHOT_STATISTICS_V1.version()
+        } else {
+            SNAPSHOT_STATISTICS_V0.version()
+        }
     }
 
     pub fn gen_last_snapshot_hint_location(&self) -> String {
@@ -154,6 +165,33 @@ impl TableMetaLocationGenerator {
         let block_name = splits[len - 1];
         format!("{prefix}/{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}/{block_name}")
     }
+
+    pub fn gen_inverted_index_location_from_block_location(
+        loc: &str,
+        index_version: &str,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        format!("{prefix}/{FUSE_TBL_INVERTED_INDEX_PREFIX}/{index_version}/{block_name}")
+    }
+
+    /// The directory of the index files of an inverted index version.
+    pub fn gen_inverted_index_dir(&self, index_version: &str) -> String {
+        format!(
+            "{}/{}/{}/",
+            &self.prefix, FUSE_TBL_INVERTED_INDEX_PREFIX, index_version
+        )
+    }
+
+    pub fn gen_vector_index_location_from_block_location(loc: &str, index_version: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        format!("{prefix}/{FUSE_TBL_VECTOR_INDEX_PREFIX}/{index_version}/{block_name}")
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -197,6 +235,7 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
     fn suffix(&self) -> String {
         match self {
             TableSnapshotStatisticsVersion::V0(_) => "_ts_v0.json".to_string(),
+            TableSnapshotStatisticsVersion::V1(_) => "_ts_v1.json".to_string(),
         }
     }
 }
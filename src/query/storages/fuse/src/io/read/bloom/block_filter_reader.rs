@@ -27,7 +27,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_storages_common_cache::LoadParams;
-use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::filters::FilterImpl;
 use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SingleColumnMeta;
@@ -105,7 +105,7 @@ async fn load_bloom_filter_by_columns<'a>(
     let futs = col_metas
         .iter()
         .map(|(idx, (name, col_chunk_meta))| {
-            load_column_xor8_filter(*idx, (*name).to_owned(), col_chunk_meta, index_path, &dal)
+            load_column_filter(*idx, (*name).to_owned(), col_chunk_meta, index_path, &dal)
         })
         .collect::<Vec<_>>();
 
@@ -128,13 +128,13 @@ async fn load_bloom_filter_by_columns<'a>(
 /// Loads bytes and index of the given column.
 /// read data from cache, or populate cache items if possible
 #[minitrace::trace]
-async fn load_column_xor8_filter<'a>(
+async fn load_column_filter<'a>(
     idx: ColumnId,
     column_name: String,
     col_chunk_meta: &'a SingleColumnMeta,
     index_path: &'a str,
     dal: &'a Operator,
-) -> Result<Arc<Xor8Filter>> {
+) -> Result<Arc<FilterImpl>> {
     let storage_runtime = GlobalIORuntime::instance();
     let bytes = {
         let column_data_reader = BloomColumnFilterReader::new(
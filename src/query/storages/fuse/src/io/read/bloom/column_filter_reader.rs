@@ -40,11 +40,14 @@ use databend_storages_common_cache::Loader;
 use databend_storages_common_cache_manager::BloomIndexFilterMeter;
 use databend_storages_common_cache_manager::CachedObject;
 use databend_storages_common_index::filters::Filter;
+use databend_storages_common_index::filters::FilterImpl;
+use databend_storages_common_index::filters::NgramFilter;
 use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::meta::SingleColumnMeta;
 use opendal::Operator;
 
-type CachedReader = InMemoryCacheReader<Xor8Filter, Xor8FilterLoader, BloomIndexFilterMeter>;
+type CachedReader = InMemoryCacheReader<FilterImpl, FilterLoader, BloomIndexFilterMeter>;
 
 /// Load the filter of a given bloom index column. Also
 /// - generates the proper cache key
@@ -84,7 +87,7 @@ impl BloomColumnFilterReader {
         };
 
         let base_parquet_type = ParquetType::PrimitiveType(base_type);
-        let loader = Xor8FilterLoader {
+        let loader = FilterLoader {
             offset: meta.offset,
             len: meta.len,
             cache_key,
@@ -96,7 +99,7 @@ impl BloomColumnFilterReader {
             ),
         };
 
-        let cached_reader = CachedReader::new(Xor8Filter::cache(), loader);
+        let cached_reader = CachedReader::new(FilterImpl::cache(), loader);
 
         let param = LoadParams {
             location: index_path,
@@ -112,13 +115,13 @@ impl BloomColumnFilterReader {
     }
 
     #[async_backtrace::framed]
-    pub async fn read(&self) -> Result<Arc<Xor8Filter>> {
+    pub async fn read(&self) -> Result<Arc<FilterImpl>> {
         self.cached_reader.read(&self.param).await
     }
 }
 
 /// Loader that fetch range of the target object with customized cache key
-pub struct Xor8FilterLoader {
+pub struct FilterLoader {
     pub offset: u64,
     pub len: u64,
     pub cache_key: String,
@@ -127,9 +130,9 @@ pub struct Xor8FilterLoader {
 }
 
 #[async_trait::async_trait]
-impl Loader<Xor8Filter> for Xor8FilterLoader {
+impl Loader<FilterImpl> for FilterLoader {
     #[async_backtrace::framed]
-    async fn load(&self, params: &LoadParams) -> Result<Xor8Filter> {
+    async fn load(&self, params: &LoadParams) -> Result<FilterImpl> {
         let bytes = self
             .operator
             .read_with(&params.location)
@@ -154,6 +157,7 @@ impl Loader<Xor8Filter> for Xor8FilterLoader {
         let decompressor = BasicDecompressor::new(page_reader, vec![]);
         let column_type = self.column_descriptor.descriptor.primitive_type.clone();
         let field_name = self.column_descriptor.path_in_schema[0].to_owned();
+        let is_ngram_filter = BloomIndex::is_ngram_column_name(&field_name);
         let field = ArrowField::new(field_name, DataType::Binary, false);
         let mut array_iter =
             column_iter_to_arrays(vec![decompressor], vec![&column_type], field, None, 1)?;
@@ -170,8 +174,13 @@ impl Loader<Xor8Filter> for Xor8FilterLoader {
                 .index(0)
                 .unwrap();
             metrics_inc_block_index_read_bytes(filter_bytes.len() as u64);
-            let (filter, _size) = Xor8Filter::from_bytes(filter_bytes)?;
-            Ok(filter)
+            if is_ngram_filter {
+                let (filter, _size) = NgramFilter::from_bytes(filter_bytes)?;
+                Ok(FilterImpl::from(filter))
+            } else {
+                let (filter, _size) = Xor8Filter::from_bytes(filter_bytes)?;
+                Ok(FilterImpl::from(filter))
+            }
         } else {
             Err(ErrorCode::StorageOther(
                 "bloom index data not available as expected",
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_storages_common_index::InvertedIndex;
+use opendal::Operator;
+use parquet_rs::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+/// Load the inverted index of a block, returns `None` if the index of the block
+/// is not refreshed yet.
+#[async_backtrace::framed]
+pub async fn load_inverted_index(dal: &Operator, location: &str) -> Result<Option<InvertedIndex>> {
+    if !dal.is_exist(location).await? {
+        return Ok(None);
+    }
+    let data = Bytes::from(dal.read(location).await?);
+    let reader = ParquetRecordBatchReaderBuilder::try_new(data)
+        .and_then(|builder| builder.build())
+        .map_err(|e| ErrorCode::StorageOther(format!("invalid inverted index file: {e}")))?;
+
+    let schema = DataSchema::from(InvertedIndex::schema());
+    let mut blocks = Vec::new();
+    for batch in reader {
+        let batch = batch
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid inverted index file: {e}")))?;
+        let (block, _) = DataBlock::from_record_batch(&schema, &batch)?;
+        blocks.push(block);
+    }
+    if blocks.is_empty() {
+        // None of the rows has terms.
+        return Ok(Some(InvertedIndex::default()));
+    }
+    let block = DataBlock::concat(&blocks)?;
+    Ok(Some(InvertedIndex::from_data_block(&block)?))
+}
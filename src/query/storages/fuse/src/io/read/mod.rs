@@ -15,10 +15,12 @@
 mod agg_index;
 mod block;
 pub mod bloom;
+mod inverted_index;
 pub mod meta;
 mod read_settings;
 mod snapshot_history_reader;
 mod utils;
+mod vector_index;
 mod virtual_column;
 
 pub use agg_index::AggIndexReader;
@@ -28,10 +30,12 @@ pub use block::NativeReaderExt;
 pub use block::NativeSourceData;
 pub use block::UncompressedBuffer;
 pub use bloom::BloomBlockFilterReader;
+pub use inverted_index::load_inverted_index;
 pub use meta::CompactSegmentInfoReader;
 pub use meta::MetaReaders;
 pub use meta::TableSnapshotReader;
 pub use read_settings::ReadSettings;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use vector_index::load_vector_index;
 pub use virtual_column::VirtualColumnReader;
 pub use virtual_column::VirtualMergeIOReadResult;
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use bytes::Bytes;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_vector::VectorDistance;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache_manager::CacheManager;
+use databend_storages_common_index::VectorIndex;
+use opendal::Operator;
+use parquet_rs::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+/// Load the vector index of a block, returns `None` if the index of the block
+/// is not refreshed yet.
+///
+/// The index files are kept in the table data cache, keyed by their locations.
+#[async_backtrace::framed]
+pub async fn load_vector_index(
+    dal: &Operator,
+    location: &str,
+    distance: VectorDistance,
+) -> Result<Option<VectorIndex>> {
+    let cache = CacheManager::instance().get_table_data_cache();
+    let data = match cache.get(location) {
+        Some(data) => data.as_ref().clone(),
+        None => {
+            if !dal.is_exist(location).await? {
+                return Ok(None);
+            }
+            let data = Bytes::from(dal.read(location).await?);
+            cache.put(location.to_string(), Arc::new(data.clone()));
+            data
+        }
+    };
+    let reader = ParquetRecordBatchReaderBuilder::try_new(data)
+        .and_then(|builder| builder.build())
+        .map_err(|e| ErrorCode::StorageOther(format!("invalid vector index file: {e}")))?;
+
+    let schema = DataSchema::from(VectorIndex::schema());
+    let mut blocks = Vec::new();
+    for batch in reader {
+        let batch = batch
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid vector index file: {e}")))?;
+        let (block, _) = DataBlock::from_record_batch(&schema, &batch)?;
+        blocks.push(block);
+    }
+    let block = if blocks.is_empty() {
+        // All the vectors of the block are NULL.
+        DataBlock::empty_with_schema(schema.into())
+    } else {
+        DataBlock::concat(&blocks)?
+    };
+    Ok(Some(VectorIndex::from_data_block(&block, distance)?))
+}
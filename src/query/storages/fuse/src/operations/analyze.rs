@@ -16,20 +16,34 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnId;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use log::warn;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::build_column_histogram;
+use crate::statistics::histogram_supported_type;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
 use crate::FuseTable;
 
+/// The max number of blocks sampled to build the histograms.
+const HISTOGRAM_SAMPLE_BLOCKS: u64 = 64;
+/// The max number of rows sampled from each block to build the histograms.
+const HISTOGRAM_SAMPLE_ROWS_PER_BLOCK: usize = 1024;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -59,6 +73,12 @@ impl FuseTable {
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
+            let mut sampled_blocks = Vec::new();
+            let sample_step = snapshot
+                .summary
+                .block_count
+                .div_ceil(HISTOGRAM_SAMPLE_BLOCKS)
+                .max(1);
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -79,10 +99,13 @@ impl FuseTable {
                     let segment = segment?;
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
-                    segment.blocks.iter().for_each(|block| {
-                        let block = block.as_ref();
+                    segment.blocks.iter().for_each(|block_meta| {
+                        let block = block_meta.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
+                            if block_count_sum % sample_step == 0 {
+                                sampled_blocks.push(block_meta.clone());
+                            }
                             block_count_sum += 1;
                             row_count_sum += row_count;
                             for (i, col_stat) in block.col_stats.iter() {
@@ -127,8 +150,13 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Sample blocks to build the histograms.
+            let histograms = self
+                .build_histograms(ctx, &sampled_blocks, &col_stats, row_count_sum, &ndv_map)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -136,7 +164,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
@@ -155,4 +183,65 @@ impl FuseTable {
 
         Ok(())
     }
+
+    #[async_backtrace::framed]
+    async fn build_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        sampled_blocks: &[Arc<BlockMeta>],
+        col_stats: &StatisticsOfColumns,
+        row_count: u64,
+        ndv_map: &HashMap<ColumnId, u64>,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let schema = self.schema();
+        let (field_indices, fields): (Vec<_>, Vec<_>) = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| histogram_supported_type(&DataType::from(field.data_type())))
+            .unzip();
+        if fields.is_empty() || sampled_blocks.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(field_indices),
+            false,
+            false,
+            false,
+        )?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+
+        let mut samples = vec![Vec::new(); fields.len()];
+        for block_meta in sampled_blocks {
+            let block = block_reader
+                .read_by_meta(&settings, block_meta, &storage_format)
+                .await?;
+            let num_rows = block.num_rows();
+            let step = num_rows.div_ceil(HISTOGRAM_SAMPLE_ROWS_PER_BLOCK).max(1);
+            for (entry, samples) in block.columns().iter().zip(samples.iter_mut()) {
+                for row in (0..num_rows).step_by(step) {
+                    if let Some(value) = entry.value.index(row) {
+                        if !value.is_null() {
+                            samples.push(value.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut histograms = HashMap::new();
+        for (field, samples) in fields.into_iter().zip(samples) {
+            let column_id = field.column_id();
+            let null_count = col_stats.get(&column_id).map_or(0, |stat| stat.null_count);
+            let num_values = row_count.saturating_sub(null_count);
+            let num_distinct = ndv_map.get(&column_id).copied().unwrap_or(num_values);
+            if let Some(histogram) = build_column_histogram(samples, num_values, num_distinct) {
+                histograms.insert(column_id, histogram);
+            }
+        }
+        Ok(histograms)
+    }
 }
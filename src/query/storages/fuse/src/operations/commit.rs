@@ -119,11 +119,12 @@ impl FuseTable {
         ctx: &dyn TableContext,
         table_info: &TableInfo,
         location_generator: &TableMetaLocationGenerator,
-        snapshot: TableSnapshot,
+        mut snapshot: TableSnapshot,
         table_statistics: Option<TableSnapshotStatistics>,
         copied_files: &Option<UpsertTableCopiedFileReq>,
         operator: &Operator,
     ) -> Result<()> {
+        snapshot.prev_table_seq = Some(table_info.ident.seq);
         let snapshot_location = location_generator
             .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
         let need_to_save_statistics =
@@ -223,7 +224,23 @@ impl FuseTable {
             update_stream_meta: update_stream_meta.to_vec(),
         };
 
-        // 3. let's roll
+        // 3. in an explicit transaction, buffer the update until COMMIT
+        let buffered = {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(req.clone(), table_info);
+                true
+            } else {
+                false
+            }
+        };
+        if buffered {
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        // 4. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -249,7 +249,8 @@ where F: SnapshotGenerator + Send + 'static
                         cluster_key_meta,
                         previous,
                     ) {
-                        Ok(snapshot) => {
+                        Ok(mut snapshot) => {
+                            snapshot.prev_table_seq = Some(table_info.ident.seq);
                             self.state = State::TryCommit {
                                 data: snapshot.to_bytes()?,
                                 snapshot,
@@ -315,6 +315,8 @@ impl FuseTable {
             self.schema_with_stream(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_args()?,
+            self.inverted_indexes(),
         )?;
 
         if let Some(inverse) = filters.map(|f| f.inverted_filter) {
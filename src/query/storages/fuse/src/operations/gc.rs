@@ -23,6 +23,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_cache_manager::CachedObject;
@@ -81,12 +82,24 @@ impl FuseTable {
         let mut purged_snapshot_count = 0;
 
         let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
-        let table_agg_index_ids = catalog
+        let agg_index_ids = catalog
             .list_index_ids_by_table_id(ListIndexesByIdReq {
                 tenant: ctx.get_tenant(),
                 table_id: self.get_id(),
             })
             .await?;
+        let inverted_index_versions = self
+            .table_info
+            .meta
+            .indexes
+            .values()
+            .filter(|index| index.index_type == TableIndexType::Inverted)
+            .map(|index| index.version.clone())
+            .collect();
+        let block_indexes = BlockIndexes {
+            agg_index_ids,
+            inverted_index_versions,
+        };
 
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -169,7 +182,7 @@ impl FuseTable {
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
-                        &table_agg_index_ids,
+                        &block_indexes,
                     )
                     .await?;
 
@@ -184,7 +197,7 @@ impl FuseTable {
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
-                        &table_agg_index_ids,
+                        &block_indexes,
                     )
                     .await?;
 
@@ -224,7 +237,7 @@ impl FuseTable {
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
-                    &table_agg_index_ids,
+                    &block_indexes,
                 )
                 .await?;
             } else {
@@ -235,7 +248,7 @@ impl FuseTable {
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
-                    &table_agg_index_ids,
+                    &block_indexes,
                 )
                 .await?;
             }
@@ -253,7 +266,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
-                &table_agg_index_ids,
+                &block_indexes,
             )
             .await?;
         }
@@ -319,7 +332,7 @@ impl FuseTable {
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
-        table_agg_index_ids: &[u64],
+        block_indexes: &BlockIndexes,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -335,13 +348,7 @@ impl FuseTable {
                     continue;
                 }
                 purge_files.push(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    purge_files.push(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    )
-                }
+                purge_files.extend(block_indexes.locations(loc));
             }
 
             for loc in &locations.bloom_location {
@@ -368,7 +375,7 @@ impl FuseTable {
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
-        table_agg_index_ids: &[u64],
+        block_indexes: &BlockIndexes,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -381,19 +388,13 @@ impl FuseTable {
                 .await?;
 
             let mut blocks_to_be_purged = HashSet::new();
-            let mut agg_indexes_to_be_purged = HashSet::new();
+            let mut indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
                 if locations_referenced_by_root.block_location.contains(loc) {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    agg_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    );
-                }
+                indexes_to_be_purged.extend(block_indexes.locations(loc));
             }
 
             let mut blooms_to_be_purged = HashSet::new();
@@ -427,7 +428,7 @@ impl FuseTable {
                 ctx,
                 counter,
                 blocks_to_be_purged,
-                agg_indexes_to_be_purged,
+                indexes_to_be_purged,
                 blooms_to_be_purged,
                 segment_locations_to_be_purged,
             )
@@ -445,7 +446,7 @@ impl FuseTable {
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
-        table_agg_index_ids: &[u64],
+        block_indexes: &BlockIndexes,
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
@@ -455,20 +456,17 @@ impl FuseTable {
                 .collect::<Vec<_>>(),
         );
 
-        let mut agg_indexes_to_be_purged = HashSet::new();
-        for index_id in table_agg_index_ids {
-            agg_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(|loc| {
-                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                    loc, *index_id,
-                )
-            }));
-        }
+        let indexes_to_be_purged = root_location_tuple
+            .block_location
+            .iter()
+            .flat_map(|loc| block_indexes.locations(loc))
+            .collect();
 
         self.purge_block_segments(
             ctx,
             counter,
             root_location_tuple.block_location,
-            agg_indexes_to_be_purged,
+            indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
         )
@@ -492,7 +490,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         blocks_to_be_purged: HashSet<String>,
-        agg_indexes_to_be_purged: HashSet<String>,
+        indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
@@ -504,10 +502,10 @@ impl FuseTable {
                 .await?;
         }
 
-        let agg_index_count = agg_indexes_to_be_purged.len();
-        if agg_index_count > 0 {
-            counter.agg_indexes += agg_index_count;
-            self.try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged)
+        let index_count = indexes_to_be_purged.len();
+        if index_count > 0 {
+            counter.indexes += index_count;
+            self.try_purge_location_files(ctx.clone(), indexes_to_be_purged)
                 .await?;
         }
 
@@ -580,6 +578,83 @@ impl FuseTable {
         Ok(())
     }
 
+    /// Purge the snapshots written by an aborted transaction, together with the segments,
+    /// blocks and bloom indexes referenced by them but not by the current snapshot.
+    #[async_backtrace::framed]
+    pub async fn purge_uncommitted_snapshots(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot_locations: Vec<String>,
+    ) -> Result<()> {
+        let committed_segments = match self.read_table_snapshot().await? {
+            Some(snapshot) => HashSet::from_iter(snapshot.segments.iter().cloned()),
+            None => HashSet::new(),
+        };
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut uncommitted_segments = HashSet::new();
+        for location in &snapshot_locations {
+            let params = LoadParams {
+                location: location.clone(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(location),
+                put_cache: false,
+            };
+            let snapshot = reader.read(&params).await?;
+            uncommitted_segments.extend(
+                snapshot
+                    .segments
+                    .iter()
+                    .filter(|segment| !committed_segments.contains(*segment))
+                    .cloned(),
+            );
+        }
+
+        if !uncommitted_segments.is_empty() {
+            // The uncommitted segments may still reference the committed blocks,
+            // e.g. the segments rewritten by deletion.
+            let committed_segments = Vec::from_iter(committed_segments);
+            let uncommitted_segments = Vec::from_iter(uncommitted_segments);
+            let committed = self
+                .get_block_locations(ctx.clone(), &committed_segments, false, true)
+                .await?;
+            let uncommitted = self
+                .get_block_locations(ctx.clone(), &uncommitted_segments, false, true)
+                .await?;
+
+            let blocks = uncommitted
+                .block_location
+                .difference(&committed.block_location)
+                .cloned()
+                .collect();
+            self.try_purge_location_files(ctx.clone(), blocks).await?;
+
+            let blooms = uncommitted
+                .bloom_location
+                .difference(&committed.bloom_location)
+                .cloned()
+                .collect();
+            self.try_purge_location_files_and_cache::<BloomIndexMeta, _, _>(ctx.clone(), blooms)
+                .await?;
+
+            let segments = uncommitted_segments
+                .into_iter()
+                .map(|(location, _)| location)
+                .collect();
+            self.try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
+                ctx.clone(),
+                segments,
+            )
+            .await?;
+        }
+
+        self.try_purge_location_files_and_cache::<TableSnapshot, _, _>(
+            ctx,
+            HashSet::from_iter(snapshot_locations),
+        )
+        .await
+    }
+
     // Purge file by location chunks.
     #[async_backtrace::framed]
     pub async fn try_purge_location_files(
@@ -696,10 +771,34 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     }
 }
 
+/// The indexes built for each block, whose files are purged together with the blocks.
+struct BlockIndexes {
+    agg_index_ids: Vec<u64>,
+    inverted_index_versions: Vec<String>,
+}
+
+impl BlockIndexes {
+    fn locations<'a>(&'a self, block_location: &'a str) -> impl Iterator<Item = String> + 'a {
+        let agg_indexes = self.agg_index_ids.iter().map(|index_id| {
+            TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                block_location,
+                *index_id,
+            )
+        });
+        let inverted_indexes = self.inverted_index_versions.iter().map(|version| {
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+                version,
+            )
+        });
+        agg_indexes.chain(inverted_indexes)
+    }
+}
+
 struct PurgeCounter {
     start: Instant,
     blocks: usize,
-    agg_indexes: usize,
+    indexes: usize,
     blooms: usize,
     segments: usize,
     table_statistics: usize,
@@ -711,7 +810,7 @@ impl PurgeCounter {
         Self {
             start: Instant::now(),
             blocks: 0,
-            agg_indexes: 0,
+            indexes: 0,
             blooms: 0,
             segments: 0,
             table_statistics: 0,
@@ -20,6 +20,7 @@ pub mod common;
 mod compact;
 mod delete;
 mod gc;
+mod inverted_index;
 mod merge;
 mod merge_into;
 mod mutation;
@@ -34,6 +35,7 @@ mod revert;
 mod truncate;
 mod update;
 pub mod util;
+mod vector_index;
 pub use agg_index_sink::AggIndexSink;
 pub use common::*;
 pub use compact::CompactOptions;
@@ -45,6 +45,7 @@ use sha2::Sha256;
 use crate::fuse_part::FusePartInfo;
 use crate::pruning::FusePruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 use crate::FuseLazyPartInfo;
 use crate::FuseTable;
 
@@ -170,6 +171,8 @@ impl FuseTable {
                 table_schema.clone(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.ngram_args()?,
+                self.inverted_indexes(),
             )?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
@@ -182,12 +185,24 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
+                self.ngram_args()?,
+                self.inverted_indexes(),
             )?
         };
 
-        let block_metas = pruner.read_pruning(segments_location).await?;
+        let mut block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
 
+        if let Some(vector_index) = push_downs.as_ref().and_then(|p| p.vector_index.as_ref()) {
+            if let Some(vector_index_pruner) =
+                VectorIndexPruner::try_create(dal.clone(), vector_index, &self.vector_indexes())?
+            {
+                block_metas = vector_index_pruner
+                    .prune(&pruner.pruning_ctx, block_metas)
+                    .await?;
+            }
+        }
+
         info!(
             "prune snapshot block end, final block numbers:{}, cost:{}",
             block_metas.len(),
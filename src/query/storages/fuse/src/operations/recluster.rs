@@ -248,6 +248,8 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            vec![],
+            vec![],
             max_concurrency,
         )?;
 
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_vector::HnswOptions;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_table_meta::meta::Location;
+
+use crate::io::write_data;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the vector index files of the blocks which are not indexed yet.
+    ///
+    /// If no segment locations are specified, iterates through all segments of the
+    /// current snapshot, at most `limit` blocks are indexed.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index: &TableIndex,
+        limit: Option<u64>,
+        segment_locs: Option<Vec<Location>>,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            // no snapshot
+            return Ok(());
+        };
+
+        let table_schema = &self.get_table_info().meta.schema;
+        let Some(field_index) = table_schema
+            .fields()
+            .iter()
+            .position(|field| index.column_ids.contains(&field.column_id()))
+        else {
+            // the indexed column is dropped
+            return Ok(());
+        };
+
+        let options = HnswOptions::from_options(&index.options)?;
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(vec![field_index]),
+            false,
+            false,
+            false,
+        )?;
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
+
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let write_settings = self.get_write_settings();
+        let storage_format = write_settings.storage_format;
+        let operator = self.get_operator_ref();
+        let index_schema = VectorIndex::schema();
+
+        let segment_locs = segment_locs.unwrap_or_else(|| snapshot.segments.clone());
+        let mut remaining = limit.map(|limit| limit as usize).unwrap_or(usize::MAX);
+        for (location, ver) in segment_locs {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.to_string(),
+                    len_hint: None,
+                    ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                if remaining == 0 {
+                    return Ok(());
+                }
+                let index_location =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &index.version,
+                    );
+                if operator.is_exist(&index_location).await? {
+                    continue;
+                }
+
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &storage_format)
+                    .await?;
+                let entry = block.get_by_offset(0);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                let vector_index = VectorIndex::try_create(options, &column)?;
+
+                let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+                let _ = blocks_to_parquet(
+                    &index_schema,
+                    vec![vector_index.serialize_to_data_block()],
+                    &mut buffer,
+                    write_settings.table_compression,
+                    false,
+                )?;
+                write_data(buffer, operator, &index_location).await?;
+                remaining -= 1;
+            }
+        }
+
+        Ok(())
+    }
+}
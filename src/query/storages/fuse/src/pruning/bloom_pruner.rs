@@ -27,6 +27,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_sql::BloomIndexColumns;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_index::FilterEvalResult;
+use databend_storages_common_index::NgramArgs;
 use databend_storages_common_table_meta::meta::Location;
 use log::warn;
 use opendal::Operator;
@@ -50,6 +51,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// columns whose n-gram filters should be loaded from filter block
+    ngram_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -70,14 +74,20 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
                 bloom_index_cols.bloom_index_fields(schema.clone(), BloomIndex::supported_type)?;
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
+            let ngram_column_fields = ngram_args
+                .iter()
+                .map(|arg| arg.field.clone())
+                .collect::<Vec<_>>();
+            let ngram_fields = BloomIndex::find_ngram_columns(expr, &ngram_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            if !point_query_cols.is_empty() || !ngram_fields.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -92,6 +102,7 @@ impl BloomPrunerCreator {
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -114,7 +125,7 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
+        let mut index_columns = self.index_fields.iter().try_fold(
             Vec::with_capacity(self.index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
@@ -123,6 +134,11 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for field in self.ngram_fields.iter() {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                index_columns.push(BloomIndex::build_ngram_column_name(field));
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
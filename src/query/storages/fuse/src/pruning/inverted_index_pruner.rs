@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::scalars::Tokenizer;
+use databend_common_meta_app::schema::TableIndex;
+use databend_storages_common_index::match_columns;
+use databend_storages_common_index::FilterEvalResult;
+use log::warn;
+use opendal::Operator;
+
+use crate::io::load_inverted_index;
+use crate::io::TableMetaLocationGenerator;
+
+/// Prunes the blocks by the inverted indexes of the columns searched by `match`.
+pub struct InvertedIndexPruner {
+    func_ctx: FunctionContext,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// the versions and tokenizers of the indexes covering the searched columns
+    indexes: Vec<(String, Tokenizer)>,
+
+    /// the data accessor
+    dal: Operator,
+
+    /// the schema of data being indexed
+    data_schema: TableSchemaRef,
+}
+
+impl InvertedIndexPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+        inverted_indexes: &[TableIndex],
+    ) -> Result<Option<Arc<InvertedIndexPruner>>> {
+        let Some(expr) = filter_expr else {
+            return Ok(None);
+        };
+        let mut indexes: Vec<(String, Tokenizer)> = Vec::new();
+        for column in match_columns(expr) {
+            let Ok(field) = schema.field_with_name(&column) else {
+                continue;
+            };
+            for index in inverted_indexes {
+                if index.column_ids.contains(&field.column_id())
+                    && !indexes.iter().any(|(version, _)| version == &index.version)
+                {
+                    let tokenizer = Tokenizer::from_options(&index.options)?;
+                    indexes.push((index.version.clone(), tokenizer));
+                }
+            }
+        }
+        if indexes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Arc::new(InvertedIndexPruner {
+            func_ctx,
+            filter_expression: expr.clone(),
+            indexes,
+            dal,
+            data_schema: schema.clone(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    async fn apply(&self, block_location: &str) -> Result<bool> {
+        for (version, tokenizer) in &self.indexes {
+            let index_location =
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    block_location,
+                    version,
+                );
+            // the block is not indexed yet
+            let Some(index) = load_inverted_index(&self.dal, &index_location).await? else {
+                continue;
+            };
+            let result = index.apply(
+                &self.func_ctx,
+                self.filter_expression.clone(),
+                &self.data_schema,
+                *tokenizer,
+            )?;
+            if result == FilterEvalResult::MustFalse {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    // returns true, if target should NOT be pruned (false positive allowed)
+    #[async_backtrace::framed]
+    pub async fn should_keep(&self, block_location: &str) -> bool {
+        match self.apply(block_location).await {
+            Ok(v) => v,
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                warn!(
+                    "failed to apply inverted index pruner, returning true. {}",
+                    e
+                );
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_vector::HnswOptions;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures_util::future;
+use log::warn;
+use opendal::Operator;
+
+use crate::io::load_vector_index;
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::PruningContext;
+
+/// Prunes the blocks by the vector index for `ORDER BY <distance> LIMIT n` queries.
+///
+/// The `n` nearest vectors of each indexed block are searched, only the blocks containing
+/// the global `n` nearest ones are kept. Blocks which are not indexed yet are always kept.
+pub struct VectorIndexPruner {
+    /// the data accessor
+    dal: Operator,
+
+    /// the version of the index
+    index_version: String,
+
+    options: HnswOptions,
+
+    query: Vec<f32>,
+
+    limit: usize,
+}
+
+impl VectorIndexPruner {
+    pub fn try_create(
+        dal: Operator,
+        vector_index: &VectorIndexInfo,
+        vector_indexes: &[TableIndex],
+    ) -> Result<Option<Arc<VectorIndexPruner>>> {
+        // the index may be dropped or replaced after the query is planned
+        let Some(index) = vector_indexes
+            .iter()
+            .find(|index| index.version == vector_index.index_version)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Arc::new(VectorIndexPruner {
+            dal,
+            index_version: index.version.clone(),
+            options: HnswOptions::from_options(&index.options)?,
+            query: vector_index.query.iter().map(|v| v.0).collect(),
+            limit: vector_index.limit,
+        })))
+    }
+
+    /// Search the nearest vectors of the block, returns `None` if the block can't be pruned.
+    #[async_backtrace::framed]
+    async fn search(&self, block_location: &str) -> Result<Option<Vec<f32>>> {
+        let index_location =
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                block_location,
+                &self.index_version,
+            );
+        // the block is not indexed yet
+        let Some(index) =
+            load_vector_index(&self.dal, &index_location, self.options.distance).await?
+        else {
+            return Ok(None);
+        };
+        let ef = self.limit.max(self.options.ef_construction);
+        let result = index.search(&self.query, self.limit, ef)?;
+        Ok(Some(
+            result.into_iter().map(|(_, distance)| distance).collect(),
+        ))
+    }
+
+    /// The indexes of the blocks are loaded and searched concurrently, bounded by
+    /// the pruning semaphore.
+    #[async_backtrace::framed]
+    pub async fn prune(
+        self: Arc<Self>,
+        pruning_ctx: &PruningContext,
+        block_metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let pruning_tasks = block_metas.iter().enumerate().map(|(i, (_, block_meta))| {
+            let pruner = self.clone();
+            let block_location = block_meta.location.0.clone();
+            move |permit: OwnedSemaphorePermit| async move {
+                let _permit = permit;
+                (i, pruner.search(&block_location).await)
+            }
+        });
+        let join_handlers = pruning_ctx
+            .pruning_runtime
+            .try_spawn_batch_with_owned_semaphore(
+                pruning_ctx.pruning_semaphore.clone(),
+                pruning_tasks,
+            )
+            .await?;
+        let joint = future::try_join_all(join_handlers)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("vector index pruning failure, {}", e)))?;
+
+        let mut keep = HashSet::new();
+        let mut candidates = Vec::new();
+        for (i, result) in joint {
+            match result {
+                Ok(Some(distances)) => {
+                    candidates.extend(distances.into_iter().map(|distance| (distance, i)));
+                }
+                Ok(None) => {
+                    keep.insert(i);
+                }
+                Err(e) => {
+                    // swallow exceptions intentionally, corrupted index should not prevent execution
+                    warn!("failed to apply vector index pruner, keep the block. {}", e);
+                    keep.insert(i);
+                }
+            }
+        }
+        if candidates.len() < self.limit {
+            // The NULL values are not indexed, they may be needed to fill the limit.
+            return Ok(block_metas);
+        }
+
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        keep.extend(candidates.iter().take(self.limit).map(|(_, i)| *i));
+        Ok(block_metas
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| keep.contains(i))
+            .map(|(_, block_meta)| block_meta)
+            .collect())
+    }
+}
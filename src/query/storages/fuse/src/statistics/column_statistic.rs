@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::NumberType;
@@ -78,30 +79,40 @@ pub fn gen_columns_statistics(
         let mut min = Scalar::Null;
         let mut max = Scalar::Null;
 
-        let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
-        let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
-
-        if mins.len() > 0 {
-            min = if let Some(v) = mins.index(0) {
-                if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
-                    v
+        if data_type.remove_nullable() == DataType::Geometry {
+            match geometry_min_max(col) {
+                Some((bbox_min, bbox_max)) => {
+                    min = bbox_min;
+                    max = bbox_max;
+                }
+                None => continue,
+            }
+        } else {
+            let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
+
+            if mins.len() > 0 {
+                min = if let Some(v) = mins.index(0) {
+                    if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
-        }
 
-        if maxs.len() > 0 {
-            max = if let Some(v) = maxs.index(0) {
-                if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
-                    v
+            if maxs.len() > 0 {
+                max = if let Some(v) = maxs.index(0) {
+                    if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
         }
 
@@ -143,7 +154,24 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+/// The min and max of a geometry column are the lower left and the upper right corners
+/// of the bounding box, returns `None` if the bounding box is unknown.
+pub fn geometry_min_max(col: &Column) -> Option<(Scalar, Scalar)> {
+    let domain = col
+        .iter()
+        .filter_map(|v| v.as_geometry().map(|v| GeometryDomain::from_ewkb(v)))
+        .reduce(|l, r| l.merge(&r));
+    match domain {
+        Some(domain) => domain.to_min_max(),
+        // all the values are null
+        None => Some((Scalar::Null, Scalar::Null)),
+    }
+}
+
 pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, Scalar)> {
+    if let Scalar::Geometry(v) = &scalar {
+        return GeometryDomain::from_ewkb(v).to_min_max();
+    }
     if RangeIndex::supported_type(data_type) {
         if let Some((min, Some(max))) = scalar
             .clone()
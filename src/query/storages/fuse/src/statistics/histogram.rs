@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Scalar;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::HistogramBucket;
+
+pub const HISTOGRAM_BUCKETS: usize = 100;
+pub const HISTOGRAM_MOST_COMMON_VALUES: usize = 20;
+
+/// Histograms are only built for the types whose values can be interpolated
+/// by the optimizer.
+pub fn histogram_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Number(_) | DataType::Date | DataType::Timestamp
+    )
+}
+
+/// Build an equi-height histogram from the sampled non-null values of a column.
+///
+/// The counts are scaled from the samples to `num_values` values and `num_distinct`
+/// distinct values, which are estimated for the whole table. The values equal to each
+/// other always fall into the same bucket, so a bucket may hold more values than the others.
+///
+/// NaN and infinities are left out, they can't be encoded in the JSON of the statistics, and
+/// `num_values` is scaled down by their share of the samples.
+pub fn build_column_histogram(
+    mut samples: Vec<Scalar>,
+    num_values: u64,
+    num_distinct: u64,
+) -> Option<ColumnHistogram> {
+    let num_all_samples = samples.len();
+    samples.retain(is_finite);
+    if samples.is_empty() || num_values == 0 {
+        return None;
+    }
+    samples.sort();
+
+    // Count the occurrences of each distinct value.
+    let mut runs: Vec<(Scalar, usize)> = Vec::new();
+    for value in samples.iter() {
+        match runs.last_mut() {
+            Some((last, count)) if last == value => *count += 1,
+            _ => runs.push((value.clone(), 1)),
+        }
+    }
+
+    let num_samples = samples.len();
+    let values_scale = num_values as f64 / num_all_samples as f64;
+    let distinct_scale = (num_distinct as f64 / runs.len() as f64).max(1.0);
+
+    // The values which occur more often than the average are the most common values.
+    let average_count = num_samples as f64 / runs.len() as f64;
+    let mut most_common_values = runs
+        .iter()
+        .filter(|(_, count)| *count > 1 && *count as f64 > average_count)
+        .collect::<Vec<_>>();
+    most_common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    let most_common_values = most_common_values
+        .into_iter()
+        .take(HISTOGRAM_MOST_COMMON_VALUES)
+        .map(|(value, count)| (value.clone(), *count as f64 * values_scale))
+        .collect();
+
+    let depth = num_samples.div_ceil(HISTOGRAM_BUCKETS);
+    let mut buckets = Vec::with_capacity(HISTOGRAM_BUCKETS);
+    let mut start = 0;
+    let mut bucket_values = 0;
+    for (i, (_, count)) in runs.iter().enumerate() {
+        bucket_values += count;
+        if bucket_values >= depth || i == runs.len() - 1 {
+            buckets.push(HistogramBucket {
+                lower_bound: runs[start].0.clone(),
+                upper_bound: runs[i].0.clone(),
+                num_values: bucket_values as f64 * values_scale,
+                num_distinct: (i + 1 - start) as f64 * distinct_scale,
+            });
+            start = i + 1;
+            bucket_values = 0;
+        }
+    }
+
+    Some(ColumnHistogram {
+        buckets,
+        most_common_values,
+    })
+}
+
+fn is_finite(value: &Scalar) -> bool {
+    match value {
+        Scalar::Number(NumberScalar::Float32(v)) => v.0.is_finite(),
+        Scalar::Number(NumberScalar::Float64(v)) => v.0.is_finite(),
+        _ => true,
+    }
+}
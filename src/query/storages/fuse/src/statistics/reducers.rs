@@ -15,6 +15,7 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
 
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
@@ -62,17 +63,22 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            let min = min_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .min_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
-
-            let max = max_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .max_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+            let (min, max) = if min_stats.iter().any(|s| s.as_geometry().is_some()) {
+                reduce_geometry_min_max(&min_stats, &max_stats)
+            } else {
+                let min = min_stats
+                    .into_iter()
+                    .filter(|s| !s.is_null())
+                    .min_by(|x, y| x.cmp(y))
+                    .unwrap_or(Scalar::Null);
+
+                let max = max_stats
+                    .into_iter()
+                    .filter(|s| !s.is_null())
+                    .max_by(|x, y| x.cmp(y))
+                    .unwrap_or(Scalar::Null);
+                (min, max)
+            };
 
             acc.insert(
                 *id,
@@ -82,6 +88,18 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+/// The min and max of geometries are the corners of the bounding box, merge the boxes
+/// instead of comparing the scalars.
+fn reduce_geometry_min_max(min_stats: &[Scalar], max_stats: &[Scalar]) -> (Scalar, Scalar) {
+    min_stats
+        .iter()
+        .zip(max_stats.iter())
+        .filter_map(|(min, max)| GeometryDomain::from_min_max(min, max))
+        .reduce(|l, r| l.merge(&r))
+        .and_then(|domain| domain.to_min_max())
+        .unwrap_or((Scalar::Null, Scalar::Null))
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
@@ -32,10 +32,10 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_sql::resolve_type_name_by_str;
 use hive_metastore as hms;
 
-use crate::hive_catalog::HIVE_CATALOG;
 use crate::hive_database::HiveDatabase;
 use crate::hive_database::HIVE_DATABASE_ENGINE;
 use crate::hive_table::HIVE_TABLE_ENGINE;
+use crate::hive_table_options::HiveFileFormat;
 use crate::hive_table_options::HiveTableOptions;
 
 /// ! Skeleton of mappers
@@ -59,6 +59,7 @@ impl From<hms::Database> for HiveDatabase {
 }
 
 pub fn try_into_table_info(
+    catalog: String,
     sp: Option<StorageParams>,
     hms_table: hms::Table,
     fields: Vec<hms::FieldSchema>,
@@ -83,14 +84,24 @@ pub fn try_into_table_info(
         None
     };
 
+    let file_format = match hms_table
+        .sd
+        .as_ref()
+        .and_then(|storage| storage.input_format.as_ref())
+    {
+        Some(input_format) => HiveFileFormat::from_input_format(input_format)?,
+        None => HiveFileFormat::default(),
+    };
+
     let table_options = HiveTableOptions {
         partition_keys,
         location,
+        file_format,
     };
 
     let meta = TableMeta {
         schema,
-        catalog: HIVE_CATALOG.to_string(),
+        catalog,
         engine: HIVE_TABLE_ENGINE.to_owned(),
         engine_options: table_options.into(),
         storage_params: sp,
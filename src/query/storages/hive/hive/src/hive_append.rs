@@ -0,0 +1,343 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Appending data to hive tables.
+//!
+//! - `HiveAppendTransform` * N: write the blocks to parquet files under the table location,
+//!   the rows of partitioned tables are written to the directories of their partitions.
+//! - `HiveCommitSink` * 1: register the partitions written to hive meta store.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_table_meta::table::TableCompression;
+use log::info;
+use opendal::Operator;
+
+use crate::hive_catalog::HiveCatalog;
+use crate::hive_partition::make_partition_name;
+use crate::hive_table::HIVE_DEFAULT_PARTITION;
+
+/// Buffered rows of a partition are written to a new file once they exceed this size.
+const MAX_FILE_BYTES: usize = 128 * 1024 * 1024;
+
+/// The largest partition buffer is written to a new file once the rows buffered for all the
+/// partitions exceed this size, bounding the memory when many partitions are written.
+const MAX_BUFFERED_BYTES: usize = 512 * 1024 * 1024;
+
+/// Values of the partitions written by a `HiveAppendTransform`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct HivePartitionsWritten {
+    pub partitions: Vec<Vec<String>>,
+}
+
+impl HivePartitionsWritten {
+    pub fn create_block(partitions: Vec<Vec<String>>) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(HivePartitionsWritten { partitions }))
+    }
+}
+
+#[typetag::serde(name = "hive_partitions_written")]
+impl BlockMetaInfo for HivePartitionsWritten {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        HivePartitionsWritten::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Rows buffered for one partition, or for the whole table if it's not partitioned.
+#[derive(Default)]
+struct PartitionBuffer {
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+}
+
+pub struct HiveAppendTransform {
+    operator: Operator,
+    /// directory of the table in opendal path format, ends with '/'
+    table_dir: String,
+    /// schema of the data columns, the partition columns are not written to the files
+    data_schema: TableSchemaRef,
+    partition_keys: Vec<String>,
+    /// the files written by this transform are named `<file_prefix>_<seq>.parquet`
+    file_prefix: String,
+    next_file_seq: usize,
+    buffers: HashMap<Vec<String>, PartitionBuffer>,
+    /// total size of the rows in `buffers`
+    buffered_bytes: usize,
+    partitions: BTreeSet<Vec<String>>,
+}
+
+impl HiveAppendTransform {
+    pub fn create(
+        operator: Operator,
+        table_dir: String,
+        data_schema: TableSchemaRef,
+        partition_keys: Vec<String>,
+    ) -> Self {
+        Self {
+            operator,
+            table_dir,
+            data_schema,
+            partition_keys,
+            file_prefix: uuid::Uuid::new_v4().simple().to_string(),
+            next_file_seq: 0,
+            buffers: HashMap::new(),
+            buffered_bytes: 0,
+            partitions: BTreeSet::new(),
+        }
+    }
+
+    /// Split the rows by the values of the partition columns, which are the last columns
+    /// of the block. The partition columns are removed from the split blocks.
+    fn split_by_partition(&self, block: DataBlock) -> Result<Vec<(Vec<String>, DataBlock)>> {
+        let num_data_columns = self.data_schema.num_fields();
+        if self.partition_keys.is_empty() {
+            let columns = block.columns()[..num_data_columns].to_vec();
+            return Ok(vec![(vec![], DataBlock::new(columns, block.num_rows()))]);
+        }
+
+        let mut rows: HashMap<Vec<String>, Vec<u32>> = HashMap::new();
+        let partition_columns = &block.columns()[num_data_columns..];
+        for row in 0..block.num_rows() {
+            let values = partition_columns
+                .iter()
+                .map(|entry| partition_value_to_string(entry.value.index(row).unwrap()))
+                .collect::<Result<Vec<_>>>()?;
+            rows.entry(values).or_default().push(row as u32);
+        }
+
+        let data_block = DataBlock::new(
+            block.columns()[..num_data_columns].to_vec(),
+            block.num_rows(),
+        );
+        if rows.len() == 1 {
+            let values = rows.into_keys().next().unwrap();
+            return Ok(vec![(values, data_block)]);
+        }
+
+        rows.into_iter()
+            .map(|(values, indices)| Ok((values, data_block.take(&indices, &mut None)?)))
+            .collect()
+    }
+
+    /// Write the rows buffered for the partition to a new file.
+    async fn flush_buffer(&mut self, values: &[String]) -> Result<()> {
+        let Some(buffer) = self.buffers.remove(values) else {
+            return Ok(());
+        };
+        self.buffered_bytes -= buffer.bytes;
+        if buffer.blocks.is_empty() {
+            return Ok(());
+        }
+        self.write_file(values, buffer.blocks).await
+    }
+
+    /// Flush the largest buffers until the buffered rows fit in `MAX_BUFFERED_BYTES`.
+    async fn flush_largest_buffers(&mut self) -> Result<()> {
+        while self.buffered_bytes > MAX_BUFFERED_BYTES {
+            let Some(values) = self
+                .buffers
+                .iter()
+                .max_by_key(|(_, buffer)| buffer.bytes)
+                .map(|(values, _)| values.clone())
+            else {
+                break;
+            };
+            self.flush_buffer(&values).await?;
+        }
+        Ok(())
+    }
+
+    async fn write_file(&mut self, values: &[String], blocks: Vec<DataBlock>) -> Result<()> {
+        let mut data = vec![];
+        blocks_to_parquet(
+            &self.data_schema,
+            blocks,
+            &mut data,
+            TableCompression::Snappy,
+            false,
+        )?;
+
+        let dir = if values.is_empty() {
+            self.table_dir.clone()
+        } else {
+            format!(
+                "{}{}/",
+                self.table_dir,
+                make_partition_name(&self.partition_keys, values)
+            )
+        };
+        let path = format!("{}{}_{}.parquet", dir, self.file_prefix, self.next_file_seq);
+        self.next_file_seq += 1;
+
+        self.operator.write(&path, data).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for HiveAppendTransform {
+    const NAME: &'static str = "HiveAppendTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        for (values, block) in self.split_by_partition(data)? {
+            let bytes = block.memory_size();
+            let buffer = self.buffers.entry(values.clone()).or_default();
+            buffer.bytes += bytes;
+            buffer.blocks.push(block);
+            self.buffered_bytes += bytes;
+
+            if buffer.bytes >= MAX_FILE_BYTES {
+                self.flush_buffer(&values).await?;
+            }
+            self.partitions.insert(values);
+        }
+        self.flush_largest_buffers().await?;
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let values = self.buffers.keys().cloned().collect::<Vec<_>>();
+        for values in values {
+            self.flush_buffer(&values).await?;
+        }
+
+        if self.partition_keys.is_empty() || self.partitions.is_empty() {
+            return Ok(None);
+        }
+        let partitions = std::mem::take(&mut self.partitions);
+        Ok(Some(HivePartitionsWritten::create_block(
+            partitions.into_iter().collect(),
+        )))
+    }
+}
+
+/// Value of the partition column in the partition name, NULL is written to the default partition.
+fn partition_value_to_string(value: ScalarRef) -> Result<String> {
+    match value {
+        ScalarRef::Null => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(s) => Ok(s.to_string()),
+        ScalarRef::Number(n) => Ok(n.to_string()),
+        other => Err(ErrorCode::Unimplemented(format!(
+            "Cannot write hive partition of value {other}, only string and number partition columns are supported"
+        ))),
+    }
+}
+
+pub struct HiveCommitSink {
+    ctx: Arc<dyn TableContext>,
+    catalog: String,
+    database: String,
+    table: String,
+    /// location of the table, like `hdfs://namenode:8020/user/hive/warehouse/db.db/tbl`
+    location: String,
+    partition_keys: Vec<String>,
+    partitions: BTreeSet<Vec<String>>,
+}
+
+impl HiveCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        catalog: String,
+        database: String,
+        table: String,
+        location: String,
+        partition_keys: Vec<String>,
+    ) -> Result<ProcessorPtr> {
+        let sinker = AsyncSinker::create(input, ctx.clone(), HiveCommitSink {
+            ctx,
+            catalog,
+            database,
+            table,
+            location,
+            partition_keys,
+            partitions: BTreeSet::new(),
+        });
+        Ok(ProcessorPtr::create(sinker))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for HiveCommitSink {
+    const NAME: &'static str = "HiveCommitSink";
+
+    /// Add the partitions which are not in hive meta store yet, the data files of the
+    /// existing partitions are visible once they are written.
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.partitions.is_empty() {
+            return Ok(());
+        }
+
+        let catalog = self.ctx.get_catalog(&self.catalog).await?;
+        let catalog = HiveCatalog::try_from_catalog(catalog.as_ref())?;
+
+        let location = self.location.trim_end_matches('/');
+        let partitions = std::mem::take(&mut self.partitions)
+            .into_iter()
+            .map(|values| {
+                let name = make_partition_name(&self.partition_keys, &values);
+                (values, format!("{location}/{name}"))
+            })
+            .collect();
+        let added = catalog
+            .add_partitions(&self.database, &self.table, partitions, true)
+            .await?;
+        info!(
+            "added {} partitions to hive table {}.{}",
+            added, self.database, self.table
+        );
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(HivePartitionsWritten::downcast_from)
+        {
+            self.partitions.extend(meta.partitions);
+        }
+        Ok(false)
+    }
+}
@@ -31,10 +31,12 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
+use databend_common_storage::orc::OrcFileMeta;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
+use crate::hive_orc_block_reader::orc_column_id;
 use crate::hive_parquet_block_reader::HiveBlockReader;
 use crate::hive_table::HIVE_DEFAULT_PARTITION;
 
@@ -96,30 +98,80 @@ impl HiveBlockFilter {
                 }
             }
 
-            for (p_key, p_value) in part_columns {
-                if let Some((idx, _)) = self.data_schema.column_with_name(&p_key) {
-                    let mut null_count = 0;
-                    let v = if p_value == HIVE_DEFAULT_PARTITION {
-                        null_count = row_group.num_rows();
-                        Scalar::Null
-                    } else {
-                        Scalar::String(p_value)
-                    };
+            return Self::apply_filter(
+                filter,
+                statistics,
+                &self.data_schema,
+                part_columns,
+                row_group.num_rows(),
+            );
+        }
+        false
+    }
 
-                    let col_stats = ColumnStatistics::new(v.clone(), v, null_count as u64, 0, None);
-                    statistics.insert(idx as u32, col_stats);
+    // true: stripe is filtered by predict
+    pub fn filter_orc_stripe(
+        &self,
+        meta: &OrcFileMeta,
+        stripe: usize,
+        part_columns: HashMap<String, String>,
+    ) -> bool {
+        if let Some(filter) = &self.range_filter {
+            let mut statistics = StatisticsOfColumns::new();
+            for col in self.projections.iter() {
+                let Some((index, _)) = self.data_schema.column_with_name(col.name()) else {
+                    continue;
+                };
+                let Some(column_id) = orc_column_id(meta, col.name(), index) else {
+                    continue;
+                };
+                if let Some(stats) =
+                    meta.stripe_column_statistics(stripe, column_id, col.data_type())
+                {
+                    let col_stats =
+                        ColumnStatistics::new(stats.min, stats.max, stats.null_count, 0, None);
+                    statistics.insert(index as u32, col_stats);
                 }
             }
 
-            if let Ok(ret) = filter.apply(&statistics, |_| false) {
-                if !ret {
-                    return true;
-                }
-            }
+            let num_rows = meta.stripes()[stripe].number_of_rows() as usize;
+            return Self::apply_filter(
+                filter,
+                statistics,
+                &self.data_schema,
+                part_columns,
+                num_rows,
+            );
         }
         false
     }
 
+    // Add the statistics of partition columns, and check if the block can be filtered out.
+    fn apply_filter(
+        filter: &RangeIndex,
+        mut statistics: StatisticsOfColumns,
+        data_schema: &TableSchema,
+        part_columns: HashMap<String, String>,
+        num_rows: usize,
+    ) -> bool {
+        for (p_key, p_value) in part_columns {
+            if let Some((idx, _)) = data_schema.column_with_name(&p_key) {
+                let mut null_count = 0;
+                let v = if p_value == HIVE_DEFAULT_PARTITION {
+                    null_count = num_rows;
+                    Scalar::Null
+                } else {
+                    Scalar::String(p_value)
+                };
+
+                let col_stats = ColumnStatistics::new(v.clone(), v, null_count as u64, 0, None);
+                statistics.insert(idx as u32, col_stats);
+            }
+        }
+
+        matches!(filter.apply(&statistics, |_| false), Ok(false))
+    }
+
     fn get_max_min_stats(
         column_type: &TableDataType,
         stats: &dyn Statistics,
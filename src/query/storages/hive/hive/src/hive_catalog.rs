@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::net::ToSocketAddrs;
 use std::sync::Arc;
@@ -91,15 +92,21 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_types::*;
 use faststr::FastStr;
 use hive_metastore::Partition;
+use hive_metastore::ThriftHiveMetastoreAddPartitionException;
 use hive_metastore::ThriftHiveMetastoreClient;
 use hive_metastore::ThriftHiveMetastoreClientBuilder;
+use hive_metastore::ThriftHiveMetastoreDropPartitionByNameException;
 use hive_metastore::ThriftHiveMetastoreGetTableException;
+use log::info;
+use opendal::Operator;
 use volo_thrift::transport::pool;
 
 use super::hive_database::HiveDatabase;
+use crate::hive_partition::parse_partition_name;
+use crate::hive_table::convert_hdfs_path;
+use crate::hive_table::list_partition_dirs;
 use crate::hive_table::HiveTable;
-
-pub const HIVE_CATALOG: &str = "hive";
+use crate::hive_table_options::HiveFileFormat;
 
 #[derive(Debug)]
 pub struct HiveCreator;
@@ -186,6 +193,18 @@ impl HiveCatalog {
         })
     }
 
+    pub fn try_from_catalog(catalog: &dyn Catalog) -> Result<&HiveCatalog> {
+        catalog
+            .as_any()
+            .downcast_ref::<HiveCatalog>()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "expects catalog of type HIVE, but got {}",
+                    catalog.name()
+                ))
+            })
+    }
+
     #[async_backtrace::framed]
     pub async fn get_partitions(
         &self,
@@ -223,15 +242,157 @@ impl HiveCatalog {
             .collect())
     }
 
+    #[async_backtrace::framed]
+    pub async fn get_hive_table(&self, db: &str, table: &str) -> Result<hive_metastore::Table> {
+        match self
+            .client
+            .get_table(FastStr::new(db), FastStr::new(table))
+            .await
+        {
+            Ok(meta) => Ok(meta),
+            Err(volo_thrift::ResponseError::UserException(
+                ThriftHiveMetastoreGetTableException::O2(e),
+            )) => Err(ErrorCode::TableInfoError(
+                e.message.clone().unwrap_or_default(),
+            )),
+            Err(e) => Err(from_thrift_error(e)),
+        }
+    }
+
+    /// Register the partitions to hive meta store, each partition is given by its values
+    /// and the location of its directory, the other storage properties are taken from the table.
+    ///
+    /// Returns the number of the partitions added, the existing ones are skipped if
+    /// `if_not_exists` is true.
+    #[async_backtrace::framed]
+    pub async fn add_partitions(
+        &self,
+        db: &str,
+        table: &str,
+        partitions: Vec<(Vec<String>, String)>,
+        if_not_exists: bool,
+    ) -> Result<usize> {
+        if partitions.is_empty() {
+            return Ok(0);
+        }
+
+        let table_meta = self.get_hive_table(db, table).await?;
+        let create_time = chrono::Utc::now().timestamp() as i32;
+
+        let mut added = 0;
+        for (values, location) in partitions {
+            let mut sd = table_meta.sd.clone().unwrap_or_default();
+            sd.location = Some(FastStr::new(location));
+            let partition = Partition {
+                values: Some(values.into_iter().map(FastStr::new).collect()),
+                db_name: Some(FastStr::new(db)),
+                table_name: Some(FastStr::new(table)),
+                create_time: Some(create_time),
+                sd: Some(sd),
+                ..Default::default()
+            };
+
+            match self.client.add_partition(partition).await {
+                Ok(_) => added += 1,
+                Err(volo_thrift::ResponseError::UserException(
+                    ThriftHiveMetastoreAddPartitionException::O2(e),
+                )) => {
+                    if !if_not_exists {
+                        return Err(ErrorCode::TableInfoError(
+                            e.message.clone().unwrap_or_default(),
+                        ));
+                    }
+                }
+                Err(e) => {
+                    return Err(from_thrift_error(e));
+                }
+            }
+        }
+        Ok(added)
+    }
+
+    /// Drop the partition from hive meta store, the partition name is like 'c_region=ASIA/c_nation=CHINA'.
+    ///
+    /// The data of the partition is removed by hive meta store, unless the table is external.
+    #[async_backtrace::framed]
+    pub async fn drop_partition(
+        &self,
+        db: &str,
+        table: &str,
+        partition_name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        match self
+            .client
+            .drop_partition_by_name(
+                FastStr::new(db),
+                FastStr::new(table),
+                FastStr::new(partition_name),
+                true,
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(volo_thrift::ResponseError::UserException(
+                ThriftHiveMetastoreDropPartitionByNameException::O1(e),
+            )) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(ErrorCode::TableInfoError(
+                        e.message.clone().unwrap_or_default(),
+                    ))
+                }
+            }
+            Err(e) => Err(from_thrift_error(e)),
+        }
+    }
+
+    /// Add the partition directories under the table location which are not in hive meta store,
+    /// like `MSCK REPAIR TABLE` of hive.
+    ///
+    /// Returns the number of the partitions added.
+    #[async_backtrace::framed]
+    pub async fn recover_partitions(
+        &self,
+        db: &str,
+        table: &str,
+        dal: &Operator,
+        location: &str,
+        partition_keys: &[String],
+    ) -> Result<usize> {
+        let location = location.trim_end_matches('/');
+        let dir = convert_hdfs_path(location, true);
+        let partition_names = list_partition_dirs(dal, &dir, partition_keys).await?;
+
+        let existing = self
+            .get_partition_names(db.to_string(), table.to_string(), -1)
+            .await?
+            .iter()
+            .filter_map(|name| parse_partition_name(partition_keys, name))
+            .collect::<HashSet<_>>();
+
+        let partitions = partition_names
+            .into_iter()
+            .filter_map(|name| {
+                let values = parse_partition_name(partition_keys, &name)?;
+                (!existing.contains(&values)).then(|| (values, format!("{location}/{name}")))
+            })
+            .collect::<Vec<_>>();
+        info!(
+            "found {} new partitions under the location of hive table {}.{}",
+            partitions.len(),
+            db,
+            table
+        );
+
+        self.add_partitions(db, table, partitions, true).await
+    }
+
     fn handle_table_meta(table_meta: &hive_metastore::Table) -> Result<()> {
         if let Some(sd) = table_meta.sd.as_ref() {
             if let Some(input_format) = sd.input_format.as_ref() {
-                if input_format != "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat" {
-                    return Err(ErrorCode::Unimplemented(format!(
-                        "only support parquet, {} not support",
-                        input_format
-                    )));
-                }
+                HiveFileFormat::from_input_format(input_format)?;
             }
         }
 
@@ -374,23 +535,7 @@ impl Catalog for HiveCatalog {
         db_name: &str,
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
-        let table_meta = match self
-            .client
-            .get_table(FastStr::new(db_name), FastStr::new(table_name))
-            .await
-        {
-            Ok(meta) => meta,
-            Err(volo_thrift::ResponseError::UserException(
-                ThriftHiveMetastoreGetTableException::O2(e),
-            )) => {
-                return Err(ErrorCode::TableInfoError(
-                    e.message.clone().unwrap_or_default(),
-                ));
-            }
-            Err(e) => {
-                return Err(from_thrift_error(e));
-            }
-        };
+        let table_meta = self.get_hive_table(db_name, table_name).await?;
 
         Self::handle_table_meta(&table_meta)?;
 
@@ -399,8 +544,12 @@ impl Catalog for HiveCatalog {
             .get_schema(FastStr::new(db_name), FastStr::new(table_name))
             .await
             .map_err(from_thrift_error)?;
-        let table_info: TableInfo =
-            super::converters::try_into_table_info(self.sp.clone(), table_meta, fields)?;
+        let table_info: TableInfo = super::converters::try_into_table_info(
+            self.name(),
+            self.sp.clone(),
+            table_meta,
+            fields,
+        )?;
         let res: Arc<dyn Table> = Arc::new(HiveTable::try_create(table_info)?);
 
         Ok(res)
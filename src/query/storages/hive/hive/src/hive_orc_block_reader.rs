@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_storage::orc::OrcFileMeta;
+use databend_common_storage::orc::OrcStripe;
+
+use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::HivePartInfo;
+
+/// Find the column id of the table column at `index` in an orc file.
+///
+/// Files written by old versions of hive don't keep the column names, the columns
+/// are named `_col0`, `_col1`, ... by their positions in the table.
+pub fn orc_column_id(meta: &OrcFileMeta, name: &str, index: usize) -> Option<u32> {
+    meta.column_id(name, false).or_else(|| {
+        if meta.fields().all(|(name, _)| name.starts_with("_col")) {
+            meta.column_id(&format!("_col{}", index), true)
+        } else {
+            None
+        }
+    })
+}
+
+impl HiveBlockReader {
+    /// The column ids of the projected columns, `None` if the column is not in the file.
+    pub fn get_orc_column_ids(&self, meta: &OrcFileMeta) -> Vec<Option<u32>> {
+        self.get_projection()
+            .iter()
+            .zip(self.get_projected_table_schema().fields())
+            .map(|(index, field)| orc_column_id(meta, field.name(), *index))
+            .collect()
+    }
+
+    pub fn read_orc_block(
+        &self,
+        meta: &OrcFileMeta,
+        stripe: &OrcStripe,
+        part: &HivePartInfo,
+    ) -> Result<DataBlock> {
+        let num_rows = stripe.num_rows();
+        let schema = self.get_projected_table_schema();
+        let column_ids = self.get_orc_column_ids(meta);
+
+        let mut columns = Vec::with_capacity(column_ids.len());
+        for (field, column_id) in schema.fields().iter().zip(column_ids) {
+            let data_type = DataType::from(field.data_type());
+            let value = match column_id {
+                Some(column_id) => Value::Column(
+                    stripe
+                        .decode_column(meta, column_id, field.data_type())
+                        .map_err(|e| {
+                            e.add_message(format!(" filename of hive part {}", part.filename))
+                        })?,
+                ),
+                // the column is added to the table after the file is written
+                None if data_type.is_nullable_or_null() => Value::Scalar(Scalar::Null),
+                None => {
+                    return Err(ErrorCode::OrcFileInvalid(format!(
+                        "couldn't find column:{} in orc file {}",
+                        field.name(),
+                        part.filename
+                    )));
+                }
+            };
+            columns.push(BlockEntry::new(data_type, value));
+        }
+
+        let block = DataBlock::new(columns, num_rows);
+        self.fill_partition_columns(block, part, num_rows)
+    }
+}
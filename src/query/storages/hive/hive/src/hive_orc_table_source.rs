@@ -0,0 +1,354 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::tokio::time::Duration;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::filter_helper::FilterHelpers;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::processors::Profile;
+use databend_common_pipeline_core::processors::ProfileStatisticsName;
+use databend_common_storage::orc::OrcFileMeta;
+use databend_common_storage::orc::OrcStripe;
+use databend_common_storage::read_orc_metadata;
+use log::debug;
+use opendal::Operator;
+
+use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::HiveBlockFilter;
+use crate::HivePartInfo;
+
+/// The stripes of an orc file which should be read for a hive partition.
+pub struct HiveOrcStripes {
+    pub file_meta: Arc<OrcFileMeta>,
+    pub part: HivePartInfo,
+    pub valid_stripes: Vec<usize>,
+    pub current_index: usize,
+}
+
+impl HiveOrcStripes {
+    pub fn create(file_meta: Arc<OrcFileMeta>, part: HivePartInfo) -> Self {
+        Self {
+            file_meta,
+            part,
+            valid_stripes: vec![],
+            current_index: 0,
+        }
+    }
+
+    // there are some conditions to filter invalid stripes:
+    // 1. the stripe doesn't belong to the partition
+    // 2. filtered by the stripe statistics
+    pub fn prune(&mut self, hive_block_filter: &HiveBlockFilter) -> bool {
+        let mut pruned_stripe_cnt = 0;
+        for (idx, stripe) in self.file_meta.stripes().iter().enumerate() {
+            let len = stripe.index_length() + stripe.data_length() + stripe.footer_length();
+            let mid = stripe.offset() + len / 2;
+            if !self.part.range.contains(&mid) {
+                continue;
+            }
+            if hive_block_filter.filter_orc_stripe(
+                &self.file_meta,
+                idx,
+                self.part.get_partition_map(),
+            ) {
+                pruned_stripe_cnt += 1;
+            } else {
+                self.valid_stripes.push(idx);
+            }
+        }
+        debug!(
+            "hive orc predict pushdown have pruned {} stripes",
+            pruned_stripe_cnt
+        );
+        self.has_stripes()
+    }
+
+    pub fn get_current_stripe(&self) -> usize {
+        self.valid_stripes[self.current_index]
+    }
+
+    pub fn advance(&mut self) {
+        self.current_index += 1;
+    }
+
+    pub fn has_stripes(&self) -> bool {
+        self.current_index < self.valid_stripes.len()
+    }
+}
+
+enum State {
+    /// Read orc file meta data
+    /// IO bound
+    ReadMeta(Option<PartInfoPtr>),
+
+    /// Read the streams of prewhere and remain columns of a stripe
+    /// IO bound
+    ReadStripe(HiveOrcStripes),
+
+    /// Deserialize the prewhere columns and do prewhere filter, the remain columns are
+    /// deserialized only if some rows are left
+    /// CPU bound
+    Deserialize(HiveOrcStripes, OrcStripe),
+
+    /// indicates that data blocks are ready, and needs to be consumed
+    Generated(HiveOrcStripes, Vec<DataBlock>),
+    Finish,
+}
+
+/// The source of hive tables stored as orc files.
+///
+/// Unlike parquet, the columns of a stripe are read in one request, since they are
+/// usually stored close to each other.
+pub struct HiveOrcTableSource {
+    state: State,
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    scan_progress: Arc<Progress>,
+    prewhere_block_reader: Arc<HiveBlockReader>,
+    remain_reader: Arc<Option<HiveBlockReader>>,
+    prewhere_filter: Arc<Option<Expr>>,
+    output: Arc<OutputPort>,
+    delay: usize,
+    hive_block_filter: Arc<HiveBlockFilter>,
+
+    /// The schema before output. Some fields might be removed when outputting.
+    source_schema: DataSchemaRef,
+    /// The final output schema
+    output_schema: DataSchemaRef,
+}
+
+impl HiveOrcTableSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        output: Arc<OutputPort>,
+        prewhere_block_reader: Arc<HiveBlockReader>,
+        remain_reader: Arc<Option<HiveBlockReader>>,
+        prewhere_filter: Arc<Option<Expr>>,
+        delay: usize,
+        hive_block_filter: Arc<HiveBlockFilter>,
+        source_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        Ok(ProcessorPtr::create(Box::new(HiveOrcTableSource {
+            ctx,
+            dal,
+            output,
+            prewhere_block_reader,
+            remain_reader,
+            prewhere_filter,
+            hive_block_filter,
+            scan_progress,
+            state: State::ReadMeta(None),
+            delay,
+            source_schema,
+            output_schema,
+        })))
+    }
+
+    fn try_get_partitions(&mut self) {
+        self.state = self
+            .ctx
+            .get_partition()
+            .map_or(State::Finish, |part_info| State::ReadMeta(Some(part_info)));
+    }
+
+    fn column_ids(&self, meta: &OrcFileMeta) -> Vec<u32> {
+        let mut column_ids = self.prewhere_block_reader.get_orc_column_ids(meta);
+        if let Some(remain_reader) = self.remain_reader.as_ref() {
+            column_ids.extend(remain_reader.get_orc_column_ids(meta));
+        }
+        column_ids.into_iter().flatten().collect()
+    }
+
+    fn do_deserialize(&mut self, stripes: HiveOrcStripes, stripe: OrcStripe) -> Result<()> {
+        let part = &stripes.part;
+        let prewhere_block =
+            self.prewhere_block_reader
+                .read_orc_block(&stripes.file_meta, &stripe, part)?;
+
+        let progress_values = ProgressValues {
+            rows: prewhere_block.num_rows(),
+            bytes: prewhere_block.memory_size(),
+        };
+        Profile::record_usize_profile(ProfileStatisticsName::ScanBytes, progress_values.bytes);
+        self.scan_progress.incr(&progress_values);
+
+        let Some(filter) = self.prewhere_filter.as_ref() else {
+            // if no prewhere filter, data should be all fetched by the prewhere reader
+            self.state = State::Generated(stripes, vec![prewhere_block]);
+            return Ok(());
+        };
+
+        assert_eq!(filter.data_type(), &DataType::Boolean);
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(&prewhere_block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let predicates = evaluator
+            .run(filter)
+            .map_err(|e| e.add_message("eval prewhere filter failed:"))?
+            .try_downcast::<BooleanType>()
+            .unwrap();
+        if FilterHelpers::is_all_unset(&predicates) {
+            // all rows in this stripe are filtered out
+            self.state = State::Generated(stripes, vec![]);
+            return Ok(());
+        }
+
+        let mut block = prewhere_block;
+        if let Some(remain_reader) = self.remain_reader.as_ref() {
+            let remain_block = remain_reader.read_orc_block(&stripes.file_meta, &stripe, part)?;
+            for column in remain_block.columns().iter() {
+                block.add_column(column.clone());
+            }
+        }
+        let block = DataBlock::filter_boolean_value(block, &predicates)?;
+        let blocks = if block.is_empty() {
+            vec![]
+        } else {
+            vec![block.resort(&self.source_schema, &self.output_schema)?]
+        };
+        self.state = State::Generated(stripes, blocks);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HiveOrcTableSource {
+    fn name(&self) -> String {
+        "HiveEngineSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if matches!(self.state, State::ReadMeta(None)) {
+            self.try_get_partitions();
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if matches!(self.state, State::Generated(_, _)) {
+            if let State::Generated(mut stripes, mut data_blocks) =
+                std::mem::replace(&mut self.state, State::Finish)
+            {
+                // 1. consume all generated blocks,
+                if let Some(data_block) = data_blocks.pop() {
+                    self.output.push_data(Ok(data_block));
+                    // 2. if not all consumed, retain generated state
+                    self.state = State::Generated(stripes, data_blocks);
+                    return Ok(Event::NeedConsume);
+                }
+
+                // 3. if all consumed, try next stripe
+                stripes.advance();
+                match stripes.has_stripes() {
+                    true => {
+                        self.state = State::ReadStripe(stripes);
+                    }
+                    false => {
+                        self.try_get_partitions();
+                    }
+                }
+            }
+        }
+
+        match self.state {
+            State::Finish => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            State::ReadMeta(_) => Ok(Event::Async),
+            State::ReadStripe(_) => Ok(Event::Async),
+            State::Deserialize(_, _) => Ok(Event::Sync),
+            State::Generated(_, _) => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::Deserialize(stripes, stripe) => self.do_deserialize(stripes, stripe),
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::ReadMeta(Some(part)) => {
+                if self.delay > 0 {
+                    sleep(Duration::from_millis(self.delay as u64)).await;
+                    debug!("sleep for {}ms", self.delay);
+                    self.delay = 0;
+                }
+                let part = HivePartInfo::from_part(&part)?;
+                let file_meta =
+                    read_orc_metadata(&self.dal, &part.filename, Some(part.filesize)).await?;
+                let mut stripes = HiveOrcStripes::create(Arc::new(file_meta), part.clone());
+
+                match stripes.prune(&self.hive_block_filter) {
+                    true => {
+                        self.state = State::ReadStripe(stripes);
+                    }
+                    false => {
+                        self.try_get_partitions();
+                    }
+                }
+                Ok(())
+            }
+            State::ReadStripe(stripes) => {
+                let column_ids = self.column_ids(&stripes.file_meta);
+                let stripe = OrcStripe::read(
+                    &self.dal,
+                    &stripes.part.filename,
+                    &stripes.file_meta,
+                    stripes.get_current_stripe(),
+                    &column_ids,
+                )
+                .await?;
+                self.state = State::Deserialize(stripes, stripe);
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+}
@@ -45,6 +45,7 @@ pub struct HiveBlockReader {
     operator: Operator,
     projection: Vec<usize>,
     arrow_schema: Arc<Schema>,
+    projected_table_schema: TableSchemaRef,
     projected_schema: DataSchemaRef,
     // have partition columns
     output_schema: DataSchemaRef,
@@ -136,11 +137,13 @@ impl HiveBlockReader {
             None
         };
 
-        let projected_schema = DataSchemaRef::new(DataSchema::from(&schema.project(&projection)));
+        let projected_table_schema = Arc::new(schema.project(&projection));
+        let projected_schema = DataSchemaRef::new(DataSchema::from(&projected_table_schema));
         let arrow_schema = schema.as_ref().into();
         Ok(Arc::new(HiveBlockReader {
             operator,
             projection,
+            projected_table_schema,
             projected_schema,
             output_schema,
             arrow_schema: Arc::new(arrow_schema),
@@ -329,6 +332,26 @@ impl HiveBlockReader {
     pub fn get_output_schema(&self) -> DataSchemaRef {
         self.output_schema.clone()
     }
+
+    pub fn get_projection(&self) -> &[usize] {
+        &self.projection
+    }
+
+    pub fn get_projected_table_schema(&self) -> TableSchemaRef {
+        self.projected_table_schema.clone()
+    }
+
+    pub fn fill_partition_columns(
+        &self,
+        block: DataBlock,
+        part: &HivePartInfo,
+        num_rows: usize,
+    ) -> Result<DataBlock> {
+        match &self.hive_partition_filler {
+            Some(filler) => filler.fill_data(block, part, num_rows),
+            None => Ok(block),
+        }
+    }
 }
 
 pub fn filter_hive_partition_from_partition_keys(
@@ -90,7 +90,104 @@ pub fn parse_hive_partitions(partitions: &str) -> HashMap<String, String> {
     let parts = partitions.split('/').collect::<Vec<_>>();
     for part in parts {
         let kv = part.split('=').collect::<Vec<_>>();
-        partition_map.insert(kv[0].to_string(), kv[1].to_string());
+        partition_map.insert(
+            unescape_partition_value(kv[0]),
+            unescape_partition_value(kv[1]),
+        );
     }
     partition_map
 }
+
+/// Build the partition name like 'c_region=ASIA/c_nation=CHINA', which is also the
+/// path of the partition directory relative to the table location.
+pub fn make_partition_name(keys: &[String], values: &[String]) -> String {
+    keys.iter()
+        .zip(values)
+        .map(|(k, v)| {
+            format!(
+                "{}={}",
+                escape_partition_value(k),
+                escape_partition_value(v)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Parse the values out of the partition name, `None` is returned if the name
+/// does not match the partition keys in order.
+pub fn parse_partition_name(keys: &[String], name: &str) -> Option<Vec<String>> {
+    let parts = name.split('/').collect::<Vec<_>>();
+    if parts.len() != keys.len() {
+        return None;
+    }
+
+    parts
+        .into_iter()
+        .zip(keys)
+        .map(|(part, key)| {
+            let (k, v) = part.split_once('=')?;
+            (unescape_partition_value(k) == *key && !v.is_empty())
+                .then(|| unescape_partition_value(v))
+        })
+        .collect()
+}
+
+// same as `FileUtils.escapePathName` of hive
+pub fn escape_partition_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if need_escape(c) {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+// same as `FileUtils.unescapePathName` of hive, invalid escapes are kept as they are
+pub fn unescape_partition_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(c) = value
+                .get(i + 1..i + 3)
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                unescaped.push(c);
+                i += 3;
+                continue;
+            }
+        }
+        unescaped.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&unescaped).into_owned()
+}
+
+fn need_escape(c: char) -> bool {
+    matches!(
+        c,
+        '\u{01}'
+            ..='\u{1F}'
+                | '"'
+                | '#'
+                | '%'
+                | '\''
+                | '*'
+                | '/'
+                | ':'
+                | '='
+                | '?'
+                | '\\'
+                | '\u{7F}'
+                | '{'
+                | '['
+                | ']'
+                | '^'
+    )
+}
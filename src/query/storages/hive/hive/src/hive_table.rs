@@ -18,13 +18,13 @@ use std::time::Instant;
 
 use async_recursion::async_recursion;
 use databend_common_base::base::tokio::sync::Semaphore;
-use databend_common_catalog::catalog_kind::CATALOG_HIVE;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableStatistics;
@@ -37,6 +37,7 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::Expr;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -49,6 +50,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_core::SourcePipeBuilder;
 use databend_common_pipeline_sources::SyncSource;
 use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
 use databend_storages_common_index::RangeIndex;
@@ -63,9 +65,15 @@ use opendal::Operator;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
+use super::hive_table_options::HiveFileFormat;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_append::HiveAppendTransform;
+use crate::hive_append::HiveCommitSink;
+use crate::hive_orc_table_source::HiveOrcTableSource;
 use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::hive_partition::make_partition_name;
+use crate::hive_partition::unescape_partition_value;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
@@ -95,6 +103,121 @@ impl HiveTable {
         })
     }
 
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&HiveTable> {
+        tbl.as_any().downcast_ref::<HiveTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine HIVE, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    // database and table name in hive meta store, the desc of hive table is 'db.table'
+    fn get_database_and_table_name(&self) -> (String, String) {
+        let names = self.table_info.desc.split('.').collect::<Vec<&str>>();
+        (names[0].to_string(), names[1].to_string())
+    }
+
+    fn get_location(&self) -> Result<&str> {
+        self.table_options.location.as_deref().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("{}, table location is empty", self.table_info.name))
+        })
+    }
+
+    // values of all the partition keys in order, the partition columns can be given in any order
+    fn get_partition_values(&self, partition: &[(String, String)]) -> Result<Vec<String>> {
+        self.partition_keys()
+            .iter()
+            .map(|key| {
+                partition
+                    .iter()
+                    .find(|(column, _)| column == key)
+                    .map(|(_, value)| value.clone())
+                    .ok_or_else(|| {
+                        ErrorCode::BadArguments(format!(
+                            "value of partition column {} is not specified",
+                            key
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    /// Add a partition to hive meta store, the location of the partition is
+    /// `<table location>/<partition name>` if not specified.
+    #[async_backtrace::framed]
+    pub async fn add_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: &[(String, String)],
+        location: Option<String>,
+        if_not_exists: bool,
+    ) -> Result<()> {
+        let values = self.get_partition_values(partition)?;
+        let location = match location {
+            Some(location) => location,
+            None => format!(
+                "{}/{}",
+                self.get_location()?.trim_end_matches('/'),
+                make_partition_name(&self.partition_keys(), &values)
+            ),
+        };
+
+        let hive_catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let hive_catalog = HiveCatalog::try_from_catalog(hive_catalog.as_ref())?;
+        let (db, table) = self.get_database_and_table_name();
+        hive_catalog
+            .add_partitions(&db, &table, vec![(values, location)], if_not_exists)
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: &[(String, String)],
+        if_exists: bool,
+    ) -> Result<()> {
+        let values = self.get_partition_values(partition)?;
+        let partition_name = make_partition_name(&self.partition_keys(), &values);
+
+        let hive_catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let hive_catalog = HiveCatalog::try_from_catalog(hive_catalog.as_ref())?;
+        let (db, table) = self.get_database_and_table_name();
+        hive_catalog
+            .drop_partition(&db, &table, &partition_name, if_exists)
+            .await
+    }
+
+    /// Add the partition directories under the table location which are not in hive meta store,
+    /// like `MSCK REPAIR TABLE` of hive.
+    ///
+    /// Returns the number of the partitions added.
+    #[async_backtrace::framed]
+    pub async fn recover_partitions(&self, ctx: Arc<dyn TableContext>) -> Result<usize> {
+        let partition_keys = self.partition_keys();
+        if partition_keys.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "table {} is not partitioned",
+                self.table_info.name
+            )));
+        }
+
+        let hive_catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let hive_catalog = HiveCatalog::try_from_catalog(hive_catalog.as_ref())?;
+        let (db, table) = self.get_database_and_table_name();
+        hive_catalog
+            .recover_partitions(
+                &db,
+                &table,
+                &self.dal,
+                self.get_location()?,
+                &partition_keys,
+            )
+            .await
+    }
+
     fn get_block_filter(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -215,12 +338,11 @@ impl HiveTable {
 
         for index in 0..std::cmp::max(1, max_threads) {
             let output = OutputPort::create();
-            source_builder.add_source(
-                output.clone(),
-                HiveTableSource::create(
+            let source = match self.table_options.file_format {
+                HiveFileFormat::Parquet => HiveTableSource::create(
                     ctx.clone(),
                     self.dal.clone(),
-                    output,
+                    output.clone(),
                     prewhere_reader.clone(),
                     remain_reader.clone(),
                     prewhere_filter.clone(),
@@ -229,7 +351,20 @@ impl HiveTable {
                     src_schema.clone(),
                     output_schema.clone(),
                 )?,
-            );
+                HiveFileFormat::Orc => HiveOrcTableSource::create(
+                    ctx.clone(),
+                    self.dal.clone(),
+                    output.clone(),
+                    prewhere_reader.clone(),
+                    remain_reader.clone(),
+                    prewhere_filter.clone(),
+                    delay_timer(index),
+                    hive_block_filter.clone(),
+                    src_schema.clone(),
+                    output_schema.clone(),
+                )?,
+            };
+            source_builder.add_source(output, source);
         }
 
         pipeline.add_pipe(source_builder.finalize());
@@ -393,13 +528,13 @@ impl HiveTable {
         partition_keys: Vec<String>,
         filter_expression: Option<Expr<String>>,
     ) -> Result<Vec<(String, Option<String>)>> {
-        let hive_catalog = ctx.get_catalog(CATALOG_HIVE).await?;
-        let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+        let hive_catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let hive_catalog = HiveCatalog::try_from_catalog(hive_catalog.as_ref())?;
 
         // todo may use get_partition_names_ps to filter
-        let table_info = self.table_info.desc.split('.').collect::<Vec<&str>>();
+        let (db, table) = self.get_database_and_table_name();
         let mut partition_names = hive_catalog
-            .get_partition_names(table_info[0].to_string(), table_info[1].to_string(), -1)
+            .get_partition_names(db.clone(), table.clone(), -1)
             .await?;
 
         let partition_num = partition_names.len();
@@ -427,11 +562,7 @@ impl HiveTable {
         );
 
         let partitions = hive_catalog
-            .get_partitions(
-                table_info[0].to_string(),
-                table_info[1].to_string(),
-                partition_names.clone(),
-            )
+            .get_partitions(db, table, partition_names.clone())
             .await?;
         let res = partitions
             .into_iter()
@@ -448,9 +579,7 @@ impl HiveTable {
         ctx: Arc<dyn TableContext>,
         push_downs: &Option<PushDownInfo>,
     ) -> Result<Vec<(String, Option<String>)>> {
-        let path = self.table_options.location.as_ref().ok_or_else(|| {
-            ErrorCode::TableInfoError(format!("{}, table location is empty", self.table_info.name))
-        })?;
+        let path = self.get_location()?;
 
         if let Some(partition_keys) = &self.table_options.partition_keys {
             if !partition_keys.is_empty() {
@@ -542,7 +671,7 @@ impl Table for HiveTable {
     }
 
     fn as_any(&self) -> &(dyn std::any::Any + 'static) {
-        todo!()
+        self
     }
 
     fn get_table_info(&self) -> &TableInfo {
@@ -571,6 +700,13 @@ impl Table for HiveTable {
         None
     }
 
+    fn partition_keys(&self) -> Vec<String> {
+        self.table_options
+            .partition_keys
+            .clone()
+            .unwrap_or_default()
+    }
+
     fn read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -581,21 +717,92 @@ impl Table for HiveTable {
         self.do_read2(ctx, plan, pipeline)
     }
 
-    fn commit_insertion(
+    fn append_data(
         &self,
         _ctx: Arc<dyn TableContext>,
-        _pipeline: &mut Pipeline,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        if self.table_options.file_format != HiveFileFormat::Parquet {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Cannot write to hive table {}, only parquet format is supported",
+                self.table_info.desc
+            )));
+        }
+
+        // the partition columns are the last columns of hive table
+        let schema = self.table_info.schema();
+        let partition_keys = self.partition_keys();
+        let num_data_columns = schema.num_fields() - partition_keys.len();
+        let fields = schema.fields();
+        for (field, key) in fields[num_data_columns..].iter().zip(&partition_keys) {
+            if field.name() != key {
+                return Err(ErrorCode::TableInfoError(format!(
+                    "{}, partition column {} is not found at the end of the columns",
+                    self.table_info.name, key
+                )));
+            }
+            if !matches!(
+                field.data_type().remove_nullable(),
+                TableDataType::String | TableDataType::Number(_)
+            ) {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Cannot write to hive table {}, type of partition column {} is {}",
+                    self.table_info.desc,
+                    key,
+                    field.data_type()
+                )));
+            }
+        }
+
+        let dir = convert_hdfs_path(self.get_location()?, true);
+        let data_schema = Arc::new(TableSchema::new(fields[..num_data_columns].to_vec()));
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input,
+                output,
+                HiveAppendTransform::create(
+                    self.dal.clone(),
+                    dir.clone(),
+                    data_schema.clone(),
+                    partition_keys.clone(),
+                ),
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
         _copied_files: Option<UpsertTableCopiedFileReq>,
         _update_stream_meta: Vec<UpdateStreamMetaReq>,
-        _overwrite: bool,
+        overwrite: bool,
         _prev_snapshot_id: Option<SnapshotId>,
         _deduplicated_label: Option<String>,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "commit_insertion operation for table {} is not implemented, table engine is {}",
-            self.name(),
-            self.get_table_info().meta.engine
-        )))
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Cannot overwrite hive table {}",
+                self.table_info.desc
+            )));
+        }
+
+        let (db, table) = self.get_database_and_table_name();
+        let location = self.get_location()?.to_string();
+        let partition_keys = self.partition_keys();
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            HiveCommitSink::try_create(
+                input,
+                ctx.clone(),
+                self.table_info.catalog().to_string(),
+                db.clone(),
+                table.clone(),
+                location.clone(),
+                partition_keys.clone(),
+            )
+        })
     }
 
     #[async_backtrace::framed]
@@ -795,6 +1002,44 @@ async fn do_list_files_from_dir(
     Ok((all_files, all_dirs))
 }
 
+/// List the partition directories under the table directory, the directories are returned
+/// as partition names like 'c_region=ASIA/c_nation=CHINA'.
+///
+/// The directories which don't match the partition keys are ignored.
+pub(crate) async fn list_partition_dirs(
+    operator: &Operator,
+    table_dir: &str,
+    partition_keys: &[String],
+) -> Result<Vec<String>> {
+    // partition names of the current level, end with '/'
+    let mut names = vec![String::new()];
+    for key in partition_keys {
+        let mut next_names = vec![];
+        for name in names {
+            let dir = format!("{}{}", table_dir, name);
+            let mut lister = operator.lister_with(&dir).metakey(Metakey::Mode).await?;
+            while let Some(de) = lister.try_next().await? {
+                if de.metadata().mode() != EntryMode::DIR || de.path() == dir {
+                    continue;
+                }
+
+                let dir_name = de.name().trim_end_matches('/');
+                if let Some((k, v)) = dir_name.split_once('=') {
+                    if unescape_partition_value(k) == *key && !v.is_empty() {
+                        next_names.push(format!("{}{}/", name, dir_name));
+                    }
+                }
+            }
+        }
+        names = next_names;
+    }
+
+    Ok(names
+        .into_iter()
+        .map(|name| name.trim_end_matches('/').to_string())
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -20,15 +20,50 @@ use databend_common_exception::Result;
 
 pub const PARTITION_KEYS: &str = "partition_keys";
 pub const LOCATION: &str = "location";
+pub const FILE_FORMAT: &str = "file_format";
+
+pub const PARQUET_INPUT_FORMAT: &str =
+    "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat";
+pub const ORC_INPUT_FORMAT: &str = "org.apache.hadoop.hive.ql.io.orc.OrcInputFormat";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HiveFileFormat {
+    #[default]
+    Parquet,
+    Orc,
+}
+
+impl HiveFileFormat {
+    // get the file format from the input format of hive storage descriptor
+    pub fn from_input_format(input_format: &str) -> Result<HiveFileFormat> {
+        match input_format {
+            PARQUET_INPUT_FORMAT => Ok(HiveFileFormat::Parquet),
+            ORC_INPUT_FORMAT => Ok(HiveFileFormat::Orc),
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "only support parquet and orc, {} not support",
+                input_format
+            ))),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            HiveFileFormat::Parquet => "parquet",
+            HiveFileFormat::Orc => "orc",
+        }
+    }
+}
 
 // represents hive table schema info
 //
 // partition_keys,  hive partition keys, such as:  "p_date", "p_hour"
 // location,  hive table location, such as: hdfs://namenode:8020/user/hive/warehouse/a.db/b.table/
+// file_format,  the format of data files, parquet or orc
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HiveTableOptions {
     pub partition_keys: Option<Vec<String>>,
     pub location: Option<String>,
+    pub file_format: HiveFileFormat,
 }
 
 impl From<HiveTableOptions> for BTreeMap<String, String> {
@@ -42,6 +77,10 @@ impl From<HiveTableOptions> for BTreeMap<String, String> {
         options
             .location
             .map(|v| map.insert(LOCATION.to_string(), v));
+        map.insert(
+            FILE_FORMAT.to_string(),
+            options.file_format.as_str().to_string(),
+        );
         map
     }
 }
@@ -61,9 +100,21 @@ impl TryFrom<&BTreeMap<String, String>> for HiveTableOptions {
             .get(LOCATION)
             .ok_or_else(|| ErrorCode::Internal("Hive engine table missing location key"))?
             .clone();
+        // tables created before orc is supported don't have this key
+        let file_format = match options.get(FILE_FORMAT).map(|v| v.as_str()) {
+            None | Some("parquet") => HiveFileFormat::Parquet,
+            Some("orc") => HiveFileFormat::Orc,
+            Some(other) => {
+                return Err(ErrorCode::Internal(format!(
+                    "Hive engine table has unknown file format {}",
+                    other
+                )));
+            }
+        };
         let options = HiveTableOptions {
             partition_keys,
             location: Some(location),
+            file_format,
         };
         Ok(options)
     }
@@ -73,6 +124,7 @@ impl TryFrom<&BTreeMap<String, String>> for HiveTableOptions {
 mod tests {
     use std::collections::BTreeMap;
 
+    use super::HiveFileFormat;
     use super::HiveTableOptions;
 
     fn do_test_hive_table_options(hive_table_options: HiveTableOptions) {
@@ -86,6 +138,7 @@ mod tests {
         let hive_table_options = HiveTableOptions {
             partition_keys: Some(vec!["a".to_string(), "b".to_string()]),
             location: Some("test".to_string()),
+            file_format: HiveFileFormat::Parquet,
         };
 
         do_test_hive_table_options(hive_table_options);
@@ -93,7 +146,15 @@ mod tests {
         let empty = HiveTableOptions {
             partition_keys: None,
             location: Some("test".to_string()),
+            file_format: HiveFileFormat::Parquet,
         };
         do_test_hive_table_options(empty);
+
+        let orc = HiveTableOptions {
+            partition_keys: Some(vec!["a".to_string()]),
+            location: Some("test".to_string()),
+            file_format: HiveFileFormat::Orc,
+        };
+        do_test_hive_table_options(orc);
     }
 }
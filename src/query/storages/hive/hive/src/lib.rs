@@ -17,12 +17,15 @@
 #![allow(clippy::diverging_sub_expression)]
 
 mod converters;
+mod hive_append;
 mod hive_block_filter;
 mod hive_blocks;
 mod hive_catalog;
 mod hive_database;
 mod hive_file_splitter;
 mod hive_meta_data_reader;
+mod hive_orc_block_reader;
+mod hive_orc_table_source;
 mod hive_parquet_block_reader;
 mod hive_partition;
 mod hive_partition_filler;
@@ -39,6 +42,10 @@ pub use hive_catalog::HiveCreator;
 pub use hive_file_splitter::HiveFileSplitter;
 pub use hive_meta_data_reader::MetaDataReader;
 pub use hive_parquet_block_reader::filter_hive_partition_from_partition_keys;
+pub use hive_partition::escape_partition_value;
+pub use hive_partition::make_partition_name;
+pub use hive_partition::parse_partition_name;
+pub use hive_partition::unescape_partition_value;
 pub use hive_partition::HivePartInfo;
 pub use hive_partition_filler::HivePartitionFiller;
 pub use hive_table::HiveFileInfo;
@@ -0,0 +1,363 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the partition management of `HiveCatalog`, against an in-process hive meta store
+//! served by the thrift server of `hive_metastore`, which keeps the partitions of one table.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CatalogId;
+use databend_common_meta_app::schema::CatalogInfo;
+use databend_common_meta_app::schema::CatalogMeta;
+use databend_common_meta_app::schema::CatalogNameIdent;
+use databend_common_meta_app::schema::CatalogOption;
+use databend_common_meta_app::schema::HiveCatalogOption;
+use databend_common_storages_hive::HiveCatalog;
+use faststr::FastStr;
+use hive_metastore::AlreadyExistsException;
+use hive_metastore::FieldSchema;
+use hive_metastore::NoSuchObjectException;
+use hive_metastore::Partition;
+use hive_metastore::StorageDescriptor;
+use hive_metastore::Table;
+use hive_metastore::ThriftHiveMetastoreAddPartitionResultSend;
+use hive_metastore::ThriftHiveMetastoreDropPartitionByNameResultSend;
+use hive_metastore::ThriftHiveMetastoreGetPartitionNamesResultSend;
+use hive_metastore::ThriftHiveMetastoreGetTableResultSend;
+use hive_metastore::ThriftHiveMetastoreRequestRecv;
+use hive_metastore::ThriftHiveMetastoreResponseSend;
+use opendal::services::Fs;
+use opendal::Operator;
+use volo::net::incoming::DefaultIncoming;
+use volo::Service;
+use volo_thrift::codec::default::DefaultMakeCodec;
+use volo_thrift::context::ServerContext;
+use volo_thrift::server::Server;
+use volo_thrift::ApplicationError;
+use volo_thrift::ApplicationErrorKind;
+
+const DB: &str = "db";
+const TABLE: &str = "t";
+const TABLE_LOCATION: &str = "hdfs://localhost:8020/warehouse/t";
+const PARTITION_KEYS: [&str; 2] = ["c_region", "c_nation"];
+
+/// Partition values to the location of the partition.
+type Partitions = Arc<Mutex<BTreeMap<Vec<String>, String>>>;
+
+fn partition_name(values: &[String]) -> String {
+    PARTITION_KEYS
+        .iter()
+        .zip(values)
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn hive_table() -> Table {
+    let partition_keys = PARTITION_KEYS
+        .iter()
+        .map(|k| FieldSchema {
+            name: Some(FastStr::new(*k)),
+            r#type: Some(FastStr::from_static_str("string")),
+            comment: None,
+        })
+        .collect();
+    Table {
+        table_name: Some(FastStr::from_static_str(TABLE)),
+        db_name: Some(FastStr::from_static_str(DB)),
+        sd: Some(StorageDescriptor {
+            location: Some(FastStr::from_static_str(TABLE_LOCATION)),
+            ..Default::default()
+        }),
+        partition_keys: Some(partition_keys),
+        table_type: Some(FastStr::from_static_str("EXTERNAL_TABLE")),
+        ..Default::default()
+    }
+}
+
+/// The mock hive meta store, only the calls made by the partition management are served.
+#[derive(Clone, Default)]
+struct MockMetastore {
+    partitions: Partitions,
+}
+
+impl MockMetastore {
+    fn add_partition(&self, partition: Partition) -> ThriftHiveMetastoreAddPartitionResultSend {
+        let values = partition
+            .values
+            .unwrap_or_default()
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        let location = partition
+            .sd
+            .and_then(|sd| sd.location)
+            .unwrap_or_default()
+            .to_string();
+
+        let mut partitions = self.partitions.lock().unwrap();
+        if partitions.contains_key(&values) {
+            let message = format!("partition {} already exists", partition_name(&values));
+            return ThriftHiveMetastoreAddPartitionResultSend::O2(AlreadyExistsException {
+                message: Some(message.into()),
+            });
+        }
+        partitions.insert(values.clone(), location);
+        ThriftHiveMetastoreAddPartitionResultSend::Ok(Partition {
+            values: Some(values.into_iter().map(FastStr::from).collect()),
+            ..Default::default()
+        })
+    }
+
+    fn drop_partition_by_name(
+        &self,
+        name: &str,
+    ) -> ThriftHiveMetastoreDropPartitionByNameResultSend {
+        let values = name
+            .split('/')
+            .map(|part| part.split_once('=').unwrap().1.to_string())
+            .collect::<Vec<_>>();
+
+        match self.partitions.lock().unwrap().remove(&values) {
+            Some(_) => ThriftHiveMetastoreDropPartitionByNameResultSend::Ok(true),
+            None => ThriftHiveMetastoreDropPartitionByNameResultSend::O1(NoSuchObjectException {
+                message: Some(format!("partition {name} not found").into()),
+            }),
+        }
+    }
+}
+
+impl Service<ServerContext, ThriftHiveMetastoreRequestRecv> for MockMetastore {
+    type Response = ThriftHiveMetastoreResponseSend;
+    type Error = ApplicationError;
+
+    async fn call(
+        &self,
+        _cx: &mut ServerContext,
+        req: ThriftHiveMetastoreRequestRecv,
+    ) -> std::result::Result<Self::Response, Self::Error> {
+        match req {
+            ThriftHiveMetastoreRequestRecv::GetTable(_) => {
+                Ok(ThriftHiveMetastoreResponseSend::GetTable(
+                    ThriftHiveMetastoreGetTableResultSend::Ok(hive_table()),
+                ))
+            }
+            ThriftHiveMetastoreRequestRecv::GetPartitionNames(_) => {
+                let names = self
+                    .partitions
+                    .lock()
+                    .unwrap()
+                    .keys()
+                    .map(|v| FastStr::from(partition_name(v)))
+                    .collect();
+                Ok(ThriftHiveMetastoreResponseSend::GetPartitionNames(
+                    ThriftHiveMetastoreGetPartitionNamesResultSend::Ok(names),
+                ))
+            }
+            ThriftHiveMetastoreRequestRecv::AddPartition(args) => Ok(
+                ThriftHiveMetastoreResponseSend::AddPartition(self.add_partition(args.new_part)),
+            ),
+            ThriftHiveMetastoreRequestRecv::DropPartitionByName(args) => {
+                Ok(ThriftHiveMetastoreResponseSend::DropPartitionByName(
+                    self.drop_partition_by_name(&args.part_name),
+                ))
+            }
+            other => Err(ApplicationError::new(
+                ApplicationErrorKind::UNKNOWN_METHOD,
+                format!("unknown method {other:?}"),
+            )),
+        }
+    }
+}
+
+/// Starts the mock hive meta store, returns its address and the partitions it keeps.
+async fn start_metastore() -> (String, Partitions) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let metastore = MockMetastore::default();
+    let partitions = metastore.partitions.clone();
+
+    // the catalog talks to hive meta store with the buffered transport
+    let server = Server::new(metastore).make_codec(DefaultMakeCodec::buffered());
+    tokio::spawn(server.run(DefaultIncoming::from(listener)));
+    (address, partitions)
+}
+
+fn create_catalog(address: &str) -> Result<HiveCatalog> {
+    let info = CatalogInfo {
+        id: CatalogId::default(),
+        name_ident: CatalogNameIdent {
+            tenant: "test".to_string(),
+            catalog_name: "hive_test".to_string(),
+        },
+        meta: CatalogMeta {
+            catalog_option: CatalogOption::Hive(HiveCatalogOption {
+                address: address.to_string(),
+                storage_params: None,
+            }),
+            created_on: Utc::now(),
+        },
+    };
+    HiveCatalog::try_create(info, None, address)
+}
+
+fn values(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_add_and_drop_partitions() -> Result<()> {
+    let (address, partitions) = start_metastore().await;
+    let catalog = create_catalog(&address)?;
+
+    let asia = values(&["ASIA", "CHINA"]);
+    let europe = values(&["EUROPE", "FRANCE"]);
+    let added = catalog
+        .add_partitions(
+            DB,
+            TABLE,
+            vec![
+                (asia.clone(), "hdfs://localhost:8020/asia".to_string()),
+                (europe.clone(), "hdfs://localhost:8020/europe".to_string()),
+            ],
+            false,
+        )
+        .await?;
+    assert_eq!(added, 2);
+    assert_eq!(
+        partitions.lock().unwrap().get(&asia).map(String::as_str),
+        Some("hdfs://localhost:8020/asia")
+    );
+
+    // existing partitions are skipped only if `if_not_exists` is true
+    let added = catalog
+        .add_partitions(
+            DB,
+            TABLE,
+            vec![(asia.clone(), "hdfs://localhost:8020/other".to_string())],
+            true,
+        )
+        .await?;
+    assert_eq!(added, 0);
+    let res = catalog
+        .add_partitions(
+            DB,
+            TABLE,
+            vec![(asia.clone(), "hdfs://localhost:8020/other".to_string())],
+            false,
+        )
+        .await;
+    assert!(res.is_err());
+    assert_eq!(
+        partitions.lock().unwrap().get(&asia).map(String::as_str),
+        Some("hdfs://localhost:8020/asia")
+    );
+
+    let mut names = catalog
+        .get_partition_names(DB.to_string(), TABLE.to_string(), -1)
+        .await?;
+    names.sort();
+    assert_eq!(names, vec![
+        "c_region=ASIA/c_nation=CHINA",
+        "c_region=EUROPE/c_nation=FRANCE"
+    ]);
+
+    catalog
+        .drop_partition(DB, TABLE, "c_region=ASIA/c_nation=CHINA", false)
+        .await?;
+    assert!(!partitions.lock().unwrap().contains_key(&asia));
+
+    // missing partitions are ignored only if `if_exists` is true
+    catalog
+        .drop_partition(DB, TABLE, "c_region=ASIA/c_nation=CHINA", true)
+        .await?;
+    let res = catalog
+        .drop_partition(DB, TABLE, "c_region=ASIA/c_nation=CHINA", false)
+        .await;
+    assert!(res.is_err());
+    assert!(partitions.lock().unwrap().contains_key(&europe));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_recover_partitions() -> Result<()> {
+    let (address, partitions) = start_metastore().await;
+    let catalog = create_catalog(&address)?;
+
+    let root = tempfile::tempdir().unwrap();
+    let table_dir = root.path().join("warehouse/t");
+    for dir in [
+        "c_region=ASIA/c_nation=CHINA",
+        "c_region=ASIA/c_nation=JAPAN",
+        "c_region=EUROPE/c_nation=FRANCE",
+        // directories not matching the partition keys are ignored
+        "c_region=ASIA/c_city=BEIJING",
+        "c_nation=CHINA",
+        "c_region=/c_nation=CHINA",
+        "_temporary",
+    ] {
+        std::fs::create_dir_all(table_dir.join(dir)).unwrap();
+    }
+    std::fs::write(table_dir.join("c_region=ASIA/data.parquet"), b"").unwrap();
+
+    let mut builder = Fs::default();
+    builder.root(root.path().to_str().unwrap());
+    let dal = Operator::new(builder)?.finish();
+
+    // the partition already in the meta store keeps its location
+    partitions.lock().unwrap().insert(
+        values(&["EUROPE", "FRANCE"]),
+        "hdfs://localhost:8020/europe".to_string(),
+    );
+
+    let keys = values(&PARTITION_KEYS);
+    let added = catalog
+        .recover_partitions(DB, TABLE, &dal, TABLE_LOCATION, &keys)
+        .await?;
+    assert_eq!(added, 2);
+
+    let partitions = partitions.lock().unwrap().clone();
+    assert_eq!(partitions.len(), 3);
+    assert_eq!(
+        partitions
+            .get(&values(&["ASIA", "CHINA"]))
+            .map(String::as_str),
+        Some("hdfs://localhost:8020/warehouse/t/c_region=ASIA/c_nation=CHINA")
+    );
+    assert_eq!(
+        partitions
+            .get(&values(&["ASIA", "JAPAN"]))
+            .map(String::as_str),
+        Some("hdfs://localhost:8020/warehouse/t/c_region=ASIA/c_nation=JAPAN")
+    );
+    assert_eq!(
+        partitions
+            .get(&values(&["EUROPE", "FRANCE"]))
+            .map(String::as_str),
+        Some("hdfs://localhost:8020/europe")
+    );
+
+    // nothing to recover the second time
+    let added = catalog
+        .recover_partitions(DB, TABLE, &dal, TABLE_LOCATION, &keys)
+        .await?;
+    assert_eq!(added, 0);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_storages_hive::escape_partition_value;
+use databend_common_storages_hive::make_partition_name;
+use databend_common_storages_hive::parse_partition_name;
+use databend_common_storages_hive::unescape_partition_value;
+
+#[test]
+fn test_escape_partition_value() {
+    let cases = vec![
+        ("2024-01-01", "2024-01-01"),
+        ("a b", "a b"),
+        ("a/b", "a%2Fb"),
+        ("a=b:c", "a%3Db%3Ac"),
+        ("100%", "100%25"),
+        ("\u{1}", "%01"),
+    ];
+
+    for (value, escaped) in cases {
+        assert_eq!(escape_partition_value(value), escaped);
+        assert_eq!(unescape_partition_value(escaped), value);
+    }
+
+    // invalid escapes are kept as they are
+    assert_eq!(unescape_partition_value("a%zzb%2"), "a%zzb%2");
+}
+
+#[test]
+fn test_partition_name() {
+    let keys = vec!["c_region".to_string(), "c_nation".to_string()];
+
+    let values = vec!["ASIA".to_string(), "CHINA".to_string()];
+    let name = make_partition_name(&keys, &values);
+    assert_eq!(name, "c_region=ASIA/c_nation=CHINA");
+    assert_eq!(parse_partition_name(&keys, &name), Some(values));
+
+    let values = vec!["A/B".to_string(), "x=y".to_string()];
+    let name = make_partition_name(&keys, &values);
+    assert_eq!(name, "c_region=A%2FB/c_nation=x%3Dy");
+    assert_eq!(parse_partition_name(&keys, &name), Some(values));
+
+    // keys in different order, missing keys and empty values don't match
+    assert_eq!(
+        parse_partition_name(&keys, "c_nation=CHINA/c_region=ASIA"),
+        None
+    );
+    assert_eq!(parse_partition_name(&keys, "c_region=ASIA"), None);
+    assert_eq!(
+        parse_partition_name(&keys, "c_region=/c_nation=CHINA"),
+        None
+    );
+    assert_eq!(parse_partition_name(&keys, "c_region/c_nation=CHINA"), None);
+}
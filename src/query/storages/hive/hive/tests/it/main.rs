@@ -12,4 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod hive_catalog;
 mod hive_file_splitter;
+mod hive_partition;
@@ -0,0 +1,348 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Appending data to iceberg tables.
+//!
+//! - `IcebergAppendTransform` * N: write the blocks to parquet data files, the rows are
+//!   split by the partition spec of the table.
+//! - `IcebergCommitSink` * 1: commit all the data files as a new snapshot of the table.
+//!
+//! The data files can't be sent across the nodes, so iceberg tables don't support distributed
+//! insert, both of the processors run on the node that commits the insertion.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use arrow_array::RecordBatch;
+use arrow_cast::cast_with_options;
+use arrow_cast::CastOptions;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_common_storage::DataOperator;
+use icelake::io::task_writer::TaskWriter;
+use icelake::transaction::Transaction;
+use icelake::types::DataFile;
+use log::info;
+use opendal::Operator;
+use parking_lot::Mutex;
+
+use crate::table::read_data_files;
+use crate::IcebergTable;
+
+/// Retry the commit if the table is changed by others since it's loaded.
+const MAX_COMMIT_RETRIES: usize = 10;
+
+/// The current version of the table written by the storage catalog of icelake.
+const VERSION_HINT_PATH: &str = "metadata/version-hint.text";
+
+/// Locks of the tables being committed in this process, keyed by the table location.
+///
+/// opendal can't create the next metadata version conditionally, so the commits of a table
+/// are serialized here to avoid overwriting each other.
+static COMMIT_LOCKS: LazyLock<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>> =
+    LazyLock::new(Default::default);
+
+/// Data files written by an `IcebergAppendTransform`.
+#[derive(Debug)]
+pub struct IcebergDataFiles {
+    pub files: Vec<DataFile>,
+}
+
+impl IcebergDataFiles {
+    pub fn create_block(files: Vec<DataFile>) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(IcebergDataFiles { files }))
+    }
+}
+
+impl Clone for IcebergDataFiles {
+    fn clone(&self) -> Self {
+        unreachable!("IcebergDataFiles should not be cloned")
+    }
+}
+
+impl serde::Serialize for IcebergDataFiles {
+    fn serialize<S>(&self, _: S) -> std::result::Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unreachable!("IcebergDataFiles should not be serialized")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IcebergDataFiles {
+    fn deserialize<D>(_: D) -> std::result::Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unreachable!("IcebergDataFiles should not be deserialized")
+    }
+}
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFiles {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("IcebergDataFiles should not be compared")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("IcebergDataFiles should not be cloned")
+    }
+}
+
+pub struct IcebergAppendTransform {
+    operator: DataOperator,
+    schema: DataSchemaRef,
+    /// created when the first block arrives, together with the arrow schema of the table
+    writer: Option<(TaskWriter, ArrowSchemaRef)>,
+}
+
+impl IcebergAppendTransform {
+    pub fn create(operator: DataOperator, schema: DataSchemaRef) -> Self {
+        Self {
+            operator,
+            schema,
+            writer: None,
+        }
+    }
+
+    async fn create_writer(&self) -> Result<(TaskWriter, ArrowSchemaRef)> {
+        let table = IcebergTable::load_iceberg_table(self.operator.clone()).await?;
+        let arrow_schema = IcebergTable::get_arrow_schema(&table)?;
+
+        let writer = table.task_writer().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot create iceberg table writer: {e:?}"))
+        })?;
+        Ok((writer, Arc::new(arrow_schema)))
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for IcebergAppendTransform {
+    const NAME: &'static str = "IcebergAppendTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        if self.writer.is_none() {
+            self.writer = Some(self.create_writer().await?);
+        }
+        let (writer, arrow_schema) = self.writer.as_mut().unwrap();
+
+        let batch = to_iceberg_record_batch(data, &self.schema, arrow_schema)?;
+        writer.write(&batch).await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot write iceberg data files: {e:?}"))
+        })?;
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let Some((writer, _)) = self.writer.take() else {
+            return Ok(None);
+        };
+
+        let files = writer.close().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot write iceberg data files: {e:?}"))
+        })?;
+        Ok(Some(IcebergDataFiles::create_block(files)))
+    }
+}
+
+/// Cast the columns to the arrow types of the iceberg table, such as `UInt64` to `Int64`.
+fn to_iceberg_record_batch(
+    block: DataBlock,
+    schema: &DataSchemaRef,
+    arrow_schema: &ArrowSchemaRef,
+) -> Result<RecordBatch> {
+    let batch = block.to_record_batch(schema)?;
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(arrow_schema.fields())
+        .map(|(array, field)| {
+            if array.data_type() == field.data_type() {
+                Ok(array.clone())
+            } else {
+                cast_with_options(array, field.data_type(), &options).map_err(ErrorCode::from)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(RecordBatch::try_new(arrow_schema.clone(), columns)?)
+}
+
+pub struct IcebergCommitSink {
+    operator: DataOperator,
+    files: Vec<DataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        operator: DataOperator,
+    ) -> Result<ProcessorPtr> {
+        let sinker = AsyncSinker::create(input, ctx, IcebergCommitSink {
+            operator,
+            files: vec![],
+        });
+        Ok(ProcessorPtr::create(sinker))
+    }
+
+    /// Check that all the data files are in the current snapshot of the latest version,
+    /// a concurrent writer may overwrite the same metadata version.
+    async fn is_committed(&self, op: &Operator) -> Result<bool> {
+        let table = IcebergTable::load_iceberg_table(self.operator.clone()).await?;
+        let meta = table.current_table_metadata();
+        let Some(snapshot) = meta.current_snapshot_id.and_then(|id| {
+            meta.snapshots
+                .iter()
+                .flatten()
+                .find(|snapshot| snapshot.snapshot_id == id)
+        }) else {
+            return Ok(false);
+        };
+        let committed = read_data_files(op, &table, snapshot)
+            .await?
+            .into_iter()
+            .map(|file| file.file_path)
+            .collect::<HashSet<_>>();
+        Ok(self
+            .files
+            .iter()
+            .all(|file| committed.contains(&file.file_path)))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    /// Append the data files to the latest version of the table.
+    ///
+    /// A new metadata version `v{n+1}.metadata.json` is created on each commit. The storage
+    /// can't create it conditionally, so iceberg tables are single-writer: the commits of a
+    /// table are serialized within the process, and the table must not be written by other
+    /// processes or engines at the same time.
+    ///
+    /// Concurrent writers are detected on a best-effort basis: the commit is skipped if the
+    /// next version already exists, and the data files are checked to be in the latest
+    /// version after it's written. If the table has changed since it's loaded, the commit is
+    /// retried on top of the new version.
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.files.is_empty() {
+            return Ok(());
+        }
+
+        let lock = COMMIT_LOCKS
+            .lock()
+            .entry(self.operator.params().to_string())
+            .or_default()
+            .clone();
+        let _guard = lock.lock().await;
+
+        let op = self.operator.operator();
+        let mut retries = 0;
+        loop {
+            let mut table = IcebergTable::load_iceberg_table(self.operator.clone()).await?;
+            let base_snapshot_id = table.current_table_metadata().current_snapshot_id;
+
+            let conflict = match read_version_hint(&op).await? {
+                // The storage catalog writes the metadata before the version hint, the next
+                // version is already taken by another writer.
+                Some(version) if op.is_exist(&metadata_path(version + 1)).await? => {
+                    format!("metadata version {} already exists", version + 1)
+                }
+                _ => {
+                    let mut txn = Transaction::new(&mut table);
+                    txn.append_data_file(self.files.clone());
+                    match txn.commit().await {
+                        Ok(_) if self.is_committed(&op).await? => return Ok(()),
+                        Ok(_) => "the committed metadata is overwritten".to_string(),
+                        Err(err) => {
+                            let latest =
+                                IcebergTable::load_iceberg_table(self.operator.clone()).await?;
+                            let latest_snapshot_id =
+                                latest.current_table_metadata().current_snapshot_id;
+                            if latest_snapshot_id == base_snapshot_id {
+                                return Err(ErrorCode::StorageOther(format!(
+                                    "Cannot commit iceberg table: {err:?}"
+                                )));
+                            }
+                            format!("{err:?}")
+                        }
+                    }
+                }
+            };
+
+            if retries >= MAX_COMMIT_RETRIES {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Cannot commit iceberg table after {retries} retries: {conflict}"
+                )));
+            }
+            retries += 1;
+            info!(
+                "iceberg table changed since snapshot {:?} while committing: {}, retry {}",
+                base_snapshot_id, conflict, retries
+            );
+        }
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(IcebergDataFiles::downcast_from)
+        {
+            self.files.extend(meta.files);
+        }
+        Ok(false)
+    }
+}
+
+fn metadata_path(version: i64) -> String {
+    format!("metadata/v{version}.metadata.json")
+}
+
+async fn read_version_hint(op: &Operator) -> Result<Option<i64>> {
+    if !op.is_exist(VERSION_HINT_PATH).await? {
+        return Ok(None);
+    }
+    let bs = op.read(VERSION_HINT_PATH).await?;
+    let version = String::from_utf8_lossy(&bs)
+        .trim()
+        .parse::<i64>()
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Invalid iceberg version hint: {e}")))?;
+    Ok(Some(version))
+}
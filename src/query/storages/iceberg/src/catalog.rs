@@ -35,6 +35,7 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReply;
@@ -91,6 +92,8 @@ use futures::TryStreamExt;
 use opendal::Metakey;
 
 use crate::database::IcebergDatabase;
+use crate::metadata::create_iceberg_table;
+use crate::rest::IcebergRestClient;
 use crate::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
@@ -108,8 +111,11 @@ impl CatalogCreator for IcebergCreator {
         };
 
         let data_operator = DataOperator::try_new(&opt.storage_params)?;
-        let catalog: Arc<dyn Catalog> =
-            Arc::new(IcebergCatalog::try_create(info.clone(), data_operator)?);
+        let mut catalog = IcebergCatalog::try_create(info.clone(), data_operator)?;
+        if let Some(rest) = &opt.rest {
+            catalog = catalog.with_rest_client(IcebergRestClient::try_create(rest)?);
+        }
+        let catalog: Arc<dyn Catalog> = Arc::new(catalog);
 
         Ok(catalog)
     }
@@ -121,6 +127,9 @@ impl CatalogCreator for IcebergCreator {
 /// - Instances of `Database` are created from reading subdirectories of
 ///    Iceberg table
 /// - Table metadata are saved in external Iceberg storage
+///
+/// If the REST catalog is set, databases and tables are listed through it instead,
+/// and the table data is read from the table location in the underlying storage.
 #[derive(Clone, Debug)]
 pub struct IcebergCatalog {
     /// info of this iceberg table.
@@ -128,6 +137,9 @@ pub struct IcebergCatalog {
 
     /// underlying storage access operator
     operator: DataOperator,
+
+    /// client of the iceberg REST catalog
+    rest: Option<Arc<IcebergRestClient>>,
 }
 
 impl IcebergCatalog {
@@ -147,7 +159,32 @@ impl IcebergCatalog {
     /// a `default` database will be generated directly
     #[minitrace::trace]
     pub fn try_create(info: CatalogInfo, operator: DataOperator) -> Result<Self> {
-        Ok(Self { info, operator })
+        Ok(Self {
+            info,
+            operator,
+            rest: None,
+        })
+    }
+
+    /// list namespaces and tables through the REST catalog
+    pub fn with_rest_client(mut self, client: IcebergRestClient) -> Self {
+        self.rest = Some(Arc::new(client));
+        self
+    }
+
+    /// list databases from the namespaces of REST catalog
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    pub async fn list_database_from_rest(
+        &self,
+        client: &IcebergRestClient,
+    ) -> Result<Vec<Arc<dyn Database>>> {
+        let mut dbs = vec![];
+        for db_name in client.list_namespaces().await? {
+            let db: Arc<dyn Database> = self.get_database("", &db_name).await?;
+            dbs.push(db);
+        }
+        Ok(dbs)
     }
 
     /// list read databases
@@ -187,6 +224,21 @@ impl Catalog for IcebergCatalog {
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        if let Some(client) = &self.rest {
+            if !client.namespace_exists(db_name).await? {
+                return Err(ErrorCode::UnknownDatabase(format!(
+                    "Database {db_name} does not exist"
+                )));
+            }
+
+            return Ok(Arc::new(IcebergDatabase::create_from_rest(
+                &self.name(),
+                db_name,
+                client.clone(),
+                self.operator.clone(),
+            )));
+        }
+
         let rel_path = format!("{db_name}/");
 
         let operator = self.operator.operator();
@@ -212,27 +264,38 @@ impl Catalog for IcebergCatalog {
 
     #[async_backtrace::framed]
     async fn list_databases(&self, _tenant: &str) -> Result<Vec<Arc<dyn Database>>> {
-        self.list_database_from_read().await
+        match &self.rest {
+            Some(client) => self.list_database_from_rest(client).await,
+            None => self.list_database_from_read().await,
+        }
     }
 
     #[async_backtrace::framed]
     async fn create_database(&self, _req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot create database in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn drop_database(&self, _req: DropDatabaseReq) -> Result<DropDatabaseReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop database in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn undrop_database(&self, _req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot undrop database in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn rename_database(&self, _req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot rename database in ICEBERG catalog",
+        ))
     }
 
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
@@ -252,7 +315,9 @@ impl Catalog for IcebergCatalog {
         &self,
         _table_id: MetaId,
     ) -> Result<(TableIdent, Arc<TableMeta>)> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot get table by id in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -293,27 +358,71 @@ impl Catalog for IcebergCatalog {
         _tenant: &str,
         _db_name: &str,
     ) -> Result<Vec<Arc<dyn Table>>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot list tables history in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
-    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
-        unimplemented!()
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        if self.rest.is_some() {
+            return Err(ErrorCode::Unimplemented(
+                "Cannot create table in ICEBERG REST catalog",
+            ));
+        }
+
+        // make sure the database exists
+        self.get_database(req.tenant(), req.db_name()).await?;
+
+        let path = format!("{}/{}/", req.db_name(), req.table_name());
+        if self.operator.operator().is_exist(&path).await? {
+            return match req.create_option {
+                CreateOption::CreateIfNotExists(true) => Ok(CreateTableReply {
+                    table_id: 0,
+                    new_table: false,
+                    spec_vec: None,
+                }),
+                CreateOption::CreateIfNotExists(false) => Err(ErrorCode::TableAlreadyExists(
+                    format!("Table {} already exists", req.name_ident),
+                )),
+                CreateOption::CreateOrReplace => Err(ErrorCode::Unimplemented(
+                    "Cannot replace table in ICEBERG catalog",
+                )),
+            };
+        }
+
+        let table_sp = self
+            .operator
+            .params()
+            .map_root(|root| format!("{root}{path}"));
+        create_iceberg_table(&table_sp, &req.table_meta.schema).await?;
+
+        Ok(CreateTableReply {
+            table_id: 0,
+            new_table: true,
+            spec_vec: None,
+        })
     }
 
     #[async_backtrace::framed]
     async fn drop_table_by_id(&self, _req: DropTableByIdReq) -> Result<DropTableReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop table by id in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn undrop_table(&self, _req: UndropTableReq) -> Result<UndropTableReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot undrop table in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn rename_table(&self, _req: RenameTableReq) -> Result<RenameTableReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot rename table in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -335,7 +444,9 @@ impl Catalog for IcebergCatalog {
         _db_name: &str,
         _req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot upsert table option in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -344,7 +455,9 @@ impl Catalog for IcebergCatalog {
         _table_info: &TableInfo,
         _req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot update table meta in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -352,12 +465,16 @@ impl Catalog for IcebergCatalog {
         &self,
         _req: SetTableColumnMaskPolicyReq,
     ) -> Result<SetTableColumnMaskPolicyReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot set table column mask policy in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn count_tables(&self, _req: CountTablesReq) -> Result<CountTablesReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot count tables in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -367,7 +484,9 @@ impl Catalog for IcebergCatalog {
         _db_name: &str,
         _req: GetTableCopiedFileReq,
     ) -> Result<GetTableCopiedFileReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot get table copied file info in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -376,64 +495,88 @@ impl Catalog for IcebergCatalog {
         _table_info: &TableInfo,
         _req: TruncateTableReq,
     ) -> Result<TruncateTableReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot truncate table in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn list_lock_revisions(&self, _req: ListLockRevReq) -> Result<Vec<(u64, LockMeta)>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot list lock revisions in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn create_lock_revision(&self, _req: CreateLockRevReq) -> Result<CreateLockRevReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot create lock revision in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn extend_lock_revision(&self, _req: ExtendLockRevReq) -> Result<()> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot extend lock revision in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn delete_lock_revision(&self, _req: DeleteLockRevReq) -> Result<()> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot delete lock revision in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn list_locks(&self, _req: ListLocksReq) -> Result<Vec<LockInfo>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot list locks in ICEBERG catalog",
+        ))
     }
 
     // Table index
 
     #[async_backtrace::framed]
     async fn create_index(&self, _req: CreateIndexReq) -> Result<CreateIndexReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot create index in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn drop_index(&self, _req: DropIndexReq) -> Result<DropIndexReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop index in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn get_index(&self, _req: GetIndexReq) -> Result<GetIndexReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot get index in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn update_index(&self, _req: UpdateIndexReq) -> Result<UpdateIndexReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot update index in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn list_indexes(&self, _req: ListIndexesReq) -> Result<Vec<(u64, String, IndexMeta)>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot list indexes in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn list_index_ids_by_table_id(&self, _req: ListIndexesByIdReq) -> Result<Vec<u64>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot list index ids by table id in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -441,7 +584,9 @@ impl Catalog for IcebergCatalog {
         &self,
         _req: ListIndexesByIdReq,
     ) -> Result<Vec<(u64, String, IndexMeta)>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot list indexes by table id in ICEBERG catalog",
+        ))
     }
 
     // Virtual column
@@ -451,7 +596,9 @@ impl Catalog for IcebergCatalog {
         &self,
         _req: CreateVirtualColumnReq,
     ) -> Result<CreateVirtualColumnReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot create virtual column in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -459,7 +606,9 @@ impl Catalog for IcebergCatalog {
         &self,
         _req: UpdateVirtualColumnReq,
     ) -> Result<UpdateVirtualColumnReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot update virtual column in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -467,7 +616,9 @@ impl Catalog for IcebergCatalog {
         &self,
         _req: DropVirtualColumnReq,
     ) -> Result<DropVirtualColumnReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop virtual column in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -475,7 +626,9 @@ impl Catalog for IcebergCatalog {
         &self,
         _req: ListVirtualColumnsReq,
     ) -> Result<Vec<VirtualColumnMeta>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot list virtual columns in ICEBERG catalog",
+        ))
     }
 
     /// Table function
@@ -486,7 +639,9 @@ impl Catalog for IcebergCatalog {
         _func_name: &str,
         _tbl_args: TableArgs,
     ) -> Result<Arc<dyn TableFunction>> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "Cannot get table function in ICEBERG catalog",
+        ))
     }
 
     // List all table functions' names.
@@ -500,6 +655,6 @@ impl Catalog for IcebergCatalog {
 
     // Get table engines
     fn get_table_engines(&self) -> Vec<StorageDescription> {
-        unimplemented!()
+        vec![IcebergTable::description()]
     }
 }
@@ -25,26 +25,58 @@ use databend_common_meta_app::schema::DatabaseIdent;
 use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use futures::StreamExt;
 use opendal::EntryMode;
 use opendal::Metakey;
 
+use crate::rest::location_to_storage_params;
+use crate::rest::IcebergRestClient;
 use crate::table::IcebergTable;
 
 #[derive(Clone, Debug)]
 pub struct IcebergDatabase {
     /// catalog this database belongs to
     ctl_name: String,
-    /// operator pointing to the directory holding iceberg tables
-    db_root: DataOperator,
+    /// where the tables of this database are discovered
+    source: DatabaseSource,
     /// database information
     info: DatabaseInfo,
 }
 
+#[derive(Clone, Debug)]
+enum DatabaseSource {
+    /// operator pointing to the directory holding iceberg tables
+    Storage(DataOperator),
+    /// namespace in the REST catalog, the table data is read from the
+    /// warehouse storage of the catalog
+    Rest {
+        client: Arc<IcebergRestClient>,
+        warehouse: DataOperator,
+    },
+}
+
 impl IcebergDatabase {
     /// create a new database, but from reading
     pub fn create(ctl_name: &str, db_name: &str, db_root: DataOperator) -> Self {
+        Self::create_with_source(ctl_name, db_name, DatabaseSource::Storage(db_root))
+    }
+
+    /// create a new database of the namespace in REST catalog
+    pub fn create_from_rest(
+        ctl_name: &str,
+        db_name: &str,
+        client: Arc<IcebergRestClient>,
+        warehouse: DataOperator,
+    ) -> Self {
+        Self::create_with_source(ctl_name, db_name, DatabaseSource::Rest {
+            client,
+            warehouse,
+        })
+    }
+
+    fn create_with_source(ctl_name: &str, db_name: &str, source: DatabaseSource) -> Self {
         let info = DatabaseInfo {
             ident: DatabaseIdent { db_id: 0, seq: 0 },
             name_ident: DatabaseNameIdent {
@@ -60,26 +92,19 @@ impl IcebergDatabase {
         };
         Self {
             ctl_name: ctl_name.to_string(),
-            db_root,
+            source,
             info,
         }
     }
-}
-
-#[async_trait]
-impl Database for IcebergDatabase {
-    fn name(&self) -> &str {
-        &self.info.name_ident.db_name
-    }
-
-    fn get_db_info(&self) -> &DatabaseInfo {
-        &self.info
-    }
 
     #[async_backtrace::framed]
-    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+    async fn get_table_from_storage(
+        &self,
+        db_root: &DataOperator,
+        table_name: &str,
+    ) -> Result<Arc<dyn Table>> {
         let path = format!("{table_name}/");
-        let op = self.db_root.operator();
+        let op = db_root.operator();
         // check existence first
         if !op.stat(&path).await?.mode().is_dir() {
             return Err(ErrorCode::UnknownTable(format!(
@@ -87,35 +112,105 @@ impl Database for IcebergDatabase {
             )));
         }
 
-        let table_sp = self.db_root.params().map_root(|r| format!("{r}{path}"));
+        let table_sp = db_root.params().map_root(|r| format!("{r}{path}"));
+        let tbl = self.load_table(table_name, table_sp, None).await?;
+        Ok(Arc::new(tbl))
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_from_rest(
+        &self,
+        client: &IcebergRestClient,
+        warehouse: &DataOperator,
+        table_name: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let location = client.load_table(self.name(), table_name).await?;
+
+        // the metadata file given by the catalog is read, relative to the table location.
+        let table_location = location.location.trim_end_matches('/');
+        let metadata_path = location
+            .metadata_location
+            .map(|path| match path.strip_prefix(table_location) {
+                Some(path) if path.starts_with('/') => Ok(path.trim_start_matches('/').to_string()),
+                _ => Err(ErrorCode::ReadTableDataError(format!(
+                    "metadata {path} of iceberg table {table_name} is not under the table location {table_location}"
+                ))),
+            })
+            .transpose()?;
+
+        // the table is read from its location with the credentials of the warehouse storage.
+        let table_sp = location_to_storage_params(&warehouse.params(), table_location);
+        let tbl = self.load_table(table_name, table_sp, metadata_path).await?;
+        Ok(Arc::new(tbl.with_read_only()))
+    }
+
+    #[async_backtrace::framed]
+    async fn load_table(
+        &self,
+        table_name: &str,
+        table_sp: StorageParams,
+        metadata_path: Option<String>,
+    ) -> Result<IcebergTable> {
         let table_sp = table_sp.auto_detect().await?;
         let tbl_root = DataOperator::try_create(&table_sp).await?;
 
-        let tbl = IcebergTable::try_create_from_iceberg_catalog(
+        IcebergTable::try_create_from_iceberg_catalog(
             &self.ctl_name,
             &self.info.name_ident.db_name,
             table_name,
             tbl_root,
+            metadata_path,
         )
-        .await?;
-        let tbl = Arc::new(tbl) as Arc<dyn Table>;
+        .await
+    }
+}
 
-        Ok(tbl)
+#[async_trait]
+impl Database for IcebergDatabase {
+    fn name(&self) -> &str {
+        &self.info.name_ident.db_name
+    }
+
+    fn get_db_info(&self) -> &DatabaseInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        match &self.source {
+            DatabaseSource::Storage(db_root) => {
+                self.get_table_from_storage(db_root, table_name).await
+            }
+            DatabaseSource::Rest { client, warehouse } => {
+                self.get_table_from_rest(client, warehouse, table_name)
+                    .await
+            }
+        }
     }
 
     #[async_backtrace::framed]
     async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
         let mut tables = vec![];
-        let op = self.db_root.operator();
-        let mut lister = op.lister_with("/").metakey(Metakey::Mode).await?;
-        while let Some(entry) = lister.next().await.transpose()? {
-            let meta = entry.metadata();
-            if meta.mode() != EntryMode::DIR {
-                continue;
+        match &self.source {
+            DatabaseSource::Storage(db_root) => {
+                let op = db_root.operator();
+                let mut lister = op.lister_with("/").metakey(Metakey::Mode).await?;
+                while let Some(entry) = lister.next().await.transpose()? {
+                    let meta = entry.metadata();
+                    if meta.mode() != EntryMode::DIR {
+                        continue;
+                    }
+                    let tbl_name = entry.name().trim_end_matches('/');
+                    let table = self.get_table(tbl_name).await?;
+                    tables.push(table);
+                }
+            }
+            DatabaseSource::Rest { client, .. } => {
+                for tbl_name in client.list_tables(self.name()).await? {
+                    let table = self.get_table(&tbl_name).await?;
+                    tables.push(table);
+                }
             }
-            let tbl_name = entry.name().trim_end_matches('/');
-            let table = self.get_table(tbl_name).await?;
-            tables.push(table);
         }
         Ok(tables)
     }
@@ -86,14 +86,62 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## REST Catalogs
+//!
+//! Namespaces and tables could also be listed through an [Iceberg REST catalog](https://iceberg.apache.org/concepts/catalog/),
+//! each namespace is seen as a database:
+//! ```sql
+//! CREATE CATALOG icb_ctl TYPE=ICEBERG CONNECTION=(
+//! TYPE='rest'
+//! URI='http://127.0.0.1:8181'
+//! WAREHOUSE='wh' -- optional
+//! TOKEN='<bearer token>' -- optional
+//! CREDENTIAL='<client_id>:<client_secret>' -- optional, exchanged for the token
+//! URL='s3://warehouse/'
+//! ... -- credentials and other options
+//! )
+//! ```
+//!
+//! The `URL` is the storage holding the tables, a table located at `s3://bucket/db0/tbl0`
+//! in the REST catalog is read from `db0/tbl0` of `bucket` with the credentials of it,
+//! starting from the metadata file given by the catalog.
+//!
+//! ## Writing Tables
+//!
+//! Tables of storage catalogs could be created and appended to:
+//! ```sql
+//! CREATE TABLE icb_ctl.db0.tbl2 AS SELECT * FROM t;
+//! INSERT INTO icb_ctl.db0.tbl2 SELECT * FROM t;
+//! ```
+//!
+//! Rows are written to parquet data files split by the partition spec of the table,
+//! and each insertion is committed as a new snapshot. Tables of REST catalogs are read only.
+//!
+//! The storage can't create the metadata files conditionally, so a table must have a single
+//! writer: insertions into a table are serialized within a query node, but they are not
+//! coordinated with other nodes or engines writing the same table.
+//!
+//! ## Time Travel
+//!
+//! Snapshots of a table are listed by `iceberg_snapshots`, and could be queried with:
+//! ```sql
+//! SELECT * FROM iceberg_snapshots('icb_ctl', 'db0', 'tbl0');
+//! SELECT * FROM icb_ctl.db0.tbl0 AT (SNAPSHOT => '3051729675574597004');
+//! SELECT * FROM icb_ctl.db0.tbl0 AT (TIMESTAMP => '2024-01-01 00:00:00'::TIMESTAMP);
+//! ```
 
 #![feature(lazy_cell)]
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod append;
 mod catalog;
 mod database;
+mod metadata;
 mod partition;
+mod rest;
+mod snapshots_table_func;
 mod stats;
 mod table;
 mod table_source;
@@ -101,4 +149,6 @@ mod table_source;
 pub use catalog::IcebergCatalog;
 pub use catalog::IcebergCreator;
 pub use catalog::ICEBERG_CATALOG;
+pub use rest::IcebergRestClient;
+pub use snapshots_table_func::IcebergSnapshotsTable;
 pub use table::IcebergTable;
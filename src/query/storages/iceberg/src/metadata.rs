@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Create new iceberg tables by writing the first version of the table metadata.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::DataOperator;
+use serde_json::json;
+use serde_json::Value;
+
+/// Write `metadata/v1.metadata.json` and the version hint of a new unpartitioned table
+/// to the table directory `sp` points to.
+#[async_backtrace::framed]
+pub async fn create_iceberg_table(sp: &StorageParams, schema: &TableSchema) -> Result<()> {
+    let location = table_location(sp)?;
+
+    let mut next_id = 0;
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| iceberg_field(f.name(), f.data_type(), &mut next_id))
+        .collect::<Result<Vec<_>>>()?;
+
+    let metadata = json!({
+        "format-version": 2,
+        "table-uuid": uuid::Uuid::new_v4().to_string(),
+        "location": location,
+        "last-sequence-number": 0,
+        "last-updated-ms": chrono::Utc::now().timestamp_millis(),
+        "last-column-id": next_id,
+        "current-schema-id": 0,
+        "schemas": [{
+            "type": "struct",
+            "schema-id": 0,
+            "fields": fields,
+        }],
+        "default-spec-id": 0,
+        "partition-specs": [{
+            "spec-id": 0,
+            "fields": [],
+        }],
+        // partition field ids start from 1000
+        "last-partition-id": 999,
+        "default-sort-order-id": 0,
+        "sort-orders": [{
+            "order-id": 0,
+            "fields": [],
+        }],
+        "properties": {},
+        "snapshots": [],
+        "snapshot-log": [],
+        "metadata-log": [],
+        "refs": {},
+    });
+
+    let op = DataOperator::try_new(sp)?.operator();
+    let data = serde_json::to_vec_pretty(&metadata)?;
+    op.write("metadata/v1.metadata.json", data).await?;
+    op.write("metadata/version-hint.text", "1").await?;
+    Ok(())
+}
+
+/// The absolute location of the table directory, such as `s3://bucket/path/to/table`.
+fn table_location(sp: &StorageParams) -> Result<String> {
+    let location = match sp {
+        StorageParams::Fs(c) => c.root.clone(),
+        StorageParams::S3(c) => format!("s3://{}{}", c.bucket, c.root),
+        StorageParams::Gcs(c) => format!("gs://{}{}", c.bucket, c.root),
+        StorageParams::Oss(c) => format!("oss://{}{}", c.bucket, c.root),
+        StorageParams::Azblob(c) => format!("azblob://{}{}", c.container, c.root),
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Cannot create iceberg table on storage {sp}"
+            )));
+        }
+    };
+    Ok(location.trim_end_matches('/').to_string())
+}
+
+fn iceberg_field(name: &str, ty: &TableDataType, next_id: &mut i32) -> Result<Value> {
+    *next_id += 1;
+    let id = *next_id;
+    Ok(json!({
+        "id": id,
+        "name": name,
+        "required": !ty.is_nullable(),
+        "type": iceberg_type(&ty.remove_nullable(), next_id)?,
+    }))
+}
+
+/// Convert the data type to the type of iceberg table, the ids of nested fields are
+/// assigned from `next_id`.
+fn iceberg_type(ty: &TableDataType, next_id: &mut i32) -> Result<Value> {
+    let ty = match ty {
+        TableDataType::Boolean => json!("boolean"),
+        TableDataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16 => json!("int"),
+            // UInt64 values larger than `i64::MAX` are rejected when writing.
+            NumberDataType::Int64 | NumberDataType::UInt32 | NumberDataType::UInt64 => {
+                json!("long")
+            }
+            NumberDataType::Float32 => json!("float"),
+            NumberDataType::Float64 => json!("double"),
+        },
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            json!(format!("decimal({}, {})", size.precision, size.scale))
+        }
+        TableDataType::String => json!("string"),
+        TableDataType::Binary => json!("binary"),
+        TableDataType::Date => json!("date"),
+        TableDataType::Timestamp => json!("timestamptz"),
+        TableDataType::Array(inner) => {
+            *next_id += 1;
+            let element_id = *next_id;
+            json!({
+                "type": "list",
+                "element-id": element_id,
+                "element-required": !inner.is_nullable(),
+                "element": iceberg_type(&inner.remove_nullable(), next_id)?,
+            })
+        }
+        TableDataType::Map(inner) => match inner.as_ref() {
+            TableDataType::Tuple { fields_type, .. } if fields_type.len() == 2 => {
+                *next_id += 2;
+                let key_id = *next_id - 1;
+                let value_id = *next_id;
+                json!({
+                    "type": "map",
+                    "key-id": key_id,
+                    "key": iceberg_type(&fields_type[0].remove_nullable(), next_id)?,
+                    "value-id": value_id,
+                    "value-required": !fields_type[1].is_nullable(),
+                    "value": iceberg_type(&fields_type[1].remove_nullable(), next_id)?,
+                })
+            }
+            _ => unreachable!("map inner type must be a tuple of key and value"),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            let fields = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| iceberg_field(name, ty, next_id))
+                .collect::<Result<Vec<_>>>()?;
+            json!({
+                "type": "struct",
+                "fields": fields,
+            })
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Data type {ty} is not supported by iceberg table"
+            )));
+        }
+    };
+    Ok(ty)
+}
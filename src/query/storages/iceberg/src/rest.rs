@@ -0,0 +1,355 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client of the [Iceberg REST catalog](https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml)
+//!
+//! Only the read only part of the protocol is implemented, which is enough to
+//! list the namespaces and tables and to find out where the table metadata lives.
+//!
+//! Requests are authorized by the bearer token, which is either given directly
+//! or exchanged from the client credential through the OAuth2 endpoint.
+
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
+use databend_common_meta_app::storage::StorageParams;
+use reqwest::StatusCode;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+/// Separator of the namespace levels in the REST path, see `NamespaceSeparator`.
+const NAMESPACE_SEPARATOR: &str = "\u{1f}";
+
+#[derive(Debug)]
+pub struct IcebergRestClient {
+    base: Url,
+    warehouse: Option<String>,
+    client: reqwest::Client,
+    /// credential exchanged for the bearer token, `client_id:client_secret` or only the secret
+    credential: Option<String>,
+    /// bearer token of the requests, given by the option or exchanged from the credential
+    token: OnceCell<Option<String>>,
+    /// prefix of the catalog endpoints, returned by `/v1/config`
+    prefix: OnceCell<Option<String>>,
+}
+
+/// Where a table of the REST catalog lives.
+pub struct RestTableLocation {
+    /// Base location of the table, like `s3://bucket/warehouse/db/tbl`.
+    pub location: String,
+    /// Location of the current metadata file of the table.
+    pub metadata_location: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CatalogConfig {
+    #[serde(default)]
+    defaults: HashMap<String, String>,
+    #[serde(default)]
+    overrides: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct ListNamespacesResponse {
+    namespaces: Vec<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct TableIdentifier {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ListTablesResponse {
+    identifiers: Vec<TableIdentifier>,
+    #[serde(rename = "next-page-token")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TableMetadata {
+    location: String,
+}
+
+#[derive(Deserialize)]
+struct LoadTableResult {
+    #[serde(rename = "metadata-location")]
+    metadata_location: Option<String>,
+    metadata: TableMetadata,
+}
+
+#[derive(Deserialize)]
+struct OAuthTokenResponse {
+    access_token: String,
+}
+
+impl IcebergRestClient {
+    pub fn try_create(opt: &IcebergRestCatalogOption) -> Result<Self> {
+        let base = Url::parse(opt.uri.trim_end_matches('/')).map_err(|e| {
+            ErrorCode::InvalidConfig(format!(
+                "iceberg rest catalog uri {} is not valid: {e}",
+                opt.uri
+            ))
+        })?;
+
+        Ok(Self {
+            base,
+            warehouse: opt.warehouse.clone(),
+            client: reqwest::Client::new(),
+            credential: opt.credential.clone(),
+            token: OnceCell::new_with(opt.token.clone().map(Some)),
+            prefix: OnceCell::new(),
+        })
+    }
+
+    /// List the namespaces of the catalog, the levels of a nested namespace are joined with `.`.
+    #[async_backtrace::framed]
+    pub async fn list_namespaces(&self) -> Result<Vec<String>> {
+        let url = self.endpoint(&["namespaces"]).await?;
+        let resp: ListNamespacesResponse = self.get(url).await?.ok_or_else(|| {
+            ErrorCode::Internal("iceberg rest catalog does not support listing namespaces")
+        })?;
+
+        Ok(resp
+            .namespaces
+            .into_iter()
+            .map(|levels| levels.join("."))
+            .collect())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn namespace_exists(&self, namespace: &str) -> Result<bool> {
+        let url = self
+            .endpoint(&["namespaces", &encode_namespace(namespace)])
+            .await?;
+        Ok(self.get::<serde_json::Value>(url).await?.is_some())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list_tables(&self, namespace: &str) -> Result<Vec<String>> {
+        let mut tables = vec![];
+        let mut page_token = None;
+        loop {
+            let mut url = self
+                .endpoint(&["namespaces", &encode_namespace(namespace), "tables"])
+                .await?;
+            if let Some(token) = &page_token {
+                url.query_pairs_mut().append_pair("pageToken", token);
+            }
+
+            let resp: ListTablesResponse = self.get(url).await?.ok_or_else(|| {
+                ErrorCode::UnknownDatabase(format!("Database {namespace} does not exist"))
+            })?;
+            tables.extend(resp.identifiers.into_iter().map(|ident| ident.name));
+
+            match resp.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(tables),
+            }
+        }
+    }
+
+    /// Get the location of the table and its current metadata file.
+    #[async_backtrace::framed]
+    pub async fn load_table(&self, namespace: &str, table: &str) -> Result<RestTableLocation> {
+        let url = self
+            .endpoint(&["namespaces", &encode_namespace(namespace), "tables", table])
+            .await?;
+        let resp: LoadTableResult = self.get(url).await?.ok_or_else(|| {
+            ErrorCode::UnknownTable(format!(
+                "table {namespace}.{table} does not exist or is not a valid table"
+            ))
+        })?;
+
+        Ok(RestTableLocation {
+            location: resp.metadata.location,
+            metadata_location: resp.metadata_location,
+        })
+    }
+
+    async fn endpoint(&self, segments: &[&str]) -> Result<Url> {
+        let prefix = self
+            .prefix
+            .get_or_try_init(|| async { self.load_prefix().await })
+            .await?;
+
+        let mut url = self.base.clone();
+        {
+            let mut path = url.path_segments_mut().map_err(|_| {
+                ErrorCode::InvalidConfig(format!(
+                    "iceberg rest catalog uri {} is not valid",
+                    self.base
+                ))
+            })?;
+            path.pop_if_empty().push("v1");
+            if let Some(prefix) = prefix {
+                path.extend(prefix.split('/').filter(|v| !v.is_empty()));
+            }
+            path.extend(segments);
+        }
+        Ok(url)
+    }
+
+    /// Url of the path under the catalog uri, without the prefix.
+    fn url(&self, segments: &[&str]) -> Result<Url> {
+        let mut url = self.base.clone();
+        url.path_segments_mut()
+            .map_err(|_| {
+                ErrorCode::InvalidConfig(format!(
+                    "iceberg rest catalog uri {} is not valid",
+                    self.base
+                ))
+            })?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    async fn load_prefix(&self) -> Result<Option<String>> {
+        let mut url = self.url(&["v1", "config"])?;
+        if let Some(warehouse) = &self.warehouse {
+            url.query_pairs_mut().append_pair("warehouse", warehouse);
+        }
+
+        let mut config: CatalogConfig = self.get(url).await?.ok_or_else(|| {
+            ErrorCode::InvalidConfig(format!("{} is not a valid iceberg rest catalog", self.base))
+        })?;
+
+        // Overrides take precedence over the defaults.
+        Ok(config
+            .overrides
+            .remove("prefix")
+            .or_else(|| config.defaults.remove("prefix")))
+    }
+
+    /// Exchange the credential for a bearer token with the OAuth2 client credentials flow.
+    async fn load_token(&self) -> Result<Option<String>> {
+        let Some(credential) = &self.credential else {
+            return Ok(None);
+        };
+
+        let mut form = vec![("grant_type", "client_credentials"), ("scope", "catalog")];
+        match credential.split_once(':') {
+            Some((client_id, client_secret)) => {
+                form.push(("client_id", client_id));
+                form.push(("client_secret", client_secret));
+            }
+            None => form.push(("client_secret", credential)),
+        }
+
+        let url = self.url(&["v1", "oauth", "tokens"])?;
+        let resp = self
+            .client
+            .post(url.clone())
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| from_reqwest_error(&url, e))?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "iceberg rest catalog rejected the credential with {status}: {body}"
+            )));
+        }
+
+        let token: OAuthTokenResponse =
+            resp.json().await.map_err(|e| from_reqwest_error(&url, e))?;
+        Ok(Some(token.access_token))
+    }
+
+    /// Send a get request, `None` is returned if the resource is not found.
+    async fn get<T: DeserializeOwned>(&self, url: Url) -> Result<Option<T>> {
+        let token = self
+            .token
+            .get_or_try_init(|| async { self.load_token().await })
+            .await?;
+
+        let mut req = self.client.get(url.clone());
+        if let Some(token) = token {
+            req = req.bearer_auth(token);
+        }
+        let resp = req.send().await.map_err(|e| from_reqwest_error(&url, e))?;
+
+        match resp.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => {
+                let body = resp
+                    .bytes()
+                    .await
+                    .map_err(|e| from_reqwest_error(&url, e))?;
+                let v = serde_json::from_slice(&body).map_err(|e| {
+                    ErrorCode::Internal(format!(
+                        "invalid response of iceberg rest catalog request {url}: {e}"
+                    ))
+                })?;
+                Ok(Some(v))
+            }
+            status => {
+                let body = resp.text().await.unwrap_or_default();
+                Err(ErrorCode::Internal(format!(
+                    "iceberg rest catalog request {url} failed with {status}: {body}"
+                )))
+            }
+        }
+    }
+}
+
+fn encode_namespace(namespace: &str) -> String {
+    namespace
+        .split('.')
+        .collect::<Vec<_>>()
+        .join(NAMESPACE_SEPARATOR)
+}
+
+fn from_reqwest_error(url: &Url, error: reqwest::Error) -> ErrorCode {
+    ErrorCode::Internal(format!(
+        "iceberg rest catalog request {url} failed: {error}, please check the catalog uri"
+    ))
+}
+
+/// Storage params of the table location, which is accessed with the credentials of the
+/// warehouse storage, `s3://bucket/warehouse/db/tbl` is read from `/warehouse/db/tbl/`
+/// of `bucket`.
+pub fn location_to_storage_params(warehouse: &StorageParams, location: &str) -> StorageParams {
+    let (bucket, path) = match location.split_once("://") {
+        Some((_, rest)) => match rest.find('/') {
+            Some(idx) => rest.split_at(idx),
+            None => (rest, "/"),
+        },
+        None => ("", location),
+    };
+    let root = format!("{}/", path.trim_end_matches('/'));
+
+    let mut sp = warehouse.clone().map_root(|_| root.clone());
+    if !bucket.is_empty() {
+        match &mut sp {
+            StorageParams::S3(v) => v.bucket = bucket.to_string(),
+            StorageParams::Gcs(v) => v.bucket = bucket.to_string(),
+            StorageParams::Obs(v) => v.bucket = bucket.to_string(),
+            StorageParams::Oss(v) => v.bucket = bucket.to_string(),
+            StorageParams::Cos(v) => v.bucket = bucket.to_string(),
+            // `abfss://container@account.dfs.core.windows.net/path`
+            StorageParams::Azblob(v) => {
+                v.container = bucket.split('@').next().unwrap_or(bucket).to_string()
+            }
+            _ => {}
+        }
+    }
+    sp
+}
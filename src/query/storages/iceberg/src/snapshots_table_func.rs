@@ -0,0 +1,276 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+
+use crate::IcebergTable;
+
+const ICEBERG_SNAPSHOTS: &str = "iceberg_snapshots";
+
+/// `iceberg_snapshots([<catalog>,] <database>, <table>)` lists the snapshots of an iceberg table,
+/// the snapshot ids could be used to query the table with `AT (SNAPSHOT => '<snapshot_id>')`.
+pub struct IcebergSnapshotsTable {
+    table_info: TableInfo,
+    arg_catalog_name: Option<String>,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl IcebergSnapshotsTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(ICEBERG_SNAPSHOTS, None)?;
+        let args = args.iter().map(string_value).collect::<Result<Vec<_>>>()?;
+        let (arg_catalog_name, arg_database_name, arg_table_name) = match args.as_slice() {
+            [db, tbl] => (None, db.clone(), tbl.clone()),
+            [ctl, db, tbl] => (Some(ctl.clone()), db.clone(), tbl.clone()),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "expecting [<catalog>,] <database> and <table_name> (as string literals), but got {:?}",
+                    args
+                )));
+            }
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: schema(),
+                engine: ICEBERG_SNAPSHOTS.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(IcebergSnapshotsTable {
+            table_info,
+            arg_catalog_name,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for IcebergSnapshotsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        let mut args = vec![];
+        if let Some(catalog) = &self.arg_catalog_name {
+            args.push(Scalar::String(catalog.clone()));
+        }
+        args.push(Scalar::String(self.arg_database_name.clone()));
+        args.push(Scalar::String(self.arg_table_name.clone()));
+        Some(TableArgs::new_positioned(args))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        let catalog_name = self
+            .arg_catalog_name
+            .clone()
+            .unwrap_or_else(|| ctx.get_current_catalog());
+        pipeline.add_source(
+            |output| {
+                IcebergSnapshotsSource::create(
+                    ctx.clone(),
+                    output,
+                    catalog_name.clone(),
+                    self.arg_database_name.clone(),
+                    self.arg_table_name.clone(),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for IcebergSnapshotsTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct IcebergSnapshotsSource {
+    ctx: Arc<dyn TableContext>,
+    finish: bool,
+    catalog_name: String,
+    database_name: String,
+    table_name: String,
+}
+
+impl IcebergSnapshotsSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        catalog_name: String,
+        database_name: String,
+        table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergSnapshotsSource {
+            ctx,
+            finish: false,
+            catalog_name,
+            database_name,
+            table_name,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergSnapshotsSource {
+    const NAME: &'static str = ICEBERG_SNAPSHOTS;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tenant_id = self.ctx.get_tenant();
+        let tbl = self
+            .ctx
+            .get_catalog(&self.catalog_name)
+            .await?
+            .get_table(tenant_id.as_str(), &self.database_name, &self.table_name)
+            .await?;
+
+        let tbl = IcebergTable::try_from_table(tbl.as_ref())?;
+        let (mut snapshots, current_snapshot_id) = tbl.snapshots().await?;
+        // latest snapshot first, same as `fuse_snapshot`
+        snapshots.sort_by(|a, b| b.timestamp_ms.cmp(&a.timestamp_ms));
+
+        let len = snapshots.len();
+        let mut snapshot_ids = Vec::with_capacity(len);
+        let mut parent_snapshot_ids = Vec::with_capacity(len);
+        let mut sequence_numbers = Vec::with_capacity(len);
+        let mut operations = Vec::with_capacity(len);
+        let mut manifest_lists = Vec::with_capacity(len);
+        let mut timestamps = Vec::with_capacity(len);
+        let mut is_current = Vec::with_capacity(len);
+        for s in snapshots {
+            snapshot_ids.push(s.snapshot_id);
+            parent_snapshot_ids.push(s.parent_snapshot_id);
+            sequence_numbers.push(s.sequence_number);
+            operations.push(s.summary.get("operation").cloned());
+            manifest_lists.push(s.manifest_list);
+            timestamps.push(s.timestamp_ms * 1000);
+            is_current.push(Some(s.snapshot_id) == current_snapshot_id);
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int64Type::from_data(snapshot_ids),
+            Int64Type::from_opt_data(parent_snapshot_ids),
+            Int64Type::from_data(sequence_numbers),
+            StringType::from_opt_data(operations),
+            StringType::from_data(manifest_lists),
+            TimestampType::from_data(timestamps),
+            BooleanType::from_data(is_current),
+        ])))
+    }
+}
+
+fn string_value(value: &Scalar) -> Result<String> {
+    match value {
+        Scalar::String(val) => Ok(val.clone()),
+        _ => Err(ErrorCode::BadArguments("invalid string.")),
+    }
+}
+
+fn schema() -> Arc<TableSchema> {
+    TableSchemaRefExt::create(vec![
+        TableField::new("snapshot_id", TableDataType::Number(NumberDataType::Int64)),
+        TableField::new(
+            "parent_snapshot_id",
+            TableDataType::Number(NumberDataType::Int64).wrap_nullable(),
+        ),
+        TableField::new(
+            "sequence_number",
+            TableDataType::Number(NumberDataType::Int64),
+        ),
+        TableField::new("operation", TableDataType::String.wrap_nullable()),
+        TableField::new("manifest_list", TableDataType::String),
+        TableField::new("timestamp", TableDataType::Timestamp),
+        TableField::new("is_current", TableDataType::Boolean),
+    ])
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -28,6 +29,8 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -39,8 +42,12 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
 use databend_common_storages_parquet::ParquetFilesPart;
@@ -48,22 +55,35 @@ use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use icelake::catalog::Catalog;
 use opendal::Operator;
 use tokio::sync::OnceCell;
 
+use crate::append::IcebergAppendTransform;
+use crate::append::IcebergCommitSink;
 use crate::partition::IcebergPartInfo;
 use crate::stats::get_stats_of_data_file;
 use crate::table_source::IcebergTableSource;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
 
+/// Table option of the snapshot the table is navigated to by time travel,
+/// the current snapshot is read if it's absent.
+const OPT_KEY_SNAPSHOT_ID: &str = "snapshot_id";
+
+/// Table option of the metadata file to load the table from, relative to the table root,
+/// the current metadata is found by `version-hint.text` if it's absent.
+const OPT_KEY_METADATA_PATH: &str = "metadata_path";
+
 /// accessor wrapper as a table
 ///
 /// TODO: we should use icelake Table instead.
 pub struct IcebergTable {
     info: TableInfo,
     table: OnceCell<icelake::Table>,
+    /// tables of REST catalogs are committed through the catalog, which is not supported yet
+    read_only: bool,
 }
 
 impl IcebergTable {
@@ -73,6 +93,7 @@ impl IcebergTable {
         Ok(Box::new(Self {
             info,
             table: OnceCell::new(),
+            read_only: false,
         }))
     }
 
@@ -94,6 +115,15 @@ impl IcebergTable {
     }
 
     pub async fn load_iceberg_table(dop: DataOperator) -> Result<icelake::Table> {
+        Self::load_iceberg_table_with_metadata(dop, None).await
+    }
+
+    /// Load the table from the given metadata file, such as the one returned by REST catalogs,
+    /// the tables there may have no `version-hint.text` to find the current metadata.
+    pub async fn load_iceberg_table_with_metadata(
+        dop: DataOperator,
+        metadata_path: Option<&str>,
+    ) -> Result<icelake::Table> {
         // FIXME: we should implement catalog for icelake.
         let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
             "databend",
@@ -101,27 +131,60 @@ impl IcebergTable {
         ));
 
         let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
-        icelake_catalog.load_table(&table_id).await.map_err(|err| {
+        let table = match metadata_path {
+            Some(path) => {
+                let bytes = dop.operator().read(path).await?;
+                let metadata = icelake::types::parse_table_metadata(&bytes).map_err(|err| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "Iceberg table metadata {path} is invalid: {err:?}"
+                    ))
+                })?;
+                icelake::Table::builder_from_catalog(icelake_catalog, metadata, table_id).build()
+            }
+            None => icelake_catalog.load_table(&table_id).await,
+        };
+        table.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Iceberg catalog load failed: {err:?}"))
         })
     }
 
-    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+    /// Build arrow schema from iceberg metadata.
+    pub fn get_arrow_schema(table: &icelake::Table) -> Result<ArrowSchema> {
         let meta = table.current_table_metadata();
+        let schema = meta.schemas.last().ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
+        })?;
+        Self::to_arrow_schema(schema)
+    }
+
+    fn to_arrow_schema(schema: &icelake::types::Schema) -> Result<ArrowSchema> {
+        schema.clone().try_into().map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+        })
+    }
+
+    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+        let arrow_schema = Self::get_arrow_schema(table)?;
+        Self::to_table_schema(arrow_schema)
+    }
 
-        // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta
+    /// The schema of the table when the snapshot is committed.
+    fn get_schema_of_snapshot(
+        table: &icelake::Table,
+        snapshot: &icelake::types::Snapshot,
+    ) -> Result<TableSchema> {
+        let meta = table.current_table_metadata();
+        let schema = meta
             .schemas
-            .last()
-            .ok_or_else(|| {
-                ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
-            })?
-            .clone()
-            .try_into()
-            .map_err(|e| {
-                ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-            })?;
+            .iter()
+            .find(|s| Some(s.schema_id as i64) == snapshot.schema_id);
+        match schema {
+            Some(schema) => Self::to_table_schema(Self::to_arrow_schema(schema)?),
+            None => Self::to_table_schema(Self::get_arrow_schema(table)?),
+        }
+    }
 
+    fn to_table_schema(arrow_schema: ArrowSchema) -> Result<TableSchema> {
         // Build arrow2 schema from arrow schema.
         let fields: Vec<Arrow2Field> = arrow_schema
             .fields()
@@ -140,10 +203,17 @@ impl IcebergTable {
         database: &str,
         table_name: &str,
         dop: DataOperator,
+        metadata_path: Option<String>,
     ) -> Result<IcebergTable> {
-        let table = Self::load_iceberg_table(dop.clone()).await?;
+        let table =
+            Self::load_iceberg_table_with_metadata(dop.clone(), metadata_path.as_deref()).await?;
         let table_schema = Self::get_schema(&table).await?;
 
+        let mut options = BTreeMap::new();
+        if let Some(path) = metadata_path {
+            options.insert(OPT_KEY_METADATA_PATH.to_string(), path);
+        }
+
         // construct table info
         let info = TableInfo {
             ident: TableIdent::new(0, 0),
@@ -155,6 +225,7 @@ impl IcebergTable {
                 engine: "iceberg".to_string(),
                 created_on: Utc::now(),
                 storage_params: Some(dop.params()),
+                options,
                 ..Default::default()
             },
             ..Default::default()
@@ -163,26 +234,62 @@ impl IcebergTable {
         Ok(Self {
             info,
             table: OnceCell::new_with(Some(table)),
+            read_only: false,
+        })
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&IcebergTable> {
+        tbl.as_any().downcast_ref::<IcebergTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine ICEBERG, but got {}",
+                tbl.engine()
+            ))
         })
     }
 
+    /// All the snapshots of the table and the id of the current one.
+    #[async_backtrace::framed]
+    pub async fn snapshots(&self) -> Result<(Vec<icelake::types::Snapshot>, Option<i64>)> {
+        let meta = self.table().await?.current_table_metadata();
+        Ok((
+            meta.snapshots.clone().unwrap_or_default(),
+            meta.current_snapshot_id,
+        ))
+    }
+
+    pub(crate) fn with_read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    fn snapshot_id(&self) -> Result<Option<i64>> {
+        self.info
+            .meta
+            .options
+            .get(OPT_KEY_SNAPSHOT_ID)
+            .map(|id| parse_snapshot_id(id))
+            .transpose()
+    }
+
+    /// All the data files of the snapshot, same as `current_data_files` of the current snapshot.
+    #[async_backtrace::framed]
+    async fn snapshot_data_files(
+        &self,
+        table: &icelake::Table,
+        snapshot_id: i64,
+    ) -> Result<Vec<icelake::types::DataFile>> {
+        let snapshot = find_snapshot(table, &NavigationPoint::SnapshotID(snapshot_id.to_string()))?;
+        let op = DataOperator::try_new(self.get_storage_params()?)?.operator();
+        read_data_files(&op, table, snapshot).await
+    }
+
     async fn table(&self) -> Result<&icelake::Table> {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
                 let op = DataOperator::try_new(sp)?;
-                // FIXME: we should implement catalog for icelake.
-                let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
-                    "databend",
-                    OperatorCreatorWrapper(op),
-                ));
-
-                let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
-                let table = icelake_catalog.load_table(&table_id).await.map_err(|err| {
-                    ErrorCode::ReadTableDataError(format!("Iceberg catalog load failed: {err:?}"))
-                })?;
-
-                Ok(table)
+                let metadata_path = self.info.meta.options.get(OPT_KEY_METADATA_PATH);
+                Self::load_iceberg_table_with_metadata(op, metadata_path.map(|v| v.as_str())).await
             })
             .await
     }
@@ -258,9 +365,12 @@ impl IcebergTable {
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
 
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let data_files = match self.snapshot_id()? {
+            Some(snapshot_id) => self.snapshot_data_files(table, snapshot_id).await?,
+            None => table.current_data_files().await.map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
+            })?,
+        };
 
         let filter = push_downs.as_ref().and_then(|extra| {
             extra
@@ -330,6 +440,11 @@ impl Table for IcebergTable {
         false
     }
 
+    // The data files written by icelake can't be sent across the nodes.
+    fn support_distributed_insert(&self) -> bool {
+        false
+    }
+
     fn get_table_info(&self) -> &TableInfo {
         &self.info
     }
@@ -359,6 +474,78 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        if self.read_only {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Cannot write to table {} of iceberg rest catalog",
+                self.info.desc
+            )));
+        }
+        if self.snapshot_id()?.is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Cannot write to historical snapshot of iceberg table {}",
+                self.info.desc
+            )));
+        }
+
+        let operator = DataOperator::try_new(self.get_storage_params()?)?;
+        let schema = Arc::new(DataSchema::from(self.schema()));
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input,
+                output,
+                IcebergAppendTransform::create(operator.clone(), schema.clone()),
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Cannot overwrite iceberg table {}",
+                self.info.desc
+            )));
+        }
+
+        let operator = DataOperator::try_new(self.get_storage_params()?)?;
+        pipeline.try_resize(1)?;
+        pipeline
+            .add_sink(|input| IcebergCommitSink::try_create(input, ctx.clone(), operator.clone()))
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let table = self.table().await?;
+        let snapshot = find_snapshot(table, instant)?;
+
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(Self::get_schema_of_snapshot(table, snapshot)?);
+        info.meta.options.insert(
+            OPT_KEY_SNAPSHOT_ID.to_string(),
+            snapshot.snapshot_id.to_string(),
+        );
+
+        Ok(Arc::new(IcebergTable {
+            info,
+            table: OnceCell::new(),
+            read_only: self.read_only,
+        }))
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -372,6 +559,80 @@ impl Table for IcebergTable {
     }
 }
 
+fn parse_snapshot_id(id: &str) -> Result<i64> {
+    id.parse::<i64>().map_err(|_| {
+        ErrorCode::BadArguments(format!(
+            "Invalid iceberg snapshot id '{id}', expecting a 64-bit integer"
+        ))
+    })
+}
+
+/// Find the snapshot of the given id, or the latest one committed at or before the time point.
+fn find_snapshot<'a>(
+    table: &'a icelake::Table,
+    instant: &NavigationPoint,
+) -> Result<&'a icelake::types::Snapshot> {
+    let snapshots = table
+        .current_table_metadata()
+        .snapshots
+        .as_deref()
+        .unwrap_or_default();
+
+    let snapshot = match instant {
+        NavigationPoint::SnapshotID(id) => {
+            let id = parse_snapshot_id(id)?;
+            snapshots.iter().find(|s| s.snapshot_id == id)
+        }
+        NavigationPoint::TimePoint(point) => {
+            let ts = point.timestamp_millis();
+            snapshots
+                .iter()
+                .filter(|s| s.timestamp_ms <= ts)
+                .max_by_key(|s| s.timestamp_ms)
+        }
+    };
+
+    snapshot.ok_or_else(|| {
+        ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
+    })
+}
+
+/// All the data files of the snapshot, the deleted entries of the manifests are skipped.
+pub(crate) async fn read_data_files(
+    op: &Operator,
+    table: &icelake::Table,
+    snapshot: &icelake::types::Snapshot,
+) -> Result<Vec<icelake::types::DataFile>> {
+    let rel_path = |path: &str| {
+        table.rel_path(path).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Invalid iceberg file path {path}: {e:?}"))
+        })
+    };
+
+    let bs = op.read(&rel_path(&snapshot.manifest_list)?).await?;
+    let manifest_list = icelake::types::parse_manifest_list(&bs)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot parse manifest list: {e:?}")))?;
+
+    let mut data_files = vec![];
+    for entry in manifest_list.entries {
+        if !matches!(entry.content, icelake::types::ManifestContentType::Data) {
+            continue;
+        }
+        let bs = op.read(&rel_path(&entry.manifest_path)?).await?;
+        let manifest = icelake::types::parse_manifest_file(&bs).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse manifest file: {e:?}"))
+        })?;
+        data_files.extend(
+            manifest
+                .entries
+                .into_iter()
+                .filter(|e| !matches!(e.status, icelake::types::ManifestStatus::Deleted))
+                .map(|e| e.data_file),
+        );
+    }
+    Ok(data_files)
+}
+
 struct OperatorCreatorWrapper(DataOperator);
 
 impl icelake::catalog::OperatorCreator for OperatorCreatorWrapper {
@@ -184,11 +184,20 @@ impl StreamTable {
         &self.table_database
     }
 
+    /// Collect the blocks changed since the offset of the stream, returns the removed
+    /// blocks and the added blocks.
+    ///
+    /// Only the segments that are not shared by the snapshot at the offset and the latest
+    /// snapshot are read, the blocks which are only moved to another segment are not changes.
     async fn collect_incremental_blocks(
         &self,
         ctx: Arc<dyn TableContext>,
         fuse_table: &FuseTable,
     ) -> Result<(Vec<Arc<BlockMeta>>, Vec<Arc<BlockMeta>>)> {
+        if fuse_table.snapshot_loc().await? == self.snapshot_location {
+            return Ok((vec![], vec![]));
+        }
+
         let operator = fuse_table.get_operator();
         let latest_segments = if let Some(snapshot) = fuse_table.read_table_snapshot().await? {
             HashSet::from_iter(snapshot.segments.clone())
@@ -238,9 +247,7 @@ impl StreamTable {
             for segment in segments {
                 let segment = segment?;
                 segment.blocks.into_iter().for_each(|block| {
-                    if base_blocks.contains_key(&block.location) {
-                        base_blocks.remove(&block.location);
-                    } else {
+                    if base_blocks.remove(&block.location).is_none() {
                         add_blocks.push(block);
                     }
                 });
@@ -309,6 +316,8 @@ impl StreamTable {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            fuse_table.ngram_args()?,
+            fuse_table.inverted_indexes(),
         )?;
 
         let block_metas = pruner.stream_pruning(blocks).await?;
@@ -346,7 +355,14 @@ impl StreamTable {
     #[minitrace::trace]
     pub async fn check_stream_status(&self, ctx: Arc<dyn TableContext>) -> Result<StreamStatus> {
         let base_table = self.source_table(ctx).await?;
-        let status = if base_table.get_table_info().ident.seq == self.table_version {
+        // The table version of a stream created at a historical snapshot is only a lower
+        // bound of the versions after it, so the snapshot locations are compared as well.
+        let status = if base_table.get_table_info().ident.seq == self.table_version
+            || FuseTable::try_from_table(base_table.as_ref())?
+                .snapshot_loc()
+                .await?
+                == self.snapshot_location
+        {
             StreamStatus::NoData
         } else {
             StreamStatus::MayHaveData
@@ -52,6 +52,21 @@ fn encode_arguments(udf_definition: &UDFDefinition) -> jsonb::Value {
             "return_type": &x.return_type.to_string(),
         }))
             .into(),
+        UDFDefinition::UDAFServer(x) => (&json!({
+            "arg_types": &x.arg_types.clone().into_iter().map(|dt| dt.to_string()).collect::<Vec<String>>(),
+            "return_type": &x.return_type.to_string(),
+        }))
+            .into(),
+        UDFDefinition::UDFScript(x) => (&json!({
+            "arg_types": &x.arg_types.clone().into_iter().map(|dt| dt.to_string()).collect::<Vec<String>>(),
+            "return_type": &x.return_type.to_string(),
+        }))
+            .into(),
+        UDFDefinition::UDTF(x) => (&json!({
+            "parameters": &x.arg_types.iter().map(|(name, dt)| format!("{name} {dt}")).collect::<Vec<String>>(),
+            "return_types": &x.return_types.iter().map(|(name, dt)| format!("{name} {dt}")).collect::<Vec<String>>(),
+        }))
+            .into(),
     }
 }
 
@@ -91,13 +106,18 @@ impl AsyncSystemTable for UserFunctionsTable {
             .map(|x| x.as_str())
             .collect();
 
-        let is_aggregate: Vec<Option<bool>> = (0..names.len()).map(|_| None).collect();
+        let is_aggregate: Vec<Option<bool>> = udfs
+            .iter()
+            .map(|udf| Some(udf.definition.is_aggregate()))
+            .collect();
 
         let languages: Vec<&str> = (0..names.len())
             .map(|i| {
                 udfs.get(i).map_or("", |udf| match &udf.definition {
-                    UDFDefinition::LambdaUDF(_) => "SQL",
+                    UDFDefinition::LambdaUDF(_) | UDFDefinition::UDTF(_) => "SQL",
                     UDFDefinition::UDFServer(x) => &x.language,
+                    UDFDefinition::UDFScript(x) => &x.language,
+                    UDFDefinition::UDAFServer(x) => &x.language,
                 })
             })
             .collect();
@@ -28,6 +28,8 @@ use databend_common_management::NetworkPolicyApi;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyApi;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PipeApi;
+use databend_common_management::PipeMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
@@ -162,6 +164,10 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<impl PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_password_policy_api_client(
         &self,
         tenant: &str,
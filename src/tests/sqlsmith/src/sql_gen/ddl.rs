@@ -266,6 +266,18 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             lit: Literal::String("POINT(0, 0)".to_string()),
         },
+        TypeName::Interval => Expr::Literal {
+            span: None,
+            lit: Literal::String("0 days".to_string()),
+        },
+        TypeName::Uuid => Expr::Literal {
+            span: None,
+            lit: Literal::String("00000000-0000-0000-0000-000000000000".to_string()),
+        },
+        TypeName::Inet => Expr::Literal {
+            span: None,
+            lit: Literal::String("::".to_string()),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             lit: Literal::Null,
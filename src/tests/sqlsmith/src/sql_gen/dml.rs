@@ -82,6 +82,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             catalog: None,
             database: None,
             table: table_name,
+            partition: None,
             // TODO
             columns: vec![],
             source,
@@ -482,6 +483,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 catalog: None,
                 database: None,
                 table: table_name,
+                partition: None,
                 columns,
                 source,
                 overwrite: false,
@@ -687,6 +687,9 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
         DataType::Variant => TypeName::Variant,
         DataType::Binary => TypeName::Binary,
         DataType::Geometry => TypeName::Geometry,
+        DataType::Interval => TypeName::Interval,
+        DataType::Uuid => TypeName::Uuid,
+        DataType::Inet => TypeName::Inet,
         DataType::Nullable(box inner_ty) => {
             TypeName::Nullable(Box::new(convert_to_type_name(inner_ty)))
         }
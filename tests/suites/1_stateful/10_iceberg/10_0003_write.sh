@@ -0,0 +1,56 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP CATALOG IF EXISTS iceberg_write_ctl" | $BENDSQL_CLIENT_CONNECT
+
+## Create iceberg catalog
+cat <<EOF | $BENDSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_write_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='s3://testbucket/iceberg_ctl/'
+    access_key_id ='minioadmin'
+    secret_access_key ='minioadmin'
+    ENDPOINT_URL='${STORAGE_S3_ENDPOINT_URL}'
+);
+EOF
+
+echo "CREATE TABLE iceberg_write_ctl.iceberg_db.iceberg_ctas AS SELECT id, data FROM iceberg_write_ctl.iceberg_db.iceberg_tbl WHERE id > 3;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT id, data FROM iceberg_write_ctl.iceberg_db.iceberg_ctas ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT INTO iceberg_write_ctl.iceberg_db.iceberg_ctas VALUES (100, 'x'), (101, 'y');" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_write_ctl.iceberg_db.iceberg_ctas;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT data FROM iceberg_write_ctl.iceberg_db.iceberg_ctas WHERE id > 99 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE IF NOT EXISTS iceberg_write_ctl.iceberg_db.iceberg_ctas AS SELECT 1 AS id, 'z' AS data;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_write_ctl.iceberg_db.iceberg_ctas;" | $BENDSQL_CLIENT_CONNECT
+
+## Time travel
+echo "SELECT count(*), sum(is_current::INT) FROM iceberg_snapshots('iceberg_write_ctl', 'iceberg_db', 'iceberg_ctas');" | $BENDSQL_CLIENT_CONNECT
+
+SNAPSHOT_ID=$(echo "SELECT snapshot_id FROM iceberg_snapshots('iceberg_write_ctl', 'iceberg_db', 'iceberg_ctas') WHERE NOT is_current;" | $BENDSQL_CLIENT_CONNECT)
+
+echo "SELECT count(*) FROM iceberg_write_ctl.iceberg_db.iceberg_ctas AT (SNAPSHOT => '${SNAPSHOT_ID}');" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_write_ctl.iceberg_db.iceberg_ctas AT (TIMESTAMP => '2000-01-01 00:00:00'::TIMESTAMP);" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -o "No historical data found at given point"
+
+## Partitioned table
+echo "INSERT INTO iceberg_write_ctl.iceberg_db.iceberg_part_tbl VALUES (1, 'us', '2024-01-01 10:00:00'), (2, 'eu', '2024-01-01 11:00:00'), (3, 'us', '2024-01-02 09:00:00'), (4, 'eu', NULL);" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT INTO iceberg_write_ctl.iceberg_db.iceberg_part_tbl SELECT number + 5, 'ap', '2024-01-03 00:00:00'::TIMESTAMP FROM numbers(2);" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT id, region, ts FROM iceberg_write_ctl.iceberg_db.iceberg_part_tbl ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT region, count(*) FROM iceberg_write_ctl.iceberg_db.iceberg_part_tbl GROUP BY region ORDER BY region;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT id FROM iceberg_write_ctl.iceberg_db.iceberg_part_tbl WHERE ts >= '2024-01-02 00:00:00'::TIMESTAMP ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_snapshots('iceberg_write_ctl', 'iceberg_db', 'iceberg_part_tbl');" | $BENDSQL_CLIENT_CONNECT
+
+echo "DROP CATALOG IF EXISTS iceberg_write_ctl" | $BENDSQL_CLIENT_CONNECT
@@ -23,5 +23,11 @@ query "select p4 from test_delta where c1 > 20 order by p4;"
 
 query "select c5, p4 from test_delta where c1 - p0 = 11 order by c5;"
 
+# files are pruned by partition values and column stats
+for filter in "p0 = 20" "c3 = 33" "c1 > 20" "p2 = 12 and c1 > 20"; do
+  echo ">>>> explain select c1 from test_delta where ${filter};"
+  echo "explain select c1 from test_delta where ${filter};" | $BENDSQL_CLIENT_CONNECT | grep -o "partitions scanned: [0-9]*"
+done
+
 stmt "drop table test_delta;"
 
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/partitioned/)
+
+stmt "drop table if exists test_delta;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+query "select version, timestamp, operation from delta_history('default', 'test_delta');"
+query "select version from delta_history('default', 'test_delta') limit 2;"
+
+query "select c1 from test_delta at (snapshot => '3') order by c1;"
+query "select c1 from test_delta at (timestamp => to_timestamp(1702988830)) order by c1;"
+query "select count(*) from test_delta at (snapshot => '1');"
+query "select count(*) from test_delta;"
+
+stmt "drop table test_delta;"
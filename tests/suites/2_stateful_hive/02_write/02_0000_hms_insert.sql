@@ -0,0 +1,3 @@
+insert into hive.default.t_write values (1, 'a'), (2, 'b');
+insert into hive.default.t_write select 3, 'c';
+select * from hive.default.t_write order by id;
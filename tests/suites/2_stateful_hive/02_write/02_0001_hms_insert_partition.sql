@@ -0,0 +1,8 @@
+-- static partition
+insert into hive.default.t_write_p partition (dt = '2024-01-01', hour = 1) values (1, 'a'), (2, 'b');
+-- dynamic partition, mixed with static partition
+insert into hive.default.t_write_p partition (dt = '2024-01-01') values (3, 'c', 2), (4, 'd', 3);
+insert into hive.default.t_write_p values (5, 'e', '2024-01-02', 1);
+select * from hive.default.t_write_p order by id;
+select dt, hour, count(*) from hive.default.t_write_p group by dt, hour order by dt, hour;
+select id from hive.default.t_write_p where dt = '2024-01-01' and hour > 1 order by id;
@@ -0,0 +1,8 @@
+alter table hive.default.t_write_p drop partition (dt = '2024-01-01', hour = 3);
+alter table hive.default.t_write_p drop if exists partition (dt = '2024-01-01', hour = 3);
+select * from hive.default.t_write_p order by id;
+alter table hive.default.t_write_p add partition (dt = '2024-01-03', hour = 1);
+alter table hive.default.t_write_p add if not exists partition (hour = 1, dt = '2024-01-03');
+select count(*) from hive.default.t_write_p where dt = '2024-01-03';
+insert into hive.default.t_write_p partition (dt = '2024-01-03', hour = 1) values (6, 'f');
+select * from hive.default.t_write_p where dt = '2024-01-03';
@@ -0,0 +1,6 @@
+-- the data files of customer_p3 are copied without registering the partitions
+select count(*) from hive.default.customer_p3;
+msck repair table hive.default.customer_p3;
+select * from hive.default.customer_p3 order by c_nation, foo;
+alter table hive.default.customer_p3 recover partitions;
+select count(*) from hive.default.customer_p3;
@@ -14,10 +14,14 @@
 
 import logging
 import datetime
+import json
 from decimal import Decimal
 import time
 from typing import List, Dict, Any, Tuple, Optional
 
+import pyarrow as pa
+from pyarrow import flight
+
 # https://github.com/datafuselabs/databend-udf
 from databend_udf import udf, UDFServer
 
@@ -297,8 +301,104 @@ def wait_concurrent(x):
     return x
 
 
+class WeightedAvg:
+    input_types = [pa.int32(), pa.int32()]
+    result_type = pa.float64()
+
+    @staticmethod
+    def init():
+        return [0, 0]
+
+    @staticmethod
+    def accumulate(state, value, weight):
+        if value is not None and weight is not None:
+            state[0] += value * weight
+            state[1] += weight
+        return state
+
+    @staticmethod
+    def merge(state, other):
+        return [state[0] + other[0], state[1] + other[1]]
+
+    @staticmethod
+    def finalize(state):
+        return state[0] / state[1] if state[1] else None
+
+
+class AggregateUDFServer(UDFServer):
+    """
+    Serves the aggregate functions besides the scalar functions.
+
+    The states are opaque binaries to databend, every phase of an aggregate function
+    is an exchange with the path `[<name>, <phase>]`.
+    """
+
+    def __init__(self, location):
+        super().__init__(location)
+        self._aggregates = {}
+
+    def add_aggregate(self, name, aggregate):
+        self._aggregates[name] = aggregate
+
+    def get_flight_info(self, context, descriptor):
+        name = descriptor.path[0].decode("utf-8")
+        if name not in self._aggregates:
+            return super().get_flight_info(context, descriptor)
+        aggregate = self._aggregates[name]
+        fields = [pa.field(f"arg{i + 1}", t) for i, t in enumerate(aggregate.input_types)]
+        fields.append(pa.field("output", aggregate.result_type))
+        return flight.FlightInfo(pa.schema(fields), descriptor, [], -1, -1)
+
+    def do_exchange(self, context, descriptor, reader, writer):
+        if len(descriptor.path) != 2:
+            return super().do_exchange(context, descriptor, reader, writer)
+        aggregate = self._aggregates[descriptor.path[0].decode("utf-8")]
+        phase = descriptor.path[1].decode("utf-8")
+        table = reader.read_all()
+        columns = [column.to_pylist() for column in table.columns]
+
+        if phase == "finalize":
+            states = [json.loads(state) for state in columns[0]]
+            output = pa.array(
+                [aggregate.finalize(state) for state in states],
+                type=aggregate.result_type,
+            )
+            name = "result"
+        else:
+            groups = columns[0]
+            num_groups = max(groups) + 1 if groups else 0
+            if phase == "init":
+                states = [aggregate.init() for _ in range(num_groups)]
+            elif phase == "accumulate":
+                states = [aggregate.init() for _ in range(num_groups)]
+                for row, group in enumerate(groups):
+                    args = [column[row] for column in columns[1:]]
+                    states[group] = aggregate.accumulate(states[group], *args)
+            elif phase == "merge":
+                states = [None] * num_groups
+                for group, state in zip(groups, columns[1]):
+                    state = json.loads(state)
+                    if states[group] is None:
+                        states[group] = state
+                    else:
+                        states[group] = aggregate.merge(states[group], state)
+            else:
+                raise ValueError(f"unknown phase of aggregate function: {phase}")
+            output = pa.array(
+                [json.dumps(state).encode("utf-8") for state in states],
+                type=pa.binary(),
+            )
+            name = "state"
+
+        batch = pa.RecordBatch.from_arrays([output], names=[name])
+        writer.begin(batch.schema)
+        writer.write_batch(batch)
+        writer.close()
+
+
 if __name__ == "__main__":
-    udf_server = UDFServer("0.0.0.0:8815")
+    udf_server = AggregateUDFServer("0.0.0.0:8815")
+    udf_server.add_aggregate("weighted_avg", WeightedAvg)
     udf_server.add_function(add_signed)
     udf_server.add_function(add_unsigned)
     udf_server.add_function(add_float)